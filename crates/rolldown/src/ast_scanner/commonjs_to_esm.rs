@@ -0,0 +1,302 @@
+use oxc::ast::ast::{
+  AssignmentOperator, AssignmentTarget, Expression, ObjectPropertyKind, Program, PropertyKind,
+  Statement,
+};
+use oxc::span::GetSpan;
+use oxc_str::CompactStr;
+use rolldown_common::{
+  EcmaModuleAstUsage, EcmaViewMeta, ImportKind, ImportRecordIdx, ImportRecordMeta, LocalExport,
+  StmtEvalFlags, StmtInfoIdx, StmtInfoMeta, TaggedSymbolRef,
+};
+use rolldown_utils::ecmascript::is_validate_identifier_name;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{
+  AstScanner,
+  cjs_export_analyzer::{CommonJsAstType, is_object_define_property_es_module},
+};
+
+/// The CommonJS export object written by an assignment.
+pub enum CommonjsExportTarget<'a> {
+  /// `exports.foo = ...`
+  ExportsProp(&'a str),
+  /// `module.exports = ...`
+  ModuleExports,
+}
+
+/// Matches `exports.foo` and `module.exports` syntactically. Callers must make sure neither
+/// `exports` nor `module` is declared by the module itself.
+pub fn commonjs_export_target<'a>(
+  target: &'a AssignmentTarget,
+) -> Option<CommonjsExportTarget<'a>> {
+  let AssignmentTarget::StaticMemberExpression(member_expr) = target else {
+    return None;
+  };
+  let Expression::Identifier(object) = &member_expr.object else {
+    return None;
+  };
+  match (object.name.as_str(), member_expr.property.name.as_str()) {
+    ("exports", name) => Some(CommonjsExportTarget::ExportsProp(name)),
+    ("module", "exports") => Some(CommonjsExportTarget::ModuleExports),
+    _ => None,
+  }
+}
+
+impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
+  /// Flags a CommonJS module that `optimization.commonjsToEsm` can rewrite to ESM bindings. That
+  /// is a module which
+  /// - writes each export once through a top-level `exports.foo = ...` statement, or declares all
+  ///   of them at once with a top-level `module.exports = { ... }` object literal,
+  /// - doesn't touch `exports` or `module` anywhere else, apart from setting the `__esModule` flag,
+  /// - only calls `require` at the top level unconditionally.
+  ///
+  /// Whether the module is actually converted depends on its importers and is decided in the link
+  /// stage.
+  pub(super) fn detect_commonjs_to_esm(&mut self, program: &Program<'ast>) {
+    if !self.immutable_ctx.options.optimization.is_commonjs_to_esm_enabled()
+      || self.immutable_ctx.options.is_dev_mode_enabled()
+      || !self.result.exports_kind.is_commonjs()
+      || self
+        .result
+        .ast_usage
+        .intersects(EcmaModuleAstUsage::TopLevelReturn | EcmaModuleAstUsage::TopLevelAwait)
+      || self.result.ecma_view_meta.contains(EcmaViewMeta::Eval)
+      || !self.top_level_this_expr_set.is_empty()
+      || self.get_root_binding("exports").is_some()
+      || self.get_root_binding("module").is_some()
+    {
+      return;
+    }
+
+    let mut exports_ref_count = 0;
+    let mut module_ref_count = 0;
+    let mut has_exports_prop_write = false;
+    let mut es_module_flag_stmts = vec![];
+    let mut object_exports = None;
+    let mut unconditional_requires = FxHashSet::default();
+
+    for (idx, stmt) in program.body.iter().enumerate() {
+      let stmt_idx = StmtInfoIdx::from_usize(idx + 1);
+      match stmt {
+        Statement::ExpressionStatement(expr_stmt) => match &expr_stmt.expression {
+          Expression::AssignmentExpression(assign_expr)
+            if assign_expr.operator == AssignmentOperator::Assign =>
+          {
+            match commonjs_export_target(&assign_expr.left) {
+              Some(CommonjsExportTarget::ExportsProp("__esModule")) => {
+                exports_ref_count += 1;
+                if !matches!(&assign_expr.right, Expression::BooleanLiteral(lit) if lit.value) {
+                  return;
+                }
+                es_module_flag_stmts.push(stmt_idx);
+              }
+              Some(CommonjsExportTarget::ExportsProp(name)) => {
+                exports_ref_count += 1;
+                // An export written more than once can't become a single binding.
+                if self.result.commonjs_exports.get(name).is_none_or(|writes| writes.len() != 1) {
+                  return;
+                }
+                has_exports_prop_write = true;
+                self
+                  .collect_unconditional_requires(&assign_expr.right, &mut unconditional_requires);
+              }
+              Some(CommonjsExportTarget::ModuleExports) => {
+                module_ref_count += 1;
+                let Expression::ObjectExpression(obj_expr) = &assign_expr.right else {
+                  return;
+                };
+                if object_exports.is_some() {
+                  return;
+                }
+                let mut exports = FxHashMap::default();
+                for prop in &obj_expr.properties {
+                  let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+                    return;
+                  };
+                  if prop.kind != PropertyKind::Init || prop.computed || prop.method {
+                    return;
+                  }
+                  let Some(name) = prop.key.static_name() else {
+                    return;
+                  };
+                  if matches!(name.as_ref(), "__proto__" | "__esModule")
+                    || !is_validate_identifier_name(&name)
+                  {
+                    return;
+                  }
+                  if exports.insert(CompactStr::new(&name), prop.key.span()).is_some() {
+                    return;
+                  }
+                  self.collect_unconditional_requires(&prop.value, &mut unconditional_requires);
+                }
+                object_exports = Some((stmt_idx, exports));
+              }
+              None => {
+                self
+                  .collect_unconditional_requires(&assign_expr.right, &mut unconditional_requires);
+              }
+            }
+          }
+          Expression::CallExpression(call_expr)
+            if matches!(
+              is_object_define_property_es_module(&self.result.symbol_ref_db.ast_scopes, call_expr),
+              Some(CommonJsAstType::EsModuleFlag)
+            ) =>
+          {
+            exports_ref_count += 1;
+            es_module_flag_stmts.push(stmt_idx);
+          }
+          expr => self.collect_unconditional_requires(expr, &mut unconditional_requires),
+        },
+        Statement::VariableDeclaration(decl) => {
+          for declarator in &decl.declarations {
+            if let Some(init) = &declarator.init {
+              self.collect_unconditional_requires(init, &mut unconditional_requires);
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+
+    // `module.exports = { ... }` replaces the object the other writes went to.
+    if object_exports.is_some() && exports_ref_count > 0 {
+      return;
+    }
+    if !has_exports_prop_write && object_exports.is_none() {
+      return;
+    }
+
+    // Any other use of `exports` or `module`, e.g. reading an export back or passing the object
+    // around, needs the real object.
+    let unresolved_references = self.result.symbol_ref_db.scoping().root_unresolved_references();
+    let reference_count =
+      |name: &str| unresolved_references.get(name).map_or(0, |references| references.len());
+    if reference_count("exports") != exports_ref_count
+      || reference_count("module") != module_ref_count
+    {
+      return;
+    }
+
+    if self.result.import_records.iter_enumerated().any(|(rec_idx, rec)| {
+      rec.kind == ImportKind::Require && !unconditional_requires.contains(&rec_idx)
+    }) {
+      return;
+    }
+
+    // Requires that run before any other side effect of the module can be evaluated like static
+    // imports, since hoisting them along with the module doesn't change what's observable.
+    for (idx, stmt) in program.body.iter().enumerate() {
+      let stmt_idx = StmtInfoIdx::from_usize(idx + 1);
+      if es_module_flag_stmts.contains(&stmt_idx) {
+        continue;
+      }
+      if let Some(requires) = self.require_only_stmt_requires(stmt) {
+        for rec_idx in requires {
+          self.result.import_records[rec_idx].meta.insert(ImportRecordMeta::HoistedRequire);
+        }
+      } else if self.result.stmt_infos[stmt_idx]
+        .eval_flags
+        .contains(StmtEvalFlags::UnknownSideEffect)
+      {
+        break;
+      }
+    }
+
+    for stmt_idx in es_module_flag_stmts {
+      self.result.stmt_infos[stmt_idx].meta.insert(StmtInfoMeta::CommonjsEsModuleFlag);
+    }
+    if let Some((stmt_idx, exports)) = object_exports {
+      let mut object_exports = FxHashMap::default();
+      for (name, span) in exports {
+        let exported_symbol = self.result.symbol_ref_db.create_facade_root_symbol_ref(&name);
+        self
+          .result
+          .stmt_infos
+          .declare_symbol_for_stmt(stmt_idx, TaggedSymbolRef::link_only(exported_symbol));
+        object_exports.insert(
+          name,
+          LocalExport { referenced: exported_symbol, span, came_from_commonjs: true },
+        );
+      }
+      self.result.commonjs_object_exports = Some(Box::new(object_exports));
+    }
+    self.result.ecma_view_meta.insert(EcmaViewMeta::CommonjsToEsm);
+  }
+
+  /// Returns the `require` calls of a statement whose only side effect is evaluating them, e.g.
+  /// `const { a } = require('a')`, `require('a')` or `exports.a = require('a').a`.
+  fn require_only_stmt_requires(&self, stmt: &Statement<'ast>) -> Option<Vec<ImportRecordIdx>> {
+    match stmt {
+      Statement::ExpressionStatement(expr_stmt) => {
+        let expr = match &expr_stmt.expression {
+          Expression::AssignmentExpression(assign_expr)
+            if assign_expr.operator == AssignmentOperator::Assign
+              && matches!(
+                commonjs_export_target(&assign_expr.left),
+                Some(CommonjsExportTarget::ExportsProp(_))
+              ) =>
+          {
+            &assign_expr.right
+          }
+          expr => expr,
+        };
+        self.require_chain(expr).map(|rec_idx| vec![rec_idx])
+      }
+      Statement::VariableDeclaration(decl) => {
+        let mut requires = vec![];
+        for declarator in &decl.declarations {
+          if let Some(init) = &declarator.init {
+            requires.push(self.require_chain(init)?);
+          }
+        }
+        (!requires.is_empty()).then_some(requires)
+      }
+      _ => None,
+    }
+  }
+
+  /// Matches `require('a')` optionally followed by property reads, e.g. `require('a').b.c`.
+  fn require_chain(&self, expr: &Expression<'ast>) -> Option<ImportRecordIdx> {
+    match expr {
+      Expression::CallExpression(call_expr) => {
+        let rec_idx = *self.result.imports.get(&call_expr.node_id())?;
+        (self.result.import_records[rec_idx].kind == ImportKind::Require).then_some(rec_idx)
+      }
+      Expression::StaticMemberExpression(member_expr) => self.require_chain(&member_expr.object),
+      Expression::ParenthesizedExpression(paren_expr) => self.require_chain(&paren_expr.expression),
+      _ => None,
+    }
+  }
+
+  /// Collects the `require` calls evaluated whenever `expr` is, looking through member accesses
+  /// and calls on their result, e.g. `require('a').b` or `require('a')()`.
+  fn collect_unconditional_requires(
+    &self,
+    expr: &Expression<'ast>,
+    requires: &mut FxHashSet<ImportRecordIdx>,
+  ) {
+    match expr {
+      Expression::CallExpression(call_expr) => {
+        if let Some(rec_idx) = self.result.imports.get(&call_expr.node_id()) {
+          requires.insert(*rec_idx);
+        } else {
+          self.collect_unconditional_requires(&call_expr.callee, requires);
+        }
+      }
+      Expression::StaticMemberExpression(member_expr) => {
+        self.collect_unconditional_requires(&member_expr.object, requires);
+      }
+      Expression::ComputedMemberExpression(member_expr) => {
+        self.collect_unconditional_requires(&member_expr.object, requires);
+      }
+      Expression::ParenthesizedExpression(paren_expr) => {
+        self.collect_unconditional_requires(&paren_expr.expression, requires);
+      }
+      Expression::AssignmentExpression(assign_expr) => {
+        self.collect_unconditional_requires(&assign_expr.right, requires);
+      }
+      _ => {}
+    }
+  }
+}
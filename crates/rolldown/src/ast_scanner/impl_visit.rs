@@ -182,6 +182,11 @@ impl<'me, 'ast: 'me> VisitJs<'ast> for AstScanner<'me, 'ast> {
   }
 
   fn visit_identifier_reference(&mut self, ident: &IdentifierReference) {
+    if self.immutable_ctx.glob_callee_spans.contains(&ident.span) {
+      self.current_stmt_info.meta.insert(StmtInfoMeta::GlobImport);
+      self.result.glob_callee_set.insert(ident.node_id());
+      return;
+    }
     self.process_identifier_ref_by_scope(ident);
     self.try_diagnostic_forbid_const_assign(ident);
     self.update_dynamic_import_binding_usage_info(ident);
@@ -286,6 +291,9 @@ impl<'me, 'ast: 'me> VisitJs<'ast> for AstScanner<'me, 'ast> {
   }
 
   fn visit_new_expression(&mut self, it: &ast::NewExpression<'ast>) {
+    if self.immutable_ctx.flat_options.worker_enabled() {
+      self.handle_new_worker_with_new_url(it);
+    }
     if self.immutable_ctx.flat_options.resolve_new_url_to_asset_enabled() {
       self.handle_new_url_with_string_literal_and_import_meta_url(it);
     }
@@ -412,6 +420,10 @@ impl<'me, 'ast: 'me> VisitJs<'ast> for AstScanner<'me, 'ast> {
 
   fn visit_call_expression(&mut self, it: &ast::CallExpression<'ast>) {
     self.try_extract_hmr_info_from_hot_accept_call(it);
+    if self.immutable_ctx.flat_options.worker_enabled() {
+      self.handle_service_worker_register_with_new_url(it);
+      self.handle_worklet_add_module_with_new_url(it);
+    }
     walk_js::walk_call_expression(self, it);
   }
 
@@ -444,7 +456,9 @@ impl<'me, 'ast: 'me> VisitJs<'ast> for AstScanner<'me, 'ast> {
 
 impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
   fn handle_top_level_await(&mut self, span: Span) {
-    if !self.immutable_ctx.flat_options.keep_esm_import_export_syntax() {
+    if !self.immutable_ctx.options.format.supports_top_level_await()
+      && !self.immutable_ctx.options.experimental.is_async_module_wrappers_enabled()
+    {
       self.result.errors.push(BuildDiagnostic::unsupported_feature(
         self.immutable_ctx.id.as_arc_str().clone(),
         self.immutable_ctx.source.clone(),
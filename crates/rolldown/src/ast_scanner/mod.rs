@@ -1,10 +1,12 @@
 mod cjs_export_analyzer;
+pub mod commonjs_to_esm;
 pub mod const_eval;
 pub mod dynamic_import;
 mod hmr;
 pub mod impl_visit;
 mod import_analyzer;
 mod new_url;
+mod new_worker;
 pub mod stmt_eval_analyzer;
 mod top_level_import_read;
 
@@ -77,6 +79,9 @@ pub struct ScanResult {
   /// may not know if it is a esm at the time, a simple case would be swap the order of two export
   /// stmt).
   pub commonjs_exports: FxHashMap<CompactStr, Vec<LocalExport>>,
+  /// Facade exports for the keys of a `module.exports = { ... }` object literal, only collected
+  /// when the module is a candidate of `optimization.commonjsToEsm`.
+  pub commonjs_object_exports: Option<Box<FxHashMap<CompactStr, LocalExport>>>,
   pub stmt_infos: StmtInfos,
   pub import_records: IndexVec<ImportRecordIdx, RawImportRecord>,
   pub default_export_ref: SymbolRef,
@@ -84,6 +89,7 @@ pub struct ScanResult {
   pub namespace_object_ref: SymbolRef,
   pub imports: FxHashMap<NodeId, ImportRecordIdx>,
   pub dummy_record_set: FxHashSet<NodeId>,
+  pub glob_callee_set: FxHashSet<NodeId>,
   pub exports_kind: ExportsKind,
   pub warnings: Vec<BuildDiagnostic>,
   pub errors: Vec<BuildDiagnostic>,
@@ -137,6 +143,8 @@ pub struct AstScannerImmutableCtx<'me, 'ast> {
   options: &'me SharedOptions,
   flat_options: FlatOptions,
   allocator: &'ast oxc::allocator::Allocator,
+  /// See [crate::utils::parse_to_ecma_ast::ParseToEcmaAstResult::glob_callee_spans].
+  glob_callee_spans: &'me FxHashSet<Span>,
 }
 
 pub struct AstScanner<'me, 'ast> {
@@ -188,6 +196,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     options: &'me SharedOptions,
     allocator: &'ast oxc::allocator::Allocator,
     flat_options: FlatOptions,
+    glob_callee_spans: &'me FxHashSet<Span>,
   ) -> Self {
     let root_scope_id = scoping.root_scope_id();
     let mut symbol_ref_db = SymbolRefDbForModule::new(scoping, idx, root_scope_id);
@@ -228,7 +237,9 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       hmr_hot_ref,
       directive_range: vec![],
       dummy_record_set: FxHashSet::default(),
+      glob_callee_set: FxHashSet::default(),
       commonjs_exports: FxHashMap::default(),
+      commonjs_object_exports: None,
       constant_export_map: FxHashMap::default(),
       ecma_view_meta: EcmaViewMeta::default(),
       import_attribute_map: FxHashMap::default(),
@@ -246,6 +257,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         comments,
         options,
         flat_options,
+        glob_callee_spans,
       },
       current_stmt_info: StmtInfo::default(),
       current_stmt_idx: StmtInfos::NAMESPACE_STMT_IDX,
@@ -363,6 +375,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     }
 
     self.result.exports_kind = exports_kind;
+    self.detect_commonjs_to_esm(program);
 
     // Resolve CJS re-export require node IDs to import record indices.
     self.result.cjs_reexport_import_record_ids = self
@@ -900,16 +913,22 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
   }
 
   fn scan_import_decl(&mut self, decl: &ImportDeclaration) {
+    let mut init_meta = if decl.source.span().is_empty() {
+      ImportRecordMeta::IsUnspannedImport
+    } else {
+      ImportRecordMeta::empty()
+    };
+    match decl.phase {
+      Some(ast::ImportPhase::Defer) => init_meta |= ImportRecordMeta::IsDeferred,
+      Some(ast::ImportPhase::Source) => init_meta |= ImportRecordMeta::IsSourcePhase,
+      None => {}
+    }
     let rec_id = self.add_import_record(
       decl.source.value.as_str(),
       ImportKind::Import,
       decl.source.span(),
       decl.span,
-      if decl.source.span().is_empty() {
-        ImportRecordMeta::IsUnspannedImport
-      } else {
-        ImportRecordMeta::empty()
-      },
+      init_meta,
       None,
     );
 
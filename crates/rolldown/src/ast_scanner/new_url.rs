@@ -1,6 +1,9 @@
-use oxc::ast::{
-  Comment,
-  ast::{Expression, NewExpression},
+use oxc::{
+  ast::{
+    Comment,
+    ast::{Expression, NewExpression},
+  },
+  span::Span,
 };
 use rolldown_common::{ImportKind, ImportRecordMeta, ModuleType, get_leading_comment};
 use rolldown_ecmascript_utils::ExpressionExt;
@@ -14,10 +17,37 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     &mut self,
     expr: &NewExpression<'ast>,
   ) {
+    // Already recorded as the url of a worker constructor.
+    if self.result.new_url_references.contains_key(&expr.node_id()) {
+      return;
+    }
+
+    let Some((path, first_arg_span)) = self.extract_new_url_with_import_meta_url(expr) else {
+      return;
+    };
+
+    let idx = self.add_import_record(
+      path,
+      ImportKind::NewUrl,
+      first_arg_span,
+      expr.span,
+      ImportRecordMeta::empty(),
+      None,
+    );
+    self.result.import_records[idx].asserted_module_type = Some(ModuleType::Asset);
+    self.result.new_url_references.insert(expr.node_id(), idx);
+  }
+
+  /// Returns the path and its span of `new URL('path', import.meta.url)`, unless the path
+  /// is a data url or marked with `/* @vite-ignore */`.
+  pub(super) fn extract_new_url_with_import_meta_url(
+    &self,
+    expr: &NewExpression<'ast>,
+  ) -> Option<(&'ast str, Span)> {
     let is_callee_global_url = matches!(expr.callee.as_identifier(), Some(ident) if ident.name == "URL" && self.is_global_identifier_reference(ident));
 
     if !is_callee_global_url {
-      return;
+      return None;
     }
 
     let is_second_arg_import_meta_url = expr
@@ -26,22 +56,17 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       .is_some_and(|arg| arg.as_expression().is_some_and(ExpressionExt::is_import_meta_url));
 
     if !is_second_arg_import_meta_url {
-      return;
+      return None;
     }
 
-    let Some(first_arg) = expr.arguments.first().and_then(|arg| arg.as_expression()) else {
-      return;
-    };
+    let first_arg = expr.arguments.first().and_then(|arg| arg.as_expression())?;
 
     let (path, first_arg_span) = match first_arg {
-      Expression::StringLiteral(lit) => (&lit.value, lit.span),
+      Expression::StringLiteral(lit) => (lit.value.as_str(), lit.span),
       Expression::TemplateLiteral(tpl) if tpl.is_no_substitution_template() => {
-        let Some(value) = &tpl.quasis[0].value.cooked else {
-          return;
-        };
-        (value, tpl.span)
+        (tpl.quasis[0].value.cooked.as_ref()?.as_str(), tpl.span)
       }
-      _ => return,
+      _ => return None,
     };
 
     let has_leading_ignore_comment = get_leading_comment(
@@ -51,22 +76,13 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     )
     .is_some();
     if has_leading_ignore_comment {
-      return;
+      return None;
     }
 
     if is_data_url(path) {
-      return;
+      return None;
     }
 
-    let idx = self.add_import_record(
-      path,
-      ImportKind::NewUrl,
-      first_arg_span,
-      expr.span,
-      ImportRecordMeta::empty(),
-      None,
-    );
-    self.result.import_records[idx].asserted_module_type = Some(ModuleType::Asset);
-    self.result.new_url_references.insert(expr.node_id(), idx);
+    Some((path, first_arg_span))
   }
 }
@@ -0,0 +1,83 @@
+use oxc::ast::ast::{Argument, CallExpression, Expression, NewExpression};
+use rolldown_common::{ImportKind, ImportRecordMeta};
+use rolldown_ecmascript_utils::ExpressionExt;
+
+use super::AstScanner;
+
+impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
+  /// Handle `new Worker(new URL('path', import.meta.url))` and `new SharedWorker(...)`
+  pub fn handle_new_worker_with_new_url(&mut self, expr: &NewExpression<'ast>) {
+    let is_callee_global_worker = matches!(expr.callee.as_identifier(), Some(ident) if matches!(ident.name.as_str(), "Worker" | "SharedWorker") && self.is_global_identifier_reference(ident));
+
+    if !is_callee_global_worker {
+      return;
+    }
+
+    self.add_worker_import_record(expr.arguments.first());
+  }
+
+  /// Handle `navigator.serviceWorker.register(new URL('path', import.meta.url))`
+  pub fn handle_service_worker_register_with_new_url(&mut self, expr: &CallExpression<'ast>) {
+    let Some(callee) = expr.callee.as_member_expression() else {
+      return;
+    };
+    if callee.static_property_name() != Some("register") {
+      return;
+    }
+    let Some(service_worker) = callee.object().as_member_expression() else {
+      return;
+    };
+    if service_worker.static_property_name() != Some("serviceWorker") {
+      return;
+    }
+    let is_global_navigator = matches!(service_worker.object(), Expression::Identifier(ident) if ident.name == "navigator" && self.is_global_identifier_reference(ident));
+    if !is_global_navigator {
+      return;
+    }
+
+    self.add_worker_import_record(expr.arguments.first());
+  }
+
+  /// Handle `CSS.paintWorklet.addModule(new URL('path', import.meta.url))` and the other worklets,
+  /// e.g. `audioContext.audioWorklet.addModule(...)`
+  pub fn handle_worklet_add_module_with_new_url(&mut self, expr: &CallExpression<'ast>) {
+    let Some(callee) = expr.callee.as_member_expression() else {
+      return;
+    };
+    if callee.static_property_name() != Some("addModule") {
+      return;
+    }
+    let is_worklet = callee.object().as_member_expression().is_some_and(|worklet| {
+      matches!(
+        worklet.static_property_name(),
+        Some("paintWorklet" | "audioWorklet" | "layoutWorklet" | "animationWorklet")
+      )
+    });
+    if !is_worklet {
+      return;
+    }
+
+    self.add_worker_import_record(expr.arguments.first());
+  }
+
+  fn add_worker_import_record(&mut self, url_arg: Option<&Argument<'ast>>) {
+    let Some(Expression::NewExpression(new_url_expr)) = url_arg.and_then(|arg| arg.as_expression())
+    else {
+      return;
+    };
+    let Some((path, first_arg_span)) = self.extract_new_url_with_import_meta_url(new_url_expr)
+    else {
+      return;
+    };
+
+    let idx = self.add_import_record(
+      path,
+      ImportKind::NewUrl,
+      first_arg_span,
+      new_url_expr.span,
+      ImportRecordMeta::IsWorker,
+      None,
+    );
+    self.result.new_url_references.insert(new_url_expr.node_id(), idx);
+  }
+}
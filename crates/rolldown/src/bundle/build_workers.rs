@@ -0,0 +1,144 @@
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use arcstr::ArcStr;
+use rolldown_common::{
+  BundlerOptions, CodeSplittingMode, EmittedAsset, InputItem, ModuleId, Output, OutputFormat,
+  StrOrBytes, WorkerOptions,
+};
+use rolldown_error::{BuildDiagnostic, BuildResult};
+use rolldown_fs::FileSystem;
+use rolldown_plugin::__inner::SharedPluginable;
+use rolldown_utils::{dashmap::FxDashSet, indexmap::FxIndexSet};
+
+use super::{
+  bundle::Bundle,
+  bundle_factory::{BundleFactory, BundleFactoryOptions},
+};
+use crate::{stages::scan_stage::NormalizedScanStageOutput, types::bundle_output::BundleOutput};
+
+/// What a bundle needs to bundle the workers it references, see `BundlerOptions::worker`.
+#[derive(Clone)]
+pub struct WorkerBuildContext {
+  /// The raw options the bundle was created with.
+  pub bundler_options: BundlerOptions,
+  /// The user plugins, before the builtin ones are applied.
+  pub plugins: Vec<SharedPluginable>,
+  /// Workers currently being bundled, from the outermost one. Used to stop circular workers.
+  pub ancestors: Vec<ModuleId>,
+}
+
+/// The result of a nested worker build.
+struct WorkerBuildOutput {
+  /// Files the worker build watched. Kept even when the build failed, so fixing them rebuilds.
+  watch_files: Arc<FxDashSet<ArcStr>>,
+  output: BuildResult<BundleOutput>,
+}
+
+impl<Fs: FileSystem + Clone + 'static> Bundle<Fs> {
+  /// Bundles every worker entry referenced by the scanned modules with its own chunk graph and
+  /// emits the outputs as assets. The entry chunk is associated with the worker id so that the
+  /// finalizer can rewrite `new URL()` to point at it.
+  pub(super) async fn build_workers(
+    &mut self,
+    scan_stage_output: &NormalizedScanStageOutput,
+  ) -> BuildResult<()> {
+    let Some(worker_ctx) = self.worker_build_context.clone() else {
+      return Ok(());
+    };
+
+    let worker_ids = scan_stage_output
+      .module_table
+      .modules
+      .iter()
+      .filter_map(|module| module.as_normal())
+      .flat_map(|module| module.ecma_view.worker_references.values())
+      .cloned()
+      .collect::<FxIndexSet<_>>();
+
+    for worker_id in worker_ids {
+      if worker_ctx.ancestors.contains(&worker_id) {
+        let mut paths =
+          worker_ctx.ancestors.iter().map(ToString::to_string).collect::<Vec<String>>();
+        paths.push(worker_id.to_string());
+        self.warnings.push(BuildDiagnostic::circular_dependency(paths).with_severity_warning());
+        continue;
+      }
+
+      let WorkerBuildOutput { watch_files, output } =
+        self.build_worker(&worker_ctx, &worker_id).await;
+      // Files the worker depends on must trigger a rebuild of this bundle.
+      for file in watch_files.iter() {
+        self.plugin_driver.watch_files.insert(file.clone());
+      }
+      let output = output?;
+      self.warnings.extend(output.warnings);
+
+      for asset in output.assets {
+        let (source, is_worker_entry) = match &asset {
+          Output::Chunk(chunk) => (
+            StrOrBytes::from(chunk.code.clone()),
+            chunk.is_entry && chunk.facade_module_id.as_ref() == Some(&worker_id),
+          ),
+          Output::Asset(asset) => (asset.source.clone(), false),
+        };
+        let reference_id = self.file_emitter.emit_file(
+          EmittedAsset {
+            name: None,
+            original_file_name: None,
+            file_name: Some(ArcStr::from(asset.filename())),
+            source,
+          },
+          None,
+          None,
+        )?;
+        if is_worker_entry {
+          self.file_emitter.associate_module_with_file_ref(&worker_id, &reference_id);
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Builds the worker on this bundle's file system and resolver.
+  fn build_worker(
+    &self,
+    worker_ctx: &WorkerBuildContext,
+    worker_id: &ModuleId,
+  ) -> Pin<Box<dyn Future<Output = WorkerBuildOutput> + Send>> {
+    let mut bundler_options = worker_ctx.bundler_options.clone();
+    let format = bundler_options.worker.as_ref().map_or(OutputFormat::Iife, WorkerOptions::format);
+    bundler_options.input = Some(vec![InputItem { name: None, import: worker_id.to_string() }]);
+    bundler_options.format = Some(format);
+    bundler_options.file = None;
+    bundler_options.entry_filenames.clone_from(&bundler_options.chunk_filenames);
+    if matches!(format, OutputFormat::Iife | OutputFormat::Umd) {
+      // These formats can only produce a single chunk.
+      bundler_options.code_splitting = Some(CodeSplittingMode::Bool(false));
+      bundler_options.preserve_modules = Some(false);
+    }
+
+    let mut ancestors = worker_ctx.ancestors.clone();
+    ancestors.push(worker_id.clone());
+    let plugins = worker_ctx.plugins.clone();
+    let fs = self.fs.clone();
+    let resolver = Arc::clone(&self.resolver);
+
+    Box::pin(async move {
+      let mut factory = match BundleFactory::new(BundleFactoryOptions {
+        bundler_options: bundler_options.clone(),
+        plugins: plugins.clone(),
+        session: None,
+        disable_tracing_setup: true,
+      }) {
+        Ok(factory) => factory,
+        Err(errs) => return WorkerBuildOutput { watch_files: Arc::default(), output: Err(errs) },
+      };
+      factory.worker_build_context =
+        Some(Arc::new(WorkerBuildContext { bundler_options, plugins, ancestors }));
+      let bundle = factory.create_bundle_with_fs(fs, resolver);
+      let watch_files = Arc::clone(bundle.get_watch_files());
+      WorkerBuildOutput { watch_files, output: bundle.generate().await }
+    })
+  }
+}
@@ -1,4 +1,4 @@
-use crate::bundle::bundle_handle::BundleHandle;
+use crate::bundle::{build_workers::WorkerBuildContext, bundle_handle::BundleHandle};
 
 use super::super::{
   SharedOptions, SharedResolver,
@@ -9,13 +9,16 @@ use super::super::{
     scan_stage::{NormalizedScanStageOutput, ScanStage, ScanStageOutput},
   },
   types::{bundle_output::BundleOutput, scan_stage_cache::ScanStageCache},
-  utils::fs_utils::clean_dir,
+  utils::{fs_utils::clean_dir, integrity::compute_integrity, metafile::collect_metafile_outputs},
 };
 use anyhow::Context;
 use arcstr::ArcStr;
-use rolldown_common::{GetLocalDbMut, Module, ScanMode, SharedFileEmitter, SymbolRefDb};
+use rolldown_common::{
+  ChunkRecords, GetLocalDbMut, InclusionReason, InclusionReasonKind, InclusionSite, Module,
+  ScanMode, SharedFileEmitter, SideEffectKind, SymbolRefDb, TreeShakingProvenance,
+};
 use rolldown_devtools::{action, trace_action, trace_action_enabled};
-use rolldown_error::{BuildDiagnostic, BuildResult, Severity};
+use rolldown_error::{BuildDiagnostic, BuildResult, EventKindSwitcher, Severity};
 use rolldown_fs::{FileSystem, OsFileSystem};
 use rolldown_plugin::{
   HookBuildEndArgs, HookCloseBundleArgs, HookRenderErrorArgs, SharedPluginDriver,
@@ -37,6 +40,7 @@ pub struct Bundle<Fs: FileSystem + Clone + 'static = OsFileSystem> {
   pub(crate) warnings: Vec<BuildDiagnostic>,
   pub(crate) cache: ScanStageCache,
   pub(crate) bundle_span: tracing::Span,
+  pub(crate) worker_build_context: Option<Arc<WorkerBuildContext>>,
 }
 
 impl<Fs: FileSystem + Clone + 'static> Bundle<Fs> {
@@ -276,17 +280,27 @@ impl<Fs: FileSystem + Clone + 'static> Bundle<Fs> {
     scan_stage_output: NormalizedScanStageOutput,
     is_write: bool,
   ) -> BuildResult<BundleOutput> {
+    // Worker outputs must be emitted before chunks are rendered, so their urls can be rewritten.
+    self.build_workers(&scan_stage_output).await?;
+
     // The one stretch of a build with no plugin in it, which is what makes it a usable
     // baseline for "was this build plugin-bound?" — see `BuildTimings`.
     let link_start = self.plugin_driver.build_timings.start();
     let (mut link_stage_output, ast_table, used_symbol_refs) =
       LinkStage::new(scan_stage_output, &self.options).link();
     self.plugin_driver.build_timings.record_link_stage(link_start);
-
-    let bundle_output =
-      GenerateStage::new(&mut link_stage_output, ast_table, &self.options, &self.plugin_driver)
-        .generate(used_symbol_refs)
-        .await; // Notice we don't use `?` to break the control flow here.
+    Self::trace_action_tree_shaking_provenance(link_stage_output.tree_shaking_provenance.as_ref());
+
+    let chunk_records = self.read_chunk_records();
+    let bundle_output = GenerateStage::new(
+      &mut link_stage_output,
+      ast_table,
+      &self.options,
+      &self.plugin_driver,
+      chunk_records,
+    )
+    .generate(used_symbol_refs)
+    .await; // Notice we don't use `?` to break the control flow here.
 
     // `create_output`/`make_copy` strip symbol-table scoping from the cache for
     // performance; reinstate it here, before the fallible steps below, so the
@@ -313,11 +327,23 @@ impl<Fs: FileSystem + Clone + 'static> Bundle<Fs> {
     // Add additional files from build plugins.
     self.file_emitter.add_additional_files(&mut output.assets, &mut output.warnings);
 
+    if let Some(algorithm) = self.options.integrity {
+      compute_integrity(&mut output.assets, algorithm);
+    }
+
     self
       .plugin_driver
       .generate_bundle(&mut output.assets, is_write, &self.options, &mut output.warnings)
       .await?;
 
+    if let Some(algorithm) = self.options.integrity {
+      compute_integrity(&mut output.assets, algorithm);
+    }
+
+    if let Some(metafile) = &mut output.metafile {
+      metafile.outputs = collect_metafile_outputs(&output.assets, &self.options);
+    }
+
     for asset in &output.assets {
       if is_filename_outside_output_dir(asset.filename()) {
         return Err(
@@ -327,6 +353,10 @@ impl<Fs: FileSystem + Clone + 'static> Bundle<Fs> {
       }
     }
 
+    if let Some(chunk_records) = &output.chunk_records {
+      self.write_chunk_records(chunk_records);
+    }
+
     if let Some(invalidate_js_side_cache) = &self.options.invalidate_js_side_cache {
       invalidate_js_side_cache.call().await?;
     }
@@ -334,6 +364,37 @@ impl<Fs: FileSystem + Clone + 'static> Bundle<Fs> {
     Ok(output)
   }
 
+  /// A missing, unreadable or malformed records file is treated as the first build.
+  fn read_chunk_records(&self) -> Option<ChunkRecords> {
+    let records_path = self.options.experimental.records_path.as_ref()?;
+    let raw = self.fs.read_to_string(&self.options.cwd.join(records_path)).ok()?;
+    ChunkRecords::from_json_str(&raw)
+  }
+
+  /// Writing the records is best-effort, failing to do so never fails the build.
+  /// Failing to write the records doesn't fail the build, the next one starts without them.
+  fn write_chunk_records(&mut self, chunk_records: &ChunkRecords) {
+    let Some(records_path) = &self.options.experimental.records_path else {
+      return;
+    };
+    let records_path = self.options.cwd.join(records_path);
+    let result = records_path
+      .parent()
+      .map_or(Ok(()), |dir| self.fs.create_dir_all(dir))
+      .and_then(|()| self.fs.write(&records_path, chunk_records.to_json_string().as_bytes()));
+    if let Err(err) = result
+      && self.options.checks.contains(EventKindSwitcher::CouldNotWriteRecords)
+    {
+      self.warnings.push(
+        BuildDiagnostic::could_not_write_records(
+          records_path.display().to_string(),
+          err.to_string(),
+        )
+        .with_severity_warning(),
+      );
+    }
+  }
+
   fn merge_immutable_fields_for_cache(&mut self, symbol_db: SymbolRefDb) {
     if !self.options.experimental.is_incremental_build_enabled() {
       return;
@@ -385,6 +446,57 @@ impl<Fs: FileSystem + Clone + 'static> Bundle<Fs> {
     }
   }
 
+  fn trace_action_tree_shaking_provenance(provenance: Option<&TreeShakingProvenance>) {
+    if trace_action_enabled!() {
+      let Some(provenance) = provenance else { return };
+      let to_action_reason = |reason: &InclusionReason| {
+        let side_effect = match &reason.kind {
+          InclusionReasonKind::SideEffect(side_effect) => Some(side_effect),
+          _ => None,
+        };
+        let (by_module_id, by_stmt_index) = match &reason.by {
+          Some(InclusionSite::Module(module_id)) => (Some(module_id.clone()), None),
+          Some(InclusionSite::Statement(module_id, index)) => {
+            (Some(module_id.clone()), u32::try_from(*index).ok())
+          }
+          None => (None, None),
+        };
+        action::InclusionReason {
+          kind: reason.kind.as_str(),
+          symbol: reason.symbol.clone(),
+          side_effect: side_effect.map(SideEffectKind::as_str),
+          global_name: match side_effect {
+            Some(SideEffectKind::GlobalAccess(name)) => Some(name.clone()),
+            _ => None,
+          },
+          by_module_id,
+          by_stmt_index,
+        }
+      };
+      let modules = provenance
+        .modules
+        .iter()
+        .map(|(module_id, module)| action::IncludedModule {
+          module_id: module_id.clone(),
+          reason: to_action_reason(&module.reason),
+          statements: module
+            .statements
+            .iter()
+            .map(|stmt| action::IncludedStatement {
+              stmt_index: u32::try_from(stmt.index).unwrap_or(u32::MAX),
+              code: stmt.code.clone(),
+              reason: to_action_reason(&stmt.reason),
+            })
+            .collect(),
+        })
+        .collect();
+      trace_action!(action::TreeShakingProvenanceReady {
+        action: "TreeShakingProvenanceReady",
+        modules
+      });
+    }
+  }
+
   fn trace_action_session_meta(&self) {
     if trace_action_enabled!() {
       trace_action!(action::SessionMeta {
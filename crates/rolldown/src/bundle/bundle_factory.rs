@@ -15,6 +15,7 @@ use rustc_hash::FxHashMap;
 
 use crate::{
   Bundle, BundleHandle,
+  bundle::build_workers::WorkerBuildContext,
   types::scan_stage_cache::ScanStageCache,
   utils::{
     apply_inner_plugins::apply_inner_plugins,
@@ -56,6 +57,9 @@ pub struct BundleFactory {
 
   /// Context for lazy compilation, if enabled
   pub lazy_compilation_context: Option<LazyCompilationContext>,
+
+  /// Context for bundling referenced workers, if `worker` is enabled
+  pub(crate) worker_build_context: Option<Arc<WorkerBuildContext>>,
 }
 
 impl BundleFactory {
@@ -65,12 +69,21 @@ impl BundleFactory {
     let maybe_guard =
       if opts.disable_tracing_setup { None } else { rolldown_tracing::try_init_tracing() };
 
+    // Nested worker builds start from the same raw options and user plugins.
+    let worker_build_context = opts.bundler_options.worker.is_some().then(|| {
+      Arc::new(WorkerBuildContext {
+        bundler_options: opts.bundler_options.clone(),
+        plugins: opts.plugins.clone(),
+        ancestors: vec![],
+      })
+    });
+
     let PrepareBuildContext { fs, resolver, options, mut warnings } =
       prepare_build_context(opts.bundler_options)?;
 
     Self::check_prefer_builtin_feature(opts.plugins.as_slice(), &options, &mut warnings);
 
-    let inner_plugins_result = apply_inner_plugins(&options, &mut opts.plugins);
+    let inner_plugins_result = apply_inner_plugins(&options, &fs, &mut opts.plugins);
 
     let file_emitter = Arc::new(FileEmitter::new(Arc::clone(&options)));
 
@@ -90,6 +103,7 @@ impl BundleFactory {
       module_infos_for_incremental_build: Arc::default(),
       transform_dependencies_for_incremental_build: Arc::default(),
       lazy_compilation_context: inner_plugins_result.lazy_compilation_context,
+      worker_build_context,
     })
   }
 
@@ -188,6 +202,7 @@ impl BundleFactory {
       warnings: std::mem::take(&mut self.warnings),
       bundle_span,
       cache,
+      worker_build_context: self.worker_build_context.clone(),
     };
     self.last_bundle_handle = Some(bundle.context());
     bundle
@@ -0,0 +1,164 @@
+use std::path::Path;
+
+use rolldown_common::{
+  CssImportConditions, CssView, ImportKind, InstantiatedChunk, InstantiationKind, Module,
+  ModuleIdx, ResolvedImportRecord,
+};
+use rolldown_error::BuildResult;
+use rolldown_std_utils::relative_path_to_slash;
+use rolldown_utils::indexmap::FxIndexSet;
+use rustc_hash::FxHashSet;
+use string_wizard::MagicString;
+
+use crate::types::generator::{GenerateContext, GenerateOutput, Generator};
+
+/// Concatenates the CSS modules of a chunk into a single stylesheet.
+pub struct CssGenerator;
+
+impl Generator for CssGenerator {
+  async fn instantiate_chunk(
+    ctx: &mut GenerateContext<'_>,
+  ) -> anyhow::Result<BuildResult<GenerateOutput>> {
+    let ctx: &GenerateContext = ctx;
+    let (Some(preliminary_filename), Some(absolute_filename)) =
+      (&ctx.chunk.css_preliminary_filename, &ctx.chunk.absolute_css_preliminary_filename)
+    else {
+      return Ok(Ok(GenerateOutput::default()));
+    };
+
+    let mut renderer = CssChunkRenderer {
+      ctx,
+      css_dir: Path::new(absolute_filename)
+        .parent()
+        .expect("absolute_css_preliminary_filename should have a parent directory"),
+      visited: FxHashSet::default(),
+      hoisted_at_imports: FxIndexSet::default(),
+      body: String::new(),
+    };
+    // `chunk.modules` is sorted by execution order, which is also the order the stylesheets apply in.
+    for &module_idx in &ctx.chunk.modules {
+      renderer.render_module(module_idx, &[]);
+    }
+
+    let CssChunkRenderer { hoisted_at_imports, body, .. } = renderer;
+    let mut content = String::with_capacity(body.len());
+    for rule in hoisted_at_imports {
+      content.push_str(&rule);
+      content.push('\n');
+    }
+    content.push_str(&body);
+
+    Ok(Ok(GenerateOutput {
+      chunks: vec![InstantiatedChunk {
+        originate_from: ctx.chunk_idx,
+        content: content.into(),
+        map: None,
+        index_map: None,
+        kind: InstantiationKind::None,
+        augment_chunk_hash: None,
+        preliminary_filename: preliminary_filename.clone(),
+        preliminary_sourcemap_filename: None,
+        post_banner: None,
+        post_footer: None,
+      }],
+      warnings: vec![],
+    }))
+  }
+}
+
+struct CssChunkRenderer<'a, 'ctx> {
+  ctx: &'a GenerateContext<'ctx>,
+  css_dir: &'a Path,
+  /// A stylesheet imported under different conditions needs to be rendered once per conditions.
+  visited: FxHashSet<(ModuleIdx, Vec<CssImportConditions>)>,
+  hoisted_at_imports: FxIndexSet<String>,
+  body: String,
+}
+
+impl CssChunkRenderer<'_, '_> {
+  fn render_module(&mut self, module_idx: ModuleIdx, conditions: &[CssImportConditions]) {
+    let module_table = &self.ctx.link_output.module_table;
+    let Some(css_view) = module_table[module_idx].as_normal().and_then(|m| m.css_view.as_ref())
+    else {
+      return;
+    };
+    if !self.visited.insert((module_idx, conditions.to_vec())) {
+      return;
+    }
+
+    for span in &css_view.external_at_imports {
+      self.hoisted_at_imports.insert(span.source_text(&css_view.source).to_string());
+    }
+
+    // `@import`ed stylesheets are placed before the importer, as if the rule were replaced in place.
+    for (rec_idx, rec) in css_view.import_records.iter_enumerated() {
+      if rec.kind != ImportKind::AtImport {
+        continue;
+      }
+      let Some(importee_idx) = rec.resolved_module else {
+        continue;
+      };
+      match &module_table[importee_idx] {
+        Module::Normal(_) => {
+          let mut nested_conditions = conditions.to_vec();
+          if let Some(rec_conditions) = css_view.import_conditions.get(&rec_idx) {
+            nested_conditions.push(rec_conditions.clone());
+          }
+          self.render_module(importee_idx, &nested_conditions);
+        }
+        Module::External(_) => {
+          self
+            .hoisted_at_imports
+            .insert(rec.importer_span.source_text(&css_view.source).to_string());
+        }
+      }
+    }
+
+    let mut magic_string = MagicString::new(css_view.source.as_str());
+    for mutation in &css_view.mutations {
+      mutation.apply(&mut magic_string);
+    }
+    for rec in &css_view.import_records {
+      if rec.kind != ImportKind::UrlImport {
+        continue;
+      }
+      if let Some(replacement) = self.render_url(css_view, rec) {
+        magic_string
+          .update(rec.importer_span.start, rec.importer_span.end, replacement)
+          .expect("url span should be within the stylesheet");
+      }
+    }
+
+    let mut content = magic_string.to_string().trim().to_string();
+    for conditions in conditions.iter().rev() {
+      content = conditions.wrap(&content).trim_end().to_string();
+    }
+    if !content.is_empty() {
+      self.body.push_str(&content);
+      self.body.push('\n');
+    }
+  }
+
+  /// Points `url()` to the emitted asset, relative to the output stylesheet.
+  fn render_url(&self, css_view: &CssView, rec: &ResolvedImportRecord) -> Option<String> {
+    let importee = self.ctx.link_output.module_table[rec.resolved_module?].as_normal()?;
+    let file_emitter = &self.ctx.plugin_driver.file_emitter;
+    let ref_id = file_emitter.file_ref_for_module(&importee.id)?;
+    let filename = file_emitter.get_file_name(&ref_id).ok()?;
+    let abs_path = self.ctx.options.cwd.join(&self.ctx.options.out_dir).join(filename.as_str());
+    let path = relative_path_to_slash(abs_path, self.css_dir);
+
+    let is_url_function = rec
+      .importer_span
+      .source_text(&css_view.source)
+      .get(..4)
+      .is_some_and(|prefix| prefix.eq_ignore_ascii_case("url("));
+    let needs_quotes =
+      path.contains(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '(' | ')'));
+    Some(match (is_url_function, needs_quotes) {
+      (true, false) => format!("url({path})"),
+      (true, true) => format!("url(\"{}\")", path.replace('"', "\\\"")),
+      (false, _) => format!("\"{}\"", path.replace('"', "\\\"")),
+    })
+  }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use arcstr::ArcStr;
+use css_module_lexer::{Dependency, Mode, collect_dependencies};
+use oxc::span::Span;
+use oxc_index::IndexVec;
+use rolldown_common::{
+  ArcSourceMutation, CssImportConditions, CssView, ImportKind, ImportRecordIdx, ModuleType,
+  RawImportRecord, RemoveCssRange, SymbolRef,
+};
+use rolldown_utils::dataurl::is_data_url;
+use rustc_hash::FxHashMap;
+
+pub struct CreateCssViewReturn {
+  pub css_view: CssView,
+  pub raw_import_records: IndexVec<ImportRecordIdx, RawImportRecord>,
+}
+
+/// Scans the stylesheet for `@import` rules and `url()` tokens.
+///
+/// CSS import records don't bind any symbol, `namespace_ref` is only used to fill the required field
+/// of `RawImportRecord`.
+pub fn create_css_view(source: ArcStr, namespace_ref: SymbolRef) -> CreateCssViewReturn {
+  let (dependencies, _warnings) = collect_dependencies(&source, Mode::Css);
+
+  let mut raw_import_records: IndexVec<ImportRecordIdx, RawImportRecord> = IndexVec::default();
+  let mut import_conditions = FxHashMap::default();
+  let mut external_at_imports = vec![];
+  let mut mutations: Vec<ArcSourceMutation> = vec![];
+
+  for dependency in &dependencies {
+    match dependency {
+      Dependency::Import { request, range, attributes, .. } => {
+        let span = Span::new(range.start, range.end);
+        // `@import` rules are either inlined or hoisted, so they never stay where they are.
+        mutations.push(Arc::new(RemoveCssRange { span }));
+        if is_absolute_url(request) {
+          external_at_imports.push(span);
+          continue;
+        }
+        let attributes = dependencies.import_attributes(*attributes);
+        let conditions = CssImportConditions {
+          layer: attributes.layer().map(Into::into),
+          supports: attributes.supports().map(Into::into),
+          media: attributes.media().map(|media| media.trim().into()),
+        };
+        let rec_idx = raw_import_records.push(RawImportRecord::new(
+          (*request).into(),
+          ImportKind::AtImport,
+          namespace_ref,
+          span,
+          span,
+          None,
+          None,
+        ));
+        if !conditions.is_empty() {
+          import_conditions.insert(rec_idx, conditions);
+        }
+      }
+      Dependency::Url { request, range, .. } => {
+        if request.is_empty()
+          || request.starts_with('#')
+          || is_data_url(request)
+          || is_absolute_url(request)
+        {
+          continue;
+        }
+        let span = Span::new(range.start, range.end);
+        raw_import_records.push(RawImportRecord::new(
+          (*request).into(),
+          ImportKind::UrlImport,
+          namespace_ref,
+          span,
+          span,
+          // Referenced files are emitted as assets, the same way `new URL(.., import.meta.url)` does.
+          Some(ModuleType::Asset),
+          None,
+        ));
+      }
+      Dependency::Charset { range, .. } => {
+        // The output stylesheet is always utf-8, and `@charset` is only valid as the first rule.
+        mutations.push(Arc::new(RemoveCssRange { span: Span::new(range.start, range.end) }));
+      }
+      _ => {}
+    }
+  }
+
+  CreateCssViewReturn {
+    css_view: CssView {
+      source,
+      import_records: IndexVec::default(),
+      import_conditions,
+      external_at_imports,
+      mutations,
+    },
+    raw_import_records,
+  }
+}
+
+/// `https://example.com/a.css`, `//example.com/a.css`
+fn is_absolute_url(request: &str) -> bool {
+  if request.starts_with("//") {
+    return true;
+  }
+  request.split_once(':').is_some_and(|(scheme, _)| {
+    // Avoid treating windows paths like `C:\a.css` as urls.
+    scheme.len() > 1
+      && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+  })
+}
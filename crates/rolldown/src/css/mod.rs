@@ -0,0 +1,2 @@
+pub mod css_generator;
+pub mod css_module_view_factory;
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{borrow::Cow, path::Path, sync::Arc};
 
 use crate::{
   types::generator::{GenerateContext, GenerateOutput, Generator},
@@ -7,19 +7,23 @@ use crate::{
 
 use anyhow::Result;
 use rolldown_common::{
-  AddonRenderContext, EcmaAssetMeta, InstantiatedChunk, InstantiationKind, ModuleId, ModuleIdx,
-  OutputFormat, RenderedModule, StrictMode,
+  AddonRenderContext, EcmaAssetMeta, InstantiatedChunk, InstantiationKind, LegalComments, ModuleId,
+  ModuleIdx, OutputFormat, RenderedModule, StrictMode,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_plugin::HookAddonArgs;
 use rolldown_sourcemap::Source;
+use rolldown_utils::indexmap::FxIndexSet;
 #[cfg(not(target_family = "wasm"))]
 use rolldown_utils::rayon::IndexedParallelIterator;
 use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
 use rustc_hash::FxHashMap;
 
 use super::format::utils::is_use_strict_directive;
-use super::format::{cjs::render_cjs, esm::render_esm, iife::render_iife, umd::render_umd};
+use super::format::{
+  amd::render_amd, cjs::render_cjs, esm::render_esm, iife::render_iife, system::render_system,
+  umd::render_umd,
+};
 
 pub type RenderedModuleSources = Vec<RenderedModuleSource>;
 
@@ -47,7 +51,7 @@ impl Generator for EcmaGenerator {
   #[expect(clippy::too_many_lines)]
   async fn instantiate_chunk(ctx: &mut GenerateContext<'_>) -> Result<BuildResult<GenerateOutput>> {
     let module_id_to_codegen_ret = std::mem::take(&mut ctx.module_id_to_codegen_ret);
-    let rendered_pairs: Vec<(RenderedModuleSource, Vec<BuildDiagnostic>)> = ctx
+    let rendered_pairs: Vec<(RenderedModuleSource, Vec<BuildDiagnostic>, Vec<String>)> = ctx
       .chunk
       .modules
       .par_iter()
@@ -58,20 +62,24 @@ impl Generator for EcmaGenerator {
           .as_normal()
           .map(|m| (m, codegen_ret.expect("should have codegen_ret")))
       })
-      .map(|(m, codegen_ret)| {
+      .map(|(m, mut codegen_ret)| {
+        let legal_comments = std::mem::take(&mut codegen_ret.legal_comments);
         let render = render_ecma_module(m, ctx.options, codegen_ret);
         (
           RenderedModuleSource::new(m.idx, m.id.clone(), m.exec_order, render.sources),
           render.warnings,
+          legal_comments,
         )
       })
       .collect::<Vec<_>>();
 
     let mut sourcemap_broken_warnings: Vec<BuildDiagnostic> = Vec::new();
+    let mut legal_comments: FxIndexSet<String> = FxIndexSet::default();
     let rendered_module_sources: RenderedModuleSources = rendered_pairs
       .into_iter()
-      .map(|(source, warnings)| {
+      .map(|(source, warnings, module_legal_comments)| {
         sourcemap_broken_warnings.extend(warnings);
+        legal_comments.extend(module_legal_comments);
         source
       })
       .collect();
@@ -197,8 +205,13 @@ impl Generator for EcmaGenerator {
     let mut warnings = sourcemap_broken_warnings;
 
     // Warn when multiple shebang sources would produce duplicate shebangs in the output.
-    // UMD format silently drops the entry hashbang, so it doesn't count as a shebang source.
-    let entry_has_shebang = hashbang.is_some() && !matches!(ctx.options.format, OutputFormat::Umd);
+    // UMD, AMD and SystemJS formats silently drop the entry hashbang, so it doesn't count as a
+    // shebang source.
+    let entry_has_shebang = hashbang.is_some()
+      && !matches!(
+        ctx.options.format,
+        OutputFormat::Umd | OutputFormat::Amd | OutputFormat::System
+      );
     let banner_has_shebang = banner.as_ref().is_some_and(|b| b.starts_with("#!"));
     let post_banner_has_shebang = post_banner.as_ref().is_some_and(|pb| pb.starts_with("#!"));
 
@@ -256,6 +269,8 @@ impl Generator for EcmaGenerator {
           Err(errors) => return Ok(Err(errors)),
         }
       }
+      OutputFormat::Amd => render_amd(ctx, addon_render_context, &rendered_module_sources),
+      OutputFormat::System => render_system(ctx, addon_render_context, &rendered_module_sources),
     };
 
     if ctx.options.experimental.is_attach_debug_info_full() && !ctx.chunk.debug_info.is_empty() {
@@ -264,7 +279,34 @@ impl Generator for EcmaGenerator {
       source_joiner.prepend_source(format!("//! {debug_info_str}"));
     }
 
-    let (content, map) = source_joiner.join();
+    let preliminary_filename =
+      ctx.chunk.preliminary_filename.as_deref().expect("should have preliminary filename");
+    let legal_comments = legal_comments.into_iter().collect::<Vec<_>>();
+    if !legal_comments.is_empty() {
+      match ctx.options.legal_comments {
+        LegalComments::Eof => source_joiner.append_source(legal_comments.join("\n")),
+        LegalComments::Linked => {
+          // The `.LEGAL.txt` file is emitted next to the chunk, see `render_chunk_to_assets`.
+          let legal_filename = Path::new(preliminary_filename.as_str())
+            .file_name()
+            .map_or(Cow::Borrowed(""), |name| name.to_string_lossy());
+          source_joiner.append_source(format!(
+            "/*! For license information please see {legal_filename}.LEGAL.txt */"
+          ));
+        }
+        LegalComments::None | LegalComments::Inline | LegalComments::External => {}
+      }
+    }
+
+    // Scopes are generated against the merged sourcemap, so they rule out index maps.
+    let (content, map, index_map) =
+      if ctx.options.sourcemap_index_map && !ctx.options.sourcemap_scopes {
+        let (content, index_map) = source_joiner.join_sections();
+        (content, None, index_map)
+      } else {
+        let (content, map) = source_joiner.join();
+        (content, map, None)
+      };
 
     // Here file path is generated by chunk file name template, it maybe including path segments.
     // So here need to read it's parent directory as file_dir.
@@ -283,13 +325,21 @@ impl Generator for EcmaGenerator {
         originate_from: ctx.chunk_idx,
         content: content.into(),
         map,
+        index_map,
         kind: InstantiationKind::from(EcmaAssetMeta {
           rendered_chunk,
           debug_id: 0,
           imports: vec![],
           dynamic_imports: vec![],
+          transitive_imports: vec![],
           file_dir: file_dir.to_path_buf(),
           sourcemap_filename: None,
+          sourcemap_scopes: None,
+          legal_comments: if ctx.options.legal_comments.emits_legal_file() {
+            legal_comments
+          } else {
+            vec![]
+          },
           preliminary_filename: ctx
             .chunk
             .preliminary_filename
@@ -7,11 +7,15 @@ use rolldown_common::{
 };
 use rolldown_error::BuildResult;
 use rolldown_utils::{ecmascript::legitimize_identifier_name, indexmap::FxIndexSet};
+use rustc_hash::FxHashMap;
 
 use crate::{
   ast_scanner::{AstScanner, ScanResult},
   types::module_factory::{CreateModuleContext, CreateModuleViewArgs},
-  utils::parse_to_ecma_ast::{ParseToEcmaAstResult, parse_to_ecma_ast},
+  utils::{
+    collect_original_scopes::collect_original_scopes,
+    parse_to_ecma_ast::{ParseToEcmaAstResult, parse_to_ecma_ast},
+  },
 };
 
 pub struct CreateEcmaViewReturn {
@@ -36,6 +40,7 @@ pub async fn create_ecma_view(
     warnings,
     preserve_jsx,
     enum_member_value_map,
+    glob_callee_spans,
   } = parse_to_ecma_ast(ctx, source).await?;
   ctx.flat_options.set(FlatOptions::JsxPreserve, preserve_jsx);
   ctx.warnings.extend(warnings);
@@ -45,6 +50,19 @@ pub async fn create_ecma_view(
   let repr_name = module_id.representative_name();
   let repr_name = legitimize_identifier_name(&repr_name);
 
+  let original_scopes = if ctx.options.sourcemap_scopes && ctx.options.sourcemap.is_some() {
+    collect_original_scopes(
+      ast.program(),
+      &scoping,
+      ast.source(),
+      module_id.as_str(),
+      &sourcemap_chain,
+    )
+    .map(Box::new)
+  } else {
+    None
+  };
+
   let scan_result = ast.program.with_mut(|fields| {
     let program = &*fields.program;
     let scanner = AstScanner::new(
@@ -58,12 +76,14 @@ pub async fn create_ecma_view(
       ctx.options,
       fields.allocator,
       ctx.flat_options,
+      &glob_callee_spans,
     );
     scanner.scan(program)
   })?;
 
   let ScanResult {
     commonjs_exports,
+    commonjs_object_exports,
     named_imports,
     mut named_exports,
     stmt_infos,
@@ -88,6 +108,7 @@ pub async fn create_ecma_view(
     hmr_hot_ref,
     directive_range,
     dummy_record_set,
+    glob_callee_set,
     constant_export_map,
     import_attribute_map,
     cjs_reexport_require_node_ids: _,
@@ -127,6 +148,7 @@ pub async fn create_ecma_view(
     dynamic_importers: FxIndexSet::default(),
     dynamic_importers_idx: FxIndexSet::default(),
     imported_ids: FxIndexSet::default(),
+    imported_ids_by_specifier: FxHashMap::default(),
     dynamically_imported_ids: FxIndexSet::default(),
     side_effects,
     meta: {
@@ -144,17 +166,21 @@ pub async fn create_ecma_view(
     hashbang_range,
     mutations: vec![],
     new_url_references: new_url_imports,
+    worker_references: FxHashMap::default(),
     rolldown_file_url_references,
     this_expr_replace_map,
     hmr_info,
     hmr_hot_ref,
     directive_range,
     dummy_record_set,
+    glob_callee_set,
     constant_export_map,
     enum_member_value_map,
     import_attribute_map,
     json_module_none_self_reference_included_symbol: None,
     cjs_reexport_import_record_ids,
+    commonjs_object_exports,
+    original_scopes,
   };
 
   let ecma_related =
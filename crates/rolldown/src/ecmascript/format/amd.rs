@@ -0,0 +1,181 @@
+use rolldown_common::{AddonRenderContext, ImportKind, OutputExports};
+use rolldown_sourcemap::SourceJoiner;
+use rolldown_utils::concat_string;
+
+use crate::{
+  ecmascript::ecma_generator::RenderedModuleSources,
+  types::generator::GenerateContext,
+  utils::chunk::{
+    namespace_marker::render_namespace_markers,
+    render_chunk_exports::{
+      get_chunk_export_names_with_ctx, render_chunk_exports, render_string_literal,
+      render_wrapped_entry_chunk,
+    },
+  },
+};
+
+use super::utils::{
+  collect_star_exported_externals, render_chunk_directives, render_chunk_external_imports,
+  render_modules_with_peek_runtime_module_at_first,
+};
+
+#[expect(clippy::needless_pass_by_value)]
+pub fn render_amd<'code>(
+  ctx: &GenerateContext<'_>,
+  addon_render_context: AddonRenderContext<'code>,
+  module_sources: &'code RenderedModuleSources,
+) -> SourceJoiner<'code> {
+  let mut source_joiner = SourceJoiner::default();
+  let AddonRenderContext { banner, intro, outro, footer, directives, .. } = addon_render_context;
+  if let Some(banner) = banner {
+    source_joiner.append_source(banner);
+  }
+
+  if !directives.is_empty() {
+    let rendered_chunk_directives = render_chunk_directives(directives.iter());
+    if !rendered_chunk_directives.is_empty() {
+      source_joiner.append_source(rendered_chunk_directives);
+    }
+  }
+
+  // Use pre-computed output_exports from the chunk
+  let export_mode = ctx.chunk.output_exports;
+  let named_exports = matches!(export_mode, OutputExports::Named);
+  let export_names = get_chunk_export_names_with_ctx(ctx);
+  let has_default_export = export_names.iter().any(|name| name.as_str() == "default");
+
+  let star_exported_externals = collect_star_exported_externals(ctx);
+  let (import_code, externals) = render_chunk_external_imports(ctx);
+
+  // `define([dependencies], function (parameters) { ... })`. Dependencies without a parameter
+  // are only loaded for their side effects and go last.
+  let mut dependencies = vec![];
+  let mut parameters = vec![];
+  let mut side_effect_dependencies = vec![];
+
+  // The local `require` reads star re-exported externals and lowers `import()`.
+  if !star_exported_externals.is_empty() || chunk_contains_dynamic_import(ctx) {
+    dependencies.push(render_string_literal("require"));
+    parameters.push("require");
+  }
+  if named_exports && (!export_names.is_empty() || !star_exported_externals.is_empty()) {
+    dependencies.push(render_string_literal("exports"));
+    parameters.push("exports");
+  }
+
+  ctx.chunk.imports_from_other_chunks.iter().for_each(|(exporter_id, items)| {
+    let importee_chunk = &ctx.chunk_graph.chunk_table[*exporter_id];
+    let import_path = ctx.chunk.import_path_for(importee_chunk);
+    // AMD module ids don't carry the `.js` extension, or the loader would resolve them against
+    // the page instead of the importing module.
+    let module_id =
+      render_string_literal(import_path.strip_suffix(".js").unwrap_or(import_path.as_str()));
+    if items.is_empty() {
+      side_effect_dependencies.push(module_id);
+    } else {
+      dependencies.push(module_id);
+      parameters.push(&ctx.chunk.require_binding_names_for_other_chunks[exporter_id]);
+    }
+  });
+
+  externals.iter().for_each(|external| {
+    let module = external.module();
+    let module_id = render_string_literal(&module.get_import_path(ctx.chunk, ctx.resolved_paths));
+    if external.is_used() {
+      dependencies.push(module_id);
+      parameters.push(
+        ctx
+          .link_output
+          .symbol_db
+          .canonical_name_for_or_original(module.namespace_ref, &ctx.chunk.canonical_names),
+      );
+    } else {
+      side_effect_dependencies.push(module_id);
+    }
+  });
+
+  star_exported_externals.iter().for_each(|idx| {
+    let module =
+      ctx.link_output.module_table[*idx].as_external().expect("Should be external module");
+    if !externals.iter().any(|external| external.module().idx == module.idx) {
+      side_effect_dependencies
+        .push(render_string_literal(&module.get_import_path(ctx.chunk, ctx.resolved_paths)));
+    }
+  });
+
+  dependencies.extend(side_effect_dependencies);
+
+  let amd_id = ctx
+    .options
+    .amd
+    .id
+    .as_deref()
+    .map(|id| concat_string!(render_string_literal(id), ", "))
+    .unwrap_or_default();
+  source_joiner.append_source(concat_string!(
+    ctx.options.amd.define(),
+    "(",
+    amd_id,
+    "[",
+    dependencies.join(", "),
+    "], (function (",
+    parameters.join(", "),
+    ") {"
+  ));
+
+  if let Some(intro) = intro {
+    source_joiner.append_source(intro);
+  }
+
+  if let (Some(entry_module), true) =
+    (ctx.chunk.user_defined_entry_module(&ctx.link_output.module_table), named_exports)
+  {
+    if entry_module.exports_kind.is_esm() {
+      // Symbol.toStringTag should only be added to module facades (chunks that represent a specific module)
+      if let Some(marker) = render_namespace_markers(
+        ctx.options.es_module,
+        has_default_export,
+        &ctx.options.generated_code,
+        ctx.chunk.is_entry_point(),
+      ) {
+        source_joiner.append_source(marker);
+      }
+    }
+  }
+
+  render_modules_with_peek_runtime_module_at_first(
+    ctx,
+    &mut source_joiner,
+    module_sources,
+    import_code,
+  );
+
+  if let Some(source) = render_wrapped_entry_chunk(ctx, Some(&export_mode)) {
+    source_joiner.append_source(source);
+  }
+
+  if let Some(exports) = render_chunk_exports(ctx, Some(&export_mode)) {
+    source_joiner.append_source(exports);
+  }
+
+  if let Some(outro) = outro {
+    source_joiner.append_source(outro);
+  }
+
+  // amd wrapper end
+  source_joiner.append_source("}));");
+
+  if let Some(footer) = footer {
+    source_joiner.append_source(footer);
+  }
+
+  source_joiner
+}
+
+fn chunk_contains_dynamic_import(ctx: &GenerateContext<'_>) -> bool {
+  ctx.chunk.modules.iter().filter_map(|idx| ctx.link_output.module_table[*idx].as_normal()).any(
+    |module| {
+      module.ecma_view.import_records.iter().any(|rec| rec.kind == ImportKind::DynamicImport)
+    },
+  )
+}
@@ -13,7 +13,10 @@ use rolldown_error::BuildDiagnostic;
 use rolldown_sourcemap::SourceJoiner;
 use rolldown_utils::concat_string;
 
-use super::utils::{render_chunk_directives, render_modules_with_peek_runtime_module_at_first};
+use super::utils::{
+  render_chunk_directives, render_external_interop,
+  render_modules_with_peek_runtime_module_at_first,
+};
 
 #[expect(clippy::needless_pass_by_value)]
 pub fn render_cjs<'code>(
@@ -149,7 +152,30 @@ fn render_cjs_chunk_imports(ctx: &GenerateContext<'_>) -> String {
           importee.namespace_ref,
           named_imports.map(Vec::as_slice),
         );
-        if let Some(interop_modes) = interop_modes {
+        if let Some(interop) = importee.interop
+          && interop_modes.is_some()
+        {
+          // `output.interop` reads the external the same way for every importer.
+          s.push_str(&concat_string!(
+            "let ",
+            external_module_symbol_name,
+            " = ",
+            require_path_str,
+            ";\n"
+          ));
+          let to_esm_fn = || {
+            ctx.finalized_string_pattern_for_symbol_ref(
+              ctx.link_output.runtime.resolve_symbol("__toESM"),
+              ctx.chunk_idx,
+              &ctx.chunk.canonical_names,
+            )
+          };
+          if let Some(value) =
+            render_external_interop(interop, external_module_symbol_name, to_esm_fn)
+          {
+            s.push_str(&concat_string!(external_module_symbol_name, " = ", value, ";\n"));
+          }
+        } else if let Some(interop_modes) = interop_modes {
           let to_esm_fn = ctx.finalized_string_pattern_for_symbol_ref(
             ctx.link_output.runtime.resolve_symbol("__toESM"),
             ctx.chunk_idx,
@@ -467,7 +467,7 @@ where
   let mut s = String::new();
   let mut default_alias = vec![];
   let specifiers = named_imports
-    .filter_map(|(_importer, named_import)| {
+    .filter_map(|(importer, named_import)| {
       let canonical_ref = ctx.link_output.symbol_db.canonical_ref_for(named_import.imported_as);
       // A named import that is itself re-exported skips the external binding merger
       // (`bind_imports_and_exports`), so its canonical ref stays importer-local; its usage
@@ -486,6 +486,19 @@ where
         .canonical_name_for_or_original(canonical_ref, &ctx.chunk.canonical_names);
       match &named_import.imported {
         Specifier::Star => {
+          // Deferred namespaces aren't merged with the eager ones, each keeps its declaration.
+          let is_deferred = ctx.link_output.module_table[*importer].as_normal().is_some_and(|m| {
+            m.import_records[named_import.record_idx].meta.contains(ImportRecordMeta::IsDeferred)
+          });
+          if is_deferred {
+            *is_importee_rendered = true;
+            s.push_str("import defer * as ");
+            s.push_str(alias);
+            s.push_str(" from ");
+            s.push_str(&escape(&importee.get_import_path(ctx.chunk, ctx.resolved_paths)));
+            s.push_str(";\n");
+            return None;
+          }
           if rendered_external_import_namespace_modules.contains(&importee.idx) {
             return None;
           }
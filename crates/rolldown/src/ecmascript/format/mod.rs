@@ -1,5 +1,7 @@
+pub mod amd;
 pub mod cjs;
 pub mod esm;
 pub mod iife;
+pub mod system;
 pub mod umd;
 pub mod utils;
@@ -0,0 +1,230 @@
+use oxc_str::CompactStr;
+use rolldown_common::{AddonRenderContext, ExportsKind, SymbolRef};
+use rolldown_sourcemap::SourceJoiner;
+use rolldown_utils::{concat_string, ecmascript::property_access_str, indexmap::FxIndexMap};
+use rustc_hash::FxHashMap;
+
+use crate::{
+  ecmascript::ecma_generator::RenderedModuleSources,
+  types::generator::GenerateContext,
+  utils::chunk::render_chunk_exports::{
+    get_chunk_export_names_with_ctx, render_chunk_exports, render_string_literal,
+    render_system_hoisted_exports, render_wrapped_entry_chunk,
+  },
+};
+
+use super::utils::{
+  collect_star_exported_externals, render_chunk_directives,
+  render_modules_with_peek_runtime_module_at_first,
+};
+
+#[expect(clippy::needless_pass_by_value)]
+pub fn render_system<'code>(
+  ctx: &GenerateContext<'_>,
+  addon_render_context: AddonRenderContext<'code>,
+  module_sources: &'code RenderedModuleSources,
+) -> SourceJoiner<'code> {
+  let mut source_joiner = SourceJoiner::default();
+  let AddonRenderContext { banner, intro, outro, footer, directives, .. } = addon_render_context;
+  if let Some(banner) = banner {
+    source_joiner.append_source(banner);
+  }
+
+  // Every dependency gets a setter, which SystemJS calls with the namespace of the dependency
+  // whenever one of its exports changes. Dependencies only loaded for their side effects get
+  // `null`.
+  let mut setters: FxIndexMap<String, Vec<String>> = FxIndexMap::default();
+  let mut bindings: Vec<&str> = vec![];
+
+  // Bindings of other chunks the chunk re-exports are forwarded by the setter, which keeps them
+  // live like the ones the chunk owns.
+  let export_names_by_symbol: FxHashMap<SymbolRef, &Vec<CompactStr>> = ctx
+    .render_export_items_index_vec[ctx.chunk_idx]
+    .iter()
+    .map(|(symbol_ref, names)| (ctx.link_output.symbol_db.canonical_ref_for(*symbol_ref), names))
+    .collect();
+  ctx.chunk.imports_from_other_chunks.iter().for_each(|(exporter_id, items)| {
+    let importee_chunk = &ctx.chunk_graph.chunk_table[*exporter_id];
+    let dependency = render_string_literal(&ctx.chunk.import_path_for(importee_chunk));
+    let binding = (!items.is_empty())
+      .then(|| ctx.chunk.require_binding_names_for_other_chunks[exporter_id].as_str());
+    let setter = add_dependency(&mut setters, &mut bindings, dependency, binding);
+    for item in items {
+      let canonical_ref = ctx.link_output.symbol_db.canonical_ref_for(item.import_ref);
+      let (Some(export_names), Some(imported_name)) = (
+        export_names_by_symbol.get(&canonical_ref),
+        importee_chunk.exports_to_other_chunks.get(&canonical_ref).and_then(|names| names.first()),
+      ) else {
+        continue;
+      };
+      for export_name in *export_names {
+        setter.push(concat_string!(
+          "exports(",
+          render_string_literal(export_name),
+          ", ",
+          property_access_str("module", imported_name),
+          ");"
+        ));
+      }
+    }
+  });
+
+  let star_exported_externals = collect_star_exported_externals(ctx);
+  ctx
+    .chunk
+    .direct_imports_from_external_modules
+    .iter()
+    .map(|(importee_idx, _)| *importee_idx)
+    .chain(ctx.chunk.import_symbol_from_external_modules.iter().copied())
+    .chain(star_exported_externals.iter().copied())
+    .for_each(|importee_idx| {
+      let importee = ctx.link_output.module_table[importee_idx]
+        .as_external()
+        .expect("Should be external module");
+      let is_used = ctx.link_output.used_external_symbols.contains(&importee.namespace_ref)
+        || star_exported_externals.contains(&importee_idx);
+      if !is_used && !importee.side_effects.has_side_effects() {
+        return;
+      }
+      let dependency =
+        render_string_literal(&importee.get_import_path(ctx.chunk, ctx.resolved_paths));
+      let binding = is_used.then(|| {
+        ctx
+          .link_output
+          .symbol_db
+          .canonical_name_for_or_original(importee.namespace_ref, &ctx.chunk.canonical_names)
+      });
+      add_dependency(&mut setters, &mut bindings, dependency, binding);
+    });
+
+  // `export * from 'external'` in an entry re-exports whatever the external provides, except the
+  // names the entry exports itself.
+  let entry_exports_esm = ctx
+    .chunk
+    .entry_module(&ctx.link_output.module_table)
+    .is_some_and(|module| matches!(module.exports_kind, ExportsKind::Esm));
+  if entry_exports_esm && !ctx.chunk.entry_level_external_module_idx.is_empty() {
+    let mut star_excludes = String::from("{ __proto__: null, default: 1");
+    get_chunk_export_names_with_ctx(ctx).iter().for_each(|name| {
+      star_excludes.push_str(&concat_string!(", ", render_string_literal(name), ": 1"));
+    });
+    star_excludes.push_str(" }");
+    for importee_idx in &ctx.chunk.entry_level_external_module_idx {
+      let Some(importee) = ctx.link_output.module_table[*importee_idx].as_external() else {
+        continue;
+      };
+      let dependency =
+        render_string_literal(&importee.get_import_path(ctx.chunk, ctx.resolved_paths));
+      setters.entry(dependency).or_default().push(concat_string!(
+        "var setter = { __proto__: null }, excludes = ",
+        star_excludes,
+        ";\nfor (var name in module) {\n  if (!excludes[name]) setter[name] = module[name];\n}\nexports(setter);"
+      ));
+    }
+  }
+
+  let register_name = ctx
+    .options
+    .name
+    .as_deref()
+    .filter(|name| !name.is_empty())
+    .map(|name| concat_string!(render_string_literal(name), ", "))
+    .unwrap_or_default();
+  source_joiner.append_source(concat_string!(
+    "System.register(",
+    register_name,
+    "[",
+    setters.keys().map(String::as_str).collect::<Vec<_>>().join(", "),
+    "], (function (exports, module) {"
+  ));
+
+  if !directives.is_empty() {
+    let rendered_chunk_directives = render_chunk_directives(directives.iter());
+    if !rendered_chunk_directives.is_empty() {
+      source_joiner.append_source(rendered_chunk_directives);
+    }
+  }
+
+  if !bindings.is_empty() {
+    source_joiner.append_source(concat_string!("var ", bindings.join(", "), ";"));
+  }
+
+  let rendered_setters = setters
+    .values()
+    .map(|statements| {
+      if statements.is_empty() {
+        "null".to_string()
+      } else {
+        concat_string!("function (module) {\n", statements.join("\n"), "\n}")
+      }
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
+  // The body is async as soon as one of its modules awaits, directly or through a dependency.
+  let is_async = ctx
+    .chunk
+    .modules
+    .iter()
+    .any(|module_idx| ctx.link_output.metas[*module_idx].is_tla_or_contains_tla_dependency);
+  source_joiner.append_source(concat_string!(
+    "return {\nsetters: [",
+    rendered_setters,
+    "],\nexecute: (",
+    if is_async { "async " } else { "" },
+    "function () {"
+  ));
+
+  if let Some(intro) = intro {
+    source_joiner.append_source(intro);
+  }
+
+  if let Some(hoisted_exports) = render_system_hoisted_exports(ctx) {
+    source_joiner.append_source(hoisted_exports);
+  }
+
+  render_modules_with_peek_runtime_module_at_first(
+    ctx,
+    &mut source_joiner,
+    module_sources,
+    String::new(),
+  );
+
+  if let Some(source) = render_wrapped_entry_chunk(ctx, None) {
+    source_joiner.append_source(source);
+  }
+
+  if let Some(exports) = render_chunk_exports(ctx, None) {
+    source_joiner.append_source(exports);
+  }
+
+  if let Some(outro) = outro {
+    source_joiner.append_source(outro);
+  }
+
+  // system wrapper end
+  source_joiner.append_source("})\n};\n}));");
+
+  if let Some(footer) = footer {
+    source_joiner.append_source(footer);
+  }
+
+  source_joiner
+}
+
+/// Registers `dependency` and binds its namespace to `binding` in the setter, returning the
+/// statements of the setter.
+fn add_dependency<'s, 'a>(
+  setters: &'s mut FxIndexMap<String, Vec<String>>,
+  bindings: &mut Vec<&'a str>,
+  dependency: String,
+  binding: Option<&'a str>,
+) -> &'s mut Vec<String> {
+  let setter = setters.entry(dependency).or_default();
+  if let Some(binding) = binding
+    && !bindings.contains(&binding)
+  {
+    bindings.push(binding);
+    setter.push(concat_string!(binding, " = module;"));
+  }
+  setter
+}
@@ -11,7 +11,8 @@ use crate::{
   utils::chunk::{
     namespace_marker::render_namespace_markers,
     render_chunk_exports::{
-      get_chunk_export_names_with_ctx, render_chunk_exports, render_wrapped_entry_chunk,
+      get_chunk_export_names_with_ctx, render_chunk_exports, render_string_literal,
+      render_wrapped_entry_chunk,
     },
   },
 };
@@ -83,10 +84,18 @@ pub async fn render_umd<'code>(
   let iife_end = if need_global { ")" } else { "" };
   let iife_export =
     render_iife_export(warnings, ctx, &externals, has_exports, named_exports).await?;
+  let amd_define = ctx.options.amd.define();
+  let amd_id = ctx
+    .options
+    .amd
+    .id
+    .as_deref()
+    .map(|id| concat_string!(render_string_literal(id), ", "))
+    .unwrap_or_default();
   source_joiner.append_source(format!(
     "(function({wrapper_parameters}) {{
   {cjs_intro}
-  typeof define === 'function' && define.amd ? define([{amd_dependencies}], factory) :
+  typeof {amd_define} === 'function' && {amd_define}.amd ? {amd_define}({amd_id}[{amd_dependencies}], factory) :
   {iife_start}{iife_export}{iife_end};
 }})({global_argument}function({factory_parameters}) {{",
   ));
@@ -1,6 +1,7 @@
 use itertools::Itertools;
-use rolldown_common::ExternalModule;
+use rolldown_common::{ExternalModule, InteropType, ModuleIdx};
 use rolldown_sourcemap::SourceJoiner;
+use rolldown_utils::{concat_string, indexmap::FxIndexSet};
 
 use crate::{
   ecmascript::ecma_generator::{RenderedModuleSource, RenderedModuleSources},
@@ -47,6 +48,42 @@ pub fn render_factory_parameters(
   parameters.join(", ")
 }
 
+/// The value the namespace binding of an external is reassigned to under an explicit
+/// `output.interop`, or `None` when the required value is read as is.
+pub fn render_external_interop(
+  interop: InteropType,
+  binding: &str,
+  to_esm_fn: impl FnOnce() -> String,
+) -> Option<String> {
+  match interop {
+    InteropType::EsModule => return None,
+    InteropType::DefaultOnly => {
+      return Some(concat_string!("{ __proto__: null, default: ", binding, " }"));
+    }
+    InteropType::Auto | InteropType::Default | InteropType::Compat => {}
+  }
+  let to_esm_fn = to_esm_fn();
+  Some(match interop {
+    InteropType::Default => concat_string!(to_esm_fn, "(", binding, ", 1)"),
+    // An object owning `default` is already shaped like a namespace; anything else is the default.
+    InteropType::Compat => concat_string!(
+      binding,
+      " && typeof ",
+      binding,
+      " === \"object\" && \"default\" in ",
+      binding,
+      " ? ",
+      binding,
+      " : ",
+      to_esm_fn,
+      "(",
+      binding,
+      ", 1)"
+    ),
+    _ => concat_string!(to_esm_fn, "(", binding, ")"),
+  })
+}
+
 pub fn render_chunk_external_imports<'a>(
   ctx: &'a GenerateContext<'_>,
 ) -> (String, Vec<ExternalImportKind<'a>>) {
@@ -56,6 +93,11 @@ pub fn render_chunk_external_imports<'a>(
     .chunk
     .direct_imports_from_external_modules
     .iter()
+    .map(|(importee_id, named_imports)| (importee_id, Some(named_imports)))
+    // Only chunks of a code-splitting build read externals imported by modules of other chunks.
+    .chain(
+      ctx.chunk.import_symbol_from_external_modules.iter().map(|importee_id| (importee_id, None)),
+    )
     .filter_map(|(importee_id, named_imports)| {
       let importee = ctx.link_output.module_table[*importee_id]
         .as_external()
@@ -75,9 +117,31 @@ pub fn render_chunk_external_imports<'a>(
           ChunkAssignments::from_graph(ctx.chunk_graph),
           ctx.chunk_idx,
           importee.namespace_ref,
-          Some(named_imports.as_slice()),
+          named_imports.map(Vec::as_slice),
         );
-        if let Some(interop_modes) = interop_modes {
+        if let Some(interop) = importee.interop
+          && interop_modes.is_some()
+        {
+          // `output.interop` reads the external the same way for every importer.
+          let to_esm_fn = || {
+            ctx
+              .link_output
+              .symbol_db
+              .canonical_name_for_or_original(
+                ctx.link_output.runtime.resolve_symbol("__toESM"),
+                &ctx.chunk.canonical_names,
+              )
+              .to_string()
+          };
+          if let Some(value) =
+            render_external_interop(interop, external_module_symbol_name, to_esm_fn)
+          {
+            import_code.push_str(external_module_symbol_name);
+            import_code.push_str(" = ");
+            import_code.push_str(&value);
+            import_code.push_str(";\n");
+          }
+        } else if let Some(interop_modes) = interop_modes {
           let to_esm_fn_name = ctx.link_output.symbol_db.canonical_name_for_or_original(
             ctx.link_output.runtime.resolve_symbol("__toESM"),
             &ctx.chunk.canonical_names,
@@ -128,6 +192,25 @@ pub fn render_chunk_external_imports<'a>(
   (import_code, externals)
 }
 
+/// Externals re-exported with `export * from '...'` by modules of the chunk. A module loader has to
+/// fetch them before the chunk runs, as their namespaces are read synchronously.
+pub fn collect_star_exported_externals(ctx: &GenerateContext<'_>) -> FxIndexSet<ModuleIdx> {
+  ctx
+    .chunk
+    .modules
+    .iter()
+    .filter_map(|idx| ctx.link_output.module_table[*idx].as_normal())
+    .flat_map(|module| {
+      ctx.link_output.metas[module.idx]
+        .star_exports_from_external_modules
+        .iter()
+        .filter_map(|rec_idx| module.ecma_view.import_records[*rec_idx].resolved_module)
+    })
+    .chain(ctx.chunk.entry_level_external_module_idx.iter().copied())
+    .filter(|idx| ctx.link_output.module_table[*idx].is_external())
+    .collect()
+}
+
 pub fn render_modules_with_peek_runtime_module_at_first<'a>(
   ctx: &GenerateContext<'_>,
   source_joiner: &mut SourceJoiner<'a>,
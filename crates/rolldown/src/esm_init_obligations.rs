@@ -82,7 +82,9 @@ pub fn record_is_init_obligation(
   rec_idx: ImportRecordIdx,
   stmt_is_included: bool,
 ) -> bool {
-  if rec.kind != ImportKind::Import {
+  // `import defer` hands the `init_*` wrapper to the deferred namespace instead of calling it at
+  // the statement position.
+  if rec.kind != ImportKind::Import || rec.meta.contains(ImportRecordMeta::IsDeferred) {
     return false;
   }
   if order_state.is_consumer_local_reexport_route(importer_idx)
@@ -151,8 +153,8 @@ pub struct EntryReexportedWrapperInit {
   pub wrapper_ref: SymbolRef,
   /// A TLA-tainted wrapper renders as `await init_*()`. This can only surface in `esm` output:
   /// the scanner rejects top-level await under every other format
-  /// (`AstScanner::handle_top_level_await`), so a TLA-tainted module never reaches emission
-  /// there.
+  /// (`AstScanner::handle_top_level_await`) unless `experimental.asyncModuleWrappers` is set, and
+  /// then the call is rendered without `await`.
   pub tla_tainted: bool,
 }
 
@@ -895,6 +895,7 @@ impl<'a, Fs: FileSystem + Clone + 'static> HmrStage<'a, Fs> {
         filename: module.id.to_string(),
         comments: PrintCommentsOptions {
           legal: false, // ignore hmr chunk comments
+          extract_legal: false,
           annotation: self.options.comments.annotation,
           jsdoc: self.options.comments.jsdoc,
         },
@@ -3,6 +3,7 @@ mod bundle;
 mod bundler;
 mod bundler_builder;
 mod chunk_graph;
+mod css;
 mod ecmascript;
 mod esm_init_obligations;
 mod hmr;
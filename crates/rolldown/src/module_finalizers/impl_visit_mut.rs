@@ -12,7 +12,7 @@ use oxc::{
   span::{SPAN, Span},
 };
 use oxc_str::CompactStr;
-use rolldown_common::{ConcatenateWrappedModuleKind, SymbolRef, ThisExprReplaceKind};
+use rolldown_common::{ConcatenateWrappedModuleKind, OutputFormat, SymbolRef, ThisExprReplaceKind};
 use rolldown_ecmascript::ToSourceString;
 use rolldown_ecmascript_utils::{
   EsmWrapperBodyKind, EsmWrapperCallKind, EsmWrapperDeclKind, EsmWrapperStmtOptions, ExpressionExt,
@@ -439,6 +439,7 @@ impl<'ast> VisitJsMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
 
   fn visit_statement(&mut self, it: &mut ast::Statement<'ast>) {
     _ = self.try_inline_json_module_prop(it);
+    _ = self.try_rewrite_commonjs_export(it);
 
     walk_js_mut::walk_statement(self, it);
 
@@ -481,6 +482,18 @@ impl<'ast> VisitJsMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
   }
 
   fn visit_expression(&mut self, expr: &mut ast::Expression<'ast>) {
+    if let Some((symbol_ref, export_names)) = self.system_exported_update_target(expr) {
+      walk_js_mut::walk_expression(self, expr);
+      self.wrap_with_system_exports_calls(expr, symbol_ref, export_names);
+      return;
+    }
+    let destructuring_targets = self.system_exported_destructuring_targets(expr);
+    if !destructuring_targets.is_empty() {
+      walk_js_mut::walk_expression(self, expr);
+      self.wrap_with_system_destructuring_exports(expr, &destructuring_targets);
+      return;
+    }
+
     // Handle keep_names for named class/function expressions in any expression context
     // (return statements, function args, array elements, etc.)
     if self.ctx.options.keep_names && self.ctx.runtime.id() != self.ctx.idx {
@@ -565,6 +578,7 @@ impl<'ast> VisitJsMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
         if self.try_rewrite_import_expression(expr) {
           // If the import expression is rewritten, we don't need to walk it again.
           // Otherwise, it might cause infinite recursion in some cases.
+          self.rewrite_import_expression_for_module_loader(expr);
           return;
         }
       }
@@ -593,6 +607,10 @@ impl<'ast> VisitJsMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
           }
         }
       }
+      ast::Expression::ImportMeta(_) if matches!(self.ctx.options.format, OutputFormat::System) => {
+        // module.meta
+        *expr = Expression::new_member_access_expr("module", "meta", self);
+      }
       ast::Expression::ImportMeta(import_meta) => {
         if !self.ctx.options.format.keep_esm_import_export_syntax() {
           self.record_surviving_import_meta(import_meta.span, EmptyImportMetaKind::Plain);
@@ -677,6 +695,10 @@ impl<'ast> VisitJsMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
     self.rewrite_import_meta_hot(expr);
 
     walk_js_mut::walk_expression(self, expr);
+
+    if matches!(expr, ast::Expression::ImportExpression(_)) {
+      self.rewrite_import_expression_for_module_loader(expr);
+    }
   }
 
   fn visit_jsx_element_name(&mut self, it: &mut ast::JSXElementName<'ast>) {
@@ -12,7 +12,7 @@ use oxc::{
   span::{GetSpan, GetSpanMut, SPAN, Span},
 };
 use rolldown_common::{
-  AstScopes, Chunk, ChunkIdx, ChunkKind, ConcatenateWrappedModuleKind, ExportsKind,
+  AstScopes, Chunk, ChunkIdx, ChunkKind, ConcatenateWrappedModuleKind, EcmaViewMeta, ExportsKind,
   ImportRecordIdx, ImportRecordMeta, InlineConstMode, MemberExprRefResolution, Module, ModuleIdx,
   ModuleNamespaceIncludedReason, ModuleType, NamespaceAlias, NormalModule, OutputExports,
   OutputFormat, Platform, RenderedConcatenatedModuleParts, Specifier, SymbolRef, WrapKind,
@@ -38,6 +38,7 @@ use rolldown_utils::indexmap::{FxIndexMap, FxIndexSet};
 use rustc_hash::{FxHashMap, FxHashSet};
 use sugar_path::SugarPath;
 
+use crate::ast_scanner::commonjs_to_esm::{CommonjsExportTarget, commonjs_export_target};
 use crate::esm_init_obligations::{
   ObligationPurpose, WrappedEsmInitTarget, WrappedEsmInitTargetContext,
   collect_entry_reexported_wrapper_inits, collect_wrapped_esm_init_targets_for_import_record,
@@ -48,6 +49,7 @@ use crate::utils;
 use crate::utils::external_import_interop::import_record_needs_interop;
 
 mod hmr;
+mod module_loader;
 mod rename;
 
 /// Helper enum for `try_rewrite_cjs_member_expr_assignment_target` to handle both static and computed member properties.
@@ -397,6 +399,32 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       return true;
     };
     let importee_linking_info = &self.ctx.linking_infos[importee.idx];
+    if rec.meta.contains(ImportRecordMeta::IsDeferred)
+      && matches!(importee_linking_info.wrap_kind(), WrapKind::Esm)
+    {
+      // `var import_foo = __importDefer(init_foo, foo_exports)`
+      let (init_ref_expr, _) = self.finalized_expr_for_symbol_ref(
+        importee_linking_info.wrapper_ref.unwrap(),
+        false,
+        false,
+      );
+      let (namespace_ref_expr, _) =
+        self.finalized_expr_for_symbol_ref(importee.namespace_object_ref, false, false);
+      let import_defer_call = ast::Expression::new_call_expression(
+        SPAN,
+        self.finalized_expr_for_runtime_symbol("__importDefer"),
+        None,
+        [ast::Argument::from(init_ref_expr), ast::Argument::from(namespace_ref_expr)],
+        false,
+        self,
+      );
+      *stmt = Statement::new_var_decl(
+        self.canonical_name_for(rec.namespace_ref),
+        import_defer_call,
+        self,
+      );
+      return false;
+    }
     // A consumer-local barrel is a routing waypoint even when linking already gave it an ESM
     // interop wrapper (for example, because an outer CommonJS module requires its namespace).
     // Calling that shared wrapper would re-couple every retained route and, after CJS carrier
@@ -588,7 +616,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       }
     } else {
       match self.ctx.options.format {
-        rolldown_common::OutputFormat::Cjs => {
+        format if format.uses_chunk_namespace_bindings() => {
           let chunk_idx_of_canonical_symbol = canonical_symbol.chunk_idx.unwrap_or_else(|| {
             // Scoped symbols don't get assigned a `ChunkIdx`. There are skipped for performance reason, because they are surely
             // belong to the chunk they are declared in and won't link to other chunks.
@@ -645,6 +673,9 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
   /// Generates the expression for accessing a symbol from another chunk in CJS output.
   ///
+  /// AMD and SystemJS output bind other chunks the same way, through the factory parameters and
+  /// setters respectively.
+  ///
   /// In CJS output, cross-chunk symbol access uses `require()` bindings:
   /// - `import { foo } from 'foo'; console.log(foo);` becomes `console.log(require_foo.foo);`
   ///
@@ -876,14 +907,24 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       ret.extend(var_decl.id.get_binding_identifiers().iter().map(|item| item.name));
       // Turn `var ... = ...` to `... = ...`
       let init_expr = var_decl.init?;
+      let system_export = match &var_decl.id {
+        ast::BindingPattern::BindingIdentifier(id) => self.system_exported_binding_named(&id.name),
+        _ => None,
+      };
       let left = var_decl.id.into_assignment_target(self);
-      Some(ast::Expression::new_assignment_expression(
+      let mut assign_expr = ast::Expression::new_assignment_expression(
         SPAN,
         ast::AssignmentOperator::Assign,
         left,
         init_expr,
         self,
-      ))
+      );
+      // The declaration is hoisted out of a lazily-run wrapper, so the initial value has to be
+      // published once the wrapper actually runs.
+      if let Some((symbol_ref, export_names)) = system_export {
+        self.wrap_with_system_exports_calls(&mut assign_expr, symbol_ref, export_names);
+      }
+      Some(assign_expr)
     });
     Some((
       ast::Expression::new_sequence_expression(
@@ -1060,7 +1101,11 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
           });
           re_export_external_stmts = Some(stmts.collect::<Vec<_>>());
         }
-        OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd => {
+        OutputFormat::System => {
+          re_export_external_stmts =
+            Some(self.system_re_export_external_stmts(export_all_externals_rec_ids));
+        }
+        OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd => {
           let stmts = export_all_externals_rec_ids.iter().copied().filter_map(|idx| {
             // importer_exports
             let (importer_namespace_ref_expr, _) = self.finalized_expr_for_symbol_ref(
@@ -1163,7 +1208,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
           } else {
             // If we don't support polyfill `import.meta.url` in this platform and format, we just keep it as it is
             // so users may handle it in their own way.
-            if !self.ctx.options.format.keep_esm_import_export_syntax() {
+            if !self.ctx.options.format.supports_import_meta() {
               // Claim the span before walking reaches the bare `import.meta`, so the warning knows
               // this is an `import.meta.url`
               self.record_surviving_import_meta(
@@ -1256,9 +1301,7 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       let absolute_asset_file_name = asset_file_name.absolutize_with(output_dir);
       let relative_asset_path = &self.ctx.chunk.relative_path_for(&absolute_asset_file_name);
 
-      if !self.ctx.options.format.keep_esm_import_export_syntax()
-        && !self.can_polyfill_import_meta_url()
-      {
+      if !self.ctx.options.format.supports_import_meta() && !self.can_polyfill_import_meta_url() {
         // Record the origin before walking the generated `import.meta.url`. The generic URL
         // handler reaches the same span later, and first-insert-wins preserves this richer kind.
         self.record_surviving_import_meta(original_expr_span, EmptyImportMetaKind::RolldownFileUrl);
@@ -1333,11 +1376,17 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       _ => return None,
     }
 
-    let importee =
-      rec.resolved_module.and_then(|module_idx| self.ctx.modules[module_idx].as_normal())?;
+    // Worker entries are not part of the module graph, so they are looked up by their resolved id.
+    let worker_id = self.ctx.module.worker_references.get(&rec_idx);
+    let importee_id = match worker_id {
+      Some(worker_id) => worker_id,
+      None => {
+        &rec.resolved_module.and_then(|module_idx| self.ctx.modules[module_idx].as_normal())?.id
+      }
+    };
 
     // Look up the emitted asset filename via the FileEmitter bridge
-    let ref_id = self.ctx.file_emitter.file_ref_for_module(&importee.id)?;
+    let ref_id = self.ctx.file_emitter.file_ref_for_module(importee_id)?;
     let filename = self.ctx.file_emitter.get_file_name(&ref_id).ok()?;
     let abs_path = self.ctx.options.cwd.join(&self.ctx.options.out_dir).join(filename.as_str());
     let import_path = self.ctx.chunk.relative_path_for(abs_path.as_path());
@@ -1348,6 +1397,25 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
       None,
       self,
     );
+
+    // Non-esm formats have no `import.meta.url`. Unless it is polyfilled for node, resolve worker
+    // urls against the url of the running chunk: the `<script>` that loaded it in a document, or
+    // `self.location` inside a worker. `document.currentScript` is only set while the script
+    // is evaluated synchronously, so fall back to the chunk's filename relative to the page.
+    if worker_id.is_some()
+      && !self.ctx.options.format.keep_esm_import_export_syntax()
+      && !self.can_polyfill_import_meta_url()
+    {
+      let chunk_filename =
+        self.ctx.chunk.preliminary_filename.as_deref().map_or("", |f| f.as_str());
+      let code = format!(
+        "typeof document === 'undefined' ? self.location.href : document.currentScript && document.currentScript.src || new URL({}, document.baseURI).href",
+        serde_json::to_string(chunk_filename).expect("a string should always be serializable"),
+      );
+      if let Ok(base_url) = parse_injected_expression(self.allocator(), &code) {
+        expr.arguments[1] = ast::Argument::from(base_url);
+      }
+    }
     None
   }
 
@@ -1607,6 +1675,27 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
                   ))
                 }
               }
+              _ if self.ctx.linking_infos[importee.idx].wrapper_ref.is_none()
+                && rec.meta.contains(ImportRecordMeta::HoistedRequire) =>
+              {
+                // The importee is evaluated ahead of the importer, so only its exports are left.
+                if rec.meta.contains(ImportRecordMeta::IsRequireUnused) {
+                  Some(ast::Expression::new_void_0(SPAN, self))
+                } else {
+                  // `__toCommonJS(xxx_exports)`
+                  let (namespace_object_ref_expr, _) =
+                    self.finalized_expr_for_symbol_ref(importee.namespace_object_ref, false, false);
+                  let to_commonjs_expr = self.finalized_expr_for_runtime_symbol("__toCommonJS");
+                  Some(ast::Expression::new_call_expression(
+                    SPAN,
+                    to_commonjs_expr,
+                    None,
+                    [ast::Argument::from(namespace_object_ref_expr)],
+                    false,
+                    self,
+                  ))
+                }
+              }
               _ => {
                 // Rewrite `require(...)` to `require_xxx(...)` or `(init_xxx(), __toCommonJS(xxx_exports))`
                 let importee_linking_info = &self.ctx.linking_infos[importee.idx];
@@ -2079,7 +2168,9 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
                   rolldown_common::OutputFormat::Esm
                   | rolldown_common::OutputFormat::Iife
                   | rolldown_common::OutputFormat::Umd
-                  | rolldown_common::OutputFormat::Cjs => {
+                  | rolldown_common::OutputFormat::Cjs
+                  | rolldown_common::OutputFormat::Amd
+                  | rolldown_common::OutputFormat::System => {
                     // Just remove the statement
                     return;
                   }
@@ -2680,13 +2771,13 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
         // The importee is bundled, so the second argument is inert and is dropped here. Import
         // attributes only reach the output for external imports, where `ecmascript/format/esm.rs`
-        // renders them from `import_attribute_map`; for an internal module they select no loader
-        // and do not affect module identity, and a static attributed import of an internal module
-        // already drops them. Dropping them makes `import(spec, options)` produce byte-identical
-        // output to `import(spec)`: the specifier below is redirected at the chunk that actually
-        // holds the module, and the merged-entry and CJS rewrites apply as usual. Keeping the
-        // argument instead would emit a specifier the source wrote for a file the bundle never
-        // emits.
+        // renders them from `import_attribute_map`; only static imports use them to select the
+        // module type of an internal module, and a static attributed import of an internal module
+        // drops them from the output as well. Dropping them makes `import(spec, options)` produce
+        // byte-identical output to `import(spec)`: the specifier below is redirected at the chunk
+        // that actually holds the module, and the merged-entry and CJS rewrites apply as usual.
+        // Keeping the argument instead would emit a specifier the source wrote for a file the
+        // bundle never emits.
         //
         // Trade accepted: an options *expression* with side effects is no longer evaluated when
         // the target is internal. The argument is spec'd as an object literal carrying `with`, and
@@ -2933,6 +3024,70 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
     Some(())
   }
+
+  /// For a CommonJS module converted by `optimization.commonjsToEsm`, rewrites the top-level
+  /// export writes to declarations of the bindings it now exports, e.g. `exports.foo = 1` becomes
+  /// `var foo = 1` and `module.exports = { a, b: 2 }` becomes `var a$1 = a, b = 2`.
+  fn try_rewrite_commonjs_export(&self, it: &mut Statement<'ast>) -> Option<()> {
+    if !self.ctx.module.meta.contains(EcmaViewMeta::CommonjsToEsm)
+      || !self.state.contains(TraverseState::IsRootLevel)
+    {
+      return None;
+    }
+    let Statement::ExpressionStatement(expr_stmt) = it else {
+      return None;
+    };
+    let Expression::AssignmentExpression(assign_expr) = &mut expr_stmt.expression else {
+      return None;
+    };
+    let declarations = match commonjs_export_target(&assign_expr.left)? {
+      CommonjsExportTarget::ExportsProp(name) => {
+        let exported = self.ctx.module.named_exports.get(name)?.referenced;
+        vec![(exported, assign_expr.right.take_in(self))]
+      }
+      CommonjsExportTarget::ModuleExports => {
+        let Expression::ObjectExpression(obj_expr) = &mut assign_expr.right else {
+          return None;
+        };
+        obj_expr
+          .properties
+          .iter_mut()
+          .filter_map(|prop| {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+              return None;
+            };
+            let exported = self.ctx.module.named_exports.get(prop.key.static_name()?.as_ref())?;
+            Some((exported.referenced, prop.value.take_in(self)))
+          })
+          .collect()
+      }
+    };
+    let span = expr_stmt.span;
+    *it = Statement::new_variable_declaration(
+      span,
+      VariableDeclarationKind::Var,
+      allocator::Vec::from_iter_in(
+        declarations.into_iter().map(|(exported, init)| {
+          ast::VariableDeclarator::new(
+            SPAN,
+            ast::BindingPattern::new_binding_identifier(
+              SPAN,
+              ast::Str::from_str_in(self.canonical_name_for(exported), self),
+              self,
+            ),
+            None,
+            Some(init),
+            false,
+            self,
+          )
+        }),
+        self,
+      ),
+      false,
+      self,
+    );
+    Some(())
+  }
 }
 
 impl<'ast> GetAstBuilder<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
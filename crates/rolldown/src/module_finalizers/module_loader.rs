@@ -0,0 +1,331 @@
+use oxc::{
+  allocator::{GetAllocator, IntoIn, ReplaceWith, TakeIn},
+  ast::ast::{
+    self, ArrowFunctionBody, AssignmentTarget, BinaryOperator, BindingPattern, CallExpression,
+    Expression, FormalParameter, FormalParameterKind, FormalParameters, NumberBase,
+    SimpleAssignmentTarget, UpdateOperator,
+  },
+  span::SPAN,
+};
+use oxc_str::CompactStr;
+use rolldown_common::{ImportRecordIdx, Module, OutputFormat, SymbolRef};
+use rolldown_ecmascript_utils::{CallExpressionFactoryExt as _, ExpressionFactoryExt as _};
+use rolldown_utils::ecmascript::is_relative_specifier;
+
+use super::ScopeHoistingFinalizer;
+
+impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
+  /// Returns the binding updated by `x = ...`, `++x` or `x++` along with the names the chunk exports
+  /// it under, if the output format is SystemJS and the binding is exported by the current chunk.
+  ///
+  /// SystemJS has no live bindings of its own: importers only see a new value once the exporter
+  /// pushes it through `exports(name, value)`.
+  pub(super) fn system_exported_update_target(
+    &self,
+    expr: &Expression<'ast>,
+  ) -> Option<(SymbolRef, &'me [CompactStr])> {
+    if !matches!(self.ctx.options.format, OutputFormat::System) {
+      return None;
+    }
+    let ident = match expr {
+      Expression::AssignmentExpression(assign_expr) => match &assign_expr.left {
+        AssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+        _ => return None,
+      },
+      Expression::UpdateExpression(update_expr) => match &update_expr.argument {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => ident,
+        _ => return None,
+      },
+      _ => return None,
+    };
+    self.system_exported_binding(ident)
+  }
+
+  /// Same as [`Self::system_exported_update_target`], for the bindings a destructuring assignment
+  /// like `[a, b] = [b, a]` updates.
+  pub(super) fn system_exported_destructuring_targets(
+    &self,
+    expr: &Expression<'ast>,
+  ) -> Vec<(SymbolRef, &'me [CompactStr])> {
+    if !matches!(self.ctx.options.format, OutputFormat::System) {
+      return vec![];
+    }
+    let Expression::AssignmentExpression(assign_expr) = expr else {
+      return vec![];
+    };
+    if !matches!(
+      assign_expr.left,
+      AssignmentTarget::ArrayAssignmentTarget(_) | AssignmentTarget::ObjectAssignmentTarget(_)
+    ) {
+      return vec![];
+    }
+    let mut idents = vec![];
+    collect_assignment_target_identifiers(&assign_expr.left, &mut idents);
+    idents.into_iter().filter_map(|ident| self.system_exported_binding(ident)).collect()
+  }
+
+  fn system_exported_binding(
+    &self,
+    ident: &ast::IdentifierReference<'ast>,
+  ) -> Option<(SymbolRef, &'me [CompactStr])> {
+    let symbol_id = self.scope.scoping().get_reference(ident.reference_id.get()?).symbol_id()?;
+    let symbol_ref: SymbolRef = (self.ctx.idx, symbol_id).into();
+    if !symbol_ref.is_declared_in_root_scope(self.ctx.symbol_db) {
+      return None;
+    }
+    let canonical_ref = self.ctx.symbol_db.canonical_ref_for(symbol_ref);
+    let export_names = self.ctx.chunk.exports_to_other_chunks.get(&canonical_ref)?;
+    Some((canonical_ref, export_names.as_slice()))
+  }
+
+  /// Same as [`Self::system_exported_update_target`], for a root-scope binding of the current
+  /// module that has already been renamed to `canonical_name` and lost its symbol.
+  pub(super) fn system_exported_binding_named(
+    &self,
+    canonical_name: &str,
+  ) -> Option<(SymbolRef, &'me [CompactStr])> {
+    if !matches!(self.ctx.options.format, OutputFormat::System) {
+      return None;
+    }
+    self.ctx.chunk.exports_to_other_chunks.iter().find_map(|(symbol_ref, export_names)| {
+      (symbol_ref.owner == self.ctx.idx && self.canonical_name_for(*symbol_ref) == canonical_name)
+        .then_some((*symbol_ref, export_names.as_slice()))
+    })
+  }
+
+  /// - `x = 1` -> `exports("x", x = 1)`
+  /// - `++x` -> `exports("x", ++x)`
+  /// - `x++` -> `(exports("x", x + 1), x++)`
+  pub(super) fn wrap_with_system_exports_calls(
+    &self,
+    expr: &mut Expression<'ast>,
+    symbol_ref: SymbolRef,
+    export_names: &[CompactStr],
+  ) {
+    let wrap = |value: Expression<'ast>| {
+      export_names
+        .iter()
+        .fold(value, |value, name| Expression::new_system_exports_call(name, value, self))
+    };
+    match expr {
+      Expression::UpdateExpression(update_expr) if !update_expr.prefix => {
+        let operator = match update_expr.operator {
+          UpdateOperator::Increment => BinaryOperator::Addition,
+          UpdateOperator::Decrement => BinaryOperator::Subtraction,
+        };
+        let next_value = Expression::new_binary_expression(
+          SPAN,
+          Expression::new_id_ref_expr(SPAN, self.canonical_name_for(symbol_ref), self),
+          operator,
+          Expression::new_numeric_literal(SPAN, 1.0, Some("1".into()), NumberBase::Decimal, self),
+          self,
+        );
+        let exports_call = wrap(next_value);
+        expr.replace_with(|update_expr| {
+          Expression::new_seq_in_parens(exports_call, update_expr, self)
+        });
+      }
+      _ => {
+        expr.replace_with(wrap);
+      }
+    }
+  }
+
+  /// `[a, b] = value` -> `((v) => (exports("a", a), exports("b", b), v))([a, b] = value)`, which
+  /// publishes the updated bindings and still evaluates to `value`.
+  pub(super) fn wrap_with_system_destructuring_exports(
+    &self,
+    expr: &mut Expression<'ast>,
+    targets: &[(SymbolRef, &[CompactStr])],
+  ) {
+    let canonical_names = targets
+      .iter()
+      .map(|(symbol_ref, _)| self.canonical_name_for(*symbol_ref))
+      .collect::<Vec<_>>();
+    // The parameter must not shadow the bindings it publishes.
+    let mut param_name = String::from("v");
+    while canonical_names.iter().any(|name| *name == param_name) {
+      param_name.push('$');
+    }
+    let param_name = ast::Str::from_str_in(&param_name, self);
+
+    let mut expressions = oxc::allocator::Vec::new_in(self);
+    for ((_, export_names), canonical_name) in targets.iter().zip(&canonical_names) {
+      for name in *export_names {
+        expressions.push(Expression::new_system_exports_call(
+          name,
+          Expression::new_id_ref_expr(SPAN, canonical_name, self),
+          self,
+        ));
+      }
+    }
+    expressions.push(Expression::new_identifier(SPAN, param_name, self));
+    let callee = Expression::new_parenthesized_expression(
+      SPAN,
+      Expression::new_arrow_function_expression(
+        SPAN,
+        false,
+        None,
+        FormalParameters::boxed(
+          SPAN,
+          FormalParameterKind::ArrowFormalParameters,
+          [FormalParameter::new(
+            SPAN,
+            [],
+            BindingPattern::new_binding_identifier(SPAN, param_name, self),
+            None,
+            None,
+            false,
+            None,
+            false,
+            false,
+            self,
+          )],
+          None,
+          self,
+        ),
+        None,
+        ArrowFunctionBody::from(Expression::new_parenthesized_expression(
+          SPAN,
+          Expression::new_sequence_expression(SPAN, expressions, self),
+          self,
+        )),
+        self,
+      ),
+      self,
+    );
+    expr
+      .replace_with(|assign_expr| Expression::new_call_with_arg(callee, assign_expr, false, self));
+  }
+
+  /// Lowers a dynamic `import()` that survived finalization to the module loader of the output
+  /// format: `module.import(...)` for SystemJS and `require([...], ...)` for AMD.
+  ///
+  /// `import()` rewritten to a `.then(...)` chain keeps the original call as the callee object, so
+  /// the chain is followed down to it.
+  pub(super) fn rewrite_import_expression_for_module_loader(&self, expr: &mut Expression<'ast>) {
+    if !matches!(self.ctx.options.format, OutputFormat::Amd | OutputFormat::System) {
+      return;
+    }
+    match expr {
+      Expression::CallExpression(call_expr) => {
+        self.rewrite_import_expression_for_module_loader(&mut call_expr.callee);
+      }
+      Expression::StaticMemberExpression(member_expr) => {
+        self.rewrite_import_expression_for_module_loader(&mut member_expr.object);
+      }
+      // Import attributes only matter to a native loader, so such calls are left untouched.
+      Expression::ImportExpression(import_expr) if import_expr.options.is_none() => {
+        let mut source = import_expr.source.take_in(self);
+        *expr = if matches!(self.ctx.options.format, OutputFormat::System) {
+          // module.import('./foo.js')
+          Expression::new_call_with_arg(
+            Expression::new_member_access_expr("module", "import", self),
+            source,
+            false,
+            self,
+          )
+        } else {
+          // AMD module ids of other chunks don't carry the `.js` extension, or the loader would
+          // resolve them against the page instead of the importing module.
+          if let Expression::StringLiteral(literal) = &mut source
+            && is_relative_specifier(&literal.value)
+            && let Some(module_id) = literal.value.strip_suffix(".js")
+          {
+            literal.value = ast::Str::from_str_in(module_id, self);
+            literal.raw = None;
+          }
+          // new Promise((resolve, reject) => require(['./foo'], resolve, reject))
+          Expression::new_amd_require_promise(source, self)
+        };
+      }
+      _ => {}
+    }
+  }
+
+  /// Generates `__reExport(importer_exports, ext)` for every `export * from 'external'` of the
+  /// module in SystemJS output.
+  ///
+  /// The external namespace is bound by a `System.register` setter, so there is no `require` call
+  /// to read it from.
+  pub(super) fn system_re_export_external_stmts(
+    &self,
+    export_all_externals_rec_ids: &[ImportRecordIdx],
+  ) -> Vec<ast::Statement<'ast>> {
+    export_all_externals_rec_ids
+      .iter()
+      .copied()
+      .filter_map(|idx| {
+        let (importer_namespace_ref_expr, _) =
+          self.finalized_expr_for_symbol_ref(self.ctx.module.namespace_object_ref, false, false);
+        let rec = &self.ctx.module.import_records[idx];
+        let Some(Module::External(importee)) =
+          rec.resolved_module.map(|module_idx| &self.ctx.modules[module_idx])
+        else {
+          return None;
+        };
+        let re_export_call_expr = CallExpression::new_re_export_call(
+          self.finalized_expr_for_runtime_symbol("__reExport"),
+          importer_namespace_ref_expr,
+          Expression::new_id_ref_expr(SPAN, self.canonical_name_for(importee.namespace_ref), self),
+          self,
+        );
+        Some(ast::Statement::new_expression_statement(
+          SPAN,
+          Expression::CallExpression(re_export_call_expr.into_in(self.allocator())),
+          self,
+        ))
+      })
+      .collect()
+  }
+}
+
+fn collect_assignment_target_identifiers<'a, 'ast>(
+  target: &'a AssignmentTarget<'ast>,
+  idents: &mut Vec<&'a ast::IdentifierReference<'ast>>,
+) {
+  match target {
+    AssignmentTarget::AssignmentTargetIdentifier(ident) => idents.push(ident),
+    AssignmentTarget::ArrayAssignmentTarget(array) => {
+      for element in array.elements.iter().flatten() {
+        collect_maybe_default_identifiers(element, idents);
+      }
+      if let Some(rest) = &array.rest {
+        collect_assignment_target_identifiers(&rest.target, idents);
+      }
+    }
+    AssignmentTarget::ObjectAssignmentTarget(object) => {
+      for property in &object.properties {
+        match property {
+          ast::AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+            idents.push(&property.binding);
+          }
+          ast::AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+            collect_maybe_default_identifiers(&property.binding, idents);
+          }
+        }
+      }
+      if let Some(rest) = &object.rest {
+        collect_assignment_target_identifiers(&rest.target, idents);
+      }
+    }
+    // Member expressions don't update bindings.
+    _ => {}
+  }
+}
+
+fn collect_maybe_default_identifiers<'a, 'ast>(
+  target: &'a ast::AssignmentTargetMaybeDefault<'ast>,
+  idents: &mut Vec<&'a ast::IdentifierReference<'ast>>,
+) {
+  match target {
+    ast::AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(with_default) => {
+      collect_assignment_target_identifiers(&with_default.binding, idents);
+    }
+    _ => {
+      if let Some(target) = target.as_assignment_target() {
+        collect_assignment_target_identifiers(target, idents);
+      }
+    }
+  }
+}
@@ -105,6 +105,10 @@ impl<'ast> ScopeHoistingFinalizer<'_, 'ast> {
       return Some(self.finalized_expr_for_runtime_symbol("__require"));
     }
 
+    if self.ctx.module.glob_callee_set.contains(&ident_ref.node_id()) {
+      return Some(self.finalized_expr_for_runtime_symbol("__glob"));
+    }
+
     if let Some(new_expr) = self.generate_finalized_expr_for_reference(ident_ref, is_callee) {
       Some(new_expr)
     } else {
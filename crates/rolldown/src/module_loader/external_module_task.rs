@@ -7,6 +7,7 @@ use rolldown_common::{
 };
 use rolldown_error::BuildResult;
 use rolldown_utils::{commondir, ecmascript::legitimize_identifier_name, indexmap::FxIndexSet};
+use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
 use rolldown_fs::FileSystem;
@@ -61,6 +62,7 @@ impl<Fs: FileSystem> ExternalModuleTask<Fs> {
         importers: FxIndexSet::default(),
         dynamic_importers: FxIndexSet::default(),
         imported_ids: FxIndexSet::default(),
+        imported_ids_by_specifier: FxHashMap::default(),
         dynamically_imported_ids: FxIndexSet::default(),
         exports: vec![],
         input_format: ExportsKind::None,
@@ -88,6 +90,10 @@ impl<Fs: FileSystem> ExternalModuleTask<Fs> {
       resolved_id.id.as_arc_str().clone()
     };
     let legitimized_identifier_name = legitimize_identifier_name(&identifier_name);
+    let interop = match &self.ctx.options.interop {
+      Some(interop) => interop.call(resolved_id.id.as_str()).await?,
+      None => None,
+    };
     let msg = ModuleLoaderMsg::ExternalModuleDone(Box::new(ExternalModuleTaskResult {
       idx: self.module_idx,
       id: resolved_id.id.clone(),
@@ -95,6 +101,7 @@ impl<Fs: FileSystem> ExternalModuleTask<Fs> {
       identifier_name: legitimized_identifier_name.into(),
       side_effects: external_module_side_effects,
       need_renormalize_render_path,
+      interop,
     }));
     self.ctx.tx.send(msg).expect(
       "ModuleLoader channel closed while sending external module completion - main thread terminated unexpectedly"
@@ -38,6 +38,7 @@ use crate::{SharedOptions, SharedResolver};
 
 use super::external_module_task::ExternalModuleTask;
 use super::module_task::ModuleTask;
+use super::persistent_cache::PersistentCache;
 use super::runtime_module_task::RuntimeModuleTask;
 use super::task_context::{TaskContext, TaskContextMeta};
 
@@ -199,7 +200,9 @@ impl<'a, Fs: FileSystem + Clone + 'static> ModuleLoader<'a, Fs> {
     // the consumer may need the JS thread — pinned by `block_on` — to run
     // plugin hooks first. Keeping the channel unbounded removes that edge.
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-    let shared_context = Arc::new(TaskContext { options, tx, resolver, fs, plugin_driver, meta });
+    let persistent_cache = PersistentCache::new(&options, &plugin_driver);
+    let shared_context =
+      Arc::new(TaskContext { options, tx, resolver, fs, plugin_driver, meta, persistent_cache });
 
     let importers = std::mem::take(&mut cache.importers);
     let intermediate_normal_modules = IntermediateNormalModules::new(is_full_scan, importers);
@@ -317,7 +320,8 @@ impl<'a, Fs: FileSystem + Clone + 'static> ModuleLoader<'a, Fs> {
     assert_module_type: Option<&ModuleType>,
     user_defined_entries: &Arc<Vec<(Option<ArcStr>, ResolvedId)>>,
   ) -> ModuleIdx {
-    let idx = match self.cache.module_id_to_idx.get(&resolved_id.id).copied() {
+    let module_id = resolved_id.module_id();
+    let idx = match self.cache.module_id_to_idx.get(&module_id).copied() {
       Some(VisitState::Seen(idx)) => {
         if self.flat_options.is_lazy_barrel_enabled() && owner.is_none() {
           self.request_all_exports_for_entry(idx, user_defined_entries);
@@ -327,7 +331,7 @@ impl<'a, Fs: FileSystem + Clone + 'static> ModuleLoader<'a, Fs> {
       Some(VisitState::Invalidate(idx)) => {
         // Full scan mode the idx will never be invalidated right?
         self.intermediate_normal_modules.alloc_ecma_module_idx_sparse(idx);
-        self.cache.module_id_to_idx.insert(resolved_id.id.clone(), VisitState::Seen(idx));
+        self.cache.module_id_to_idx.insert(module_id, VisitState::Seen(idx));
         idx
       }
       None if !self.is_full_scan => {
@@ -335,13 +339,13 @@ impl<'a, Fs: FileSystem + Clone + 'static> ModuleLoader<'a, Fs> {
         let len = self.cache.module_id_to_idx.len();
         let idx = self.intermediate_normal_modules.alloc_ecma_module_idx_sparse(len.into());
         self.new_added_modules_from_partial_scan.insert(idx);
-        self.new_module_ids.push(resolved_id.id.clone());
-        self.cache.module_id_to_idx.insert(resolved_id.id.clone(), VisitState::Seen(idx));
+        self.new_module_ids.push(module_id.clone());
+        self.cache.module_id_to_idx.insert(module_id, VisitState::Seen(idx));
         idx
       }
       None => {
         let idx = self.intermediate_normal_modules.alloc_ecma_module_idx();
-        self.cache.module_id_to_idx.insert(resolved_id.id.clone(), VisitState::Seen(idx));
+        self.cache.module_id_to_idx.insert(module_id, VisitState::Seen(idx));
         idx
       }
     };
@@ -353,13 +357,15 @@ impl<'a, Fs: FileSystem + Clone + 'static> ModuleLoader<'a, Fs> {
       let task = ExternalModuleTask::new(ctx, idx, resolved_id, Arc::clone(user_defined_entries));
       tokio::spawn(task.run().instrument(tracing::info_span!("external_module_task")));
     } else {
+      let assert_module_type =
+        assert_module_type.cloned().or_else(|| resolved_id.attributes_module_type.clone());
       let task = ModuleTask::new(
         ctx,
         idx,
         resolved_id,
         owner,
         is_user_defined_entry,
-        assert_module_type.cloned(),
+        assert_module_type,
         self.flat_options,
         self.magic_string_tx.clone(),
       );
@@ -509,9 +515,15 @@ impl<'a, Fs: FileSystem + Clone + 'static> ModuleLoader<'a, Fs> {
             && let Some(previous_module) =
               self.cache.get_snapshot().module_table.modules.get(module_idx)
           {
+            let css_import_records = previous_module
+              .as_normal()
+              .and_then(|module| module.css_view.as_ref())
+              .into_iter()
+              .flat_map(|css_view| &css_view.import_records);
             let resolved_deps = previous_module
               .import_records()
               .into_iter()
+              .chain(css_import_records)
               .filter_map(|r| r.resolved_module)
               .collect::<Vec<_>>();
             for dep_idx in resolved_deps {
@@ -568,6 +580,11 @@ impl<'a, Fs: FileSystem + Clone + 'static> ModuleLoader<'a, Fs> {
             }
 
             let is_external = resolved_id.external.is_external();
+            // Worker entries are bundled separately, see `Bundle::build_workers`.
+            if raw_rec.meta.contains(ImportRecordMeta::IsWorker) && !is_external {
+              import_records.push(raw_rec.into_resolved(None));
+              continue;
+            }
             let idx = self.try_spawn_new_task(
               resolved_id,
               Some(ModuleTaskOwner::new(normal_module, raw_rec.span)),
@@ -659,6 +676,7 @@ impl<'a, Fs: FileSystem + Clone + 'static> ModuleLoader<'a, Fs> {
             identifier_name,
             side_effects,
             need_renormalize_render_path,
+            interop,
           } = *task_result;
 
           self.symbol_ref_db.store_local_db(
@@ -674,6 +692,7 @@ impl<'a, Fs: FileSystem + Clone + 'static> ModuleLoader<'a, Fs> {
             side_effects,
             symbol_ref,
             need_renormalize_render_path,
+            interop,
           )));
 
           *self.intermediate_normal_modules.modules.get_mut(idx) = Some(external_module);
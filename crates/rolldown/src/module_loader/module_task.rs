@@ -4,25 +4,36 @@ use arcstr::ArcStr;
 use oxc::span::Span;
 
 use rolldown_common::{
-  ExportsKind, FlatOptions, ImportKind, ModuleIdx, ModuleInfo, ModuleLoaderMsg, ModuleType,
-  NormalModule, NormalModuleTaskResult, ResolvedId, SourceMapGenMsg, SourcemapChainElement,
-  StrOrBytes, try_extract_lazy_barrel_info,
+  ExportsKind, FlatOptions, ImportKind, ImportRecordMeta, ModuleId, ModuleIdx, ModuleInfo,
+  ModuleLoaderMsg, ModuleType, NormalModule, NormalModuleTaskResult, ResolvedId, SourceMapGenMsg,
+  SourcemapChainElement, StrOrBytes,
+  side_effects::{DeterminedSideEffects, HookSideEffects},
+  try_extract_lazy_barrel_info,
 };
 use rolldown_error::{
   BuildDiagnostic, BuildResult, DiagnosticOptions, EventKindSwitcher, UnloadableDependencyContext,
   downcast_napi_error_diagnostics,
 };
-use rolldown_utils::{ecmascript::legitimize_identifier_name, indexmap::FxIndexSet};
+use rolldown_plugin_wasm_module::SOURCE_PHASE_QUERY;
+use rolldown_utils::{concat_string, ecmascript::legitimize_identifier_name, indexmap::FxIndexSet};
+use rustc_hash::FxHashMap;
 
 use rolldown_fs::FileSystem;
 
 use crate::{
+  css::css_module_view_factory::{CreateCssViewReturn, create_css_view},
   ecmascript::ecma_module_view_factory::{CreateEcmaViewReturn, create_ecma_view},
   types::module_factory::{CreateModuleContext, CreateModuleViewArgs},
-  utils::{load_source::load_source, transform_source::transform_source},
+  utils::{
+    load_source::{get_module_loader_from_file_extension, load_source},
+    transform_source::transform_source,
+  },
 };
 
-use super::{resolve_utils::resolve_dependencies, task_context::TaskContext};
+use super::{
+  persistent_cache::PersistentCacheEntry, resolve_utils::resolve_dependencies,
+  task_context::TaskContext,
+};
 
 pub struct ModuleTaskOwner {
   source: ArcStr,
@@ -33,7 +44,10 @@ pub struct ModuleTaskOwner {
 impl ModuleTaskOwner {
   pub fn new(normal_module: &NormalModule, importee_span: Span) -> Self {
     Self {
-      source: normal_module.source.clone(),
+      source: normal_module
+        .css_view
+        .as_ref()
+        .map_or_else(|| normal_module.source.clone(), |css_view| css_view.source.clone()),
       importer_id: normal_module.stable_id.as_arc_str().clone(),
       importee_span,
     }
@@ -79,7 +93,7 @@ impl<Fs: FileSystem + Clone + 'static> ModuleTask<Fs> {
   #[tracing::instrument(name="NormalModuleTask::run", level = "trace", skip_all, fields(module_id = ?self.resolved_id.id))]
   pub async fn run(mut self) {
     if let Err(errs) = self.run_inner().await {
-      self.ctx.plugin_driver.mark_context_load_modules_loaded(self.resolved_id.id.clone());
+      self.ctx.plugin_driver.mark_context_load_modules_loaded(self.resolved_id.module_id());
       self
         .ctx
         .tx
@@ -89,7 +103,7 @@ impl<Fs: FileSystem + Clone + 'static> ModuleTask<Fs> {
   }
 
   async fn run_inner(&mut self) -> BuildResult<()> {
-    let id = self.resolved_id.id.clone();
+    let id = self.resolved_id.module_id();
 
     self.ctx.plugin_driver.set_module_info(
       &id,
@@ -100,6 +114,7 @@ impl<Fs: FileSystem + Clone + 'static> ModuleTask<Fs> {
         importers: FxIndexSet::default(),
         dynamic_importers: FxIndexSet::default(),
         imported_ids: FxIndexSet::default(),
+        imported_ids_by_specifier: FxHashMap::default(),
         dynamically_imported_ids: FxIndexSet::default(),
         exports: vec![],
         input_format: ExportsKind::None,
@@ -108,65 +123,112 @@ impl<Fs: FileSystem + Clone + 'static> ModuleTask<Fs> {
 
     let mut sourcemap_chain = vec![];
     let mut hook_side_effects = self.resolved_id.side_effects.take();
-    let (source, module_type) = self
+    let (source, module_type, loaded_source_len) = self
       .load_source(&mut sourcemap_chain, &mut hook_side_effects, self.magic_string_tx.clone())
       .await?;
 
     let stable_id = id.stabilize(&self.ctx.options.cwd);
 
-    if matches!(module_type, ModuleType::Css) {
-      Err(BuildDiagnostic::unsupported_feature(
-        match &source { StrOrBytes::Bytes(_) => ArcStr::new(), StrOrBytes::Str(s) => s.into() },
-        id.as_arc_str().clone(),
-        Span::empty(0),
-        "Bundling CSS is no longer supported (experimental support has been removed). See https://github.com/rolldown/rolldown/issues/4271 for details.".to_string())
-      )?;
-    }
+    // The js side of a css module is an empty module, the stylesheet goes to `CssView`.
+    let (source, css_source) = if matches!(module_type, ModuleType::Css) {
+      let css_source: ArcStr = source.try_into_string()?.into();
+      (StrOrBytes::Str(String::new()), Some(css_source))
+    } else {
+      (source, None)
+    };
 
     let mut warnings = vec![];
 
-    let CreateEcmaViewReturn { mut ecma_view, ecma_related, raw_import_records, tla_keyword_span } =
-      create_ecma_view(
-        &mut CreateModuleContext {
-          stable_id: &stable_id,
-          module_idx: self.module_idx,
-          plugin_driver: &self.ctx.plugin_driver,
-          resolved_id: &self.resolved_id,
-          options: &self.ctx.options,
-          warnings: &mut warnings,
-          module_type: module_type.clone(),
-          replace_global_define_config: self.ctx.meta.replace_global_define_config.clone(),
-          is_user_defined_entry: self.is_user_defined_entry,
-          flat_options: self.flat_options,
-        },
-        CreateModuleViewArgs { source, sourcemap_chain, hook_side_effects },
-      )
-      .await?;
+    let CreateEcmaViewReturn {
+      mut ecma_view,
+      ecma_related,
+      mut raw_import_records,
+      tla_keyword_span,
+    } = create_ecma_view(
+      &mut CreateModuleContext {
+        stable_id: &stable_id,
+        module_idx: self.module_idx,
+        plugin_driver: &self.ctx.plugin_driver,
+        resolved_id: &self.resolved_id,
+        options: &self.ctx.options,
+        warnings: &mut warnings,
+        module_type: module_type.clone(),
+        replace_global_define_config: self.ctx.meta.replace_global_define_config.clone(),
+        is_user_defined_entry: self.is_user_defined_entry,
+        flat_options: self.flat_options,
+        fs: &self.ctx.fs,
+      },
+      CreateModuleViewArgs { source, sourcemap_chain, hook_side_effects },
+    )
+    .await?;
 
-    let resolved_deps = resolve_dependencies(
+    let css_view = css_source.map(|css_source| {
+      let CreateCssViewReturn { css_view, raw_import_records: css_import_records } =
+        create_css_view(css_source, ecma_view.namespace_object_ref);
+      debug_assert!(raw_import_records.is_empty());
+      raw_import_records = css_import_records;
+      // An empty module is analyzed as side-effect free, but importing a stylesheet is what applies it.
+      if matches!(ecma_view.side_effects, DeterminedSideEffects::Analyzed(false)) {
+        ecma_view.side_effects = DeterminedSideEffects::Analyzed(true);
+      }
+      css_view
+    });
+
+    let mut resolved_deps = resolve_dependencies(
       &self.resolved_id,
       &self.ctx.options,
       &self.ctx.resolver,
       &self.ctx.plugin_driver,
       &raw_import_records,
-      ecma_view.source.clone(),
+      &ecma_view.import_attribute_map,
+      css_view.as_ref().map_or_else(|| ecma_view.source.clone(), |view| view.source.clone()),
       &mut warnings,
     )
     .await?;
 
-    for (record, info) in raw_import_records.iter().zip(&resolved_deps) {
+    for (record, info) in raw_import_records.iter().zip(&mut resolved_deps) {
+      if !record.meta.contains(ImportRecordMeta::IsSourcePhase) {
+        continue;
+      }
+      let is_wasm = !info.external.is_external()
+        && matches!(
+          get_module_loader_from_file_extension(&info.id, &self.ctx.options.module_types),
+          Some(ModuleType::Wasm)
+        );
+      if !is_wasm {
+        Err(BuildDiagnostic::unsupported_feature(
+          self.resolved_id.id.as_arc_str().clone(),
+          ecma_view.source.clone(),
+          record.span,
+          "Source phase imports are only supported for WebAssembly modules".to_string(),
+        ))?;
+      }
+      // The source phase is a different module that only exposes the compiled `WebAssembly.Module`.
+      info.id = ModuleId::new(concat_string!(info.id, SOURCE_PHASE_QUERY));
+    }
+
+    for ((rec_idx, record), info) in raw_import_records.iter_enumerated().zip(&resolved_deps) {
+      if record.meta.contains(ImportRecordMeta::IsWorker) && !info.external.is_external() {
+        ecma_view.worker_references.insert(rec_idx, info.id.clone());
+        continue;
+      }
       match record.kind {
-        ImportKind::Import | ImportKind::Require | ImportKind::NewUrl => {
-          ecma_view.imported_ids.insert(info.id.clone());
+        ImportKind::Import
+        | ImportKind::Require
+        | ImportKind::NewUrl
+        | ImportKind::AtImport
+        | ImportKind::UrlImport => {
+          ecma_view.imported_ids.insert(info.module_id());
+          ecma_view
+            .imported_ids_by_specifier
+            .insert(record.module_request.clone(), info.module_id());
         }
         ImportKind::DynamicImport => {
-          ecma_view.dynamically_imported_ids.insert(info.id.clone());
+          ecma_view.dynamically_imported_ids.insert(info.module_id());
         }
         ImportKind::HotAccept => {
           ecma_view.hmr_info.deps.insert(info.id.clone());
         }
-        // for a none css module, we should not have `at-import` or `url-import`
-        ImportKind::AtImport | ImportKind::UrlImport => unreachable!(),
       }
     }
 
@@ -217,7 +279,9 @@ impl<Fs: FileSystem + Clone + 'static> ModuleTask<Fs> {
       exec_order: u32::MAX,
       module_type: module_type.clone(),
       ecma_view,
+      css_view,
       originative_resolved_id: self.resolved_id.clone(),
+      loaded_source_len,
     };
 
     let module_info =
@@ -249,7 +313,32 @@ impl<Fs: FileSystem + Clone + 'static> ModuleTask<Fs> {
     sourcemap_chain: &mut Vec<SourcemapChainElement>,
     hook_side_effects: &mut Option<rolldown_common::side_effects::HookSideEffects>,
     magic_string_tx: Option<std::sync::mpsc::Sender<SourceMapGenMsg>>,
-  ) -> BuildResult<(StrOrBytes, ModuleType)> {
+  ) -> BuildResult<(StrOrBytes, ModuleType, usize)> {
+    let persistent_cache_key = self.persistent_cache_key(*hook_side_effects);
+    if let Some(entry) = persistent_cache_key.as_ref().and_then(|key| {
+      self.ctx.persistent_cache.as_ref().and_then(|cache| cache.get(&self.ctx.fs, key))
+    }) {
+      self.add_watch_files();
+      for file in &entry.watch_files {
+        self.ctx.plugin_driver.watch_files.insert(file.as_str().into());
+        self.ctx.plugin_driver.add_transform_dependency(self.module_idx, file);
+      }
+      let mut code = entry.code;
+      for emitted_file in entry.emitted_files {
+        let cached_reference_id = emitted_file.reference_id.clone();
+        let reference_id = self.ctx.plugin_driver.reemit_module_file(emitted_file).await?;
+        // Reference ids of assets without a file name are assigned in emission order, so they
+        // might differ from the ones baked into the cached code. Both have the same length, which
+        // keeps the cached sourcemaps valid.
+        if reference_id != cached_reference_id {
+          code = code.replace(cached_reference_id.as_str(), &reference_id);
+        }
+      }
+      *sourcemap_chain = entry.sourcemap_chain;
+      *hook_side_effects = entry.side_effects;
+      return Ok((code.into(), entry.module_type, entry.loaded_source_len));
+    }
+
     let mut is_read_from_disk = true;
     let result = load_source(
       &self.ctx.plugin_driver,
@@ -266,17 +355,7 @@ impl<Fs: FileSystem + Clone + 'static> ModuleTask<Fs> {
     if is_read_from_disk {
       // - Only add watch files for files read from disk.
       // - Add watch files as early as possible for we might be able to recover from build errors.
-      self.ctx.plugin_driver.watch_files.insert(self.resolved_id.id.as_arc_str().clone());
-      // The tsconfig governing this module affects its transform and
-      // resolution results, so watch it as well (#9598).
-      if let Some(tsconfig_path) = self
-        .ctx
-        .options
-        .transform_options
-        .discover_tsconfig_file(std::path::Path::new(self.resolved_id.id.as_str()))
-      {
-        self.ctx.plugin_driver.watch_files.insert(tsconfig_path.to_string_lossy().as_ref().into());
-      }
+      self.add_watch_files();
     }
     let (source, mut module_type) = result.map_err(|err| {
       downcast_napi_error_diagnostics(err).unwrap_or_else(|e| {
@@ -291,6 +370,10 @@ impl<Fs: FileSystem + Clone + 'static> ModuleTask<Fs> {
         )
       })
     })?;
+    let loaded_source_len = match &source {
+      StrOrBytes::Str(source) => source.len(),
+      StrOrBytes::Bytes(source) => source.len(),
+    };
     let source = match source {
       _ if self.resolved_id.id.starts_with("rolldown:") => source,
       StrOrBytes::Str(source) => {
@@ -310,6 +393,35 @@ impl<Fs: FileSystem + Clone + 'static> ModuleTask<Fs> {
       }
       StrOrBytes::Bytes(_) => source,
     };
+    let emitted_files = self
+      .ctx
+      .persistent_cache
+      .as_ref()
+      .and_then(|_| self.ctx.plugin_driver.module_emitted_files.remove(&self.module_idx))
+      .map(|(_, emitted_files)| emitted_files)
+      .unwrap_or_default();
+    if let (Some(cache), Some(key), StrOrBytes::Str(code)) =
+      (&self.ctx.persistent_cache, &persistent_cache_key, &source)
+    {
+      let watch_files = self
+        .ctx
+        .plugin_driver
+        .transform_dependencies
+        .get(&self.module_idx)
+        .map(|files| files.iter().map(|file| file.to_string()).collect())
+        .unwrap_or_default();
+      let entry = PersistentCacheEntry {
+        code: code.clone(),
+        loaded_source_len,
+        module_type: module_type.clone(),
+        side_effects: *hook_side_effects,
+        sourcemap_chain: std::mem::take(sourcemap_chain),
+        watch_files,
+        emitted_files,
+      };
+      cache.set(&self.ctx.fs, key, &entry);
+      *sourcemap_chain = entry.sourcemap_chain;
+    }
     if let ModuleType::Custom(_) = module_type {
       // TODO: should provide some diagnostics for user how they should handle the module type.
       // e.g.
@@ -319,6 +431,40 @@ impl<Fs: FileSystem + Clone + 'static> ModuleTask<Fs> {
         self.resolved_id.id
       ))?;
     }
-    Ok((source, module_type))
+    Ok((source, module_type, loaded_source_len))
+  }
+
+  fn add_watch_files(&self) {
+    self.ctx.plugin_driver.watch_files.insert(self.resolved_id.id.as_arc_str().clone());
+    // The tsconfig governing this module affects its transform and
+    // resolution results, so watch it as well (#9598).
+    if let Some(tsconfig_path) = self
+      .ctx
+      .options
+      .transform_options
+      .discover_tsconfig_file(std::path::Path::new(self.resolved_id.id.as_str()))
+    {
+      self.ctx.plugin_driver.watch_files.insert(tsconfig_path.to_string_lossy().as_ref().into());
+    }
+  }
+
+  fn persistent_cache_key(&self, side_effects: Option<HookSideEffects>) -> Option<String> {
+    let cache = self.ctx.persistent_cache.as_ref()?;
+    // Sourcemaps sent through the native magic string channel are attached after the scan,
+    // so they would be missing from the entry.
+    if self.magic_string_tx.is_some() {
+      return None;
+    }
+    // Asset, copy, wasm and html modules emit files as a side effect of their `load` hook.
+    let module_type = self.asserted_module_type.clone().or_else(|| {
+      get_module_loader_from_file_extension(&self.resolved_id.id, &self.ctx.options.module_types)
+    });
+    if matches!(
+      module_type,
+      Some(ModuleType::Asset | ModuleType::Copy | ModuleType::Wasm | ModuleType::Html)
+    ) {
+      return None;
+    }
+    cache.key_for(&self.ctx.fs, &self.resolved_id, self.asserted_module_type.as_ref(), side_effects)
   }
 }
@@ -0,0 +1,333 @@
+use std::path::{Path, PathBuf};
+
+use rolldown_common::{
+  EmittedAsset, ModuleType, NormalizedBundlerOptions, PluginIdx, ResolvedId, SourcemapChainElement,
+  StrOrBytes, side_effects::HookSideEffects,
+};
+use rolldown_fs::FileSystem;
+use rolldown_plugin::{HookUsage, ModuleEmittedFile, PluginDriver};
+use rolldown_sourcemap::OwnedSourceMap;
+use rolldown_utils::base64::{from_standard_base64, to_standard_base64};
+use serde_json::{Value, json};
+use xxhash_rust::xxh3::Xxh3;
+
+/// Bumped whenever the layout of a cache entry changes.
+const CACHE_ENTRY_VERSION: u32 = 4;
+
+/// The result of the `load` and `transform` hooks of a module, as stored by [`PersistentCache`].
+pub struct PersistentCacheEntry {
+  pub code: String,
+  /// Byte length of the source before the `transform` hooks ran.
+  pub loaded_source_len: usize,
+  pub module_type: ModuleType,
+  pub side_effects: Option<HookSideEffects>,
+  pub sourcemap_chain: Vec<SourcemapChainElement>,
+  /// Files the hooks added with `this.addWatchFile`.
+  pub watch_files: Vec<String>,
+  /// Assets the hooks emitted with `this.emitFile`.
+  pub emitted_files: Vec<ModuleEmittedFile>,
+}
+
+/// An on-disk cache of loaded and transformed sources, enabled by `experimental.persistentCacheDir`.
+///
+/// Unlike [`ScanStageCache`](crate::types::scan_stage_cache::ScanStageCache), entries survive process
+/// restarts. An entry is keyed on
+/// - the content of the file on disk,
+/// - the resolved id, the asserted module type and the side effects reported by the resolver,
+/// - the rolldown version, the options that affect loading and the ordered plugin names together
+///   with their [`Plugin::cache_key`](rolldown_plugin::Plugin::cache_key).
+///
+/// The cache is disabled when a plugin using the `load`, `transform` or `transform_ast` hook doesn't
+/// provide a cache key, since its output can't be told apart between option changes.
+///
+/// Besides the source, the sourcemap chain and the hook side effects, an entry records the files
+/// added with `this.addWatchFile` and the assets emitted with `this.emitFile`, so they are watched
+/// and emitted again on a cache hit. The content hash of every watch file is stored as well, and
+/// an entry whose watch files changed, appeared or disappeared is a miss.
+///
+/// Only the output of the `load` and `transform` hooks is persisted. Scan results are not: they
+/// borrow the arena-allocated AST and its scoping, which can't be serialized, so a module served
+/// from the cache is still parsed and scanned.
+pub struct PersistentCache {
+  dir: PathBuf,
+  fingerprint: u128,
+}
+
+impl PersistentCache {
+  pub fn new(options: &NormalizedBundlerOptions, plugin_driver: &PluginDriver) -> Option<Self> {
+    let dir = options.experimental.persistent_cache_dir.as_ref()?;
+
+    let mut hasher = Xxh3::default();
+    hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+    hasher.update(&CACHE_ENTRY_VERSION.to_le_bytes());
+    hasher.update(options.cwd.as_os_str().as_encoded_bytes());
+    hasher.update(format!("{:?}", options.platform).as_bytes());
+    let mut module_types =
+      options.module_types.iter().map(|(ext, ty)| format!("{ext}={ty}")).collect::<Vec<_>>();
+    module_types.sort_unstable();
+    for module_type in module_types {
+      hasher.update(module_type.as_bytes());
+      hasher.update(b"\0");
+    }
+    hasher.update(format!("{:?}", options.transform_options).as_bytes());
+    for plugin in plugin_driver.plugins() {
+      let cache_key = plugin.call_cache_key();
+      if cache_key.is_none()
+        && plugin
+          .call_hook_usage()
+          .intersects(HookUsage::Load | HookUsage::Transform | HookUsage::TransformAst)
+      {
+        return None;
+      }
+      hasher.update(plugin.call_name().as_bytes());
+      hasher.update(b"\0");
+      hasher.update(cache_key.unwrap_or_default().as_bytes());
+      hasher.update(b"\0");
+    }
+
+    Some(Self { dir: options.cwd.join(dir), fingerprint: hasher.digest128() })
+  }
+
+  /// Returns `None` for modules that are not backed by a readable file.
+  pub fn key_for<Fs: FileSystem>(
+    &self,
+    fs: &Fs,
+    resolved_id: &ResolvedId,
+    asserted_module_type: Option<&ModuleType>,
+    side_effects: Option<HookSideEffects>,
+  ) -> Option<String> {
+    let id = resolved_id.id.as_str();
+    if resolved_id.id.is_empty_module() || id.starts_with("rolldown:") {
+      return None;
+    }
+    let path = Path::new(id);
+    if !path.is_absolute() {
+      return None;
+    }
+    let content = fs.read(path).ok()?;
+
+    let mut hasher = Xxh3::default();
+    hasher.update(&self.fingerprint.to_le_bytes());
+    hasher.update(id.as_bytes());
+    hasher.update(b"\0");
+    hasher.update(asserted_module_type.map(ToString::to_string).unwrap_or_default().as_bytes());
+    hasher.update(b"\0");
+    hasher.update(format!("{side_effects:?}").as_bytes());
+    hasher.update(b"\0");
+    hasher.update(&content);
+    Some(format!("{:032x}", hasher.digest128()))
+  }
+
+  /// A missing, unreadable or malformed entry is treated as a cache miss, as is an entry whose
+  /// watch files no longer have the recorded content.
+  pub fn get<Fs: FileSystem>(&self, fs: &Fs, key: &str) -> Option<PersistentCacheEntry> {
+    let raw = fs.read_to_string(&self.entry_path(key)).ok()?;
+    let value = serde_json::from_str::<Value>(&raw).ok()?;
+    let (entry, watch_file_hashes) = deserialize_entry(&value)?;
+    let is_fresh = entry
+      .watch_files
+      .iter()
+      .zip(watch_file_hashes)
+      .all(|(file, hash)| content_hash(fs, file) == hash);
+    is_fresh.then_some(entry)
+  }
+
+  /// Writing the cache is best-effort, failing to do so never fails the build.
+  pub fn set<Fs: FileSystem>(&self, fs: &Fs, key: &str, entry: &PersistentCacheEntry) {
+    let watch_file_hashes =
+      entry.watch_files.iter().map(|file| content_hash(fs, file)).collect::<Vec<_>>();
+    let Some(value) = serialize_entry(entry, &watch_file_hashes) else {
+      return;
+    };
+    if fs.create_dir_all(&self.dir).is_ok() {
+      let _ = fs.write(&self.entry_path(key), value.to_string().as_bytes());
+    }
+  }
+
+  fn entry_path(&self, key: &str) -> PathBuf {
+    self.dir.join(format!("{key}.json"))
+  }
+}
+
+/// `None` for files that don't exist or can't be read, so that creating them is noticed too.
+fn content_hash<Fs: FileSystem>(fs: &Fs, file: &str) -> Option<String> {
+  let content = fs.read(Path::new(file)).ok()?;
+  let mut hasher = Xxh3::default();
+  hasher.update(&content);
+  Some(format!("{:032x}", hasher.digest128()))
+}
+
+fn serialize_entry(
+  entry: &PersistentCacheEntry,
+  watch_file_hashes: &[Option<String>],
+) -> Option<Value> {
+  // Custom module types are rejected right after loading, so they are never worth caching.
+  if matches!(entry.module_type, ModuleType::Custom(_)) {
+    return None;
+  }
+  let sourcemap_chain = entry
+    .sourcemap_chain
+    .iter()
+    .map(|element| match element {
+      SourcemapChainElement::Load(map) => json!({ "kind": "load", "map": map.to_json_string() }),
+      SourcemapChainElement::Transform((plugin_idx, map)) => json!({
+        "kind": "transform",
+        "pluginIdx": plugin_idx.raw(),
+        "map": map.to_json_string(),
+      }),
+      SourcemapChainElement::Omitted { plugin_idx, plugin_name } => json!({
+        "kind": "omitted",
+        "pluginIdx": plugin_idx.raw(),
+        "pluginName": plugin_name.as_str(),
+      }),
+      SourcemapChainElement::Null { plugin_idx, original_content } => json!({
+        "kind": "null",
+        "pluginIdx": plugin_idx.raw(),
+        "originalContent": original_content.as_str(),
+      }),
+    })
+    .collect::<Vec<_>>();
+  let side_effects = entry.side_effects.map(|side_effects| match side_effects {
+    HookSideEffects::True => "true",
+    HookSideEffects::False => "false",
+    HookSideEffects::NoTreeshake => "no-treeshake",
+  });
+  let emitted_files = entry
+    .emitted_files
+    .iter()
+    .map(|emitted| {
+      let source = match &emitted.file.source {
+        StrOrBytes::Str(source) => json!({ "str": source }),
+        StrOrBytes::Bytes(source) => json!({ "base64": to_standard_base64(source) }),
+      };
+      json!({
+        "pluginIdx": emitted.plugin_idx.raw(),
+        "referenceId": emitted.reference_id.as_str(),
+        "name": emitted.file.name,
+        "originalFileName": emitted.file.original_file_name,
+        "fileName": emitted.file.file_name.as_deref(),
+        "source": source,
+        "fileNameArgs": emitted.file_name_args,
+      })
+    })
+    .collect::<Vec<_>>();
+  let watch_files = entry
+    .watch_files
+    .iter()
+    .zip(watch_file_hashes)
+    .map(|(file, hash)| json!({ "path": file, "contentHash": hash }))
+    .collect::<Vec<_>>();
+  Some(json!({
+    "code": entry.code,
+    "loadedSourceLen": entry.loaded_source_len,
+    "moduleType": entry.module_type.to_string(),
+    "sideEffects": side_effects,
+    "sourcemapChain": sourcemap_chain,
+    "watchFiles": watch_files,
+    "emittedFiles": emitted_files,
+  }))
+}
+
+/// Returns the entry along with the recorded content hash of each of its watch files.
+fn deserialize_entry(value: &Value) -> Option<(PersistentCacheEntry, Vec<Option<String>>)> {
+  let code = value.get("code")?.as_str()?.to_string();
+  let loaded_source_len = usize::try_from(value.get("loadedSourceLen")?.as_u64()?).ok()?;
+  let module_type = ModuleType::from_known_str(value.get("moduleType")?.as_str()?).ok()?;
+  let side_effects = match value.get("sideEffects")? {
+    Value::Null => None,
+    Value::String(s) => Some(match s.as_str() {
+      "true" => HookSideEffects::True,
+      "false" => HookSideEffects::False,
+      "no-treeshake" => HookSideEffects::NoTreeshake,
+      _ => return None,
+    }),
+    _ => return None,
+  };
+  let sourcemap_chain = value
+    .get("sourcemapChain")?
+    .as_array()?
+    .iter()
+    .map(deserialize_sourcemap_chain_element)
+    .collect::<Option<Vec<_>>>()?;
+  let (watch_files, watch_file_hashes) = value
+    .get("watchFiles")?
+    .as_array()?
+    .iter()
+    .map(|watch_file| {
+      let hash = match watch_file.get("contentHash")? {
+        Value::Null => None,
+        Value::String(hash) => Some(hash.clone()),
+        _ => return None,
+      };
+      Some((watch_file.get("path")?.as_str()?.to_string(), hash))
+    })
+    .collect::<Option<(Vec<_>, Vec<_>)>>()?;
+  let emitted_files = value
+    .get("emittedFiles")?
+    .as_array()?
+    .iter()
+    .map(deserialize_emitted_file)
+    .collect::<Option<Vec<_>>>()?;
+  Some((
+    PersistentCacheEntry {
+      code,
+      loaded_source_len,
+      module_type,
+      side_effects,
+      sourcemap_chain,
+      watch_files,
+      emitted_files,
+    },
+    watch_file_hashes,
+  ))
+}
+
+fn deserialize_emitted_file(value: &Value) -> Option<ModuleEmittedFile> {
+  let optional_string = |key: &str| match value.get(key)? {
+    Value::Null => Some(None),
+    Value::String(s) => Some(Some(s.clone())),
+    _ => None,
+  };
+  let plugin_idx = u32::try_from(value.get("pluginIdx")?.as_u64()?).ok()?;
+  let source = value.get("source")?;
+  let source = if let Some(source) = source.get("str") {
+    StrOrBytes::Str(source.as_str()?.to_string())
+  } else {
+    StrOrBytes::Bytes(from_standard_base64(source.get("base64")?.as_str()?).ok()?)
+  };
+  Some(ModuleEmittedFile {
+    plugin_idx: PluginIdx::from_raw(plugin_idx),
+    reference_id: value.get("referenceId")?.as_str()?.into(),
+    file: EmittedAsset {
+      name: optional_string("name")?,
+      original_file_name: optional_string("originalFileName")?,
+      file_name: optional_string("fileName")?.map(Into::into),
+      source,
+    },
+    file_name_args: serde_json::from_value(value.get("fileNameArgs")?.clone()).ok()?,
+  })
+}
+
+fn deserialize_sourcemap_chain_element(value: &Value) -> Option<SourcemapChainElement> {
+  let plugin_idx = || {
+    let raw = value.get("pluginIdx")?.as_u64()?;
+    Some(PluginIdx::from_raw(u32::try_from(raw).ok()?))
+  };
+  let map = || {
+    let map = OwnedSourceMap::from_json_string(value.get("map")?.as_str()?).ok()?;
+    Some(map.into_inner())
+  };
+  Some(match value.get("kind")?.as_str()? {
+    "load" => SourcemapChainElement::Load(map()?),
+    "transform" => SourcemapChainElement::Transform((plugin_idx()?, map()?)),
+    "omitted" => SourcemapChainElement::Omitted {
+      plugin_idx: plugin_idx()?,
+      plugin_name: value.get("pluginName")?.as_str()?.into(),
+    },
+    "null" => SourcemapChainElement::Null {
+      plugin_idx: plugin_idx()?,
+      original_content: value.get("originalContent")?.as_str()?.into(),
+    },
+    _ => return None,
+  })
+}
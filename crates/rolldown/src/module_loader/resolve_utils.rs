@@ -4,8 +4,8 @@ use arcstr::ArcStr;
 use futures::future::join_all;
 use oxc_index::{IndexVec, index_vec};
 use rolldown_common::{
-  ImportKind, ImportRecordIdx, ImportRecordMeta, ModuleDefFormat, ModuleId,
-  NormalizedBundlerOptions, RUNTIME_MODULE_KEY, RawImportRecord, ResolvedId,
+  ImportAttribute, ImportKind, ImportRecordIdx, ImportRecordMeta, ModuleDefFormat, ModuleId,
+  ModuleType, NormalizedBundlerOptions, RUNTIME_MODULE_KEY, RawImportRecord, ResolvedId,
 };
 use rolldown_error::{
   BuildDiagnostic, BuildResult, DiagnosableArcstr, DiagnosticOptions, EventKind,
@@ -16,7 +16,9 @@ use rolldown_resolver::{ResolveError, Resolver};
 use rolldown_utils::ecmascript;
 use rustc_hash::FxHashMap;
 
-use crate::{SharedOptions, SharedResolver};
+use crate::{
+  SharedOptions, SharedResolver, utils::load_source::get_module_loader_from_file_extension,
+};
 
 #[tracing::instrument(skip_all, fields(CONTEXT_hook_resolve_id_trigger = "automatic"))]
 pub async fn resolve_id<Fs: FileSystem>(
@@ -26,6 +28,7 @@ pub async fn resolve_id<Fs: FileSystem>(
   importer: &str,
   specifier: &str,
   kind: ImportKind,
+  attributes: Option<&ImportAttribute>,
 ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
   // Check runtime module
   if specifier == RUNTIME_MODULE_KEY {
@@ -45,32 +48,39 @@ pub async fn resolve_id<Fs: FileSystem>(
     kind,
     None,
     Arc::default(),
+    attributes,
     false,
     bundle_options,
   )
   .await
 }
 
+#[expect(clippy::too_many_arguments)]
 pub async fn resolve_dependencies<Fs: FileSystem>(
   self_resolved_id: &ResolvedId,
   options: &SharedOptions,
   resolver: &SharedResolver<Fs>,
   plugin_driver: &SharedPluginDriver,
   dependencies: &IndexVec<ImportRecordIdx, RawImportRecord>,
+  import_attribute_map: &FxHashMap<ImportRecordIdx, ImportAttribute>,
   source: ArcStr,
   warnings: &mut Vec<BuildDiagnostic>,
 ) -> BuildResult<IndexVec<ImportRecordIdx, ResolvedId>> {
-  // NOTE: this dedupes the identical (specifier, kind) resolve calls
-  let dedup_map: FxHashMap<(&str, ImportKind), ImportRecordIdx> = dependencies
-    .iter_enumerated()
-    .map(|(idx, item)| ((item.module_request.as_str(), item.kind), idx))
-    .collect();
+  // NOTE: this dedupes the identical (specifier, kind) resolve calls. Imports with attributes are
+  // resolved on their own, since plugins may resolve them differently.
+  let attributed = |idx: ImportRecordIdx| import_attribute_map.contains_key(&idx).then_some(idx);
+  let dedup_map: FxHashMap<(&str, ImportKind, Option<ImportRecordIdx>), ImportRecordIdx> =
+    dependencies
+      .iter_enumerated()
+      .map(|(idx, item)| ((item.module_request.as_str(), item.kind, attributed(idx)), idx))
+      .collect();
 
   let jobs = dedup_map.values().map(|&idx| async move {
     let item = &dependencies[idx];
     let importer = &self_resolved_id.id;
     let specifier = &item.module_request;
-    resolve_id(options, resolver, plugin_driver, importer, specifier, item.kind)
+    let attributes = import_attribute_map.get(&idx);
+    resolve_id(options, resolver, plugin_driver, importer, specifier, item.kind, attributes)
       .await
       .map(|id| (idx, id))
   });
@@ -80,17 +90,42 @@ pub async fn resolve_dependencies<Fs: FileSystem>(
     let (idx, resolved) = result?;
     sparse_results[idx] = Some(resolved);
   }
-  let resolved_results = dependencies.iter().map(|dep| {
-    let repr_idx = dedup_map[&(dep.module_request.as_str(), dep.kind)];
+  let resolved_results = dependencies.iter_enumerated().map(|(idx, dep)| {
+    let repr_idx = dedup_map[&(dep.module_request.as_str(), dep.kind, attributed(idx))];
     sparse_results[repr_idx].as_ref().expect("dedup representative should be resolved")
   });
 
   let mut ret = IndexVec::with_capacity(dependencies.len());
   let mut build_errors = vec![];
-  for (dep, resolved_id) in dependencies.iter().zip(resolved_results) {
+  for ((idx, dep), resolved_id) in dependencies.iter_enumerated().zip(resolved_results) {
     match resolved_id {
       Ok(info) => {
-        ret.push(info.clone());
+        let mut info = info.clone();
+        if let Some(attributes) = import_attribute_map.get(&idx)
+          && !info.external.is_external()
+        {
+          if let Some(attributes_module_type) = attributes.module_type() {
+            match get_module_loader_from_file_extension(&info.id, &options.module_types) {
+              Some(module_type) if module_type == attributes_module_type => {}
+              // Code can be imported as text or bytes, but not parsed as JSON or CSS.
+              Some(
+                module_type @ (ModuleType::Js | ModuleType::Jsx | ModuleType::Ts | ModuleType::Tsx),
+              ) if matches!(attributes_module_type, ModuleType::Json | ModuleType::Css) => {
+                build_errors.push(BuildDiagnostic::import_attribute_mismatch(
+                  self_resolved_id.id.as_arc_str().clone(),
+                  source.clone(),
+                  dep.state.span,
+                  attributes.type_value().unwrap_or_default().to_string(),
+                  info.id.to_string(),
+                  module_type.to_string(),
+                ));
+              }
+              _ => info.attributes_module_type = Some(attributes_module_type),
+            }
+          }
+          info.attributes = Some(Arc::new(attributes.clone()));
+        }
+        ret.push(info);
       }
       Err(e) => {
         let specifier = &dep.module_request;
@@ -74,6 +74,7 @@ impl<Fs: FileSystem + Clone + 'static> RuntimeModuleTask<Fs> {
     };
 
     let original_source = source.clone();
+    let loaded_source_len = source.len();
 
     // Call transform hook on runtime module
     let mut sourcemap_chain = vec![];
@@ -146,6 +147,7 @@ impl<Fs: FileSystem + Clone + 'static> RuntimeModuleTask<Fs> {
       &self.ctx.resolver,
       &self.ctx.plugin_driver,
       &raw_import_records,
+      &FxHashMap::default(),
       source.clone(),
       &mut vec![],
     )
@@ -172,6 +174,7 @@ impl<Fs: FileSystem + Clone + 'static> RuntimeModuleTask<Fs> {
         dynamic_importers: FxIndexSet::default(),
         dynamic_importers_idx: FxIndexSet::default(),
         imported_ids: FxIndexSet::default(),
+        imported_ids_by_specifier: FxHashMap::default(),
         dynamically_imported_ids: FxIndexSet::default(),
         side_effects: determined_side_effects,
         named_imports,
@@ -187,20 +190,26 @@ impl<Fs: FileSystem + Clone + 'static> RuntimeModuleTask<Fs> {
         meta: ecma_view_meta,
         mutations: vec![],
         new_url_references,
+        worker_references: FxHashMap::default(),
         rolldown_file_url_references,
         this_expr_replace_map: FxHashMap::default(),
         hmr_info: scan_result.hmr_info,
         hmr_hot_ref: None,
         directive_range: vec![],
         dummy_record_set,
+        glob_callee_set: FxHashSet::default(),
         constant_export_map: FxHashMap::default(),
         enum_member_value_map: FxHashMap::default(),
         import_attribute_map: FxHashMap::default(),
         json_module_none_self_reference_included_symbol: None,
         cjs_reexport_import_record_ids: Vec::new(),
+        commonjs_object_exports: None,
+        original_scopes: None,
       },
       // TODO(hyf0/hmr): We might need to find a better way to handle this.
+      css_view: None,
       originative_resolved_id: resolved_id,
+      loaded_source_len,
     };
 
     let mut runtime = RuntimeModuleBrief::new(self.module_idx, &symbol_ref_db.ast_scopes);
@@ -228,7 +237,7 @@ impl<Fs: FileSystem + Clone + 'static> RuntimeModuleTask<Fs> {
     let mut ast = EcmaCompiler::parse(filename, source.clone(), source_type)?;
 
     ast.program.with_mut(|fields| {
-      let mut pre_processor = PreProcessor::new(fields.allocator, false, None);
+      let mut pre_processor = PreProcessor::new(fields.allocator, false, None, None);
       pre_processor.visit_program(fields.program);
     });
 
@@ -237,6 +246,7 @@ impl<Fs: FileSystem + Clone + 'static> RuntimeModuleTask<Fs> {
     // Always respect annotations in the runtime module, regardless of user config.
     // The runtime is trusted internal code.
     let runtime_flat_options = self.flat_options - FlatOptions::IgnoreAnnotations;
+    let glob_callee_spans = FxHashSet::default();
     let scan_result = ast.program.with_mut(|fields| {
       let program = &*fields.program;
       let scanner = AstScanner::new(
@@ -250,6 +260,7 @@ impl<Fs: FileSystem + Clone + 'static> RuntimeModuleTask<Fs> {
         &self.ctx.options,
         fields.allocator,
         runtime_flat_options,
+        &glob_callee_spans,
       );
       scanner.scan(program)
     })?;
@@ -5,6 +5,8 @@ use rolldown_plugin::SharedPluginDriver;
 
 use crate::{SharedOptions, SharedResolver};
 
+use super::persistent_cache::PersistentCache;
+
 /// Used to store common data shared between all tasks.
 pub struct TaskContext<Fs: FileSystem> {
   pub options: SharedOptions,
@@ -13,6 +15,7 @@ pub struct TaskContext<Fs: FileSystem> {
   pub fs: Fs,
   pub plugin_driver: SharedPluginDriver,
   pub meta: TaskContextMeta,
+  pub persistent_cache: Option<PersistentCache>,
 }
 
 pub struct TaskContextMeta {
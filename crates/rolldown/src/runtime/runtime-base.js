@@ -93,3 +93,21 @@ export var __toBinary = /* @__PURE__ */ (() => {
     return bytes;
   };
 })();
+// Namespace of `import defer * as ns from './foo'`: `init` evaluates `./foo` on the first
+// string-keyed access, so symbol lookups like `Symbol.toStringTag` don't trigger evaluation.
+export var __importDefer = (init, ns, load) => (
+  (load = (key) => (typeof key !== 'symbol' && init(), ns)),
+  new Proxy(ns, {
+    get: (_, key) => load(key)[key],
+    has: (_, key) => key in load(key),
+    ownKeys: () => Reflect.ownKeys(load()),
+    getOwnPropertyDescriptor: (_, key) => __getOwnPropDesc(load(key), key),
+  })
+);
+// Lookup of a glob `import()` / `require()`: `map` holds the modules matched at build time,
+// keyed by the path the expression evaluates to.
+export var __glob = (map) => (path) => {
+  var fn = map[path];
+  if (fn) return fn();
+  throw new Error('Module not found in bundle: ' + path);
+};
@@ -31,6 +31,11 @@ pub enum ChunkCreationReason<'a> {
     bits: &'a BitSet,
     link_output: &'a LinkStageOutput,
   },
+  ChunkRecord {
+    name: &'a str,
+    bits: &'a BitSet,
+    link_output: &'a LinkStageOutput,
+  },
 }
 
 impl ChunkDebugExt for Chunk {
@@ -52,6 +57,9 @@ impl ChunkDebugExt for Chunk {
       ChunkCreationReason::CommonChunk { .. } => {
         *self.chunk_reason_type = ChunkReasonType::Common;
       }
+      ChunkCreationReason::ChunkRecord { .. } => {
+        *self.chunk_reason_type = ChunkReasonType::ChunkRecord;
+      }
     }
 
     if !options.experimental.is_attach_debug_info_full() && !options.devtools {
@@ -88,6 +96,10 @@ impl ChunkDebugExt for Chunk {
         let entries = resolve_bits_to_entry_names(bits, link_output);
         format!("Common Chunk: [Shared-By: {entries}]")
       }
+      ChunkCreationReason::ChunkRecord { name, bits, link_output } => {
+        let entries = resolve_bits_to_entry_names(bits, link_output);
+        format!("Chunk Record: [Name: {name}] [Shared-By: {entries}]")
+      }
     };
 
     self.debug_info.push(ChunkDebugInfo::CreateReason(reason));
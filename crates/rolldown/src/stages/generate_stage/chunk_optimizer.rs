@@ -9,6 +9,7 @@ use rolldown_common::{
   PostChunkOptimizationOperation, PreserveEntrySignatures, RuntimeHelper, UsedSymbolRefsBuilder,
   WrapKind,
 };
+use rolldown_error::{BuildDiagnostic, EventKindSwitcher};
 use rolldown_utils::{BitSet, IndexBitSet, indexmap::FxIndexMap};
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -291,6 +292,16 @@ impl ChunkOptimizationGraph {
     self.chunks[target_chunk_idx].dependencies.remove(&target_chunk_idx);
     self.chunks[target_chunk_idx].has_side_effects |= source_has_side_effects;
   }
+
+  /// Points every chunk that depends on the source chunk at the target chunk instead, as it
+  /// holds the source's modules after a merge.
+  pub fn retarget_dependents(&mut self, target_chunk_idx: ChunkIdx, source_chunk_idx: ChunkIdx) {
+    for (chunk_idx, chunk) in self.chunks.iter_mut_enumerated() {
+      if chunk.dependencies.remove(&source_chunk_idx) && chunk_idx != target_chunk_idx {
+        chunk.dependencies.insert(target_chunk_idx);
+      }
+    }
+  }
 }
 
 /// Result of assigning modules during chunk optimization.
@@ -1544,6 +1555,218 @@ impl GenerateStage<'_> {
     false
   }
 
+  /// Enforces `codeSplitting.maxInitialRequests` and `codeSplitting.maxAsyncRequests`.
+  ///
+  /// Loading an entry requests every chunk holding a module the entry's bit is set for. While an
+  /// entry requests too many, the least-shared common chunk it requests is merged into the
+  /// requested chunk loaded by the most similar set of entries. Afterwards, every entry loading
+  /// one of the two chunks loads both, so a merge is only done when it:
+  /// - runs no side-effectful module for an entry that didn't run it before,
+  /// - leaves the signature of a `preserveEntrySignatures: 'strict'` entry intact,
+  /// - doesn't create a circular import between chunks,
+  /// - involves no top-level await, whose evaluation order merging could change.
+  ///
+  /// Chunks created by manual code splitting groups and the runtime chunk are never merged. An
+  /// entry still over its limit is reported with an `EXCEEDED_MAX_REQUESTS` warning.
+  pub(super) fn enforce_max_requests(
+    &mut self,
+    chunk_graph: &mut ChunkGraph,
+    index_splitting_info: &IndexSplittingInfo,
+  ) {
+    let Some(options) = self.options.manual_code_splitting.as_ref() else {
+      return;
+    };
+    let (max_initial_requests, max_async_requests) =
+      (options.max_initial_requests, options.max_async_requests);
+    if max_initial_requests.is_none() && max_async_requests.is_none() {
+      return;
+    }
+    let entries_len: u32 = self
+      .link_output
+      .entries
+      .values()
+      .map(Vec::len)
+      .sum::<usize>()
+      .try_into()
+      .expect("Too many entries, u32 overflowed.");
+    let runtime_module_idx = self.link_output.runtime.id();
+
+    // The entries loading each live chunk.
+    let mut loaded_by: IndexVec<ChunkIdx, Option<BitSet>> = chunk_graph
+      .chunk_table
+      .iter_enumerated()
+      .map(|(chunk_idx, chunk)| {
+        let is_removed = chunk_graph.post_chunk_optimization_operations.get(&chunk_idx)
+          == Some(&PostChunkOptimizationOperation::Removed);
+        (!is_removed && !chunk.modules.is_empty()).then(|| {
+          let mut bits = BitSet::new(entries_len);
+          for &module_idx in &chunk.modules {
+            bits.union(&index_splitting_info[module_idx].bits);
+          }
+          bits
+        })
+      })
+      .collect();
+    let mut temp_chunk_graph = ChunkOptimizationGraph::new(
+      true,
+      chunk_graph,
+      &FxHashMap::default(),
+      &self.link_output.module_table,
+    );
+    temp_chunk_graph.calc_chunk_dependencies(&self.link_output.metas);
+
+    let budgets = chunk_graph
+      .chunk_table
+      .iter_enumerated()
+      .filter(|(chunk_idx, _)| loaded_by[*chunk_idx].is_some())
+      .filter_map(|(_, chunk)| {
+        let ChunkKind::EntryPoint { meta, bit, module } = chunk.kind else {
+          return None;
+        };
+        let is_async = meta.contains(ChunkMeta::DynamicImported)
+          && !meta.intersects(ChunkMeta::UserDefinedEntry | ChunkMeta::EmittedChunk);
+        let (limit, option_name) = if is_async {
+          (max_async_requests?, "maxAsyncRequests")
+        } else {
+          (max_initial_requests?, "maxInitialRequests")
+        };
+        Some((bit, module, limit, option_name))
+      })
+      .collect_vec();
+
+    for (bit, entry_module_idx, limit, option_name) in budgets {
+      loop {
+        let requested = loaded_by
+          .iter_enumerated()
+          .filter(|(_, bits)| bits.as_ref().is_some_and(|bits| bits.has_bit(bit)))
+          .map(|(chunk_idx, _)| chunk_idx)
+          .collect_vec();
+        let requests = u32::try_from(requested.len()).expect("Too many chunks, u32 overflowed.");
+        if requests <= limit {
+          break;
+        }
+
+        let is_mergeable = |chunk_idx: ChunkIdx| {
+          let chunk = &chunk_graph.chunk_table[chunk_idx];
+          matches!(chunk.kind, ChunkKind::Common)
+            && chunk.chunk_reason_type.group_index().is_none()
+            && !chunk.modules.contains(&runtime_module_idx)
+        };
+        let share_count =
+          |chunk_idx: ChunkIdx| loaded_by[chunk_idx].as_ref().map_or(0, BitSet::bit_count);
+        // Chunks recreated from `experimental.recordsPath` are merged last, to keep them stable.
+        let is_recorded = |chunk_idx: ChunkIdx| {
+          matches!(
+            chunk_graph.chunk_table[chunk_idx].chunk_reason_type.as_ref(),
+            ChunkReasonType::ChunkRecord
+          )
+        };
+        let merge = requested
+          .iter()
+          .copied()
+          .filter(|&chunk_idx| is_mergeable(chunk_idx))
+          .sorted_by_key(|&chunk_idx| (is_recorded(chunk_idx), share_count(chunk_idx), chunk_idx))
+          .find_map(|from_chunk_idx| {
+            requested
+              .iter()
+              .copied()
+              .filter(|&to_chunk_idx| {
+                to_chunk_idx != from_chunk_idx
+                  && (is_mergeable(to_chunk_idx)
+                    || matches!(
+                      chunk_graph.chunk_table[to_chunk_idx].kind,
+                      ChunkKind::EntryPoint { .. }
+                    ))
+                  && self.can_merge_for_max_requests(
+                    chunk_graph,
+                    &temp_chunk_graph,
+                    &loaded_by,
+                    from_chunk_idx,
+                    to_chunk_idx,
+                  )
+              })
+              .min_by_key(|&to_chunk_idx| {
+                let (Some(from_bits), Some(to_bits)) =
+                  (&loaded_by[from_chunk_idx], &loaded_by[to_chunk_idx])
+                else {
+                  unreachable!("requested chunks are live");
+                };
+                let mut shared = from_bits.clone();
+                shared.intersect(to_bits);
+                let differing =
+                  from_bits.bit_count() + to_bits.bit_count() - 2 * shared.bit_count();
+                (differing, to_chunk_idx)
+              })
+              .map(|to_chunk_idx| CommonChunkMerge { from_chunk_idx, to_chunk_idx })
+          });
+
+        let Some(merge) = merge else {
+          if self.options.checks.contains(EventKindSwitcher::ExceededMaxRequests) {
+            self.link_output.diagnostics.push(
+              BuildDiagnostic::exceeded_max_requests(
+                self.link_output.module_table[entry_module_idx].id().to_string(),
+                option_name,
+                limit,
+                requests,
+              )
+              .with_severity_warning(),
+            );
+          }
+          break;
+        };
+        let from_bits = loaded_by[merge.from_chunk_idx].take().expect("requested chunks are live");
+        loaded_by[merge.to_chunk_idx]
+          .as_mut()
+          .expect("requested chunks are live")
+          .union(&from_bits);
+        let from_chunk_bits = chunk_graph.chunk_table[merge.from_chunk_idx].bits.clone();
+        chunk_graph.chunk_table[merge.to_chunk_idx].bits.union(&from_chunk_bits);
+        temp_chunk_graph.merge_chunk_dependencies(merge.to_chunk_idx, merge.from_chunk_idx);
+        temp_chunk_graph.retarget_dependents(merge.to_chunk_idx, merge.from_chunk_idx);
+        Self::apply_common_chunk_merges(chunk_graph, &[merge], &mut FxHashSet::default());
+      }
+    }
+  }
+
+  /// See [`Self::enforce_max_requests`].
+  fn can_merge_for_max_requests(
+    &self,
+    chunk_graph: &ChunkGraph,
+    temp_chunk_graph: &ChunkOptimizationGraph,
+    loaded_by: &IndexVec<ChunkIdx, Option<BitSet>>,
+    from_chunk_idx: ChunkIdx,
+    to_chunk_idx: ChunkIdx,
+  ) -> bool {
+    let (Some(from_bits), Some(to_bits)) = (&loaded_by[from_chunk_idx], &loaded_by[to_chunk_idx])
+    else {
+      return false;
+    };
+    // Entries loading one chunk but not the other start running the other's modules.
+    let is_subset = |lhs: &BitSet, rhs: &BitSet| lhs.index_of_one().all(|bit| rhs.has_bit(bit));
+    if (!is_subset(to_bits, from_bits) && temp_chunk_graph.chunks[from_chunk_idx].has_side_effects)
+      || (!is_subset(from_bits, to_bits) && temp_chunk_graph.chunks[to_chunk_idx].has_side_effects)
+    {
+      return false;
+    }
+    if self.chunk_has_tla_or_tla_dependency(chunk_graph, from_chunk_idx)
+      || self.chunk_has_tla_or_tla_dependency(chunk_graph, to_chunk_idx)
+    {
+      return false;
+    }
+    let to_chunk = &chunk_graph.chunk_table[to_chunk_idx];
+    if let ChunkKind::EntryPoint { meta, .. } = to_chunk.kind
+      && meta != ChunkMeta::DynamicImported
+      && matches!(to_chunk.preserve_entry_signature, Some(PreserveEntrySignatures::Strict))
+      && !self.can_merge_without_changing_entry_signature(
+        to_chunk,
+        &chunk_graph.chunk_table[from_chunk_idx].modules,
+      )
+    {
+      return false;
+    }
+    !temp_chunk_graph.would_create_circular_dependency(from_chunk_idx, to_chunk_idx)
+  }
+
   /// Move modules from common chunks into facade entry chunks, then retarget
   /// all chunk-graph references that still point at the removed common chunk.
   fn apply_common_chunk_merges(
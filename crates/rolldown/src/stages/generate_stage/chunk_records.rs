@@ -0,0 +1,126 @@
+use arcstr::ArcStr;
+use rolldown_common::{
+  Chunk, ChunkIdx, ChunkKind, ChunkReasonType, ChunkRecord, ChunkRecords, ModuleIdx,
+  PostChunkOptimizationOperation,
+};
+use rolldown_utils::{BitSet, IndexBitSet, indexmap::FxIndexMap};
+use rustc_hash::FxHashMap;
+
+use crate::chunk_graph::ChunkGraph;
+
+use super::{
+  GenerateStage,
+  chunk_ext::{ChunkCreationReason, ChunkDebugExt},
+  code_splitting::IndexSplittingInfo,
+};
+
+impl GenerateStage<'_> {
+  /// Recreates the common chunks recorded by the previous build, see [`ChunkRecords`]. Runs before
+  /// the automatic assignment, which leaves the modules placed here alone.
+  ///
+  /// A chunk may only hold modules loaded by the same set of entries. If its modules no longer
+  /// are, the chunk is recreated with the largest group of them that still is. Modules now owned
+  /// by an entry or manual code splitting chunk are left to it.
+  pub(super) fn apply_chunk_records(
+    &self,
+    index_splitting_info: &IndexSplittingInfo,
+    module_is_assigned: &mut IndexBitSet<ModuleIdx>,
+    chunk_graph: &mut ChunkGraph,
+    bits_to_chunk: &FxHashMap<BitSet, ChunkIdx>,
+    input_base: &ArcStr,
+  ) {
+    let Some(chunk_records) = &self.chunk_records else {
+      return;
+    };
+    let module_by_stable_id = self
+      .link_output
+      .module_table
+      .modules
+      .iter()
+      .filter_map(|module| module.as_normal())
+      .map(|module| (module.stable_id.as_str(), module.idx))
+      .collect::<FxHashMap<_, _>>();
+
+    for record in &chunk_records.chunks {
+      let mut modules_by_bits: FxIndexMap<&BitSet, Vec<ModuleIdx>> = FxIndexMap::default();
+      for stable_id in &record.modules {
+        let Some(&module_idx) = module_by_stable_id.get(stable_id.as_str()) else {
+          continue;
+        };
+        if !self.link_output.metas[module_idx].is_included || module_is_assigned.has_bit(module_idx)
+        {
+          continue;
+        }
+        let bits = &index_splitting_info[module_idx].bits;
+        if !bits_to_chunk.contains_key(bits) {
+          modules_by_bits.entry(bits).or_default().push(module_idx);
+        }
+      }
+      // `max_by_key` picks the last of equally large groups, so reverse to prefer the first.
+      let Some((bits, modules)) =
+        modules_by_bits.into_iter().rev().max_by_key(|(_, modules)| modules.len())
+      else {
+        continue;
+      };
+
+      let mut chunk = Chunk::new(
+        Some(record.name.as_str().into()),
+        None,
+        bits.clone(),
+        vec![],
+        ChunkKind::Common,
+        input_base.clone(),
+        None,
+      );
+      chunk.add_creation_reason(
+        ChunkCreationReason::ChunkRecord {
+          name: &record.name,
+          bits,
+          link_output: self.link_output,
+        },
+        self.options,
+      );
+      let chunk_idx = chunk_graph.add_chunk(chunk);
+      for module_idx in modules {
+        module_is_assigned.set_bit(module_idx);
+        chunk_graph.add_module_to_chunk(
+          module_idx,
+          chunk_idx,
+          self.link_output.metas[module_idx].depended_runtime_helper,
+        );
+      }
+    }
+  }
+
+  /// Collects the common chunks of this build for `experimental.recordsPath`. Chunks of manual
+  /// code splitting groups are left out, as their composition is pinned by the options already.
+  pub(super) fn collect_chunk_records(&self, chunk_graph: &ChunkGraph) -> ChunkRecords {
+    let runtime_module_idx = self.link_output.runtime.id();
+    let chunks = chunk_graph
+      .sorted_chunk_idx_vec
+      .iter()
+      .filter(|chunk_idx| {
+        chunk_graph.post_chunk_optimization_operations.get(chunk_idx)
+          != Some(&PostChunkOptimizationOperation::Removed)
+      })
+      .map(|&chunk_idx| &chunk_graph.chunk_table[chunk_idx])
+      .filter(|chunk| {
+        matches!(
+          chunk.chunk_reason_type.as_ref(),
+          ChunkReasonType::Common | ChunkReasonType::ChunkRecord
+        )
+      })
+      .filter_map(|chunk| {
+        let modules = chunk
+          .modules
+          .iter()
+          .filter(|&&module_idx| module_idx != runtime_module_idx)
+          .map(|&module_idx| self.link_output.module_table[module_idx].stable_id().to_string())
+          .collect::<Vec<_>>();
+        let name = chunk.name.as_ref()?.to_string();
+        (!modules.is_empty()).then_some(ChunkRecord { name, modules })
+      })
+      .collect();
+    ChunkRecords { chunks }
+  }
+}
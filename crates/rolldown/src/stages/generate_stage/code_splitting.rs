@@ -1000,6 +1000,14 @@ impl GenerateStage<'_> {
       )
       .await?;
 
+    self.apply_chunk_records(
+      index_splitting_info,
+      &mut module_is_assigned,
+      chunk_graph,
+      bits_to_chunk,
+      input_base,
+    );
+
     // If it is allow to allow that entry chunks have the different exports as the underlying entry module.
     // This is used to generate less chunks when possible.
     // TODO: maybe we could bailout peer chunk?
@@ -1089,6 +1097,8 @@ impl GenerateStage<'_> {
 
     self.try_merge_runtime_chunk(chunk_graph, None, RuntimeMergeCascade::Full);
 
+    self.enforce_max_requests(chunk_graph, index_splitting_info);
+
     Ok(())
   }
 
@@ -12,10 +12,11 @@ use itertools::{Itertools, multizip};
 use oxc_index::{IndexVec, index_vec};
 use oxc_str::CompactStr;
 use rolldown_common::{
-  ChunkIdx, ChunkKind, ChunkMeta, CrossChunkImportItem, EntryPointKind, ExportsKind, ImportKind,
-  ImportRecordMeta, Module, ModuleIdx, NamedImport, OutputFormat, PostChunkOptimizationOperation,
-  PreserveEntrySignatures, RUNTIME_HELPER_NAMES, ResolvedImportRecord, RuntimeHelper, SymbolRef,
-  SymbolRefDb, TaggedSymbolRef, UsedSymbolRefs, UsedSymbolRefsBuilder, WrapKind,
+  ChunkIdx, ChunkKind, ChunkMeta, CrossChunkImportItem, EntryPointKind, ExportsKind,
+  HoistTransitiveImports, ImportKind, ImportRecordMeta, Module, ModuleIdx, NamedImport,
+  OutputFormat, PostChunkOptimizationOperation, PreserveEntrySignatures, RUNTIME_HELPER_NAMES,
+  ResolvedImportRecord, RuntimeHelper, SymbolRef, SymbolRefDb, TaggedSymbolRef, UsedSymbolRefs,
+  UsedSymbolRefsBuilder, WrapKind,
 };
 use rolldown_utils::index_vec_ext::IndexVecRefExt as _;
 use rolldown_utils::indexmap::{FxIndexMap, FxIndexSet};
@@ -295,6 +296,58 @@ impl GenerateStage<'_> {
     }
   }
 
+  /// Implements `output.hoistTransitiveImports`: collects, for every entry chunk, the chunks that are
+  /// only reachable through the static imports of the chunks it imports. With `import`, each of
+  /// them becomes an empty import of the entry chunk, appended after its direct imports so the
+  /// execution order stays the same. With `modulepreload`, they are only reported as metadata.
+  ///
+  /// Like rollup, this is skipped in the `preserveModules` mode, which produces a chunk per module.
+  pub(super) fn hoist_transitive_imports(&self, chunk_graph: &mut ChunkGraph) {
+    let mode = self.options.hoist_transitive_imports;
+    if !mode.is_enabled()
+      || !matches!(self.options.format, OutputFormat::Esm)
+      || self.options.preserve_modules
+    {
+      return;
+    }
+
+    let index_transitive_imports = chunk_graph
+      .chunk_table
+      .iter_enumerated()
+      .map(|(chunk_idx, chunk)| {
+        if !matches!(chunk.kind, ChunkKind::EntryPoint { .. }) {
+          return vec![];
+        }
+        let mut visited = chunk.cross_chunk_imports.iter().copied().collect::<FxHashSet<_>>();
+        visited.insert(chunk_idx);
+        let mut stack = chunk.cross_chunk_imports.clone();
+        let mut transitive_imports = vec![];
+        while let Some(importee_idx) = stack.pop() {
+          for &dep in &chunk_graph.chunk_table[importee_idx].cross_chunk_imports {
+            if visited.insert(dep) {
+              transitive_imports.push(dep);
+              stack.push(dep);
+            }
+          }
+        }
+        transitive_imports.sort_unstable_by_key(|idx| chunk_graph.chunk_table[*idx].exec_order);
+        transitive_imports
+      })
+      .collect::<Vec<_>>();
+
+    for (chunk, transitive_imports) in
+      chunk_graph.chunk_table.iter_mut().zip(index_transitive_imports)
+    {
+      if matches!(mode, HoistTransitiveImports::Import) {
+        for importee_idx in &transitive_imports {
+          chunk.imports_from_other_chunks.entry(*importee_idx).or_default();
+        }
+        chunk.cross_chunk_imports.extend_from_slice(&transitive_imports);
+      }
+      chunk.transitive_imports = transitive_imports;
+    }
+  }
+
   /// Compute provisional links for order analysis. Uses an empty order state and explicitly marks
   /// final-init metadata unavailable, so the edges are the *pre-lowering* baseline topology (value
   /// and side-effect imports, before wrapping adds `init_*` imports). The emergent-cycle fixpoint
@@ -1430,7 +1483,8 @@ impl GenerateStage<'_> {
           // related to https://github.com/rolldown/rolldown/blob/c100a53c6cfc67b4f92e230da072eef8494862ef/crates/rolldown/src/ecmascript/format/cjs.rs?plain=1#L120-L124
           let import_ref = if self.link_output.module_table[import_ref.owner].is_external() {
             index_chunk_indirect_imports_from_external_modules[chunk_id].insert(import_ref.owner);
-            if matches!(self.options.format, OutputFormat::Esm) {
+            // SystemJS hands setters real module namespaces, so no interop is needed either.
+            if matches!(self.options.format, OutputFormat::Esm | OutputFormat::System) {
               continue;
             }
 
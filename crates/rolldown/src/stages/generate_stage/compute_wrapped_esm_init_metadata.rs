@@ -221,7 +221,9 @@ fn transitive_esm_init_targets(
     }
     for &rec_idx in &stmt_info.import_records {
       let rec = &module.import_records[rec_idx];
-      if rec.kind != ImportKind::Import {
+      // A deferred namespace runs its module's `init_*` on first use, never in place of the
+      // statement.
+      if rec.kind != ImportKind::Import || rec.meta.contains(ImportRecordMeta::IsDeferred) {
         continue;
       }
       let is_reexport =
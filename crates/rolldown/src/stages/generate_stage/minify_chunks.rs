@@ -28,6 +28,7 @@ impl GenerateStage<'_> {
       }
       match chunk.kind {
         rolldown_common::InstantiationKind::Ecma(_) => {
+          chunk.flatten_index_map();
           let codegen_options = CodegenOptions {
             minify: remove_whitespace,
             comments: CommentOptions {
@@ -5,8 +5,8 @@ use futures::future::try_join_all;
 use oxc_index::IndexVec;
 use render_chunk_to_assets::set_emitted_chunk_preliminary_filenames;
 use rolldown_common::{
-  ChunkIdx, ChunkKind, InstantiationKind, ModuleIdx, OutputExports, PackageJson, PathsOutputOption,
-  RUNTIME_HELPER_NAMES, UsedSymbolRefs, UsedSymbolRefsBuilder,
+  ChunkIdx, ChunkKind, ChunkRecords, InstantiationKind, ModuleIdx, OutputExports, PackageJson,
+  PathsOutputOption, RUNTIME_HELPER_NAMES, UsedSymbolRefs, UsedSymbolRefsBuilder,
 };
 use rolldown_devtools::{action, trace_action, trace_action_enabled};
 use rolldown_error::{BuildDiagnostic, BuildResult};
@@ -98,6 +98,7 @@ use crate::{
 
 mod chunk_ext;
 mod chunk_optimizer;
+mod chunk_records;
 mod code_splitting;
 mod compute_cross_chunk_links;
 mod compute_wrapped_esm_init_metadata;
@@ -130,6 +131,9 @@ pub struct GenerateStage<'a> {
   /// `paths` option, it is resolved asynchronously here before entering sync rendering code,
   /// avoiding the need for `invoke_sync` which can cause deadlocks.
   resolved_paths: Option<PathsOutputOption>,
+  /// Read from `experimental.recordsPath` by the caller. `None` when the option is unset or the
+  /// file is missing or malformed.
+  chunk_records: Option<ChunkRecords>,
 }
 
 impl<'a> GenerateStage<'a> {
@@ -138,8 +142,9 @@ impl<'a> GenerateStage<'a> {
     ast_table: IndexEcmaAst,
     options: &'a SharedOptions,
     plugin_driver: &'a SharedPluginDriver,
+    chunk_records: Option<ChunkRecords>,
   ) -> Self {
-    Self { link_output, ast_table, options, plugin_driver, resolved_paths: None }
+    Self { link_output, ast_table, options, plugin_driver, resolved_paths: None, chunk_records }
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
@@ -247,6 +252,8 @@ impl<'a> GenerateStage<'a> {
       &final_esm_init_metadata,
     )?;
     self.detect_ineffective_dynamic_imports(&chunk_graph);
+    // The cross-chunk imports are final only once every pass above had the chance to add some.
+    self.hoist_transitive_imports(&mut chunk_graph);
     self.render_chunk_to_assets(&chunk_graph, ast_table, &used_symbol_refs, &order_state).await
   }
 
@@ -440,6 +447,21 @@ impl<'a> GenerateStage<'a> {
         )
         .await?;
       chunk.preliminary_sourcemap_filename = preliminary_sourcemap_filename;
+      if chunk.has_css_modules(&self.link_output.module_table) {
+        let css_preliminary_filename = chunk
+          .generate_css_preliminary_filename(
+            self.options,
+            &pre_rendered_chunk,
+            &pre_generated_chunk_name.chunk_filename,
+            &mut hash_placeholder_generator,
+            &used_name_counts,
+          )
+          .await?;
+        chunk.absolute_css_preliminary_filename = Some(
+          css_preliminary_filename.absolutize_with(&output_dir).into_owned().expect_into_string(),
+        );
+        chunk.css_preliminary_filename = Some(css_preliminary_filename);
+      }
       chunk.pre_rendered_chunk = Some(pre_rendered_chunk);
     }
     Ok(index_chunk_id_to_representative_name)
@@ -1,5 +1,5 @@
 use rolldown_error::BuildResult;
-use rolldown_sourcemap::{SourceJoiner, SourceMapSource, adjust_sourcemap_dst_lines};
+use rolldown_sourcemap::{Source, SourceJoiner, SourceMapSource, adjust_sourcemap_dst_lines};
 use rolldown_utils::rayon::{IntoParallelRefMutIterator, ParallelIterator};
 
 use crate::type_alias::IndexInstantiatedChunks;
@@ -58,6 +58,14 @@ impl GenerateStage<'_> {
 
         source_joiner.join()
       };
+      if let Some(index_map) = &mut chunk.index_map {
+        // The rest of the content was joined without its map, so re-base the sections instead
+        // of merging them. The shebang stays on the first line, which leaves the lines the
+        // post banner adds in front of the rest.
+        if let Some(post_banner) = &chunk.post_banner {
+          index_map.offset_lines(post_banner.as_str().lines_count() + 1);
+        }
+      }
       chunk.content = content.into();
       chunk.map = map;
 
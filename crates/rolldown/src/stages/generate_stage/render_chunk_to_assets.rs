@@ -4,9 +4,9 @@ use futures::future::try_join_all;
 use oxc_index::{IndexVec, index_vec};
 use oxc_str::CompactStr;
 use rolldown_common::{
-  Asset, ChunkIdx, ConcatenateWrappedModuleKind, EmittedChunkInfo, InstantiationKind,
-  ModuleRenderArgs, ModuleRenderOutput, Output, OutputAsset, OutputChunk, SharedFileEmitter,
-  SymbolRef, UsedSymbolRefs,
+  Asset, ChunkIdx, ConcatenateWrappedModuleKind, EmittedAsset, EmittedChunkInfo, InstantiationKind,
+  Metafile, ModuleRenderArgs, ModuleRenderOutput, Output, OutputAsset, OutputChunk,
+  SharedFileEmitter, SymbolRef, UsedSymbolRefs,
 };
 use rolldown_devtools::{action, trace_action, trace_action_enabled};
 use rolldown_error::{BatchedBuildDiagnostic, BuildResult, Diagnostics};
@@ -14,16 +14,19 @@ use rolldown_utils::{
   indexmap::{FxIndexMap, FxIndexSet},
   rayon::{IntoParallelRefIterator, ParallelIterator},
 };
+use rustc_hash::FxHashMap;
 
 use crate::{
   BundleOutput,
   chunk_graph::ChunkGraph,
+  css::css_generator::CssGenerator,
   ecmascript::ecma_generator::EcmaGenerator,
   type_alias::{AssetVec, IndexChunkToInstances, IndexEcmaAst, IndexInstantiatedChunks},
   types::generator::{GenerateContext, GenerateOutput, Generator},
   utils::{
     augment_chunk_hash::augment_chunk_hash,
     chunk::{finalize_chunks::finalize_assets, render_chunk_exports::get_export_items},
+    metafile::collect_metafile_inputs,
     render_chunks::render_chunks,
   },
 };
@@ -87,11 +90,34 @@ impl GenerateStage<'_> {
 
     Self::trace_action_assets_ready(&assets);
 
+    // Only the stylesheets of chunks are instantiated without metadata.
+    let css_filenames = assets
+      .iter()
+      .filter_map(|asset| match (&asset.meta, asset.originate_from) {
+        (InstantiationKind::None, Some(chunk_idx)) => Some((chunk_idx, asset.filename.clone())),
+        _ => None,
+      })
+      .collect::<FxHashMap<_, _>>();
+
     let mut output = Vec::with_capacity(assets.len());
-    for Asset { map, meta: rendered_chunk, content: code, filename, .. } in assets {
+    for Asset { map, meta: rendered_chunk, content: code, filename, originate_from, .. } in assets {
       match rendered_chunk {
         InstantiationKind::Ecma(ecma_meta) => {
           let code = code.try_into_string()?;
+          if !ecma_meta.legal_comments.is_empty() {
+            let mut source = ecma_meta.legal_comments.join("\n");
+            source.push('\n');
+            self.plugin_driver.file_emitter.emit_file(
+              EmittedAsset {
+                name: None,
+                original_file_name: None,
+                file_name: Some(arcstr::format!("{filename}.LEGAL.txt")),
+                source: source.into(),
+              },
+              None,
+              None,
+            )?;
+          }
           let rendered_chunk = ecma_meta.rendered_chunk;
           output.push(Output::Chunk(Arc::new(OutputChunk {
             name: rendered_chunk.name.clone(),
@@ -105,9 +131,15 @@ impl GenerateStage<'_> {
             module_ids: rendered_chunk.module_ids.clone(),
             imports: ecma_meta.imports,
             dynamic_imports: ecma_meta.dynamic_imports,
+            transitive_imports: ecma_meta.transitive_imports,
+            imported_css: originate_from
+              .and_then(|chunk_idx| css_filenames.get(&chunk_idx).cloned())
+              .into_iter()
+              .collect(),
             map,
             sourcemap_filename: ecma_meta.sourcemap_filename,
             preliminary_filename: ecma_meta.preliminary_filename.to_string(),
+            integrity: None,
           })));
         }
         InstantiationKind::Sourcemap(sourcemap_meta) => {
@@ -116,6 +148,7 @@ impl GenerateStage<'_> {
             source: code,
             original_file_names: sourcemap_meta.original_file_names,
             names: sourcemap_meta.names,
+            integrity: None,
           })));
         }
         InstantiationKind::None => {
@@ -124,6 +157,7 @@ impl GenerateStage<'_> {
             source: code,
             original_file_names: vec![],
             names: vec![],
+            integrity: None,
           })));
         }
       }
@@ -143,7 +177,19 @@ impl GenerateStage<'_> {
     // aborts the build, otherwise the warnings ride out on the `BundleOutput`.
     // `into_result` fast-paths the common no-error case, skipping the partition.
     let warnings = diagnostics.into_result()?;
-    Ok(BundleOutput { assets: output, warnings })
+    // Outputs are filled in once the `generateBundle` hook has settled the final output.
+    let metafile = self.options.metafile.then(|| Metafile {
+      inputs: collect_metafile_inputs(self.link_output),
+      outputs: FxIndexMap::default(),
+    });
+    let tree_shaking_provenance = self.link_output.tree_shaking_provenance.take();
+    let chunk_records = self
+      .options
+      .experimental
+      .records_path
+      .is_some()
+      .then(|| self.collect_chunk_records(chunk_graph));
+    Ok(BundleOutput { assets: output, warnings, metafile, tree_shaking_provenance, chunk_records })
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
@@ -206,7 +252,23 @@ impl GenerateStage<'_> {
             let ecma_chunks = ecma_chunks_future.await?;
             Ok(ecma_chunks)
           });
-          [ecma_chunks_future]
+          let css_chunks_future: ChunkGeneratorFuture = Box::pin(async move {
+            let mut css_ctx = GenerateContext {
+              chunk_idx,
+              chunk,
+              options: self.options,
+              link_output: self.link_output,
+              used_symbol_refs,
+              order_wrap_state: order_state,
+              chunk_graph,
+              plugin_driver: self.plugin_driver,
+              module_id_to_codegen_ret: vec![],
+              render_export_items_index_vec,
+              resolved_paths,
+            };
+            CssGenerator::instantiate_chunk(&mut css_ctx).await
+          });
+          [ecma_chunks_future, css_chunks_future]
         }),
     )
     .await?
@@ -252,7 +314,10 @@ impl GenerateStage<'_> {
   ) -> Vec<Vec<Option<ModuleRenderOutput>>> {
     let needs_extra_indent = matches!(
       self.options.format,
-      rolldown_common::OutputFormat::Iife | rolldown_common::OutputFormat::Umd
+      rolldown_common::OutputFormat::Iife
+        | rolldown_common::OutputFormat::Umd
+        | rolldown_common::OutputFormat::Amd
+        | rolldown_common::OutputFormat::System
     );
     chunk_graph
       .chunk_table
@@ -92,6 +92,7 @@ impl GenerateStage<'_> {
       body_demand_keys: &body_demand_keys,
       body_demand_swept: FxHashSet::default(),
       pending: Vec::new(),
+      provenance: None,
     };
 
     if f(context) {
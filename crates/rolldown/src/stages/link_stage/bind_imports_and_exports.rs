@@ -7,10 +7,10 @@ use oxc_str::CompactStr;
 // TODO: The current implementation for matching imports is enough so far but incomplete. It needs to be refactored
 // if we want more enhancements related to exports.
 use rolldown_common::{
-  EcmaModuleAstUsage, ExportsKind, ImportRecordIdx, IndexModules, MemberExprObjectReferencedType,
-  MemberExprRefResolution, Module, ModuleIdx, ModuleType, NamespaceAlias, NormalModule,
-  OutputFormat, ResolvedExport, Specifier, StmtInfos, SymbolOrMemberExprRef, SymbolRef,
-  SymbolRefDb, SymbolRefFlags,
+  EcmaModuleAstUsage, ExportsKind, ImportRecordIdx, ImportRecordMeta, IndexModules, InteropType,
+  MemberExprObjectReferencedType, MemberExprRefResolution, Module, ModuleIdx, ModuleType,
+  NamespaceAlias, NormalModule, OutputFormat, ResolvedExport, Specifier, StmtInfos,
+  SymbolOrMemberExprRef, SymbolRef, SymbolRefDb, SymbolRefFlags,
 };
 use rolldown_error::{
   AmbiguousExternalNamespaceModule, BuildDiagnostic, Diagnostics, EventKindSwitcher,
@@ -148,6 +148,10 @@ pub enum ImportStatus {
   /// The imported file is CommonJS and has unknown exports
   CommonJS,
 
+  /// The import is `import defer * as ns`, which is bound to a namespace that evaluates the
+  /// imported file on first use instead of to its module namespace object
+  Deferred,
+
   /// The import is missing but there is a dynamic fallback object
   DynamicFallback {
     namespace_ref: SymbolRef,
@@ -1154,8 +1158,28 @@ impl BindImportsAndExportsContext<'_> {
       }
       let is_external = matches!(self.index_modules[resolved_module_idx], Module::External(_));
 
+      // A `defaultOnly` external is read as `{ default: require(...) }`, so it has no named exports.
+      if !is_esm
+        && let Module::External(external) = &self.index_modules[resolved_module_idx]
+        && external.interop == Some(InteropType::DefaultOnly)
+        && let Specifier::Literal(name) = &named_import.imported
+        && name.as_str() != "default"
+      {
+        self.diagnostics.push(BuildDiagnostic::unexpected_named_import(
+          module.id.to_string(),
+          module.stable_id.to_string(),
+          module.source.clone(),
+          external.stable_id.to_string(),
+          name.to_string(),
+          named_import.span_imported,
+        ));
+      }
+
       if is_esm && is_external {
         match named_import.imported {
+          // `import defer * as ns` keeps its own declaration, it can't share a binding with the
+          // eager namespace imports of the same external.
+          Specifier::Star if rec.meta.contains(ImportRecordMeta::IsDeferred) => {}
           Specifier::Star => {
             self
               .external_import_namespace_merger
@@ -1342,6 +1366,11 @@ impl BindImportsAndExportsContext<'_> {
       return ImportStatus::CommonJS;
     }
 
+    if importer.import_records[named_import.record_idx].meta.contains(ImportRecordMeta::IsDeferred)
+    {
+      return ImportStatus::Deferred;
+    }
+
     match &named_import.imported {
       Specifier::Star => ImportStatus::Found {
         symbol: importee.namespace_object_ref,
@@ -1465,7 +1494,7 @@ impl BindImportsAndExportsContext<'_> {
       let importer_record = &importer.as_normal().unwrap().import_records[named_import.record_idx];
 
       let kind = match import_status {
-        ImportStatus::CommonJS => match &tracker.imported {
+        ImportStatus::CommonJS | ImportStatus::Deferred => match &tracker.imported {
           Specifier::Star => {
             MatchImportKind::Namespace { namespace_ref: importer_record.namespace_ref }
           }
@@ -0,0 +1,161 @@
+use rolldown_common::{
+  EcmaModuleAstUsage, EcmaViewMeta, ExportsKind, ImportKind, ImportRecordMeta, Module, ModuleIdx,
+  Specifier, StmtEvalFlags, StmtInfoMeta, TaggedSymbolRef, side_effects::DeterminedSideEffects,
+};
+use rustc_hash::FxHashSet;
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Converts the CommonJS modules flagged by the scanner to ESM when
+  /// `optimization.commonjsToEsm` is enabled, so they can be hoisted and tree-shaken like any
+  /// other ES module instead of being wrapped in `__commonJS`.
+  ///
+  /// A candidate only stays CommonJS if it's an entry or one of its importers could observe the
+  /// difference, e.g. a default import of a module without the `__esModule` flag, which reads the
+  /// whole `module.exports` object.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub(super) fn convert_commonjs_to_esm(&mut self) {
+    let candidates = self
+      .module_table
+      .modules
+      .iter()
+      .filter_map(Module::as_normal)
+      .filter(|module| module.meta.contains(EcmaViewMeta::CommonjsToEsm))
+      .map(|module| module.idx)
+      .collect::<FxHashSet<_>>();
+    if candidates.is_empty() {
+      return;
+    }
+
+    let mut blocked = candidates
+      .iter()
+      .copied()
+      .filter(|idx| self.entries.contains_key(idx))
+      .collect::<FxHashSet<_>>();
+
+    for importer in self.module_table.modules.iter().filter_map(Module::as_normal) {
+      let is_node_mode = importer.should_consider_node_esm_spec_for_static_import();
+      for (rec_idx, rec) in importer.import_records.iter_enumerated() {
+        let Some(importee_idx) = rec.resolved_module else { continue };
+        if !candidates.contains(&importee_idx) || blocked.contains(&importee_idx) {
+          continue;
+        }
+        let Module::Normal(importee) = &self.module_table[importee_idx] else { continue };
+        let has_es_module_flag = importee.ast_usage.contains(EcmaModuleAstUsage::EsModuleFlag);
+        let has_export = |name: &str| {
+          name != "__esModule"
+            && (importee.named_exports.contains_key(name)
+              || importee
+                .commonjs_object_exports
+                .as_ref()
+                .is_some_and(|exports| exports.contains_key(name)))
+        };
+        let is_safe = match rec.kind {
+          ImportKind::Import if rec.meta.contains(ImportRecordMeta::IsExportStar) => {
+            // `export *` from a CommonJS module forwards `default` as well.
+            !has_export("default")
+          }
+          ImportKind::Import => {
+            importer.named_imports.values().filter(|named| named.record_idx == rec_idx).all(
+              |named| match &named.imported {
+                Specifier::Literal(name) if name != "default" => has_export(name),
+                // Without the `__esModule` flag, or under Node.js semantics, the default import
+                // and the namespace are built from `module.exports` itself.
+                Specifier::Literal(_) => {
+                  has_es_module_flag && !is_node_mode && has_export("default")
+                }
+                Specifier::Star => has_es_module_flag && !is_node_mode,
+              },
+            )
+          }
+          ImportKind::Require => has_es_module_flag,
+          ImportKind::DynamicImport => has_es_module_flag && !is_node_mode,
+          _ => true,
+        };
+        if !is_safe {
+          blocked.insert(importee_idx);
+        }
+      }
+    }
+
+    for &idx in &candidates {
+      let Some(module) = self.module_table[idx].as_normal_mut() else { continue };
+      if blocked.contains(&idx) {
+        module.meta.remove(EcmaViewMeta::CommonjsToEsm);
+        continue;
+      }
+      module.exports_kind = ExportsKind::Esm;
+      module.ast_usage.remove(EcmaModuleAstUsage::ModuleOrExports);
+      if let Some(object_exports) = module.commonjs_object_exports.take() {
+        module.named_exports.extend(*object_exports);
+      }
+      module.named_exports.remove("__esModule");
+      for local_export in module.named_exports.values_mut() {
+        local_export.came_from_commonjs = false;
+      }
+
+      let stmt_infos = &mut self.stmt_infos[idx];
+      for stmt_info in stmt_infos.iter_mut() {
+        stmt_info.eval_flags.remove(StmtEvalFlags::PureCjs);
+        for declared in &mut stmt_info.declared_symbols {
+          if declared.is_link_only() {
+            *declared = TaggedSymbolRef::normal(declared.inner());
+          }
+        }
+        // The flag only exists for CommonJS consumers and is dropped unless something reads it.
+        if stmt_info.meta.contains(StmtInfoMeta::CommonjsEsModuleFlag) {
+          stmt_info.eval_flags = StmtEvalFlags::empty();
+          stmt_info.force_tree_shaking = true;
+        }
+      }
+      if matches!(module.side_effects, DeterminedSideEffects::Analyzed(_)) {
+        module.side_effects = DeterminedSideEffects::Analyzed(
+          stmt_infos
+            .iter()
+            .any(|stmt_info| stmt_info.eval_flags.contains(StmtEvalFlags::UnknownSideEffect)),
+        );
+      }
+    }
+
+    self.hoist_requires_of_converted_modules(&candidates);
+  }
+
+  /// Lowers the hoistable `require` calls of converted modules to static dependencies, so a
+  /// required ES module is evaluated ahead of its importer instead of behind an `init_*` wrapper.
+  /// Requires of modules that stayed CommonJS, or of importees that aren't plain ES modules, keep
+  /// their usual lazy semantics.
+  fn hoist_requires_of_converted_modules(&mut self, candidates: &FxHashSet<ModuleIdx>) {
+    for &idx in candidates {
+      let Module::Normal(importer) = &self.module_table[idx] else { continue };
+      let is_converted = importer.meta.contains(EcmaViewMeta::CommonjsToEsm);
+      let hoisted = importer
+        .import_records
+        .iter_enumerated()
+        .filter(|(_, rec)| rec.meta.contains(ImportRecordMeta::HoistedRequire))
+        .map(|(rec_idx, rec)| {
+          let importee_idx = rec.resolved_module.filter(|&importee_idx| {
+            is_converted
+              && self.module_table[importee_idx].as_normal().is_some_and(|importee| {
+                matches!(importee.exports_kind, ExportsKind::Esm)
+                  && !importee.meta.has_lazy_export()
+              })
+          });
+          (rec_idx, importee_idx)
+        })
+        .collect::<Vec<_>>();
+
+      for (rec_idx, importee_idx) in hoisted {
+        match importee_idx {
+          Some(importee_idx) => {
+            self.metas[idx].dependencies.insert(importee_idx);
+          }
+          None => {
+            let Some(importer) = self.module_table[idx].as_normal_mut() else { continue };
+            importer.import_records[rec_idx].meta.remove(ImportRecordMeta::HoistedRequire);
+          }
+        }
+      }
+    }
+  }
+}
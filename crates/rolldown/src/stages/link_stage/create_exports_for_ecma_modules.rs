@@ -112,7 +112,11 @@ impl LinkStage<'_> {
                   .push(TaggedSymbolRef::normal(ecma_module.import_records[rec_idx].namespace_ref));
               });
             }
-            OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd => {}
+            OutputFormat::Cjs
+            | OutputFormat::Iife
+            | OutputFormat::Umd
+            | OutputFormat::Amd
+            | OutputFormat::System => {}
           }
         }
         // Create a StmtInfo to represent the statement that declares and constructs the Module Namespace Object.
@@ -0,0 +1,69 @@
+use oxc::diagnostics::LabeledSpan;
+use rolldown_common::{ExportsKind, ImportRecordIdx, ImportRecordMeta, Module, ModuleIdx};
+use rolldown_error::{BuildDiagnostic, EventKind};
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Decide which `import defer * as ns from '...'` records keep their deferred phase.
+  ///
+  /// A kept record on a bundled module is linked to a per-record namespace that evaluates the
+  /// module's `init_*` wrapper on first use (see `wrap_modules` and `reference_needed_symbols`).
+  /// A kept record on an external module is rendered as `import defer` again, which requires
+  /// ESM output and a target without downleveling. Everything else falls back to a regular import:
+  /// - CommonJS modules and unsupported externals, with an `UNSUPPORTED_FEATURE` warning.
+  /// - Modules with top-level await in their graph, silently, since the spec evaluates the
+  ///   asynchronous part of a deferred graph eagerly anyway.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub(super) fn determine_deferred_imports(&mut self) {
+    let can_preserve_external_import_defer = self.options.format.keep_esm_import_export_syntax()
+      && self.options.transform_options.target.is_any_target();
+
+    let mut lowered_records: Vec<(ModuleIdx, ImportRecordIdx)> = vec![];
+    for module in self.module_table.modules.iter().filter_map(Module::as_normal) {
+      for (rec_idx, rec) in module.import_records.iter_enumerated() {
+        if !rec.meta.contains(ImportRecordMeta::IsDeferred) {
+          continue;
+        }
+        let unsupported_reason = match rec.resolved_module.map(|idx| &self.module_table[idx]) {
+          Some(Module::Normal(importee)) => {
+            if self.metas[importee.idx].is_tla_or_contains_tla_dependency {
+              lowered_records.push((module.idx, rec_idx));
+              continue;
+            }
+            matches!(importee.exports_kind, ExportsKind::CommonJs)
+              .then_some("the imported module is CommonJS")
+          }
+          Some(Module::External(_)) => (!can_preserve_external_import_defer)
+            .then_some("the output format or target doesn't support `import defer`"),
+          None => None,
+        };
+        if let Some(reason) = unsupported_reason {
+          lowered_records.push((module.idx, rec_idx));
+          self.diagnostics.push(
+            BuildDiagnostic::oxc_error(
+              module.source.clone(),
+              module.id.to_string(),
+              String::new(),
+              format!(
+                "`import defer` is lowered to a normal import because {reason}. This changes execution timing because side effects run immediately instead of when the deferred namespace is first used."
+              ),
+              vec![LabeledSpan::at(
+                rec.importer_span.start..rec.importer_span.end,
+                "The deferred phase is removed here.",
+              )],
+              EventKind::UnsupportedFeatureError,
+            )
+            .with_severity_warning(),
+          );
+        }
+      }
+    }
+
+    for (module_idx, rec_idx) in lowered_records {
+      if let Some(module) = self.module_table[module_idx].as_normal_mut() {
+        module.import_records[rec_idx].meta.remove(ImportRecordMeta::IsDeferred);
+      }
+    }
+  }
+}
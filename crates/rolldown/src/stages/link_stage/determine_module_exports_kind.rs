@@ -28,11 +28,11 @@ impl LinkStage<'_> {
       };
 
       for rec_pos in 0..n_records {
-        let (kind, importee_idx) = {
+        let (kind, rec_meta, importee_idx) = {
           let Module::Normal(m) = &self.module_table[importer_idx] else { continue };
           let rec = &m.import_records[ImportRecordIdx::from_usize(rec_pos)];
           let Some(importee_idx) = rec.resolved_module else { continue };
-          (rec.kind, importee_idx)
+          (rec.kind, rec.meta, importee_idx)
         };
         let (importee_kind, has_lazy) = match &self.module_table[importee_idx] {
           Module::Normal(m) => (m.exports_kind, m.meta.has_lazy_export()),
@@ -48,6 +48,8 @@ impl LinkStage<'_> {
             }
           }
           ImportKind::Require => match importee_kind {
+            // Evaluated like a static import, see `ImportRecordMeta::HoistedRequire`.
+            ExportsKind::Esm if rec_meta.contains(ImportRecordMeta::HoistedRequire) => {}
             ExportsKind::Esm => {
               self.metas[importee_idx].set_wrap_kind(WrapKind::Esm);
             }
@@ -98,9 +100,11 @@ impl LinkStage<'_> {
       let is_entry = self.entries.contains_key(&importer.idx);
       if matches!(importer.exports_kind, ExportsKind::CommonJs)
         && (!is_entry
-          || matches!(self.options.format, OutputFormat::Esm)
-          || (matches!(self.options.format, OutputFormat::Iife | OutputFormat::Umd)
-            && importer.ast_usage.intersects(EcmaModuleAstUsage::ModuleOrExports)))
+          || matches!(self.options.format, OutputFormat::Esm | OutputFormat::System)
+          || (matches!(
+            self.options.format,
+            OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd
+          ) && importer.ast_usage.intersects(EcmaModuleAstUsage::ModuleOrExports)))
       {
         self.metas[importer.idx].set_wrap_kind(WrapKind::Cjs);
       }
@@ -7,7 +7,7 @@ use rolldown_common::common_debug_symbol_ref;
 use rolldown_common::{
   ConstExportMeta, DependedRuntimeHelperMap, EntryPoint, EntryPointKind, FlatOptions, ImportKind,
   ModuleIdx, ModuleTable, PreserveEntrySignatures, RetainedExportSymbols, RuntimeModuleBrief,
-  SymbolRef, SymbolRefDb, UsedExternalSymbols, UsedSymbolRefsBuilder,
+  SymbolRef, SymbolRefDb, TreeShakingProvenance, UsedExternalSymbols, UsedSymbolRefsBuilder,
   dynamic_import_usage::DynamicImportExportsUsage,
 };
 use rolldown_error::Diagnostics;
@@ -30,8 +30,10 @@ use super::scan_stage::NormalizedScanStageOutput;
 
 mod bind_imports_and_exports;
 mod compute_tla;
+mod convert_commonjs_to_esm;
 mod create_exports_for_ecma_modules;
 mod cross_module_optimization;
+mod determine_deferred_imports;
 mod determine_module_exports_kind;
 mod generate_lazy_export;
 mod patch_module_dependencies;
@@ -83,6 +85,8 @@ pub struct LinkStageOutput {
   /// True if any module has enum member values to inline. Computed once to avoid
   /// repeated full module table scans.
   pub has_enum_inlining: bool,
+  /// Only present when `experimental.treeShakingProvenance` is enabled.
+  pub tree_shaking_provenance: Option<TreeShakingProvenance>,
 }
 
 #[derive(Debug)]
@@ -126,6 +130,8 @@ pub struct LinkStage<'a> {
   pub tla_keyword_span_map: FxHashMap<ModuleIdx, Span>,
   /// Computed during `include_statements`, reused when building `LinkStageOutput`.
   pub has_enum_inlining: bool,
+  /// Computed during `include_statements` when `experimental.treeShakingProvenance` is enabled.
+  pub tree_shaking_provenance: Option<TreeShakingProvenance>,
 }
 
 impl<'a> LinkStage<'a> {
@@ -228,6 +234,7 @@ impl<'a> LinkStage<'a> {
       tla_module_count: scan_stage_output.tla_module_count,
       tla_keyword_span_map: scan_stage_output.tla_keyword_span_map,
       has_enum_inlining: false,
+      tree_shaking_provenance: None,
     }
   }
 
@@ -235,7 +242,9 @@ impl<'a> LinkStage<'a> {
   pub fn link(mut self) -> (LinkStageOutput, IndexEcmaAst, UsedSymbolRefsBuilder) {
     self.sort_modules();
     self.compute_tla();
+    self.convert_commonjs_to_esm();
     self.determine_module_exports_kind();
+    self.determine_deferred_imports();
     self.determine_safely_merge_cjs_ns();
     self.wrap_modules();
     self.generate_lazy_export();
@@ -271,6 +280,7 @@ impl<'a> LinkStage<'a> {
         star_reexport_records_by_imported_symbol: self.star_reexport_records_by_imported_symbol,
         user_defined_entry_modules: self.user_defined_entry_modules,
         has_enum_inlining: self.has_enum_inlining,
+        tree_shaking_provenance: self.tree_shaking_provenance,
       },
       self.ast_table,
       self.used_symbol_refs,
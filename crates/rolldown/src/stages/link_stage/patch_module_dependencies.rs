@@ -51,6 +51,13 @@ impl LinkStage<'_> {
           else {
             return;
           };
+          // `output.interop` may read this external without the helper at all.
+          if self.module_table[namespace_ref.owner]
+            .as_external()
+            .is_some_and(|external| !external.needs_to_esm_helper())
+          {
+            return;
+          }
           // Mirror `chunk_recorded_external_interop`, which puts the wrapper only in the chunks the
           // observers land in: a module that merely reads a *name* off the same external renders no
           // `__toESM` call and must not demand the helper, or its chunk gains a cross-chunk import
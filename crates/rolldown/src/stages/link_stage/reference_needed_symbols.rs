@@ -1,6 +1,6 @@
 use rolldown_common::{
   ExportsKind, ImportKind, ImportRecordIdx, ImportRecordMeta, Module, OutputFormat, RuntimeHelper,
-  StmtInfoMeta, SymbolRefDb, TaggedSymbolRef, WrapKind,
+  StmtEvalFlags, StmtInfoMeta, SymbolRefDb, TaggedSymbolRef, WrapKind,
 };
 #[cfg(not(target_family = "wasm"))]
 use rolldown_utils::rayon::IndexedParallelIterator;
@@ -47,6 +47,9 @@ impl LinkStage<'_> {
           if stmt_info.meta.contains(StmtInfoMeta::HasDummyRecord) {
             depended_runtime_helper_map.push(RuntimeHelper::Require, stmt_info_idx);
           }
+          if stmt_info.meta.contains(StmtInfoMeta::GlobImport) {
+            depended_runtime_helper_map.push(RuntimeHelper::Glob, stmt_info_idx);
+          }
           // Handle non-static dynamic imports like `import(foo)` or `import('a' + 'b')`
           if stmt_info.meta.intersects(StmtInfoMeta::NonStaticDynamicImport) {
             depended_runtime_helper_map.push(RuntimeHelper::ToEsm, stmt_info_idx);
@@ -70,11 +73,17 @@ impl LinkStage<'_> {
                       // import ... from 'external' or export ... from 'external'
                       if matches!(
                         self.options.format,
-                        OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd
+                        OutputFormat::Cjs
+                          | OutputFormat::Iife
+                          | OutputFormat::Umd
+                          | OutputFormat::Amd
                       ) {
                         stmt_info.eval_flags = true.into();
                         // Only reference __toESM if this import needs interop (namespace or default import)
-                        if import_record_needs_interop(importer, *rec_id) {
+                        // and the `output.interop` of the external reads it through the helper.
+                        if importee.needs_to_esm_helper()
+                          && import_record_needs_interop(importer, *rec_id)
+                        {
                           depended_runtime_helper_map.push(RuntimeHelper::ToEsm, stmt_info_idx);
                         }
                       }
@@ -172,6 +181,23 @@ impl LinkStage<'_> {
                           );
                         }
                       }
+                      WrapKind::Esm if rec.meta.contains(ImportRecordMeta::IsDeferred) => {
+                        // Turn `import defer * as bar from 'bar_esm'` into
+                        // `var import_bar_esm = __importDefer(init_bar_esm, bar_esm_exports)`.
+                        // Nothing runs until the namespace is used, so the statement has no
+                        // side effects of its own.
+                        stmt_info.eval_flags = false.into();
+                        stmt_info
+                          .referenced_symbols
+                          .push(importee_linking_info.wrapper_ref.unwrap().into());
+                        stmt_info.referenced_symbols.push(importee.namespace_object_ref.into());
+                        depended_runtime_helper_map.push(RuntimeHelper::ImportDefer, stmt_info_idx);
+                        symbols_to_be_declared.push((rec.namespace_ref, stmt_info_idx));
+                        symbol_db.ast_scopes.set_symbol_name(
+                          rec.namespace_ref.symbol,
+                          &concat_string!("import_", importee.repr_name),
+                        );
+                      }
                       WrapKind::Esm => {
                         // Turn `import ... from 'bar_esm'` into `init_bar_esm()`
                         stmt_info.eval_flags =
@@ -193,6 +219,17 @@ impl LinkStage<'_> {
                     }
                   }
                   ImportKind::Require => match importee_linking_info.wrap_kind() {
+                    WrapKind::None if rec.meta.contains(ImportRecordMeta::HoistedRequire) => {
+                      if rec.meta.contains(ImportRecordMeta::IsRequireUnused) {
+                        // `require('foo')` is a bare statement and the importee is evaluated
+                        // ahead of the importer, so there's nothing left to do.
+                        stmt_info.eval_flags = StmtEvalFlags::empty();
+                      } else {
+                        // `__toCommonJS(foo_exports)`
+                        stmt_info.referenced_symbols.push(importee.namespace_object_ref.into());
+                        depended_runtime_helper_map.push(RuntimeHelper::ToCommonJs, stmt_info_idx);
+                      }
+                    }
                     WrapKind::None => {}
                     WrapKind::Cjs => {
                       // something like `require_foo()`
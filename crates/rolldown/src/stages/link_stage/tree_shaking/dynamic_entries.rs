@@ -14,9 +14,12 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::stages::link_stage::LinkStage;
 
-use super::include_statements::{
-  IncludeContext, StmtInclusionVec, SymbolIncludeReason, include_declaring_statements,
-  include_module, include_symbol_and_check_cjs_bailout,
+use super::{
+  include_statements::{
+    IncludeContext, StmtInclusionVec, SymbolIncludeReason, include_declaring_statements,
+    include_module, include_symbol_and_check_cjs_bailout,
+  },
+  provenance::CauseKind,
 };
 
 impl LinkStage<'_> {
@@ -47,6 +50,7 @@ impl LinkStage<'_> {
         return true;
       }
     };
+    context.set_provenance_root(CauseKind::DynamicEntry);
     let meta = &self.metas[entry.idx];
     meta.referenced_symbols_by_entry_point_chunk.iter().for_each(|(symbol_ref, _came_from_cjs)| {
       if let Module::Normal(_) = &context.modules[symbol_ref.owner] {
@@ -2,12 +2,12 @@ use itertools::Itertools;
 use oxc::semantic::NodeId;
 use oxc_index::IndexVec;
 use rolldown_common::{
-  ConstExportMeta, EcmaModuleAstUsage, EcmaViewMeta, ExportsKind, ExternalInteropUse, ImportKind,
-  ImportRecordMeta, IndexModules, MemberExprRef, Module, ModuleIdx, ModuleNamespaceIncludedReason,
-  ModuleType, NormalModule, NormalizedBundlerOptions, RUNTIME_MODULE_ID, RuntimeHelper,
-  StmtEvalFlags, StmtInfo, StmtInfoIdx, StmtInfoMeta, StmtInfos, SymbolOrMemberExprRef, SymbolRef,
-  SymbolRefDb, UsedExternalSymbols, UsedSymbolRefsBuilder, WrapKind,
-  side_effects::DeterminedSideEffects,
+  ConstExportMeta, EcmaModuleAstUsage, EcmaViewMeta, ExportsKind, ExternalInteropUse,
+  ExternalModule, ImportKind, ImportRecordMeta, IndexModules, MemberExprRef, Module, ModuleIdx,
+  ModuleNamespaceIncludedReason, ModuleType, NormalModule, NormalizedBundlerOptions,
+  RUNTIME_MODULE_ID, RuntimeHelper, StmtEvalFlags, StmtInfo, StmtInfoIdx, StmtInfoMeta, StmtInfos,
+  SymbolOrMemberExprRef, SymbolRef, SymbolRefDb, UsedExternalSymbols, UsedSymbolRefsBuilder,
+  WrapKind, side_effects::DeterminedSideEffects,
 };
 #[cfg(not(target_family = "wasm"))]
 use rolldown_utils::rayon::IndexedParallelIterator;
@@ -33,6 +33,7 @@ use super::{
     collect_depended_runtime_helpers, include_cjs_bailout_exports, include_runtime_symbol,
     preserve_reexported_interfaces,
   },
+  provenance::{CauseKind, ProvenanceRecorder},
 };
 
 pub type StmtInclusionVec = IndexVec<ModuleIdx, IndexBitSet<StmtInfoIdx>>;
@@ -109,6 +110,9 @@ pub struct IncludeContext<'a> {
   /// `chunk_optimizer` constructs `IncludeContext` with a struct literal; nothing outside this
   /// module should touch it.
   pub(in crate::stages) pending: Vec<WorkItem>,
+  /// Set when `experimental.treeShakingProvenance` is enabled. Every push to `pending` and every
+  /// first inclusion is reported to it.
+  pub provenance: Option<&'a mut ProvenanceRecorder>,
 }
 
 impl<'a> IncludeContext<'a> {
@@ -129,6 +133,7 @@ impl<'a> IncludeContext<'a> {
     module_namespace_included_reason: &'a mut ModuleNamespaceReasonVec,
     entry_module_idxs: &'a FxHashSet<ModuleIdx>,
     body_demand_keys: &'a FxHashMap<SymbolRef, ModuleIdx>,
+    provenance: Option<&'a mut ProvenanceRecorder>,
   ) -> Self {
     Self {
       modules,
@@ -154,6 +159,15 @@ impl<'a> IncludeContext<'a> {
       body_demand_keys,
       body_demand_swept: FxHashSet::default(),
       pending: Vec::new(),
+      provenance,
+    }
+  }
+
+  /// Sets the provenance recorded for what the following `include_*` calls include directly, and
+  /// for everything included through the symbols they include.
+  pub fn set_provenance_root(&mut self, kind: CauseKind) {
+    if let Some(provenance) = self.provenance.as_deref_mut() {
+      provenance.root = kind;
     }
   }
 }
@@ -178,6 +192,9 @@ pub(in crate::stages) enum WorkItem {
 /// drain order affects only traversal order, never the result.
 fn drain_work_items(ctx: &mut IncludeContext) {
   while let Some(item) = ctx.pending.pop() {
+    if let Some(provenance) = ctx.provenance.as_deref_mut() {
+      provenance.begin(item);
+    }
     match item {
       WorkItem::Module(module_idx) => handle_include_module(ctx, module_idx),
       WorkItem::Symbol(symbol_ref, reason) => handle_include_symbol(ctx, symbol_ref, reason),
@@ -186,6 +203,26 @@ fn drain_work_items(ctx: &mut IncludeContext) {
       }
     }
   }
+  if let Some(provenance) = ctx.provenance.as_deref_mut() {
+    provenance.end();
+  }
+}
+
+/// Push a work item from a handler. `kind` and `symbol` describe the edge for provenance.
+fn enqueue(ctx: &mut IncludeContext, item: WorkItem, kind: CauseKind, symbol: Option<SymbolRef>) {
+  ctx.pending.push(item);
+  if let Some(provenance) = ctx.provenance.as_deref_mut() {
+    provenance.push_cause(kind, symbol);
+  }
+}
+
+/// Push a work item from a public entry point, attributed to the root set by
+/// [`IncludeContext::set_provenance_root`].
+fn enqueue_root(ctx: &mut IncludeContext, item: WorkItem, symbol: Option<SymbolRef>) {
+  ctx.pending.push(item);
+  if let Some(provenance) = ctx.provenance.as_deref_mut() {
+    provenance.push_root_cause(symbol);
+  }
 }
 
 /// Include a symbol and check for CJS tree-shaking bailout.
@@ -199,7 +236,8 @@ pub(super) fn include_symbol_and_check_cjs_bailout(
   include_reason: SymbolIncludeReason,
 ) {
   debug_assert!(ctx.pending.is_empty(), "engine queue must be empty between public entry points");
-  push_symbol_and_check_cjs_bailout(ctx, symbol_ref, include_reason);
+  enqueue_root(ctx, WorkItem::Symbol(symbol_ref, include_reason), Some(symbol_ref));
+  check_cjs_bailout(ctx, symbol_ref);
   drain_work_items(ctx);
   debug_assert!(ctx.pending.is_empty(), "public entry points must drain the queue to empty");
 }
@@ -211,8 +249,9 @@ fn push_symbol_and_check_cjs_bailout(
   ctx: &mut IncludeContext,
   symbol_ref: SymbolRef,
   include_reason: SymbolIncludeReason,
+  kind: CauseKind,
 ) {
-  ctx.pending.push(WorkItem::Symbol(symbol_ref, include_reason));
+  enqueue(ctx, WorkItem::Symbol(symbol_ref, include_reason), kind, Some(symbol_ref));
   check_cjs_bailout(ctx, symbol_ref);
 }
 
@@ -253,6 +292,7 @@ fn check_cjs_bailout(ctx: &mut IncludeContext, symbol_ref: SymbolRef) {
 
 impl LinkStage<'_> {
   #[tracing::instrument(level = "debug", skip_all)]
+  #[expect(clippy::too_many_lines)]
   pub fn include_statements(
     &mut self,
     unreachable_import_expression_node_ids: &FxHashSet<(ModuleIdx, NodeId)>,
@@ -278,6 +318,11 @@ impl LinkStage<'_> {
       .iter()
       .any(|m| m.as_normal().is_some_and(|n| !n.ecma_view.enum_member_value_map.is_empty()));
     let entry_module_idxs = self.user_defined_entry_module_idxs();
+    let mut provenance = self
+      .options
+      .experimental
+      .is_tree_shaking_provenance_enabled()
+      .then(ProvenanceRecorder::default);
     let body_demand_keys = compute_body_demand_keys(
       &self.module_table.modules,
       &self.stmt_infos,
@@ -301,6 +346,7 @@ impl LinkStage<'_> {
       &mut module_namespace_included_reason,
       &entry_module_idxs,
       &body_demand_keys,
+      provenance.as_mut(),
     );
 
     let (user_defined_entries, mut dynamic_entries): (Vec<_>, Vec<_>) =
@@ -317,6 +363,7 @@ impl LinkStage<'_> {
         }
       };
       context.bailout_cjs_tree_shaking_modules.insert(module.idx);
+      context.set_provenance_root(CauseKind::EntryExport);
       let meta = &self.metas[entry.idx];
       meta.referenced_symbols_by_entry_point_chunk.iter().for_each(
         |(symbol_ref, _came_from_cjs)| {
@@ -330,6 +377,7 @@ impl LinkStage<'_> {
           }
         },
       );
+      context.set_provenance_root(CauseKind::Entry);
       include_module(context, module);
     });
 
@@ -442,8 +490,13 @@ impl LinkStage<'_> {
     // so it dies with the module holding it — routinely a re-export shim that tree-shaking drops
     // while the `<external_ns>.default` reference it produced lives on elsewhere (issue #10069).
     // The recorded uses are the post-linking truth about who still reads the external as an ES
-    // module, so they keep the helper alive on their own.
-    if used_external_symbols.has_interop_use() {
+    // module, so they keep the helper alive on their own — unless `output.interop` reads that
+    // external without it.
+    if used_external_symbols.iter_interop_uses().any(|(namespace_ref, _)| {
+      self.module_table[namespace_ref.owner]
+        .as_external()
+        .is_none_or(ExternalModule::needs_to_esm_helper)
+    }) {
       depended_runtime_helper |= RuntimeHelper::ToEsm;
     }
     let context = &mut IncludeContext::new(
@@ -462,11 +515,15 @@ impl LinkStage<'_> {
       &mut module_namespace_included_reason,
       &entry_module_idxs,
       &body_demand_keys,
+      provenance.as_mut(),
     );
     include_runtime_symbol(context, &self.runtime, depended_runtime_helper);
 
     self.used_symbol_refs = used_symbol_refs;
     self.used_external_symbols = used_external_symbols;
+    self.tree_shaking_provenance = provenance.map(|provenance| {
+      provenance.into_provenance(&self.module_table.modules, &self.ast_table, &self.symbols)
+    });
     // Store the final statement inclusion results back to metas.
     is_stmt_info_included_vec.into_iter_enumerated().for_each(|(module_idx, stmt_included_vec)| {
       self.metas[module_idx].stmt_info_included = stmt_included_vec;
@@ -511,7 +568,12 @@ fn enqueue_declaring_statements(ctx: &mut IncludeContext, symbol_ref: &SymbolRef
   if let Module::Normal(_) = &ctx.modules[symbol_ref.owner] {
     ctx.stmt_infos[symbol_ref.owner].declared_stmts_by_symbol(symbol_ref).iter().copied().for_each(
       |stmt_info_id| {
-        ctx.pending.push(WorkItem::Statement(symbol_ref.owner, stmt_info_id));
+        enqueue(
+          ctx,
+          WorkItem::Statement(symbol_ref.owner, stmt_info_id),
+          CauseKind::UsedSymbol,
+          Some(*symbol_ref),
+        );
       },
     );
   }
@@ -519,7 +581,7 @@ fn enqueue_declaring_statements(ctx: &mut IncludeContext, symbol_ref: &SymbolRef
 
 pub fn include_module(ctx: &mut IncludeContext, module: &NormalModule) {
   debug_assert!(ctx.pending.is_empty(), "engine queue must be empty between public entry points");
-  ctx.pending.push(WorkItem::Module(module.idx));
+  enqueue_root(ctx, WorkItem::Module(module.idx), None);
   drain_work_items(ctx);
   debug_assert!(ctx.pending.is_empty(), "public entry points must drain the queue to empty");
 }
@@ -532,6 +594,9 @@ fn handle_include_module(ctx: &mut IncludeContext, module_idx: ModuleIdx) {
     return;
   }
   ctx.module_inclusion_changed = true;
+  if let Some(provenance) = ctx.provenance.as_deref_mut() {
+    provenance.record_module(module.idx);
+  }
 
   if module.idx == ctx.runtime_idx && !module.side_effects.has_side_effects() {
     // Unmodified runtime: statements included only via references.
@@ -550,12 +615,17 @@ fn handle_include_module(ctx: &mut IncludeContext, module_idx: ModuleIdx) {
   if module.meta.has_eval() && matches!(module.module_type, ModuleType::Js | ModuleType::Jsx) {
     // `eval` can observe any module-level binding, so every import must survive.
     module.named_imports.keys().for_each(|symbol| {
-      push_symbol_and_check_cjs_bailout(ctx, *symbol, SymbolIncludeReason::Normal);
+      push_symbol_and_check_cjs_bailout(ctx, *symbol, SymbolIncludeReason::Normal, CauseKind::Eval);
     });
   }
 
   ctx.metas[module.idx].included_commonjs_export_symbol.iter().for_each(|symbol_ref| {
-    push_symbol_and_check_cjs_bailout(ctx, *symbol_ref, SymbolIncludeReason::Normal);
+    push_symbol_and_check_cjs_bailout(
+      ctx,
+      *symbol_ref,
+      SymbolIncludeReason::Normal,
+      CauseKind::UsedSymbol,
+    );
   });
 
   // With enabling HMR, rolldown will register included esm module's namespace object to the runtime.
@@ -563,7 +633,12 @@ fn handle_include_module(ctx: &mut IncludeContext, module_idx: ModuleIdx) {
     && module.idx != ctx.runtime_idx
     && matches!(module.exports_kind, ExportsKind::Esm)
   {
-    ctx.pending.push(WorkItem::Statement(module.idx, StmtInfos::NAMESPACE_STMT_IDX));
+    enqueue(
+      ctx,
+      WorkItem::Statement(module.idx, StmtInfos::NAMESPACE_STMT_IDX),
+      CauseKind::DevModeNamespace,
+      None,
+    );
     ctx.module_namespace_included_reason[module.idx].insert(ModuleNamespaceIncludedReason::Unknown);
   }
 }
@@ -590,10 +665,11 @@ fn sweep_side_effect_statements(ctx: &mut IncludeContext, module: &NormalModule)
       } else {
         stmt_info.eval_flags.has_side_effect_for_tree_shaking()
       };
-      if (has_side_effects && !(on_demand_side_effects && is_gated_side_effect_stmt(stmt_info)))
-        || bail_eval
-      {
-        ctx.pending.push(WorkItem::Statement(module.idx, stmt_info_id));
+      let kept_for_side_effects =
+        has_side_effects && !(on_demand_side_effects && is_gated_side_effect_stmt(stmt_info));
+      if kept_for_side_effects || bail_eval {
+        let kind = if kept_for_side_effects { CauseKind::SideEffect } else { CauseKind::Eval };
+        enqueue(ctx, WorkItem::Statement(module.idx, stmt_info_id), kind, None);
       }
     },
   );
@@ -606,14 +682,15 @@ fn include_statements_without_treeshaking(ctx: &mut IncludeContext, module: &Nor
   // Skip the namespace statement. It should be included only if it is used no matter tree shaking is enabled or not.
   ctx.stmt_infos[module.idx].iter_enumerated_without_namespace_stmt().for_each(
     |(stmt_info_id, stmt_info)| {
+      let item = WorkItem::Statement(module.idx, stmt_info_id);
       if stmt_info.force_tree_shaking {
         if stmt_info.eval_flags.has_side_effect_for_tree_shaking() {
           // If `force_tree_shaking` is true, the statement should be included either by itself having side effects
           // or by other statements referencing it.
-          ctx.pending.push(WorkItem::Statement(module.idx, stmt_info_id));
+          enqueue(ctx, item, CauseKind::SideEffect, None);
         }
       } else {
-        ctx.pending.push(WorkItem::Statement(module.idx, stmt_info_id));
+        enqueue(ctx, item, CauseKind::NoTreeshake, None);
       }
     },
   );
@@ -634,7 +711,7 @@ fn include_side_effectful_dependencies(ctx: &mut IncludeContext, module: &Normal
     match &ctx.modules[dependency_idx] {
       Module::Normal(importee) => {
         if !ctx.tree_shaking || importee.side_effects.has_side_effects() {
-          ctx.pending.push(WorkItem::Module(importee.idx));
+          enqueue(ctx, WorkItem::Module(importee.idx), CauseKind::SideEffectfulImport, None);
         }
       }
       Module::External(_) => {}
@@ -653,7 +730,7 @@ pub fn include_symbol(
   include_reason: SymbolIncludeReason,
 ) {
   debug_assert!(ctx.pending.is_empty(), "engine queue must be empty between public entry points");
-  ctx.pending.push(WorkItem::Symbol(symbol_ref, include_reason));
+  enqueue_root(ctx, WorkItem::Symbol(symbol_ref, include_reason), Some(symbol_ref));
   drain_work_items(ctx);
   debug_assert!(ctx.pending.is_empty(), "public entry points must drain the queue to empty");
 }
@@ -705,7 +782,7 @@ fn handle_include_symbol(
     note_json_self_reference(ctx, module, canonical_ref, include_reason);
     enqueue_declaring_statements(ctx, &canonical_ref);
     if !is_simulated_facade_chunk {
-      ctx.pending.push(WorkItem::Module(module.idx));
+      enqueue(ctx, WorkItem::Module(module.idx), CauseKind::UsedSymbol, Some(canonical_ref));
     }
   }
 
@@ -751,7 +828,8 @@ fn drain_body_demand_stmts(ctx: &mut IncludeContext, canonical_ref: SymbolRef) {
     ctx.stmt_infos[module_idx].iter_enumerated_without_namespace_stmt().for_each(
       |(stmt_info_idx, stmt_info)| {
         if is_gated_side_effect_stmt(stmt_info) {
-          ctx.pending.push(WorkItem::Statement(module_idx, stmt_info_idx));
+          let item = WorkItem::Statement(module_idx, stmt_info_idx);
+          enqueue(ctx, item, CauseKind::BodyDemand, Some(canonical_ref));
         }
       },
     );
@@ -781,7 +859,8 @@ fn follow_cjs_namespace_alias(ctx: &mut IncludeContext, canonical_ref: &mut Symb
           return;
         };
         if namespace_alias.property_name.as_str() != "default" {
-          ctx.pending.push(WorkItem::Symbol(export_symbol.symbol_ref, SymbolIncludeReason::Normal));
+          let item = WorkItem::Symbol(export_symbol.symbol_ref, SymbolIncludeReason::Normal);
+          enqueue(ctx, item, CauseKind::UsedSymbol, Some(export_symbol.symbol_ref));
         }
       });
     }
@@ -934,7 +1013,8 @@ fn demand_esm_init_wrapper(ctx: &mut IncludeContext, canonical_ref: SymbolRef) {
       .filter(|wrapper_ref| *wrapper_ref != canonical_ref)
   };
   if let Some(wrapper_ref) = wrapper_ref {
-    ctx.pending.push(WorkItem::Symbol(wrapper_ref, SymbolIncludeReason::Normal));
+    let item = WorkItem::Symbol(wrapper_ref, SymbolIncludeReason::Normal);
+    enqueue(ctx, item, CauseKind::UsedSymbol, Some(canonical_ref));
   }
 }
 
@@ -971,7 +1051,8 @@ fn include_property_write_referencing_stmts(ctx: &mut IncludeContext, symbol_ref
       .unwrap_or(&[]);
     if ctx.modules[symbol_ref.owner].as_normal().is_some() {
       for stmt_info_id in stmt_ids.iter().copied() {
-        ctx.pending.push(WorkItem::Statement(symbol_ref.owner, stmt_info_id));
+        let item = WorkItem::Statement(symbol_ref.owner, stmt_info_id);
+        enqueue(ctx, item, CauseKind::PropertyWrite, Some(symbol_ref));
       }
     }
   }
@@ -983,7 +1064,7 @@ pub fn include_statement(
   stmt_info_idx: StmtInfoIdx,
 ) {
   debug_assert!(ctx.pending.is_empty(), "engine queue must be empty between public entry points");
-  ctx.pending.push(WorkItem::Statement(module.idx, stmt_info_idx));
+  enqueue_root(ctx, WorkItem::Statement(module.idx, stmt_info_idx), None);
   drain_work_items(ctx);
   debug_assert!(ctx.pending.is_empty(), "public entry points must drain the queue to empty");
 }
@@ -1000,6 +1081,9 @@ fn handle_include_statement(
   if !ctx.is_included_vec[module.idx].set_bit(stmt_info_idx) {
     return;
   }
+  if let Some(provenance) = ctx.provenance.as_deref_mut() {
+    provenance.record_statement(module.idx, stmt_info_idx);
+  }
 
   let stmt_info = ctx.stmt_infos[module.idx].get(stmt_info_idx);
 
@@ -1030,7 +1114,12 @@ fn handle_include_statement(
         member_expr_resolution.depended_refs.iter().for_each(|sym_ref| {
           enqueue_declaring_statements(ctx, sym_ref);
         });
-        ctx.pending.push(WorkItem::Symbol(resolved_ref, include_kind));
+        enqueue(
+          ctx,
+          WorkItem::Symbol(resolved_ref, include_kind),
+          CauseKind::UsedSymbol,
+          Some(resolved_ref),
+        );
         // When the member expression resolves to a specific CJS export property
         // (e.g., `ns.x`), we skip the bailout check — we know the access is partial
         // and CJS tree-shaking can work. Otherwise, the full namespace may be used
@@ -1058,7 +1147,7 @@ fn handle_include_statement(
         .for_each(|sym_ref| {
           enqueue_declaring_statements(ctx, sym_ref);
         });
-      push_symbol_and_check_cjs_bailout(ctx, *original_ref, include_kind);
+      push_symbol_and_check_cjs_bailout(ctx, *original_ref, include_kind, CauseKind::UsedSymbol);
     }
   });
 }
@@ -3,6 +3,7 @@ mod dynamic_entries;
 pub mod include_statements;
 mod on_demand;
 mod passes;
+mod provenance;
 
 pub use include_statements::{
   IncludeContext, ModuleInclusionVec, ModuleNamespaceReasonVec, StmtInclusionVec,
@@ -18,9 +18,12 @@ use rustc_hash::FxHashSet;
 
 use crate::types::linking_metadata::LinkingMetadataVec;
 
-use super::include_statements::{
-  IncludeContext, ModuleInclusionVec, SymbolIncludeReason, include_module, include_statement,
-  include_symbol, include_symbol_and_check_cjs_bailout,
+use super::{
+  include_statements::{
+    IncludeContext, ModuleInclusionVec, SymbolIncludeReason, include_module, include_statement,
+    include_symbol, include_symbol_and_check_cjs_bailout,
+  },
+  provenance::CauseKind,
 };
 
 pub(super) fn include_cjs_bailout_exports(
@@ -28,6 +31,7 @@ pub(super) fn include_cjs_bailout_exports(
   metas: &LinkingMetadataVec,
   bailout_modules: impl IntoIterator<Item = ModuleIdx>,
 ) {
+  context.set_provenance_root(CauseKind::CommonJsBailout);
   for idx in bailout_modules {
     metas[idx].resolved_exports.values().filter(|local| local.came_from_commonjs).for_each(
       |local| {
@@ -70,6 +74,7 @@ pub fn include_runtime_symbol(
   depended_runtime_helper: RuntimeHelper,
 ) {
   let runtime_module = &ctx.modules[runtime.id()].as_normal().expect("runtime should be normal");
+  ctx.set_provenance_root(CauseKind::RuntimeHelper);
 
   if depended_runtime_helper.is_empty() {
     // No runtime helpers needed, but if the runtime has side effects (e.g. from
@@ -120,6 +125,7 @@ pub(super) fn preserve_reexported_interfaces(ctx: &mut IncludeContext) {
       consumed_facades.extend(reexports.iter().copied());
     }
   }
+  ctx.set_provenance_root(CauseKind::PreservedReexport);
   for symbol_ref in consumed_facades {
     let module_idx = symbol_ref.owner;
     if module_idx == ctx.runtime_idx || !ctx.is_module_included_vec.has_bit(module_idx) {
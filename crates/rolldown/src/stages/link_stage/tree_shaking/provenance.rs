@@ -0,0 +1,284 @@
+//! Opt-in recording of why each module and statement was included (see
+//! [`TreeShakingProvenance`]). The inclusion engine reports every edge it pushes and every first
+//! inclusion; this module keeps the cause of each and resolves them into the public shape.
+
+use oxc::ast::ast::{
+  ArrowFunctionExpression, AssignmentExpression, CallExpression, Function, IdentifierReference,
+  NewExpression, Statement, TaggedTemplateExpression, UnaryExpression, UnaryOperator,
+  UpdateExpression,
+};
+use oxc::ast_visit::{VisitJs, walk_js};
+use oxc::semantic::{ScopeFlags, Scoping};
+use oxc::span::GetSpan;
+use rolldown_common::{
+  GetLocalDb, InclusionReason, InclusionReasonKind, InclusionSite, IndexModules, ModuleIdx,
+  ModuleProvenance, SideEffectKind, StatementProvenance, StmtInfoIdx, SymbolRef, SymbolRefDb,
+  TreeShakingProvenance,
+};
+use rolldown_utils::indexmap::FxIndexMap;
+use rustc_hash::FxHashMap;
+
+use crate::type_alias::IndexEcmaAst;
+
+use super::include_statements::WorkItem;
+
+/// Statement excerpts are cut to this many characters.
+const MAX_CODE_LEN: usize = 80;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Site {
+  Module(ModuleIdx),
+  Statement(ModuleIdx, StmtInfoIdx),
+}
+
+/// Mirrors [`InclusionReasonKind`], but `Copy`: side effects are only classified once the
+/// statements are known, in [`ProvenanceRecorder::into_provenance`].
+#[derive(Debug, Default, Clone, Copy)]
+pub enum CauseKind {
+  #[default]
+  Entry,
+  DynamicEntry,
+  EntryExport,
+  RuntimeHelper,
+  CommonJsBailout,
+  PreservedReexport,
+  SideEffectfulImport,
+  UsedSymbol,
+  SideEffect,
+  BodyDemand,
+  PropertyWrite,
+  NoTreeshake,
+  Eval,
+  DevModeNamespace,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cause {
+  kind: CauseKind,
+  symbol: Option<SymbolRef>,
+  by: Option<Site>,
+}
+
+#[derive(Debug, Default)]
+pub struct ProvenanceRecorder {
+  modules: FxIndexMap<ModuleIdx, Cause>,
+  statements: FxHashMap<ModuleIdx, Vec<(StmtInfoIdx, Cause)>>,
+  /// Causes of the items in `IncludeContext::pending`, in the same order.
+  pending: Vec<Cause>,
+  /// Cause of the work item being handled.
+  current: Option<Cause>,
+  /// The site items pushed by the current handler are attributed to. A symbol has no site of its
+  /// own, so its handler passes on the site that used it.
+  current_site: Option<Site>,
+  /// Kind of what the driver includes directly. See `IncludeContext::set_provenance_root`.
+  pub(super) root: CauseKind,
+}
+
+impl ProvenanceRecorder {
+  /// Records the cause of an item a handler just pushed to the work queue. Pushes for a symbol
+  /// the driver included aren't attributed to any site, and keep the root kind instead of `kind`.
+  pub(super) fn push_cause(&mut self, kind: CauseKind, symbol: Option<SymbolRef>) {
+    let cause = match (self.current_site, self.current) {
+      (Some(site), _) => Cause { kind, symbol, by: Some(site) },
+      (None, Some(current)) => Cause { kind: current.kind, symbol, by: None },
+      (None, None) => unreachable!("handlers only push while handling a work item"),
+    };
+    self.pending.push(cause);
+  }
+
+  /// Records the cause of an item pushed by the driver.
+  pub(super) fn push_root_cause(&mut self, symbol: Option<SymbolRef>) {
+    debug_assert!(self.current.is_none(), "the driver only pushes between drains");
+    self.pending.push(Cause { kind: self.root, symbol, by: None });
+  }
+
+  /// Called as `item` is popped off the work queue, before it's handled.
+  pub(super) fn begin(&mut self, item: WorkItem) {
+    let cause = self.pending.pop().expect("every work item should have a cause");
+    self.current_site = match item {
+      WorkItem::Module(module_idx) => Some(Site::Module(module_idx)),
+      WorkItem::Statement(module_idx, stmt_info_idx) => {
+        Some(Site::Statement(module_idx, stmt_info_idx))
+      }
+      WorkItem::Symbol(..) => cause.by,
+    };
+    self.current = Some(cause);
+  }
+
+  /// Called once the work queue is drained.
+  pub(super) fn end(&mut self) {
+    debug_assert!(self.pending.is_empty());
+    self.current = None;
+    self.current_site = None;
+  }
+
+  /// Records why the module being handled was included. Only called on its first inclusion.
+  pub(super) fn record_module(&mut self, module_idx: ModuleIdx) {
+    let cause = self.current.expect("a module is only included while handling a work item");
+    self.modules.insert(module_idx, cause);
+  }
+
+  /// Records why the statement being handled was included. Only called on its first inclusion.
+  pub(super) fn record_statement(&mut self, module_idx: ModuleIdx, stmt_info_idx: StmtInfoIdx) {
+    let cause = self.current.expect("a statement is only included while handling a work item");
+    self.statements.entry(module_idx).or_default().push((stmt_info_idx, cause));
+  }
+
+  pub(super) fn into_provenance(
+    mut self,
+    modules: &IndexModules,
+    ast_table: &IndexEcmaAst,
+    symbols: &SymbolRefDb,
+  ) -> TreeShakingProvenance {
+    let site = |site: Site| match site {
+      Site::Module(module_idx) => {
+        InclusionSite::Module(modules[module_idx].stable_id().to_string())
+      }
+      Site::Statement(module_idx, stmt_info_idx) => {
+        InclusionSite::Statement(modules[module_idx].stable_id().to_string(), stmt_info_idx.index())
+      }
+    };
+    let reason = |cause: Cause, side_effect: Option<SideEffectKind>| InclusionReason {
+      kind: match cause.kind {
+        CauseKind::Entry => InclusionReasonKind::Entry,
+        CauseKind::DynamicEntry => InclusionReasonKind::DynamicEntry,
+        CauseKind::EntryExport => InclusionReasonKind::EntryExport,
+        CauseKind::RuntimeHelper => InclusionReasonKind::RuntimeHelper,
+        CauseKind::CommonJsBailout => InclusionReasonKind::CommonJsBailout,
+        CauseKind::PreservedReexport => InclusionReasonKind::PreservedReexport,
+        CauseKind::SideEffectfulImport => InclusionReasonKind::SideEffectfulImport,
+        CauseKind::UsedSymbol => InclusionReasonKind::UsedSymbol,
+        CauseKind::SideEffect => {
+          InclusionReasonKind::SideEffect(side_effect.unwrap_or(SideEffectKind::Other))
+        }
+        CauseKind::BodyDemand => InclusionReasonKind::BodyDemand,
+        CauseKind::PropertyWrite => InclusionReasonKind::PropertyWrite,
+        CauseKind::NoTreeshake => InclusionReasonKind::NoTreeshake,
+        CauseKind::Eval => InclusionReasonKind::Eval,
+        CauseKind::DevModeNamespace => InclusionReasonKind::DevModeNamespace,
+      },
+      symbol: cause.symbol.map(|symbol| symbol.name(symbols).to_string()),
+      by: cause.by.map(site),
+    };
+
+    let modules = self
+      .modules
+      .iter()
+      .map(|(&module_idx, &cause)| {
+        let mut statements = self.statements.remove(&module_idx).unwrap_or_default();
+        statements.sort_unstable_by_key(|(stmt_info_idx, _)| *stmt_info_idx);
+        let ast = ast_table[module_idx].as_ref();
+        let statements = statements
+          .into_iter()
+          .map(|(stmt_info_idx, cause)| {
+            // Statement infos are offset by one from the program body, as the first one is the
+            // namespace object.
+            let stmt =
+              stmt_info_idx.index().checked_sub(1).and_then(|index| ast?.program().body.get(index));
+            let code = ast
+              .zip(stmt)
+              .and_then(|(ast, stmt)| {
+                let span = stmt.span();
+                ast.source().get(span.start as usize..span.end as usize)
+              })
+              .map(excerpt)
+              .unwrap_or_default();
+            let side_effect =
+              stmt.filter(|_| matches!(cause.kind, CauseKind::SideEffect)).map(|stmt| {
+                classify_side_effect(stmt, symbols.local_db(module_idx).ast_scopes.scoping())
+              });
+            StatementProvenance {
+              index: stmt_info_idx.index(),
+              code,
+              reason: reason(cause, side_effect),
+            }
+          })
+          .collect();
+        let module = ModuleProvenance { reason: reason(cause, None), statements };
+        (modules[module_idx].stable_id().to_string(), module)
+      })
+      .collect();
+    TreeShakingProvenance { modules }
+  }
+}
+
+fn excerpt(code: &str) -> String {
+  let line = code.lines().next().unwrap_or_default().trim_end();
+  if line.len() == code.len() && line.chars().count() <= MAX_CODE_LEN {
+    return line.to_string();
+  }
+  let mut excerpt = line.chars().take(MAX_CODE_LEN - 1).collect::<String>();
+  excerpt.push('…');
+  excerpt
+}
+
+/// Picks the most telling side effect of a top-level statement: a property write, then an access
+/// to a global, then a call. Function bodies are skipped, as declaring a function runs none of
+/// its body.
+fn classify_side_effect(stmt: &Statement<'_>, scoping: &Scoping) -> SideEffectKind {
+  let mut classifier =
+    SideEffectClassifier { scoping, property_write: false, global: None, call: false };
+  classifier.visit_statement(stmt);
+  if classifier.property_write {
+    SideEffectKind::PropertyWrite
+  } else if let Some(global) = classifier.global {
+    SideEffectKind::GlobalAccess(global)
+  } else if classifier.call {
+    SideEffectKind::Call
+  } else {
+    SideEffectKind::Other
+  }
+}
+
+struct SideEffectClassifier<'a> {
+  scoping: &'a Scoping,
+  property_write: bool,
+  global: Option<String>,
+  call: bool,
+}
+
+impl<'ast> VisitJs<'ast> for SideEffectClassifier<'_> {
+  fn visit_function(&mut self, _it: &Function<'ast>, _flags: ScopeFlags) {}
+
+  fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'ast>) {}
+
+  fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'ast>) {
+    self.property_write |= it.left.is_member_expression();
+    walk_js::walk_assignment_expression(self, it);
+  }
+
+  fn visit_update_expression(&mut self, it: &UpdateExpression<'ast>) {
+    self.property_write |= it.argument.is_member_expression();
+    walk_js::walk_update_expression(self, it);
+  }
+
+  fn visit_unary_expression(&mut self, it: &UnaryExpression<'ast>) {
+    self.property_write |= it.operator == UnaryOperator::Delete
+      && it.argument.without_parentheses().is_member_expression();
+    walk_js::walk_unary_expression(self, it);
+  }
+
+  fn visit_identifier_reference(&mut self, it: &IdentifierReference<'ast>) {
+    if self.global.is_none()
+      && self.scoping.get_reference(it.reference_id()).symbol_id().is_none()
+      && !matches!(it.name.as_str(), "undefined" | "NaN" | "Infinity")
+    {
+      self.global = Some(it.name.to_string());
+    }
+  }
+
+  fn visit_call_expression(&mut self, it: &CallExpression<'ast>) {
+    self.call = true;
+    walk_js::walk_call_expression(self, it);
+  }
+
+  fn visit_new_expression(&mut self, it: &NewExpression<'ast>) {
+    self.call = true;
+    walk_js::walk_new_expression(self, it);
+  }
+
+  fn visit_tagged_template_expression(&mut self, it: &TaggedTemplateExpression<'ast>) {
+    self.call = true;
+    walk_js::walk_tagged_template_expression(self, it);
+  }
+}
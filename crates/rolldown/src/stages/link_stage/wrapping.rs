@@ -1,6 +1,7 @@
 use rolldown_common::{
-  ExportsKind, ImportKind, IndexModules, Module, ModuleIdx, NormalModule, NormalizedBundlerOptions,
-  RuntimeModuleBrief, StmtInfo, StmtInfoMeta, StmtInfos, SymbolRefDb, TaggedSymbolRef, WrapKind,
+  ExportsKind, ImportKind, ImportRecordMeta, IndexModules, Module, ModuleIdx, NormalModule,
+  NormalizedBundlerOptions, RuntimeModuleBrief, StmtInfo, StmtInfoMeta, StmtInfos, SymbolRefDb,
+  TaggedSymbolRef, WrapKind,
 };
 use rolldown_utils::IndexBitSet;
 use smallvec::smallvec;
@@ -125,7 +126,11 @@ impl LinkStage<'_> {
             self.metas[importee.idx].required_by_other_module = true;
           }
           // Commonjs as a dependency must be wrapped. The wrapper is like a commonjs runtime to help initialize the commonjs module correctly.
-          if matches!(importee.exports_kind, ExportsKind::CommonJs) {
+          // A deferred namespace postpones the evaluation of its module until first use by calling
+          // the module's `init_*` wrapper, so the module and its dependencies must be wrapped too.
+          if matches!(importee.exports_kind, ExportsKind::CommonJs)
+            || rec.meta.contains(ImportRecordMeta::IsDeferred)
+          {
             wrap_module_recursively(
               &mut Context {
                 visited_modules: &mut visited_modules_for_wrapping,
@@ -140,6 +145,28 @@ impl LinkStage<'_> {
       }
     }
 
+    // Formats without top-level await can only wait for it inside an async function. With
+    // `experimental.asyncModuleWrappers`, an entry depending on top-level await is evaluated
+    // through `init_*` wrappers along with everything it imports, so the wrappers of the
+    // TLA-tainted modules become `async` and await their dependencies in import order.
+    if self.options.experimental.is_async_module_wrappers_enabled()
+      && !self.options.format.supports_top_level_await()
+    {
+      for &entry_idx in self.entries.keys() {
+        if self.metas[entry_idx].is_tla_or_contains_tla_dependency {
+          wrap_module_recursively(
+            &mut Context {
+              visited_modules: &mut visited_modules_for_wrapping,
+              linking_infos: &mut self.metas,
+              modules: &self.module_table.modules,
+              runtime_idx: self.runtime.id(),
+            },
+            entry_idx,
+          );
+        }
+      }
+    }
+
     // Under strict execution order every CommonJS module must stay behind its lazy `require_*`
     // wrapper once a co-locating `codeSplitting` group is in play. The generate-stage order
     // lowering only wraps ESM modules, and the interop rules above leave a CommonJS module that
@@ -90,7 +90,7 @@ impl GenerateContext<'_> {
     }
 
     match self.options.format {
-      rolldown_common::OutputFormat::Cjs => {
+      format if format.uses_chunk_namespace_bindings() => {
         let chunk_idx_of_canonical_symbol = canonical_symbol.chunk_idx.unwrap_or_else(|| {
           // Scoped symbols don't get assigned a `ChunkIdx`. There are skipped for performance reason, because they are surely
           // belong to the chunk they are declared in and won't link to other chunks.
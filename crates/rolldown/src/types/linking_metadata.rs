@@ -158,7 +158,11 @@ impl LinkingMetadata {
         !rec_meta.contains(ImportRecordMeta::EntryLevelExternal)
           || self.module_namespace_included_reason.contains(ModuleNamespaceIncludedReason::Unknown)
       }
-      OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd => true,
+      OutputFormat::Cjs
+      | OutputFormat::Iife
+      | OutputFormat::Umd
+      | OutputFormat::Amd
+      | OutputFormat::System => true,
     }
   }
 
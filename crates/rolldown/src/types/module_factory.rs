@@ -4,6 +4,7 @@ use rolldown_common::{
   side_effects::HookSideEffects,
 };
 use rolldown_error::BuildDiagnostic;
+use rolldown_fs::FileSystem;
 use rolldown_plugin::SharedPluginDriver;
 
 use crate::SharedOptions;
@@ -19,6 +20,8 @@ pub struct CreateModuleContext<'a> {
   pub replace_global_define_config: Option<ReplaceGlobalDefinesConfig>,
   pub is_user_defined_entry: bool,
   pub flat_options: FlatOptions,
+  /// Used to expand `import()` / `require()` calls whose path is built at runtime.
+  pub fs: &'a dyn FileSystem,
 }
 
 pub struct CreateModuleViewArgs {
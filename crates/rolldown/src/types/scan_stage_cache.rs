@@ -107,7 +107,9 @@ impl ScanStageCache {
       let Some(module) = module.as_normal() else {
         continue;
       };
-      for record in &module.ecma_view.import_records {
+      let css_import_records =
+        module.css_view.as_ref().into_iter().flat_map(|css_view| &css_view.import_records);
+      for record in module.ecma_view.import_records.iter().chain(css_import_records) {
         if let Some(dep_idx) = record.resolved_module {
           importers[dep_idx].push(ImporterRecord {
             importer_path: module.id.clone(),
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
-use rolldown_common::NormalizedBundlerOptions;
+use rolldown_common::{ModuleType, NormalizedBundlerOptions};
+use rolldown_fs::OsFileSystem;
 use rolldown_plugin::__inner::SharedPluginable;
 use rolldown_plugin_lazy_compilation::LazyCompilationContext;
 
@@ -19,15 +20,29 @@ pub struct ApplyInnerPluginsReturn {
 /// - Control the order of plugins via `PluginHookMeta` mechanism.
 pub fn apply_inner_plugins(
   options: &NormalizedBundlerOptions,
+  fs: &OsFileSystem,
   user_plugins: &mut Vec<SharedPluginable>,
 ) -> ApplyInnerPluginsReturn {
   let mut before_user_plugins: Vec<SharedPluginable> = vec![
     Arc::new(rolldown_plugin_copy_module::CopyModulePlugin::new(&options.module_types)),
     Arc::new(rolldown_plugin_asset_module::AssetModulePlugin::new(&options.module_types)),
+    Arc::new(rolldown_plugin_wasm_module::WasmModulePlugin::new(
+      &options.module_types,
+      options.platform,
+      options.format,
+      options.wasm.embed(),
+      fs.clone(),
+    )),
     Arc::new(rolldown_plugin_data_url::DataUrlPlugin::default()),
     Arc::new(rolldown_plugin_oxc_runtime::OxcRuntimePlugin),
   ];
 
+  // Html pages are only bundled when an extension is mapped to the `html` module type.
+  if options.module_types.values().any(|module_type| matches!(module_type, ModuleType::Html)) {
+    before_user_plugins
+      .push(Arc::new(rolldown_plugin_html::HtmlPlugin::new(&options.module_types, fs.clone())));
+  }
+
   if let Some(config) = &options.experimental.chunk_import_map {
     before_user_plugins.push(Arc::new(rolldown_plugin_chunk_import_map::ChunkImportMapPlugin {
       base_url: config.base_url.clone(),
@@ -36,6 +51,11 @@ pub fn apply_inner_plugins(
     }));
   }
 
+  #[cfg(feature = "precompress")]
+  if let Some(config) = &options.experimental.precompress {
+    before_user_plugins.push(Arc::new(rolldown_plugin_precompress::PrecompressPlugin::new(config)));
+  }
+
   let mut lazy_compilation_context = None;
 
   if let Some(dev_mode) = &options.experimental.dev_mode {
@@ -15,7 +15,7 @@ use rolldown_utils::ecmascript::legitimize_identifier_name;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 #[tracing::instrument(level = "trace", skip_all)]
-#[expect(clippy::too_many_arguments)]
+#[expect(clippy::too_many_arguments, clippy::too_many_lines)]
 pub fn deconflict_chunk_symbols(
   chunk_idx: ChunkIdx,
   chunk: &mut Chunk,
@@ -48,9 +48,8 @@ pub fn deconflict_chunk_symbols(
       renamer.reserve(CompactStr::new(name));
     });
 
-  if matches!(format, OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Cjs) {
+  if !matches!(format, OutputFormat::Esm) {
     // deconflict iife introduce symbols by external
-    // Also AMD, but we don't support them yet.
     chunk
       .direct_imports_from_external_modules
       .iter()
@@ -70,6 +69,25 @@ pub fn deconflict_chunk_symbols(
       });
   }
 
+  if matches!(format, OutputFormat::System) {
+    // SystemJS has no `require` to read a star re-exported external from, so its namespace is bound
+    // by a setter at chunk scope as well.
+    chunk
+      .modules
+      .iter()
+      .filter_map(|idx| link_output.module_table[*idx].as_normal())
+      .flat_map(|module| {
+        link_output.metas[module.idx]
+          .star_exports_from_external_modules
+          .iter()
+          .filter_map(|rec_idx| module.ecma_view.import_records[*rec_idx].resolved_module)
+      })
+      .filter_map(|idx| link_output.module_table[idx].as_external())
+      .for_each(|external_module| {
+        renamer.add_symbol_in_root_scope(external_module.namespace_ref, true);
+      });
+  }
+
   match chunk.kind {
     ChunkKind::EntryPoint { module, .. } => {
       let meta = &link_output.metas[module];
@@ -222,7 +240,10 @@ pub fn deconflict_chunk_symbols(
 
   // Detect mixed-mode external imports: both ESM (node-mode) and non-ESM importers
   // needing interop on the same external. Create a separate binding name for node-mode.
-  if matches!(format, OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Cjs) {
+  if matches!(
+    format,
+    OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Cjs | OutputFormat::Amd
+  ) {
     let mut node_mode_names = FxHashMap::default();
     // Externals the chunk only *references* (their importing module lives in another chunk or was
     // tree-shaken away) carry no `named_imports`, but the inclusion pass still recorded how they
@@ -243,6 +264,10 @@ pub fn deconflict_chunk_symbols(
     for (ext_idx, named_imports) in externals {
       let ext =
         link_output.module_table[ext_idx].as_external().expect("Should be external module here");
+      // An explicit `output.interop` reads the external the same way for every importer.
+      if ext.interop.is_some() {
+        continue;
+      }
       let Some(modes) = chunk_external_interop_modes(
         link_output,
         chunk_assignments,
@@ -303,9 +328,12 @@ fn collect_chunk_scope_captured_names(
       captured.insert(CompactStr::new(declared.inner().name(&link_output.symbol_db)));
     }
   }
-  if matches!(format, OutputFormat::Iife | OutputFormat::Umd) {
+  if matches!(
+    format,
+    OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd | OutputFormat::System
+  ) {
     // Mirror the set rendered as factory params by `render_chunk_external_imports` +
-    // `render_factory_parameters`.
+    // `render_factory_parameters` (or as setter-bound variables under SystemJS).
     for (external_idx, _) in &chunk.direct_imports_from_external_modules {
       let Some(external) = link_output.module_table[*external_idx].as_external() else {
         continue;
@@ -375,12 +403,9 @@ fn rename_shadowing_symbols_in_nested_scopes<'a>(
 
     ctx.rename_bindings_shadowing_star_imports();
     ctx.rename_bindings_shadowing_named_imports();
-    ctx.rename_bindings_shadowing_wrapper_params(matches!(
-      output_format,
-      OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Cjs
-    ));
+    ctx.rename_bindings_shadowing_wrapper_params(!matches!(output_format, OutputFormat::Esm));
 
-    ctx.rename_bindings_shadowing_cjs_ambient_names(output_format);
+    ctx.rename_bindings_shadowing_format_ambient_names(output_format);
     ctx.rename_cjs_locals_shadowing_referenced_chunk_bindings();
   }
 }
@@ -1,7 +1,7 @@
 use crate::types::generator::GenerateContext;
 use arcstr::ArcStr;
 use oxc_str::CompactStr;
-use rolldown_common::{NormalModule, OutputExports};
+use rolldown_common::{NormalModule, OutputExports, OutputFormat};
 use rolldown_error::{BuildDiagnostic, BuildResult};
 
 // Port from https://github.com/rollup/rollup/blob/master/src/utils/getExportMode.ts
@@ -11,6 +11,10 @@ pub fn determine_export_mode(
   module: &NormalModule,
   export_names: &[CompactStr],
 ) -> BuildResult<OutputExports> {
+  // A SystemJS module always exposes its exports as a namespace object, like an ES module does.
+  if matches!(ctx.options.format, OutputFormat::System) {
+    return Ok(if export_names.is_empty() { OutputExports::None } else { OutputExports::Named });
+  }
   let export_mode = &ctx.options.exports;
   match export_mode {
     OutputExports::Named => Ok(OutputExports::Named),
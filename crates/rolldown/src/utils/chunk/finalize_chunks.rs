@@ -9,6 +9,7 @@ use rolldown_common::{
   PathsOutputOption, SourceMapType, StrOrBytes,
 };
 use rolldown_error::BuildResult;
+use rolldown_sourcemap::generate_scopes;
 #[cfg(not(target_family = "wasm"))]
 use rolldown_utils::rayon::IndexedParallelIterator;
 use rolldown_utils::{
@@ -31,7 +32,9 @@ use crate::{
   chunk_graph::ChunkGraph,
   stages::link_stage::LinkStageOutput,
   type_alias::{AssetVec, IndexChunkToInstances, IndexInstantiatedChunks},
-  utils::process_code_and_sourcemap::{emit_sourcemap, prepare_sourcemap},
+  utils::process_code_and_sourcemap::{
+    emit_index_sourcemap, emit_sourcemap, prepare_index_sourcemap, prepare_sourcemap,
+  },
 };
 
 #[tracing::instrument(level = "debug", skip_all)]
@@ -159,11 +162,12 @@ pub async fn finalize_assets(
 
       // Only chunks that actually produced a map report a sourcemap filename; Rollup reports
       // `sourcemapFileName: null` otherwise.
-      let preliminary_filename_str = if instantiated_chunk.map.is_some() {
-        instantiated_chunk.preliminary_sourcemap_filename.as_ref().map(|f| f.as_str())
-      } else {
-        None
-      };
+      let preliminary_filename_str =
+        if instantiated_chunk.map.is_some() || instantiated_chunk.index_map.is_some() {
+          instantiated_chunk.preliminary_sourcemap_filename.as_ref().map(|f| f.as_str())
+        } else {
+          None
+        };
 
       if let InstantiationKind::Ecma(ecma_meta) = &mut instantiated_chunk.kind {
         let (_, debug_id) = index_final_hashes[asset_idx];
@@ -224,14 +228,53 @@ pub async fn finalize_assets(
             .get_file_name(resolved_paths)
         }))
         .collect();
+
+      ecma_meta.transitive_imports = chunk
+        .transitive_imports
+        .iter()
+        .flat_map(|importee_idx| &index_chunk_to_instances[*importee_idx])
+        .map(|importee_asset_idx| index_ins_chunk_to_filename[*importee_asset_idx].clone())
+        .collect();
     }
   });
 
+  if options.sourcemap_scopes {
+    generate_sourcemap_scopes(&mut assets, chunk_graph, link_output);
+  }
+
   finalize_sourcemaps(&mut assets, &index_sourcemap_hash_placeholders, options, hash_base).await?;
 
   Ok(assets)
 }
 
+/// Encodes the `scopes` field of each chunk's sourcemap from the original scope trees of the
+/// modules in that chunk. This runs before `finalize_sourcemaps` relativizes the sources, as the
+/// sources are still the ids the scope trees were collected with.
+fn generate_sourcemap_scopes(
+  assets: &mut AssetVec,
+  chunk_graph: &ChunkGraph,
+  link_output: &LinkStageOutput,
+) {
+  assets.par_iter_mut().for_each(|asset| {
+    let (Some(chunk_idx), InstantiationKind::Ecma(ecma_meta), Some(map)) =
+      (asset.originate_from, &mut asset.meta, asset.map.as_mut())
+    else {
+      return;
+    };
+    let Ok(code) = asset.content.try_as_inner_str() else { return };
+    let scopes_by_source = chunk_graph.chunk_table[chunk_idx]
+      .modules
+      .iter()
+      .filter_map(|module_idx| link_output.module_table[*module_idx].as_normal())
+      .filter_map(|module| module.ecma_view.original_scopes.as_deref())
+      .map(|scopes| (scopes.source.as_str(), &scopes.scope))
+      .collect::<FxHashMap<_, _>>();
+    let original_scopes =
+      map.get_sources().map(|source| scopes_by_source.get(source).copied()).collect::<Vec<_>>();
+    ecma_meta.sourcemap_scopes = generate_scopes(map, code, &original_scopes);
+  });
+}
+
 async fn finalize_sourcemaps(
   assets: &mut AssetVec,
   index_sourcemap_hash_placeholders: &IndexVec<InsChunkIdx, Option<Vec<String>>>,
@@ -240,11 +283,18 @@ async fn finalize_sourcemaps(
 ) -> BuildResult<()> {
   try_join_all(assets.iter_mut().map(async |asset| {
     let filename = asset.filename.clone();
-    if let (InstantiationKind::Ecma(ecma_meta), Some(map)) = (&mut asset.meta, asset.map.as_mut()) {
+    if let InstantiationKind::Ecma(ecma_meta) = &mut asset.meta
+      && (asset.map.is_some() || asset.index_map.is_some())
+    {
       let file_path = options.cwd.as_path().join(&options.out_dir).join(filename.as_str());
       ecma_meta.file_dir =
         file_path.parent().expect("chunk file name should have a parent").to_path_buf();
-      prepare_sourcemap(options, map, &ecma_meta.file_dir, filename.as_str()).await?;
+      if let Some(map) = asset.map.as_mut() {
+        prepare_sourcemap(options, map, &ecma_meta.file_dir, filename.as_str()).await?;
+      }
+      if let Some(index_map) = asset.index_map.as_mut() {
+        prepare_index_sourcemap(options, index_map, &ecma_meta.file_dir, filename.as_str()).await?;
+      }
     }
     Ok::<(), anyhow::Error>(())
   }))
@@ -277,24 +327,33 @@ async fn finalize_sourcemaps(
       InstantiationKind::Ecma(ecma_meta) => {
         let asset_code = mem::take(&mut asset.content);
         let mut code = asset_code.try_into_string()?;
-        if let Some(map) = asset.map.as_mut() {
+        if asset.map.is_some() || asset.index_map.is_some() {
           let map_filename = ecma_meta
             .sourcemap_filename
             .clone()
             .unwrap_or_else(|| format!("{}.map", asset.filename));
-          if let Some(sourcemap_asset) = emit_sourcemap(
-            options,
-            &mut code,
-            map,
-            &map_filename,
-            ecma_meta.debug_id,
-            /*is_css*/ false,
-          )? {
+          let sourcemap_asset = if let Some(index_map) = asset.index_map.as_mut() {
+            emit_index_sourcemap(options, &mut code, index_map, &map_filename, ecma_meta.debug_id)?
+          } else if let Some(map) = asset.map.as_mut() {
+            emit_sourcemap(
+              options,
+              &mut code,
+              map,
+              ecma_meta.sourcemap_scopes.as_deref(),
+              &map_filename,
+              ecma_meta.debug_id,
+              /*is_css*/ false,
+            )?
+          } else {
+            None
+          };
+          if let Some(sourcemap_asset) = sourcemap_asset {
             derived_assets.push(Asset {
               originate_from: None,
               content: sourcemap_asset.source,
               filename: sourcemap_asset.filename.clone(),
               map: None,
+              index_map: None,
               meta: InstantiationKind::Sourcemap(Box::new(rolldown_common::SourcemapAssetMeta {
                 names: sourcemap_asset.names,
                 original_file_names: sourcemap_asset.original_file_names,
@@ -332,12 +391,17 @@ fn generate_sourcemap_hashes_by_idx(
     .enumerate()
     .filter_map(|(idx, asset)| {
       let idx = InsChunkIdx::from(idx);
-      let (Some(map), InstantiationKind::Ecma(_)) = (&asset.map, &asset.meta) else {
+      let InstantiationKind::Ecma(_) = &asset.meta else {
         return None;
       };
       index_sourcemap_hash_placeholders[idx].as_ref()?;
+      let json = match (&asset.map, &asset.index_map) {
+        (_, Some(index_map)) => index_map.to_json_string(),
+        (Some(map), None) => map.to_json_string(),
+        (None, None) => return None,
+      };
       let mut hasher = Xxh3::default();
-      hasher.update(map.to_json_string().as_bytes());
+      hasher.update(json.as_bytes());
       let hash = encode_hash_with_base(&hasher.digest128().to_le_bytes(), hash_base);
       Some((idx, hash))
     })
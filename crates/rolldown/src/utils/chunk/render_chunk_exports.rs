@@ -16,6 +16,10 @@ use rustc_hash::FxHashSet;
 use crate::esm_init_obligations::{WrappedEsmInitTarget, collect_entry_reexported_wrapper_inits};
 use crate::{stages::link_stage::LinkStageOutput, types::generator::GenerateContext};
 
+/// With `experimental.asyncModuleWrappers`, the non-enumerable export holding the promise of an
+/// entry whose named exports depend on top-level await.
+const ASYNC_ENTRY_READY_EXPORT: &str = "__tla";
+
 pub fn render_wrapped_entry_chunk(
   ctx: &GenerateContext<'_>,
   export_mode: Option<&OutputExports>,
@@ -44,7 +48,7 @@ pub fn render_wrapped_entry_chunk(
             Some(concat_string!("module.exports = ", wrapper_ref_name, "();\n"))
           }
         }
-        OutputFormat::Iife | OutputFormat::Umd => {
+        OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd => {
           if matches!(&export_mode, Some(OutputExports::Named)) {
             Some(render_object_define_property("default", &concat_string!(wrapper_ref_name, "()")))
           } else {
@@ -52,6 +56,10 @@ pub fn render_wrapped_entry_chunk(
             Some(concat_string!("return ", wrapper_ref_name, "();\n"))
           }
         }
+        OutputFormat::System => {
+          // exports("default", require_xxx());
+          Some(concat_string!("exports(\"default\", ", wrapper_ref_name, "());\n"))
+        }
       };
     }
 
@@ -92,17 +100,24 @@ pub fn render_wrapped_entry_chunk(
           ctx.chunk_idx,
           &ctx.chunk.canonical_names,
         );
-        let own_init_call = if target.tla_tainted {
-          concat_string!("await ", wrapper_ref_name, "();")
+        // Without top-level await (`experimental.asyncModuleWrappers`), the chunk only starts the
+        // async wrapper; the exports are populated once it settles. If the chunk has exports,
+        // `render_chunk_exports` starts it to hand its promise to the consumer.
+        let own_init_call = if !target.tla_tainted {
+          Some(concat_string!(wrapper_ref_name, "();"))
+        } else if ctx.options.format.supports_top_level_await() {
+          Some(concat_string!("await ", wrapper_ref_name, "();"))
+        } else if exports_start_async_entry(ctx, export_mode) {
+          None
         } else {
-          concat_string!(wrapper_ref_name, "();")
+          Some(concat_string!(wrapper_ref_name, "();"))
         };
-        match reexport_init_calls {
-          Some(mut calls) => {
+        match (reexport_init_calls, own_init_call) {
+          (Some(mut calls), Some(own_init_call)) => {
             calls.push_str(&own_init_call);
             Some(calls)
           }
-          None => Some(own_init_call),
+          (calls, own_init_call) => calls.or(own_init_call),
         }
       }
       None => reexport_init_calls,
@@ -165,10 +180,11 @@ fn render_entry_reexported_wrapper_init_calls(
       ctx.chunk_idx,
       &ctx.chunk.canonical_names,
     );
-    if init.tla_tainted {
+    if init.tla_tainted && ctx.options.format.supports_top_level_await() {
       // Defensive parity with `wrapped_esm_init_call_expr`'s `await_if_tla`; believed
       // unreachable today. Non-`esm` formats reject top-level await at scan time
-      // (`AstScanner::handle_top_level_await`), so this cannot produce an `await` inside a
+      // (`AstScanner::handle_top_level_await`) unless `experimental.asyncModuleWrappers` is
+      // set, which the condition above excludes, so this cannot produce an `await` inside a
       // plain iife/umd/cjs factory. Under `esm`, every off-strict `WrapKind::Esm` cause is
       // blocked from combining with TLA: a `require` reaching a TLA subtree is a build error
       // (`REQUIRE_TLA`), and the dynamic-import-with-splitting-disabled cause is single-chunk,
@@ -187,14 +203,7 @@ pub fn render_chunk_exports(
   export_mode: Option<&OutputExports>,
 ) -> Option<String> {
   let GenerateContext { chunk, link_output, options, .. } = ctx;
-  let mut export_items: Vec<(CompactStr, SymbolRef)> = ctx.render_export_items_index_vec
-    [ctx.chunk_idx]
-    .iter()
-    .flat_map(|(symbol_ref, names)| {
-      let symbol_ref = *symbol_ref;
-      names.iter().map(move |name| (name.clone(), symbol_ref))
-    })
-    .collect();
+  let mut export_items = get_render_export_items(ctx);
 
   match options.format {
     OutputFormat::Esm => {
@@ -254,7 +263,15 @@ pub fn render_chunk_exports(
       s.push_str(" };");
       Some(s)
     }
-    OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd => {
+    OutputFormat::System => {
+      // The snapshot runs once the chunk body has executed. Hoisted functions are published
+      // before it, see `render_system_hoisted_exports`, and later reassignments of exported
+      // bindings are pushed by the `exports(...)` calls the finalizer wraps them with.
+      let export_items = system_export_items(ctx, export_items)
+        .filter(|(_, canonical_ref)| !is_system_hoisted_export(ctx, *canonical_ref));
+      render_system_exports_call(ctx, export_items)
+    }
+    OutputFormat::Cjs | OutputFormat::Iife | OutputFormat::Umd | OutputFormat::Amd => {
       let mut s = String::new();
       match chunk.kind {
         ChunkKind::EntryPoint { module, .. } => {
@@ -266,6 +283,9 @@ pub fn render_chunk_exports(
               canonical_ref.owner != module.idx
             });
           }
+          let async_wrapper_name = async_entry_wrapper_name(ctx, module.idx);
+          let is_ready_export_taken =
+            export_items.iter().any(|(name, _)| name.as_str() == ASYNC_ENTRY_READY_EXPORT);
           if !export_items.is_empty() {
             let rendered_items = export_items
               .into_iter()
@@ -307,7 +327,7 @@ pub fn render_chunk_exports(
                        export branch (`exports.{exported_name} = {exported_value}`): it would \
                        freeze the pre-rebind function and re-run the module body",
                     );
-                    if is_order_wrapper_ref || keep_live_binding {
+                    if is_order_wrapper_ref || keep_live_binding || async_wrapper_name.is_some() {
                       render_object_define_property(&exported_name, &exported_value)
                     } else if exported_name.as_str() == "__proto__" {
                       // `__proto__` has special semantics - assigning to it sets the prototype
@@ -324,7 +344,21 @@ pub fn render_chunk_exports(
                     }
                   }
                   Some(OutputExports::Default) => {
-                    if matches!(options.format, OutputFormat::Cjs) {
+                    if let Some(async_wrapper_name) = &async_wrapper_name {
+                      // The value only exists once the async wrapper settles, so the default
+                      // export is a promise of it.
+                      let promise = concat_string!(
+                        async_wrapper_name,
+                        "().then(function() { return ",
+                        exported_value.as_str(),
+                        "; })"
+                      );
+                      if matches!(options.format, OutputFormat::Cjs) {
+                        concat_string!("module.exports = ", promise, ";")
+                      } else {
+                        concat_string!("return ", promise, ";")
+                      }
+                    } else if matches!(options.format, OutputFormat::Cjs) {
                       concat_string!("module.exports = ", exported_value.as_str(), ";")
                     } else {
                       concat_string!("return ", exported_value.as_str(), ";")
@@ -336,6 +370,25 @@ pub fn render_chunk_exports(
               })
               .collect::<Vec<_>>();
             s.push_str(&rendered_items.join("\n"));
+
+            if let Some(async_wrapper_name) = &async_wrapper_name
+              && matches!(export_mode, Some(OutputExports::Named))
+            {
+              // The named exports are only populated once the async wrapper settles, so hand its
+              // promise to the consumer as a non-enumerable export, unless the name is taken.
+              s.push('\n');
+              if is_ready_export_taken {
+                s.push_str(&concat_string!(async_wrapper_name, "();"));
+              } else {
+                s.push_str(&concat_string!(
+                  "Object.defineProperty(exports, '",
+                  ASYNC_ENTRY_READY_EXPORT,
+                  "', { value: ",
+                  async_wrapper_name,
+                  "() });"
+                ));
+              }
+            }
           }
 
           let meta = &ctx.link_output.metas[module.idx];
@@ -426,6 +479,91 @@ pub fn render_chunk_exports(
 }
 
 #[inline]
+/// Publishes the exported function declarations of a SystemJS chunk before its body runs, like
+/// Rollup does. Function declarations are hoisted, so a chunk in an import cycle can already call
+/// them while the body of this chunk hasn't run yet.
+pub fn render_system_hoisted_exports(ctx: &GenerateContext<'_>) -> Option<String> {
+  let export_items = system_export_items(ctx, get_render_export_items(ctx))
+    .filter(|(_, canonical_ref)| is_system_hoisted_export(ctx, *canonical_ref));
+  render_system_exports_call(ctx, export_items)
+}
+
+/// The export items of a SystemJS chunk, resolved to their canonical symbols.
+fn system_export_items<'a>(
+  ctx: &'a GenerateContext<'_>,
+  mut export_items: Vec<(CompactStr, SymbolRef)>,
+) -> impl Iterator<Item = (CompactStr, SymbolRef)> + 'a {
+  let link_output = ctx.link_output;
+  if let ChunkKind::EntryPoint { module, .. } = ctx.chunk.kind {
+    let module = &link_output.module_table[module].as_normal().expect("should be normal module");
+    if !matches!(module.exports_kind, ExportsKind::Esm) {
+      export_items.retain(|(_, export_ref)| {
+        let canonical_ref = link_output.symbol_db.canonical_ref_for(*export_ref);
+        canonical_ref.owner != module.idx
+      });
+    }
+  }
+  export_items.into_iter().map(move |(exported_name, export_ref)| {
+    (exported_name, link_output.symbol_db.canonical_ref_for(export_ref))
+  })
+}
+
+/// Whether `canonical_ref` is a function declaration of the chunk, which exists before the body
+/// of the chunk runs.
+fn is_system_hoisted_export(ctx: &GenerateContext<'_>, canonical_ref: SymbolRef) -> bool {
+  ctx.chunk_graph.module_to_chunk[canonical_ref.owner] == Some(ctx.chunk_idx)
+    && ctx.link_output.module_table[canonical_ref.owner].is_normal()
+    && ctx.link_output.symbol_db.get(canonical_ref).namespace_alias.is_none()
+    && canonical_ref.is_declared_by_function(&ctx.link_output.symbol_db)
+}
+
+/// `exports("name", value);` for a single item and `exports({ ... });` for several.
+fn render_system_exports_call(
+  ctx: &GenerateContext<'_>,
+  export_items: impl Iterator<Item = (CompactStr, SymbolRef)>,
+) -> Option<String> {
+  let mut rendered_items = export_items
+    .map(|(exported_name, canonical_ref)| {
+      let exported_value = ctx.finalized_string_pattern_for_symbol_ref(
+        canonical_ref,
+        ctx.chunk_idx,
+        &ctx.chunk.canonical_names,
+      );
+      (exported_name, exported_value)
+    })
+    .collect::<Vec<_>>();
+  match rendered_items.len() {
+    0 => None,
+    1 => {
+      let (exported_name, exported_value) = rendered_items.pop().expect("should have one item");
+      Some(concat_string!(
+        "exports(",
+        render_string_literal(&exported_name),
+        ", ",
+        exported_value,
+        ");"
+      ))
+    }
+    _ => {
+      let mut s = String::from("exports({\n");
+      for (exported_name, exported_value) in rendered_items {
+        s.push_str("  ");
+        if exported_name.as_str() == "__proto__" {
+          // A plain `__proto__` key would set the prototype of the object literal instead.
+          s.push_str("[\"__proto__\"]");
+        } else {
+          s.push_str(&to_module_import_export_name(&exported_name));
+        }
+        s.push_str(": ");
+        s.push_str(&exported_value);
+        s.push_str(",\n");
+      }
+      s.push_str("});");
+      Some(s)
+    }
+  }
+}
+
 pub fn render_object_define_property(key: &str, value: &str) -> String {
   concat_string!(
     "Object.defineProperty(exports, '",
@@ -441,6 +579,11 @@ pub fn render_object_define_property(key: &str, value: &str) -> String {
   )
 }
 
+#[inline]
+pub fn render_string_literal(value: &str) -> String {
+  serde_json::to_string(value).expect("a string should always be serializable")
+}
+
 #[inline]
 pub fn render_object_define_property_value(key: &str, value: &str) -> String {
   concat_string!(
@@ -455,6 +598,16 @@ pub fn render_object_define_property_value(key: &str, value: &str) -> String {
   )
 }
 
+fn get_render_export_items(ctx: &GenerateContext<'_>) -> Vec<(CompactStr, SymbolRef)> {
+  ctx.render_export_items_index_vec[ctx.chunk_idx]
+    .iter()
+    .flat_map(|(symbol_ref, names)| {
+      let symbol_ref = *symbol_ref;
+      names.iter().map(move |name| (name.clone(), symbol_ref))
+    })
+    .collect()
+}
+
 pub fn get_export_items(chunk: &Chunk) -> Vec<(CompactStr, SymbolRef)> {
   let mut export_items = chunk
     .exports_to_other_chunks
@@ -491,6 +644,31 @@ pub fn get_chunk_export_names_with_ctx(ctx: &GenerateContext<'_>) -> Vec<Compact
   render_export_items_index_vec[ctx.chunk_idx].values().flatten().cloned().collect::<Vec<_>>()
 }
 
+/// With `experimental.asyncModuleWrappers`, whether the exports of an entry chunk start the async
+/// wrapper of the entry: the default export is a promise of the value, and named exports come with
+/// an [`ASYNC_ENTRY_READY_EXPORT`] promise that settles once they are populated.
+fn exports_start_async_entry(
+  ctx: &GenerateContext<'_>,
+  export_mode: Option<&OutputExports>,
+) -> bool {
+  matches!(export_mode, Some(OutputExports::Named | OutputExports::Default))
+    && !ctx.render_export_items_index_vec[ctx.chunk_idx].is_empty()
+}
+
+/// With `experimental.asyncModuleWrappers`, the name of the `async` wrapper of an entry that
+/// depends on top-level await in a format that can't await it at the top level.
+fn async_entry_wrapper_name(ctx: &GenerateContext<'_>, entry_id: ModuleIdx) -> Option<String> {
+  if ctx.options.format.supports_top_level_await() {
+    return None;
+  }
+  let target = ctx.esm_init_target(entry_id).filter(|target| target.tla_tainted)?;
+  Some(ctx.finalized_string_pattern_for_symbol_ref(
+    target.wrapper_ref,
+    ctx.chunk_idx,
+    &ctx.chunk.canonical_names,
+  ))
+}
+
 fn must_keep_live_binding(
   export_ref: SymbolRef,
   symbol_db: &SymbolRefDb,
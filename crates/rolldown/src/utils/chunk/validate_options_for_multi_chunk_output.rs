@@ -9,6 +9,9 @@ pub fn validate_options_for_multi_chunk_output(
       options.format.to_string(),
     )))?;
   }
+  if matches!(options.format, OutputFormat::Amd) && options.amd.id.is_some() {
+    Err(BuildDiagnostic::invalid_option(InvalidOptionType::AmdIdWithMultipleChunks))?;
+  }
   if options.file.is_some() {
     Err(BuildDiagnostic::invalid_option(InvalidOptionType::InvalidOutputFile))?;
   }
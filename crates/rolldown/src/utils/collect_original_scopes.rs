@@ -0,0 +1,242 @@
+use oxc::{
+  ast::ast::{
+    AssignmentExpression, AssignmentTarget, Class, ExportDefaultDeclaration,
+    ExportDefaultDeclarationKind, Expression, Function, MethodDefinition, MethodDefinitionKind,
+    ObjectProperty, Program, PropertyDefinition, VariableDeclarator,
+  },
+  ast_visit::{VisitJs, walk_js},
+  semantic::{ScopeFlags, ScopeId, Scoping, SymbolFlags},
+  span::Span,
+};
+use rolldown_common::SourcemapChainElement;
+use rolldown_sourcemap::{
+  OriginalScope, OriginalScopeKind, PositionLocator, ScopeVariable, SourceMap, SourceScopes,
+  collapse_sourcemaps,
+};
+
+/// Collects the scope tree of a module for the `scopes` field of sourcemaps (see
+/// `output.sourcemapScopes`). Only the module scope and function and class scopes are recorded;
+/// block scopes are left out as they don't show up in stack traces.
+///
+/// The tree is collected in the coordinates of `source` and traced back through the sourcemaps of
+/// the `sourcemap_chain`, if any, so it's in the same coordinates as the final sourcemap. Returns
+/// `None` if the chain is broken by a plugin that omitted its sourcemap.
+pub fn collect_original_scopes(
+  program: &Program<'_>,
+  scoping: &Scoping,
+  source: &str,
+  module_id: &str,
+  sourcemap_chain: &[SourcemapChainElement],
+) -> Option<SourceScopes> {
+  let mut collector = OriginalScopesCollector {
+    scoping,
+    source,
+    locator: PositionLocator::new(source),
+    stack: vec![],
+    pending_name: None,
+  };
+  collector.visit_program(program);
+  let scope = collector.stack.pop()?;
+  let scopes = SourceScopes { source: module_id.to_string(), scope };
+
+  let mut maps: Vec<&SourceMap> = vec![];
+  for element in sourcemap_chain {
+    match element {
+      SourcemapChainElement::Load(map) | SourcemapChainElement::Transform((_, map)) => {
+        maps.push(map);
+      }
+      SourcemapChainElement::Omitted { .. } => return None,
+      // `map: null` keeps positions as they are.
+      SourcemapChainElement::Null { .. } => {}
+    }
+  }
+  match maps.as_slice() {
+    [] => Some(scopes),
+    [map] => scopes.remap(map),
+    maps => scopes.remap(&collapse_sourcemaps(maps)),
+  }
+}
+
+struct OriginalScopesCollector<'a> {
+  scoping: &'a Scoping,
+  source: &'a str,
+  locator: PositionLocator<'a>,
+  /// The scopes entered so far; a scope is added to its parent when it's left.
+  stack: Vec<OriginalScope>,
+  /// The name inferred for the function or class expression visited next, e.g. `foo` for
+  /// `const foo = () => {}`.
+  pending_name: Option<String>,
+}
+
+impl OriginalScopesCollector<'_> {
+  fn enter(
+    &mut self,
+    span: Span,
+    name: Option<String>,
+    kind: OriginalScopeKind,
+    scope_id: Option<ScopeId>,
+  ) {
+    self.stack.push(OriginalScope {
+      start: self.locator.locate(span.start as usize),
+      end: self.locator.locate(span.end as usize),
+      name,
+      kind,
+      is_stack_frame: kind == OriginalScopeKind::Function,
+      variables: scope_id.map(|scope_id| self.variables(scope_id)).unwrap_or_default(),
+      children: vec![],
+    });
+  }
+
+  fn leave(&mut self) {
+    let scope = self.stack.pop().expect("should have entered a scope");
+    match self.stack.last_mut() {
+      Some(parent) => parent.children.push(scope),
+      // Keep the root scope around for `collect_original_scopes` to take.
+      None => self.stack.push(scope),
+    }
+  }
+
+  fn variables(&self, scope_id: ScopeId) -> Vec<ScopeVariable> {
+    let mut symbols = self
+      .scoping
+      .iter_bindings_in(scope_id)
+      .filter(|symbol_id| self.scoping.symbol_flags(*symbol_id).intersects(SymbolFlags::Value))
+      .map(|symbol_id| (self.scoping.symbol_span(symbol_id), symbol_id))
+      // Symbols created by transforms have no place in the source.
+      .filter(|(span, _)| !span.is_empty())
+      .collect::<Vec<_>>();
+    symbols.sort_unstable_by_key(|(span, _)| span.start);
+    symbols
+      .into_iter()
+      .map(|(span, symbol_id)| ScopeVariable {
+        name: self.scoping.symbol_name(symbol_id).to_string(),
+        declaration: self.locator.locate(span.start as usize),
+      })
+      .collect()
+  }
+
+  fn visit_named_expression(&mut self, expr: &Expression<'_>, name: Option<String>) {
+    if matches!(
+      expr.without_parentheses(),
+      Expression::FunctionExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::ClassExpression(_)
+    ) {
+      self.pending_name = name;
+    }
+    self.visit_expression(expr);
+  }
+
+  fn is_in_source(&self, span: Span) -> bool {
+    !span.is_empty() && (span.end as usize) <= self.source.len()
+  }
+}
+
+impl<'ast> VisitJs<'ast> for OriginalScopesCollector<'_> {
+  fn visit_program(&mut self, it: &Program<'ast>) {
+    let kind = if it.source_type.is_module() {
+      OriginalScopeKind::Module
+    } else {
+      OriginalScopeKind::Global
+    };
+    self.enter(Span::new(0, it.span.end), None, kind, it.scope_id.get());
+    walk_js::walk_program(self, it);
+    self.leave();
+  }
+
+  fn visit_function(&mut self, it: &Function<'ast>, flags: ScopeFlags) {
+    let name = it.id.as_ref().map(|id| id.name.to_string()).or_else(|| self.pending_name.take());
+    self.pending_name = None;
+    if !self.is_in_source(it.span) {
+      walk_js::walk_function(self, it, flags);
+      return;
+    }
+    self.enter(it.span, name, OriginalScopeKind::Function, it.scope_id.get());
+    walk_js::walk_function(self, it, flags);
+    self.leave();
+  }
+
+  fn visit_arrow_function_expression(
+    &mut self,
+    it: &oxc::ast::ast::ArrowFunctionExpression<'ast>,
+  ) {
+    let name = self.pending_name.take();
+    if !self.is_in_source(it.span) {
+      walk_js::walk_arrow_function_expression(self, it);
+      return;
+    }
+    self.enter(it.span, name, OriginalScopeKind::Function, it.scope_id.get());
+    walk_js::walk_arrow_function_expression(self, it);
+    self.leave();
+  }
+
+  fn visit_class(&mut self, it: &Class<'ast>) {
+    let name = it.id.as_ref().map(|id| id.name.to_string()).or_else(|| self.pending_name.take());
+    self.pending_name = None;
+    if !self.is_in_source(it.span) {
+      walk_js::walk_class(self, it);
+      return;
+    }
+    self.enter(it.span, name, OriginalScopeKind::Class, it.scope_id.get());
+    walk_js::walk_class(self, it);
+    self.leave();
+  }
+
+  fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'ast>) {
+    self.visit_binding_pattern(&it.id);
+    if let Some(init) = &it.init {
+      let name = it.id.get_binding_identifier().map(|id| id.name.to_string());
+      self.visit_named_expression(init, name);
+    }
+  }
+
+  fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'ast>) {
+    self.visit_assignment_target(&it.left);
+    let name = match &it.left {
+      AssignmentTarget::AssignmentTargetIdentifier(ident) => Some(ident.name.to_string()),
+      AssignmentTarget::StaticMemberExpression(member) => Some(member.property.name.to_string()),
+      _ => None,
+    };
+    self.visit_named_expression(&it.right, name);
+  }
+
+  fn visit_object_property(&mut self, it: &ObjectProperty<'ast>) {
+    self.visit_property_key(&it.key);
+    self.visit_named_expression(&it.value, it.key.static_name().map(|name| name.to_string()));
+  }
+
+  fn visit_property_definition(&mut self, it: &PropertyDefinition<'ast>) {
+    self.visit_decorators(&it.decorators);
+    self.visit_property_key(&it.key);
+    if let Some(value) = &it.value {
+      self.visit_named_expression(value, it.key.static_name().map(|name| name.to_string()));
+    }
+  }
+
+  fn visit_method_definition(&mut self, it: &MethodDefinition<'ast>) {
+    self.visit_decorators(&it.decorators);
+    self.visit_property_key(&it.key);
+    let flags = match it.kind {
+      MethodDefinitionKind::Get => ScopeFlags::Function | ScopeFlags::GetAccessor,
+      MethodDefinitionKind::Set => ScopeFlags::Function | ScopeFlags::SetAccessor,
+      MethodDefinitionKind::Constructor => ScopeFlags::Function | ScopeFlags::Constructor,
+      MethodDefinitionKind::Method => ScopeFlags::Function,
+    };
+    self.pending_name = it.key.static_name().map(|name| name.to_string());
+    self.visit_function(&it.value, flags);
+  }
+
+  fn visit_export_default_declaration(&mut self, it: &ExportDefaultDeclaration<'ast>) {
+    match &it.declaration {
+      ExportDefaultDeclarationKind::FunctionDeclaration(_)
+      | ExportDefaultDeclarationKind::ClassDeclaration(_) => {
+        self.pending_name = Some("default".to_string());
+        walk_js::walk_export_default_declaration(self, it);
+      }
+      kind => match kind.as_expression() {
+        Some(expr) => self.visit_named_expression(expr, Some("default".to_string())),
+        None => walk_js::walk_export_default_declaration(self, it),
+      },
+    }
+  }
+}
@@ -0,0 +1,208 @@
+use std::fmt::{self, Display};
+use std::path::Path;
+
+use oxc::ast::ast::{BindingIdentifier, Expression, Program, TemplateLiteral};
+use oxc::ast_visit::VisitJs;
+use oxc::syntax::operator::BinaryOperator;
+use rolldown_fs::FileSystem;
+use rolldown_utils::concat_string;
+
+#[derive(Debug, PartialEq, Eq)]
+enum GlobPart<'a> {
+  Literal(&'a str),
+  /// `nested` wildcards directly follow a `/` and may span several directories.
+  Wildcard {
+    nested: bool,
+  },
+}
+
+/// The path of an `import()` / `require()` that is built at runtime, seen as a glob like esbuild
+/// does.
+///
+/// - `` `./src/${name}.js` `` -> `./src/**/*.js`
+/// - `'./src/file-' + name + '.js'` -> `./src/file-*.js`
+#[derive(Debug)]
+pub struct GlobImportPattern<'a> {
+  parts: Vec<GlobPart<'a>>,
+}
+
+impl<'a> GlobImportPattern<'a> {
+  /// Returns `None` if the path has no dynamic part or doesn't start with `./` or `../`, as there
+  /// is no directory to search then.
+  pub fn from_expr(expr: &'a Expression<'a>) -> Option<Self> {
+    let mut parts = vec![];
+    collect_glob_parts(expr, &mut parts)?;
+    if !matches!(parts.first(), Some(GlobPart::Literal(prefix)) if prefix.starts_with("./") || prefix.starts_with("../"))
+      || !parts.iter().any(|part| matches!(part, GlobPart::Wildcard { .. }))
+    {
+      return None;
+    }
+    Some(Self { parts })
+  }
+
+  /// Lists the matched files relative to `importer_dir`, written the way the path is built at
+  /// runtime, e.g. `./src/a.js`. The result is sorted to keep the output stable.
+  pub fn expand(&self, fs: &dyn FileSystem, importer_dir: &Path) -> Vec<String> {
+    let GlobPart::Literal(prefix) = self.parts[0] else { unreachable!() };
+    // Only the directory the static prefix points to needs to be searched.
+    let base = &prefix[..=prefix.rfind('/').unwrap_or_default()];
+    let max_depth = if self.parts.contains(&GlobPart::Wildcard { nested: true }) {
+      usize::MAX
+    } else {
+      self.to_string()[base.len()..].matches('/').count()
+    };
+
+    let mut matched = vec![];
+    let mut pending = vec![(importer_dir.join(base), base.to_string(), 0)];
+    while let Some((dir, dir_prefix, depth)) = pending.pop() {
+      let Ok(entries) = fs.read_dir(&dir) else { continue };
+      for entry in entries {
+        let Some(name) = entry.file_name().and_then(|name| name.to_str()) else { continue };
+        let path = concat_string!(dir_prefix, name);
+        let Ok(metadata) = fs.metadata(&entry) else { continue };
+        if metadata.is_dir() {
+          if depth < max_depth && name != "node_modules" {
+            pending.push((entry, concat_string!(path, "/"), depth + 1));
+          }
+        } else if metadata.is_file() && matches_glob_parts(&self.parts, &path) {
+          matched.push(path);
+        }
+      }
+    }
+    matched.sort_unstable();
+    matched
+  }
+}
+
+impl Display for GlobImportPattern<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for part in &self.parts {
+      match part {
+        GlobPart::Literal(literal) => f.write_str(literal)?,
+        GlobPart::Wildcard { nested: true } => f.write_str("**/*")?,
+        GlobPart::Wildcard { nested: false } => f.write_str("*")?,
+      }
+    }
+    Ok(())
+  }
+}
+
+/// Whether `program` declares its own `require`, e.g. as a parameter of an already wrapped
+/// module. Glob `require()` calls are only expanded when they call the global `require`.
+pub fn declares_require(program: &Program<'_>) -> bool {
+  struct RequireBindingFinder(bool);
+
+  impl<'a> VisitJs<'a> for RequireBindingFinder {
+    fn visit_binding_identifier(&mut self, it: &BindingIdentifier<'a>) {
+      self.0 |= it.name == "require";
+    }
+  }
+
+  let mut finder = RequireBindingFinder(false);
+  finder.visit_program(program);
+  finder.0
+}
+
+fn collect_glob_parts<'a>(expr: &'a Expression<'a>, parts: &mut Vec<GlobPart<'a>>) -> Option<()> {
+  match expr.without_parentheses() {
+    Expression::StringLiteral(lit) => {
+      if !lit.value.is_empty() {
+        parts.push(GlobPart::Literal(lit.value.as_str()));
+      }
+    }
+    Expression::TemplateLiteral(tpl) => collect_template_literal_parts(tpl, parts)?,
+    Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+      collect_glob_parts(&expr.left, parts)?;
+      collect_glob_parts(&expr.right, parts)?;
+    }
+    _ => match parts.last() {
+      Some(GlobPart::Wildcard { .. }) => {}
+      Some(GlobPart::Literal(literal)) if literal.ends_with('/') => {
+        parts.push(GlobPart::Wildcard { nested: true });
+      }
+      _ => parts.push(GlobPart::Wildcard { nested: false }),
+    },
+  }
+  Some(())
+}
+
+fn collect_template_literal_parts<'a>(
+  tpl: &'a TemplateLiteral<'a>,
+  parts: &mut Vec<GlobPart<'a>>,
+) -> Option<()> {
+  for (quasi, expr) in tpl.quasis.iter().zip(tpl.expressions.iter().map(Some).chain([None])) {
+    let cooked = quasi.value.cooked?.as_str();
+    if !cooked.is_empty() {
+      parts.push(GlobPart::Literal(cooked));
+    }
+    if let Some(expr) = expr {
+      collect_glob_parts(expr, parts)?;
+    }
+  }
+  Some(())
+}
+
+fn matches_glob_parts(parts: &[GlobPart<'_>], path: &str) -> bool {
+  match parts.split_first() {
+    None => path.is_empty(),
+    Some((GlobPart::Literal(literal), rest)) => {
+      path.strip_prefix(literal).is_some_and(|path| matches_glob_parts(rest, path))
+    }
+    Some((GlobPart::Wildcard { nested }, rest)) => path
+      .char_indices()
+      .map(|(i, _)| i)
+      .chain([path.len()])
+      .take_while(|&i| *nested || !path[..i].contains('/'))
+      .any(|i| matches_glob_parts(rest, &path[i..])),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use oxc::{allocator::Allocator, parser::Parser, span::SourceType};
+
+  use super::{GlobImportPattern, matches_glob_parts};
+
+  fn with_pattern(source: &str, f: impl FnOnce(Option<GlobImportPattern<'_>>)) {
+    let allocator = Allocator::default();
+    let expr = Parser::new(&allocator, source, SourceType::mjs()).parse_expression().unwrap();
+    f(GlobImportPattern::from_expr(&expr));
+  }
+
+  fn glob_of(source: &str) -> Option<String> {
+    let mut glob = None;
+    with_pattern(source, |pattern| glob = pattern.map(|pattern| pattern.to_string()));
+    glob
+  }
+
+  #[test]
+  fn wildcard_after_slash_spans_directories() {
+    assert_eq!(glob_of("`./src/${a}`").as_deref(), Some("./src/**/*"));
+    assert_eq!(glob_of("'./src/' + a + '.js'").as_deref(), Some("./src/**/*.js"));
+    with_pattern("`./src/${a}.js`", |pattern| {
+      let parts = pattern.unwrap().parts;
+      assert!(matches_glob_parts(&parts, "./src/a.js"));
+      assert!(matches_glob_parts(&parts, "./src/nested/dir/a.js"));
+      assert!(!matches_glob_parts(&parts, "./src/a.js.map"));
+    });
+  }
+
+  #[test]
+  fn wildcard_inside_file_name_stays_in_directory() {
+    assert_eq!(glob_of("`./src/file-${a}.js`").as_deref(), Some("./src/file-*.js"));
+    assert_eq!(glob_of("'../i18n/' + (a + b) + '.json'").as_deref(), Some("../i18n/**/*.json"));
+    with_pattern("`./src/file-${a}.js`", |pattern| {
+      let parts = pattern.unwrap().parts;
+      assert!(matches_glob_parts(&parts, "./src/file-a.js"));
+      assert!(!matches_glob_parts(&parts, "./src/file-nested/a.js"));
+    });
+  }
+
+  #[test]
+  fn non_relative_or_static_paths_are_ignored() {
+    assert_eq!(glob_of("`${a}.js`"), None);
+    assert_eq!(glob_of("`pkg/${a}.js`"), None);
+    assert_eq!(glob_of("'./a' + '.js'"), None);
+    assert_eq!(glob_of("a"), None);
+  }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use rolldown_common::{IntegrityAlgorithm, Output};
+use rolldown_utils::rayon::{IntoParallelRefMutIterator, ParallelIterator};
+
+/// Computes the `output.integrity` digest of every output.
+///
+/// Runs once before the `generateBundle` hook, so plugins can read the digests, and once after it,
+/// as the hook may have changed or emitted outputs.
+pub fn compute_integrity(outputs: &mut [Output], algorithm: IntegrityAlgorithm) {
+  outputs.par_iter_mut().for_each(|output| match output {
+    Output::Chunk(chunk) => {
+      let integrity = algorithm.digest(chunk.code.as_bytes());
+      if chunk.integrity.as_ref() != Some(&integrity) {
+        Arc::make_mut(chunk).integrity = Some(integrity);
+      }
+    }
+    Output::Asset(asset) => {
+      let integrity = algorithm.digest(asset.source.as_bytes());
+      if asset.integrity.as_ref() != Some(&integrity) {
+        Arc::make_mut(asset).integrity = Some(integrity);
+      }
+    }
+  });
+}
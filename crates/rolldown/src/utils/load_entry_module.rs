@@ -24,6 +24,7 @@ pub async fn load_entry_module<Fs: FileSystem>(
     ImportKind::Import,
     None,
     Arc::default(),
+    None,
     true,
   )
   .await?;
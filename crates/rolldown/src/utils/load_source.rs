@@ -24,7 +24,12 @@ pub async fn load_source<Fs: FileSystem + 'static>(
     (Some(String::new()), Some(ModuleType::Empty))
   } else {
     plugin_driver
-      .load(&HookLoadArgs { id: &resolved_id.id, module_idx, asserted_module_type })
+      .load(&HookLoadArgs {
+        id: &resolved_id.module_id(),
+        module_idx,
+        asserted_module_type,
+        attributes: resolved_id.attributes.as_deref(),
+      })
       .await?
       .map(|load_hook_output| {
         sourcemap_chain.extend(load_hook_output.map.map(SourcemapChainElement::Load));
@@ -76,7 +81,7 @@ pub async fn load_source<Fs: FileSystem + 'static>(
           ))
         }
         (source, Some(guessed)) => match &guessed {
-          ModuleType::Base64 | ModuleType::Binary | ModuleType::Dataurl => Ok((
+          ModuleType::Base64 | ModuleType::Binary | ModuleType::Dataurl | ModuleType::Wasm => Ok((
             StrOrBytes::Bytes({
               match source {
                 Some(s) => s.into_bytes(),
@@ -112,6 +117,7 @@ pub async fn load_source<Fs: FileSystem + 'static>(
           | ModuleType::Text
           | ModuleType::Empty
           | ModuleType::Css
+          | ModuleType::Html
           | ModuleType::Custom(_) => Ok((
             StrOrBytes::Str({
               if let Some(s) = source {
@@ -144,7 +150,7 @@ pub async fn load_source<Fs: FileSystem + 'static>(
 }
 
 /// ref: https://github.com/evanw/esbuild/blob/9c13ae1f06dfa909eb4a53882e3b7e4216a503fe/internal/bundler/bundler.go#L1161-L1183
-fn get_module_loader_from_file_extension<S: AsRef<str>>(
+pub fn get_module_loader_from_file_extension<S: AsRef<str>>(
   id: S,
   module_types: &FxHashMap<Cow<'static, str>, ModuleType>,
 ) -> Option<ModuleType> {
@@ -170,6 +176,7 @@ async fn read_file_by_module_type<Fs: FileSystem + 'static>(
     | ModuleType::Tsx
     | ModuleType::Json
     | ModuleType::Css
+    | ModuleType::Html
     | ModuleType::Empty
     | ModuleType::Copy
     | ModuleType::Custom(_)
@@ -184,12 +191,14 @@ async fn read_file_by_module_type<Fs: FileSystem + 'static>(
       "Encountered a module with type `asset` in read_file_by_module_type. \
          Asset modules should be handled by the builtin asset-module plugin."
     ))?,
-    ModuleType::Base64 | ModuleType::Binary | ModuleType::Dataurl => Ok(StrOrBytes::Bytes({
-      if cfg!(target_family = "wasm") {
-        fs.read(&path)?
-      } else {
-        tokio::runtime::Handle::current().spawn_blocking(move || fs.read(&path)).await??
-      }
-    })),
+    ModuleType::Base64 | ModuleType::Binary | ModuleType::Dataurl | ModuleType::Wasm => {
+      Ok(StrOrBytes::Bytes({
+        if cfg!(target_family = "wasm") {
+          fs.read(&path)?
+        } else {
+          tokio::runtime::Handle::current().spawn_blocking(move || fs.read(&path)).await??
+        }
+      }))
+    }
   }
 }
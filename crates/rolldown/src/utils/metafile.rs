@@ -0,0 +1,111 @@
+use rolldown_common::{
+  ExportsKind, ImportKind, MetafileImport, MetafileInput, MetafileOutput, Module,
+  NormalizedBundlerOptions, Output, StableModuleId,
+};
+use rolldown_std_utils::relative_path_to_slash;
+use rolldown_utils::indexmap::FxIndexMap;
+use rustc_hash::FxHashSet;
+
+use crate::stages::link_stage::LinkStageOutput;
+
+/// Collects the `inputs` of the metafile: every module of the build, with the size of its loaded
+/// source and what it imports. The runtime is included, as outputs attribute bytes to it like to
+/// any other module.
+pub fn collect_metafile_inputs(link_output: &LinkStageOutput) -> FxIndexMap<String, MetafileInput> {
+  link_output
+    .module_table
+    .modules
+    .iter()
+    .filter_map(Module::as_normal)
+    .map(|module| {
+      let import_records = module
+        .css_view
+        .as_ref()
+        .map_or(&module.ecma_view.import_records, |css_view| &css_view.import_records);
+      let imports = import_records
+        .iter()
+        // `import.meta.hot.accept()` isn't a dependency of the module.
+        .filter(|rec| rec.kind != ImportKind::HotAccept)
+        .filter_map(|rec| {
+          let importee = &link_output.module_table[rec.resolved_module?];
+          let path = importee.stable_id().to_string();
+          Some(MetafileImport {
+            original: (rec.module_request != path).then(|| rec.module_request.to_string()),
+            path,
+            kind: rec.kind,
+            external: importee.is_external(),
+          })
+        })
+        .collect();
+      let format = match module.exports_kind {
+        ExportsKind::Esm => Some("esm"),
+        ExportsKind::CommonJs => Some("cjs"),
+        ExportsKind::None => None,
+      };
+      (
+        module.stable_id.to_string(),
+        MetafileInput { bytes: module.loaded_source_len, imports, format },
+      )
+    })
+    .collect()
+}
+
+/// Collects the `outputs` of the metafile from the final output, so files added or removed by
+/// the `generateBundle` hook are reflected too.
+pub fn collect_metafile_outputs(
+  assets: &[Output],
+  options: &NormalizedBundlerOptions,
+) -> FxIndexMap<String, MetafileOutput> {
+  let out_dir = options.cwd.join(&options.out_dir);
+  let output_path = |filename: &str| relative_path_to_slash(out_dir.join(filename), &options.cwd);
+  let filenames = assets.iter().map(Output::filename).collect::<FxHashSet<_>>();
+  let to_import = |filename: &str, kind: ImportKind| {
+    let external = !filenames.contains(filename);
+    MetafileImport {
+      path: if external { filename.to_string() } else { output_path(filename) },
+      kind,
+      external,
+      original: None,
+    }
+  };
+
+  assets
+    .iter()
+    .map(|output| {
+      let metafile_output = match output {
+        Output::Chunk(chunk) => MetafileOutput {
+          bytes: output.content_as_bytes().len(),
+          inputs: chunk
+            .modules
+            .keys
+            .iter()
+            .zip(&chunk.modules.values)
+            .map(|(id, module)| {
+              (StableModuleId::new(id, &options.cwd).to_string(), module.rendered_length())
+            })
+            .collect(),
+          imports: chunk
+            .imports
+            .iter()
+            .map(|filename| to_import(filename, ImportKind::Import))
+            .chain(
+              chunk
+                .dynamic_imports
+                .iter()
+                .map(|filename| to_import(filename, ImportKind::DynamicImport)),
+            )
+            .collect(),
+          exports: chunk.exports.iter().map(ToString::to_string).collect(),
+          entry_point: (chunk.is_entry || chunk.is_dynamic_entry)
+            .then(|| chunk.facade_module_id.as_ref())
+            .flatten()
+            .map(|id| StableModuleId::new(id, &options.cwd).to_string()),
+        },
+        Output::Asset(_) => {
+          MetafileOutput { bytes: output.content_as_bytes().len(), ..Default::default() }
+        }
+      };
+      (output_path(output.filename()), metafile_output)
+    })
+    .collect()
+}
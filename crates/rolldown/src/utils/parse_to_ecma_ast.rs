@@ -1,7 +1,10 @@
 use std::{borrow::Cow, path::Path};
 
 use json_escape_simd::escape;
-use oxc::{semantic::Scoping, span::SourceType as OxcSourceType};
+use oxc::{
+  semantic::Scoping,
+  span::{SourceType as OxcSourceType, Span},
+};
 use oxc_str::CompactStr;
 use rolldown_common::{
   ConstExportMeta, ModuleDefFormat, ModuleId, ModuleType, NormalizedBundlerOptions,
@@ -11,7 +14,7 @@ use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
 use rolldown_error::{BuildDiagnostic, BuildResult, EventKindSwitcher};
 use rolldown_plugin::HookTransformAstArgs;
 use rolldown_utils::mime::guess_mime;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use sugar_path::SugarPath as _;
 
 use super::pre_process_ecma_ast::PreProcessEcmaAst;
@@ -46,6 +49,8 @@ pub struct ParseToEcmaAstResult {
   /// Enum member constant values, keyed by enum name → member name → value.
   /// Used by the finalizer to inline cross-module enum member accesses (e.g., `Direction.Up` → `0`).
   pub enum_member_value_map: FxHashMap<CompactStr, FxHashMap<CompactStr, ConstExportMeta>>,
+  /// Spans of the `__glob` callees generated for glob `import()` / `require()` calls.
+  pub glob_callee_spans: FxHashSet<Span>,
 }
 
 pub async fn parse_to_ecma_ast(
@@ -59,6 +64,7 @@ pub async fn parse_to_ecma_ast(
     module_type,
     plugin_driver,
     replace_global_define_config,
+    fs,
     ..
   } = ctx;
 
@@ -127,6 +133,7 @@ pub async fn parse_to_ecma_ast(
     replace_global_define_config.as_ref(),
     options,
     has_lazy_export,
+    *fs,
   )
 }
 
@@ -153,9 +160,6 @@ fn pre_process_source(
     ModuleType::Js | ModuleType::Jsx | ModuleType::Ts | ModuleType::Tsx | ModuleType::Json => {
       Cow::Owned(source.try_into_string()?)
     }
-    ModuleType::Css => {
-      unreachable!("CSS modules should error before reaching parse_to_ecma_ast")
-    }
     ModuleType::Text => {
       let text = source.try_into_string()?;
       // Strip UTF-8 BOM if present
@@ -169,6 +173,20 @@ fn pre_process_source(
          please check your plugin and loader configuration."
       ))?;
     }
+    ModuleType::Wasm => {
+      return Err(anyhow::format_err!(
+        "Encountered a module with type `wasm` during AST parsing. \
+         Modules with type `wasm` must be handled by the builtin WasmModulePlugin before this stage; \
+         please check your plugin and loader configuration."
+      ))?;
+    }
+    ModuleType::Html => {
+      return Err(anyhow::format_err!(
+        "Encountered a module with type `html` during AST parsing. \
+         Modules with type `html` must be handled by the builtin HtmlPlugin before this stage; \
+         please check your plugin and loader configuration."
+      ))?;
+    }
     ModuleType::Base64 => {
       let encoded = rolldown_utils::base64::to_standard_base64(source.as_bytes());
       Cow::Owned(escape(&encoded))
@@ -197,7 +215,8 @@ fn pre_process_source(
         "')"
       ))
     }
-    ModuleType::Empty => Cow::Borrowed(""),
+    // The stylesheet itself lives in `CssView`, the js side of a css module is an empty module.
+    ModuleType::Css | ModuleType::Empty => Cow::Borrowed(""),
     ModuleType::Copy => {
       return Err(anyhow::format_err!(
         "Encountered a module with type `copy` during AST parsing. \
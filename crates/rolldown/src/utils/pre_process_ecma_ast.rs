@@ -18,13 +18,17 @@ use oxc_str::CompactStr;
 use rolldown_common::{ConstExportMeta, ConstantValue, NormalizedBundlerOptions};
 use rolldown_ecmascript::{EcmaAst, WithMutFields, semantic_builder_for_transform};
 use rolldown_ecmascript_utils::contains_script_closing_tag;
-use rolldown_error::{BatchedBuildDiagnostic, BuildDiagnostic, BuildResult, EventKind, Severity};
+use rolldown_error::{
+  BatchedBuildDiagnostic, BuildDiagnostic, BuildResult, EventKind, EventKindSwitcher, Severity,
+};
+use rolldown_fs::FileSystem;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::types::oxc_parse_type::OxcParseType;
 
+use super::glob_import::declares_require;
 use super::parse_to_ecma_ast::ParseToEcmaAstResult;
-use super::tweak_ast_for_scanning::PreProcessor;
+use super::tweak_ast_for_scanning::{GlobImportContext, PreProcessor};
 
 #[derive(Default)]
 pub struct PreProcessEcmaAst {
@@ -33,7 +37,7 @@ pub struct PreProcessEcmaAst {
 }
 
 impl PreProcessEcmaAst {
-  #[expect(clippy::too_many_arguments)]
+  #[expect(clippy::too_many_arguments, clippy::too_many_lines)]
   pub fn build(
     &mut self,
     mut ast: EcmaAst,
@@ -44,6 +48,7 @@ impl PreProcessEcmaAst {
     replace_global_define_config: Option<&ReplaceGlobalDefinesConfig>,
     bundle_options: &NormalizedBundlerOptions,
     has_lazy_export: bool,
+    fs: &dyn FileSystem,
   ) -> BuildResult<ParseToEcmaAstResult> {
     let source = ast.source().clone();
 
@@ -65,6 +70,18 @@ impl PreProcessEcmaAst {
       });
     }
 
+    // `/** @license */` is both a JSDoc and a legal comment. When legal comments are moved out
+    // of the chunk, treat it as a legal comment only, so it isn't printed in place as JSDoc too.
+    if bundle_options.legal_comments.is_extracted() {
+      ast.program.with_mut(|WithMutFields { program, .. }| {
+        for comment in &mut program.comments {
+          if comment.content == CommentContent::JsdocLegal {
+            comment.content = CommentContent::Legal;
+          }
+        }
+      });
+    }
+
     // Step 1: Build initial semantic data and check for semantic errors.
     let semantic_ret = ast.program.with_dependent(|_owner, dep| {
       semantic_builder_for_transform().with_check_syntax_error(true).build(&dep.program)
@@ -245,16 +262,34 @@ impl PreProcessEcmaAst {
     }
 
     // Step 6: Modify AST for Rolldown.
-    let (scoping, import_defer_spans) =
+    // Glob imports are only expanded for modules on disk, as virtual modules have no directory
+    // to search.
+    let importer_path = Path::new(resolved_id);
+    let importer_dir = importer_path.is_absolute().then(|| importer_path.parent()).flatten();
+    let (scoping, import_defer_spans, glob_callee_spans, empty_glob_imports) =
       ast.program.with_mut(|WithMutFields { program, allocator, .. }| {
+        let glob_import_ctx = importer_dir.map(|importer_dir| GlobImportContext {
+          fs,
+          importer_dir,
+          module_types: &bundle_options.module_types,
+          expand_require: !declares_require(program),
+        });
         let mut pre_processor = PreProcessor::new(
           allocator,
           bundle_options.keep_names,
           Some(&bundle_options.drop_labels),
+          glob_import_ctx,
         );
         pre_processor.visit_program(program);
         let defer_spans = pre_processor.take_defer_spans();
-        (self.recreate_scoping(&mut None, program), defer_spans)
+        let glob_callee_spans = pre_processor.take_glob_callee_spans();
+        let empty_glob_imports = pre_processor.take_empty_glob_imports();
+        (
+          self.recreate_scoping(&mut None, program),
+          defer_spans,
+          glob_callee_spans,
+          empty_glob_imports,
+        )
       });
 
     warnings.extend(import_defer_spans.into_iter().map(|span| {
@@ -262,7 +297,7 @@ impl PreProcessEcmaAst {
         source.clone(),
         resolved_id.to_string(),
         String::new(),
-        "`import defer` is currently lowered to a normal import. This changes execution timing because side effects run immediately instead of when the deferred import is first used.".to_string(),
+        "`import.defer()` is currently lowered to `import()`. This changes execution timing because side effects run once the module is loaded instead of when the deferred namespace is first used.".to_string(),
         vec![LabeledSpan::at(
           span.start..span.end,
           "The deferred phase is removed here.",
@@ -272,6 +307,13 @@ impl PreProcessEcmaAst {
       .with_severity_warning()
     }));
 
+    if bundle_options.checks.contains(EventKindSwitcher::EmptyGlobImport) {
+      warnings.extend(empty_glob_imports.into_iter().map(|(span, glob)| {
+        BuildDiagnostic::empty_glob_import(resolved_id.to_string(), source.clone(), span, glob)
+          .with_severity_warning()
+      }));
+    }
+
     Ok(ParseToEcmaAstResult {
       ast,
       scoping,
@@ -279,6 +321,7 @@ impl PreProcessEcmaAst {
       warnings,
       preserve_jsx,
       enum_member_value_map,
+      glob_callee_spans,
     })
   }
 
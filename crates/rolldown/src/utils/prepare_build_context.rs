@@ -156,6 +156,19 @@ fn verify_raw_options(raw_options: &crate::BundlerOptions) -> BuildResult<Vec<Bu
     }
   }
 
+  // The compression libraries are C code that does not build for every target, e.g. wasm32-wasi.
+  #[cfg(not(feature = "precompress"))]
+  if raw_options
+    .experimental
+    .as_ref()
+    .is_some_and(|experimental| experimental.precompress.is_some())
+  {
+    warnings.push(
+      BuildDiagnostic::invalid_option(InvalidOptionType::PrecompressUnavailable)
+        .with_severity_warning(),
+    );
+  }
+
   if errors.is_empty() { Ok(warnings) } else { Err(errors.into()) }
 }
 
@@ -194,7 +207,11 @@ pub fn prepare_build_context(
 
   let platform = raw_options.platform.unwrap_or(match format {
     OutputFormat::Cjs => Platform::Node,
-    OutputFormat::Esm | OutputFormat::Iife | OutputFormat::Umd => Platform::Browser,
+    OutputFormat::Esm
+    | OutputFormat::Iife
+    | OutputFormat::Umd
+    | OutputFormat::Amd
+    | OutputFormat::System => Platform::Browser,
   });
 
   let raw_minify = raw_options.minify.unwrap_or_default();
@@ -233,6 +250,7 @@ pub fn prepare_build_context(
       ("json".into(), ModuleType::Json),
       ("txt".into(), ModuleType::Text),
       ("css".into(), ModuleType::Css),
+      ("wasm".into(), ModuleType::Wasm),
     ]
     .into_iter()
     .collect(),
@@ -392,6 +410,12 @@ pub fn prepare_build_context(
     chunk_filenames: raw_options
       .chunk_filenames
       .unwrap_or_else(|| "[name]-[hash].js".to_string().into()),
+    css_entry_filenames: raw_options
+      .css_entry_filenames
+      .unwrap_or_else(|| "[name].css".to_string().into()),
+    css_chunk_filenames: raw_options
+      .css_chunk_filenames
+      .unwrap_or_else(|| "[name]-[hash].css".to_string().into()),
     asset_filenames: raw_options
       .asset_filenames
       .unwrap_or_else(|| "assets/[name]-[hash][extname]".to_string().into()),
@@ -403,6 +427,7 @@ pub fn prepare_build_context(
     intro: raw_options.intro,
     outro: raw_options.outro,
     es_module: raw_options.es_module.unwrap_or_default(),
+    hoist_transitive_imports: raw_options.hoist_transitive_imports.unwrap_or_default(),
     dir: raw_options.dir,
     out_dir,
     file: raw_options.file,
@@ -411,12 +436,15 @@ pub fn prepare_build_context(
     hash_characters: raw_options.hash_characters.unwrap_or(crate::HashCharacters::Base64),
     globals,
     paths: raw_options.paths,
+    interop: raw_options.interop,
     generated_code,
     sourcemap: raw_options.sourcemap,
     sourcemap_base_url: raw_options.sourcemap_base_url,
     sourcemap_ignore_list: raw_options.sourcemap_ignore_list,
     sourcemap_path_transform: raw_options.sourcemap_path_transform,
     sourcemap_debug_ids: raw_options.sourcemap_debug_ids.unwrap_or(false),
+    sourcemap_scopes: raw_options.sourcemap_scopes.unwrap_or(false),
+    sourcemap_index_map: raw_options.sourcemap_index_map.unwrap_or(false),
     sourcemap_exclude_sources: raw_options.sourcemap_exclude_sources.unwrap_or(false),
     sourcemap_filenames: raw_options.sourcemap_filenames,
     shim_missing_exports: raw_options.shim_missing_exports.unwrap_or(false),
@@ -429,6 +457,7 @@ pub fn prepare_build_context(
     inject: raw_options.inject.unwrap_or_default(),
     oxc_inject_global_variables_config,
     extend: raw_options.extend.unwrap_or(false),
+    amd: raw_options.amd.unwrap_or_default(),
     external_live_bindings: raw_options.external_live_bindings.unwrap_or(true),
     code_splitting,
     dynamic_import_in_cjs: raw_options.dynamic_import_in_cjs.unwrap_or(true),
@@ -441,7 +470,7 @@ pub fn prepare_build_context(
       // When `comments` option is not explicitly set, `legalComments` can override `comments.legal`
       if raw_options.comments.is_none() {
         if let Some(legal) = raw_options.legal_comments {
-          comments.legal = matches!(legal, LegalComments::Inline);
+          comments.legal = !matches!(legal, LegalComments::None);
         }
       }
       comments
@@ -477,9 +506,13 @@ pub fn prepare_build_context(
       .minify_internal_exports
       .unwrap_or_else(|| determine_minify_internal_exports_default(Some(format), &raw_minify)),
     clean_dir,
+    metafile: raw_options.metafile.unwrap_or(false),
+    integrity: raw_options.integrity,
     context: raw_options.context.unwrap_or_default(),
     strict_execution_order: raw_options.strict_execution_order.unwrap_or(false),
     strict: raw_options.strict.unwrap_or_default(),
+    worker: raw_options.worker,
+    wasm: raw_options.wasm.unwrap_or_default(),
   };
 
   normalized.minify = raw_minify.normalize(&normalized);
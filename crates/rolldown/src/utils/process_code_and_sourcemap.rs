@@ -4,8 +4,9 @@ use futures::future::try_join_all;
 use oxc::ast::CommentKind;
 use rolldown_common::{NormalizedBundlerOptions, OutputAsset, SourceMapType};
 use rolldown_error::{BuildResult, ResultExt};
-use rolldown_sourcemap::SourceMap;
+use rolldown_sourcemap::{IndexSourceMap, SourceMap, to_json_string_with_scopes};
 use rolldown_std_utils::relative_path_to_slash;
+use rolldown_utils::{base64::to_standard_base64, concat_string};
 use sugar_path::SugarPath;
 use url::Url;
 
@@ -25,7 +26,7 @@ pub async fn process_code_and_sourcemap(
 ) -> BuildResult<Option<OutputAsset>> {
   prepare_sourcemap(options, map, file_dir, filename).await?;
   let map_filename = sourcemap_filename.unwrap_or_else(|| format!("{filename}.map"));
-  emit_sourcemap(options, code, map, &map_filename, debug_id, is_css)
+  emit_sourcemap(options, code, map, None, &map_filename, debug_id, is_css)
 }
 
 pub async fn prepare_sourcemap(
@@ -108,36 +109,90 @@ pub async fn prepare_sourcemap(
   Ok(())
 }
 
+/// Runs [`prepare_sourcemap`] on every section of an index map.
+pub async fn prepare_index_sourcemap(
+  options: &NormalizedBundlerOptions,
+  map: &mut IndexSourceMap,
+  file_dir: &Path,
+  filename: &str,
+) -> BuildResult<()> {
+  try_join_all(
+    map
+      .sections_mut()
+      .iter_mut()
+      .map(|section| prepare_sourcemap(options, &mut section.map, file_dir, filename)),
+  )
+  .await?;
+  let file_base_name = Path::new(filename).file_name().expect("should have file name");
+  map.set_file(file_base_name.to_string_lossy().as_ref());
+  Ok(())
+}
+
 pub fn emit_sourcemap(
   options: &NormalizedBundlerOptions,
   code: &mut String,
   map: &mut SourceMap,
+  scopes: Option<&str>,
   map_filename: &str,
   debug_id: u128,
   is_css: bool,
 ) -> BuildResult<Option<OutputAsset>> {
-  let source_map_link_comment_kind =
-    if is_css { CommentKind::SingleLineBlock } else { CommentKind::Line };
-
-  if options.sourcemap_debug_ids && options.sourcemap.is_some() {
-    let debug_id_str = uuid_v4_string_from_u128(debug_id);
-    map.set_debug_id(&debug_id_str);
-
-    process_sourcemap_related_reference(
-      code,
-      |source| {
-        source.push_str("# debugId=");
-        source.push_str(debug_id_str.as_str());
-        Ok(())
-      },
-      source_map_link_comment_kind,
-    )?;
+  if let Some(debug_id) = inject_debug_id(options, code, debug_id, is_css)? {
+    map.set_debug_id(&debug_id);
+  }
+  emit_sourcemap_json(options, code, map_filename, is_css, || sourcemap_to_json_string(map, scopes))
+}
+
+/// Same as [`emit_sourcemap`] for chunks whose sourcemap is kept as an index map.
+pub fn emit_index_sourcemap(
+  options: &NormalizedBundlerOptions,
+  code: &mut String,
+  map: &mut IndexSourceMap,
+  map_filename: &str,
+  debug_id: u128,
+) -> BuildResult<Option<OutputAsset>> {
+  if let Some(debug_id) = inject_debug_id(options, code, debug_id, /*is_css*/ false)? {
+    map.set_debug_id(&debug_id);
+  }
+  emit_sourcemap_json(options, code, map_filename, /*is_css*/ false, || map.to_json_string())
+}
+
+/// Appends the `debugId` comment to `code`, returning the debug id to set on the sourcemap.
+fn inject_debug_id(
+  options: &NormalizedBundlerOptions,
+  code: &mut String,
+  debug_id: u128,
+  is_css: bool,
+) -> BuildResult<Option<String>> {
+  if !(options.sourcemap_debug_ids && options.sourcemap.is_some()) {
+    return Ok(None);
   }
+  let debug_id_str = uuid_v4_string_from_u128(debug_id);
+  process_sourcemap_related_reference(
+    code,
+    |source| {
+      source.push_str("# debugId=");
+      source.push_str(debug_id_str.as_str());
+      Ok(())
+    },
+    source_map_link_comment_kind(is_css),
+  )?;
+  Ok(Some(debug_id_str))
+}
+
+fn emit_sourcemap_json(
+  options: &NormalizedBundlerOptions,
+  code: &mut String,
+  map_filename: &str,
+  is_css: bool,
+  to_json_string: impl FnOnce() -> String,
+) -> BuildResult<Option<OutputAsset>> {
+  let source_map_link_comment_kind = source_map_link_comment_kind(is_css);
 
   if let Some(sourcemap) = &options.sourcemap {
     match sourcemap {
       SourceMapType::File | SourceMapType::Hidden => {
-        let source = map.to_json_string();
+        let source = to_json_string();
         if matches!(sourcemap, SourceMapType::File) {
           process_sourcemap_related_reference(
             code,
@@ -170,10 +225,12 @@ pub fn emit_sourcemap(
           source: source.into(),
           original_file_names: vec![],
           names: vec![],
+          integrity: None,
         }));
       }
       SourceMapType::Inline => {
-        let data_url = map.to_data_url();
+        let base64 = to_standard_base64(to_json_string());
+        let data_url = concat_string!("data:application/json;charset=utf-8;base64,", base64);
         process_sourcemap_related_reference(
           code,
           |source| {
@@ -190,6 +247,17 @@ pub fn emit_sourcemap(
   Ok(None)
 }
 
+fn source_map_link_comment_kind(is_css: bool) -> CommentKind {
+  if is_css { CommentKind::SingleLineBlock } else { CommentKind::Line }
+}
+
+fn sourcemap_to_json_string(map: &SourceMap, scopes: Option<&str>) -> String {
+  match scopes {
+    Some(scopes) => to_json_string_with_scopes(map, scopes),
+    None => map.to_json_string(),
+  }
+}
+
 fn process_sourcemap_related_reference(
   source: &mut String,
   mut reference_body_processor: impl FnMut(&mut String) -> BuildResult<()>,
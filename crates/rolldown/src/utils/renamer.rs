@@ -36,7 +36,9 @@ impl<'name> Renamer<'name> {
     let mut manual_reserved = match format {
       OutputFormat::Esm => vec![],
       OutputFormat::Cjs => vec!["module", "require", "__filename", "__dirname", "exports"],
-      OutputFormat::Iife | OutputFormat::Umd => vec!["exports"], // Also for AMD, but we don't support it yet.
+      OutputFormat::Iife | OutputFormat::Umd => vec!["exports"],
+      OutputFormat::Amd => vec!["exports", "require"],
+      OutputFormat::System => vec!["exports", "module"],
     };
     // https://github.com/rollup/rollup/blob/bfbea66569491f5466fbba99de2ba6a0225f851b/src/Chunk.ts#L1359
     manual_reserved.extend(["Object", "Promise"]);
@@ -480,7 +482,7 @@ impl NestedScopeRenamer<'_, '_> {
     }
   }
 
-  /// Rename nested bindings that would shadow the ambient names of CommonJS output.
+  /// Rename nested bindings that would shadow the ambient names of the output format.
   ///
   /// Several rewrites emit bare, renamer-invisible identifiers into the module body, at arbitrary
   /// nesting depth:
@@ -514,17 +516,23 @@ impl NestedScopeRenamer<'_, '_> {
   /// The same capture breaks a nested `var __filename`/`var __dirname` the same way
   /// (`pathToFileURL(__filename)` reads the still-undefined local and throws).
   ///
-  /// Only CommonJS output injects these names. The pass does nothing for the other formats.
-  pub fn rename_bindings_shadowing_cjs_ambient_names(&mut self, output_format: OutputFormat) {
-    if !matches!(output_format, OutputFormat::Cjs) {
-      return;
-    }
+  /// AMD and SystemJS output inject names of their own: AMD lowers dynamic imports to
+  /// `require([...])`, and SystemJS pushes live bindings through `exports(...)` and lowers dynamic
+  /// imports and `import.meta` to `module.import(...)` and `module.meta`. The pass does nothing for
+  /// the other formats.
+  pub fn rename_bindings_shadowing_format_ambient_names(&mut self, output_format: OutputFormat) {
+    let ambient_names: &[&str] = match output_format {
+      OutputFormat::Cjs => &["require", "__filename", "__dirname"],
+      OutputFormat::Amd => &["require"],
+      OutputFormat::System => &["exports", "module"],
+      OutputFormat::Esm | OutputFormat::Iife | OutputFormat::Umd => return,
+    };
 
     // Skip root scope (index 0), check nested scopes only. Root-scope bindings are already covered
-    // by the renamer's `manual_reserved` list for CommonJS output.
+    // by the renamer's `manual_reserved` list.
     for (_, bindings) in self.scoping.iter_bindings().skip(1) {
       for (&name, symbol_id) in bindings {
-        if matches!(name.as_str(), "require" | "__filename" | "__dirname") {
+        if ambient_names.contains(&name.as_str()) {
           let symbol_ref = (self.module_idx, *symbol_id).into();
           self.renamer.register_nested_scope_symbols(symbol_ref, name.as_str());
         }
@@ -61,6 +61,7 @@ pub async fn render_chunks(
     let asset = &mut assets[index];
     asset.content = code.into();
     if !sourcemaps.is_empty() {
+      asset.flatten_index_map();
       if let Some(asset_map) = &asset.map {
         let mut sourcemap_chain = Vec::with_capacity(sourcemaps.len() + 1);
         sourcemap_chain.push(asset_map);
@@ -23,7 +23,7 @@ pub async fn transform_source(
 ) -> Result<String> {
   plugin_driver
     .transform(
-      &resolved_id.id,
+      &resolved_id.module_id(),
       module_idx,
       source,
       sourcemap_chain,
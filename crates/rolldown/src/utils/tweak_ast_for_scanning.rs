@@ -1,12 +1,30 @@
+use std::borrow::Cow;
+use std::path::Path;
+
 use itertools::Itertools;
 use oxc::allocator::GetAllocator;
 use oxc::allocator::{Allocator, ReplaceWith, TakeIn};
 use oxc::ast::ast::{self, BindingPattern, Declaration, Statement};
 use oxc::ast::builder::{AstBuilder, GetAstBuilder};
 use oxc::ast_visit::{VisitJsMut, walk_js_mut};
-use oxc::span::{SPAN, Span};
-use rolldown_ecmascript_utils::StatementExt;
-use rustc_hash::FxHashSet;
+use oxc::span::{GetSpan, SPAN, Span};
+use rolldown_common::ModuleType;
+use rolldown_ecmascript_utils::{ExpressionFactoryExt as _, StatementExt};
+use rolldown_fs::FileSystem;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{glob_import::GlobImportPattern, load_source::get_module_loader_from_file_extension};
+
+/// Where glob `import()` / `require()` calls of a module are expanded from.
+#[derive(Clone, Copy)]
+pub struct GlobImportContext<'a> {
+  pub fs: &'a dyn FileSystem,
+  pub importer_dir: &'a Path,
+  /// Only files with a known module type are matched, as any other file would fail to load.
+  pub module_types: &'a FxHashMap<Cow<'static, str>, ModuleType>,
+  /// `false` if the module declares its own `require`, see [`super::glob_import::declares_require`].
+  pub expand_require: bool,
+}
 
 /// Pre-process is a essential step to make rolldown generate correct and efficient code.
 pub struct PreProcessor<'ast, 'a> {
@@ -19,10 +37,18 @@ pub struct PreProcessor<'ast, 'a> {
   /// so dynamic imports nested inside the dropped block never enter the
   /// module graph.
   drop_labels: Option<&'a FxHashSet<String>>,
-  /// Spans of `import defer ...` statements / expressions whose `defer` phase
-  /// was lowered to a regular import. Read after `visit_program` to emit the
-  /// `UNSUPPORTED_FEATURE` warning.
+  /// Spans of `import.defer(...)` expressions whose `defer` phase was lowered
+  /// to a regular dynamic import. Read after `visit_program` to emit the
+  /// `UNSUPPORTED_FEATURE` warning. Static `import defer` declarations are kept
+  /// and handled in the link stage.
   defer_spans: Vec<Span>,
+  /// `None` for modules whose glob imports must not be expanded, like the runtime module.
+  glob_import_ctx: Option<GlobImportContext<'a>>,
+  /// Spans of the `__glob` callees generated for glob imports. The scanner binds identifiers
+  /// with these spans to the runtime helper.
+  glob_callee_spans: FxHashSet<Span>,
+  /// Glob imports that matched no file, with the glob rendered like `import("./src/**/*.js")`.
+  empty_glob_imports: Vec<(Span, String)>,
 }
 
 impl<'ast, 'a> PreProcessor<'ast, 'a> {
@@ -30,6 +56,7 @@ impl<'ast, 'a> PreProcessor<'ast, 'a> {
     alloc: &'ast Allocator,
     keep_names: bool,
     drop_labels: Option<&'a FxHashSet<String>>,
+    glob_import_ctx: Option<GlobImportContext<'a>>,
   ) -> Self {
     Self {
       ast_builder: AstBuilder::new(alloc),
@@ -37,6 +64,9 @@ impl<'ast, 'a> PreProcessor<'ast, 'a> {
       keep_names,
       drop_labels: drop_labels.filter(|set| !set.is_empty()),
       defer_spans: vec![],
+      glob_import_ctx,
+      glob_callee_spans: FxHashSet::default(),
+      empty_glob_imports: vec![],
     }
   }
 
@@ -44,6 +74,123 @@ impl<'ast, 'a> PreProcessor<'ast, 'a> {
     std::mem::take(&mut self.defer_spans)
   }
 
+  pub fn take_glob_callee_spans(&mut self) -> FxHashSet<Span> {
+    std::mem::take(&mut self.glob_callee_spans)
+  }
+
+  pub fn take_empty_glob_imports(&mut self) -> Vec<(Span, String)> {
+    std::mem::take(&mut self.empty_glob_imports)
+  }
+
+  /// Rewrite an `import()` / `require()` whose path is built at runtime, like
+  /// `` import(`./locales/${lang}.js`) ``, into a lookup of every module matching the path:
+  ///
+  /// ```js
+  /// __glob({
+  ///   "./locales/en.js": () => import("./locales/en.js"),
+  ///   "./locales/fr.js": () => import("./locales/fr.js"),
+  /// })(`./locales/${lang}.js`)
+  /// ```
+  ///
+  /// The matched modules then go through the regular `import()` / `require()` handling. Calls
+  /// whose glob matches nothing are left untouched and reported.
+  fn try_rewrite_glob_import(&mut self, it: &mut ast::Expression<'ast>) {
+    let Some(GlobImportContext { fs, importer_dir, module_types, expand_require }) =
+      self.glob_import_ctx
+    else {
+      return;
+    };
+    let span = it.span();
+    let (path_expr, is_import) = match &*it {
+      ast::Expression::ImportExpression(expr) if expr.options.is_none() && expr.phase.is_none() => {
+        (&expr.source, true)
+      }
+      ast::Expression::CallExpression(expr)
+        if expand_require && expr.callee.is_specific_id("require") && expr.arguments.len() == 1 =>
+      {
+        let Some(arg) = expr.arguments[0].as_expression() else { return };
+        (arg, false)
+      }
+      _ => return,
+    };
+    let Some(pattern) = GlobImportPattern::from_expr(path_expr) else { return };
+    let mut matched = pattern.expand(fs, importer_dir);
+    matched.retain(|path| get_module_loader_from_file_extension(path, module_types).is_some());
+    if matched.is_empty() {
+      let callee = if is_import { "import" } else { "require" };
+      self.empty_glob_imports.push((span, format!("{callee}(\"{pattern}\")")));
+      return;
+    }
+
+    let map = ast::Expression::new_object_expression(
+      SPAN,
+      oxc::allocator::Vec::from_iter_in(
+        matched.iter().map(|path| {
+          let request =
+            ast::Argument::new_string_literal(SPAN, ast::Str::from_str_in(path, self), None, self);
+          let load = if is_import {
+            ast::Expression::new_import_expression(
+              SPAN,
+              request.into_expression(),
+              None,
+              None,
+              self,
+            )
+          } else {
+            ast::Expression::new_call_expression(
+              SPAN,
+              ast::Expression::new_identifier(SPAN, "require", self),
+              None,
+              [request],
+              false,
+              self,
+            )
+          };
+          ast::ObjectPropertyKind::new_object_property(
+            SPAN,
+            ast::PropertyKind::Init,
+            ast::PropertyKey::new_string_literal(
+              SPAN,
+              ast::Str::from_str_in(path, self),
+              None,
+              self,
+            ),
+            ast::Expression::new_arrow_returning(load, self),
+            false,
+            false,
+            false,
+            self,
+          )
+        }),
+        self,
+      ),
+      self,
+    );
+    let path = match it {
+      ast::Expression::ImportExpression(expr) => expr.source.take_in(self),
+      ast::Expression::CallExpression(expr) => expr.arguments[0].to_expression_mut().take_in(self),
+      _ => unreachable!(),
+    };
+    self.glob_callee_spans.insert(span);
+    *it = ast::Expression::new_call_expression(
+      span,
+      ast::Expression::new_call_expression(
+        SPAN,
+        // A placeholder name: it is reserved as a global by the renamer, so it must not be the
+        // runtime helper's own name. The finalizer replaces it with the real `__glob`.
+        ast::Expression::new_identifier(span, "__rolldown_glob", self),
+        None,
+        [ast::Argument::from(map)],
+        false,
+        self,
+      ),
+      None,
+      [ast::Argument::from(path)],
+      false,
+      self,
+    );
+  }
+
   /// Replace `it` with an empty statement when it is a `LabeledStatement`
   /// whose label name appears in `drop_labels`. Returns true if a replacement
   /// was performed, so callers can skip walking into the dropped subtree.
@@ -148,14 +295,6 @@ impl<'ast, 'a> PreProcessor<'ast, 'a> {
 }
 
 impl<'ast> VisitJsMut<'ast> for PreProcessor<'ast, '_> {
-  fn visit_import_declaration(&mut self, it: &mut ast::ImportDeclaration<'ast>) {
-    if matches!(it.phase, Some(ast::ImportPhase::Defer)) {
-      self.defer_spans.push(it.span);
-      it.phase = None;
-    }
-    walk_js_mut::walk_import_declaration(self, it);
-  }
-
   fn visit_program(&mut self, program: &mut ast::Program<'ast>) {
     let original_body = program.body.take_in(self);
     program.body.reserve_exact(original_body.len());
@@ -286,6 +425,8 @@ impl<'ast> VisitJsMut<'ast> for PreProcessor<'ast, '_> {
           self,
         )
       });
+    } else {
+      self.try_rewrite_glob_import(it);
     }
     walk_js_mut::walk_expression(self, it);
   }
@@ -0,0 +1,120 @@
+// Minimal `System.register` and AMD loaders, enough to execute rolldown's `system` and `amd`
+// output in tests without pulling SystemJS or RequireJS in.
+import fs from 'node:fs';
+import path from 'node:path';
+import vm from 'node:vm';
+
+function runScript(file, globals) {
+  vm.runInNewContext(fs.readFileSync(file, 'utf-8'), { console, setTimeout, URL, ...globals }, {
+    filename: file,
+  });
+}
+
+/**
+ * Imports `entry` from `dir` as a SystemJS module and resolves to its namespace object.
+ * `externals` maps bare specifiers to the namespace objects they resolve to.
+ */
+export async function importSystem(dir, entry, externals = {}) {
+  const records = new Map();
+
+  function resolve(specifier, parentFile) {
+    if (specifier in externals) return specifier;
+    return path.resolve(path.dirname(parentFile), specifier);
+  }
+
+  function getRecord(id) {
+    if (records.has(id)) return records.get(id);
+    const record = { id, namespace: Object.create(null), importers: [], evaluated: false };
+    records.set(id, record);
+    if (id in externals) {
+      Object.assign(record.namespace, externals[id]);
+      record.evaluated = true;
+      return record;
+    }
+    let registration;
+    runScript(id, { System: { register: (deps, declare) => (registration = { deps, declare }) } });
+    const exportFn = (name, value) => {
+      if (typeof name === 'object') Object.assign(record.namespace, name);
+      else record.namespace[name] = value;
+      for (const notify of record.importers) notify();
+      return value;
+    };
+    const moduleContext = {
+      import: (specifier) => load(resolve(specifier, id)),
+      meta: { url: `file://${id}` },
+    };
+    const { setters, execute } = registration.declare(exportFn, moduleContext);
+    record.execute = execute;
+    record.deps = registration.deps.map((dep, i) => {
+      const depRecord = getRecord(resolve(dep, id));
+      const setter = setters[i];
+      if (setter) {
+        const notify = () => setter(depRecord.namespace);
+        depRecord.importers.push(notify);
+        notify();
+      }
+      return depRecord;
+    });
+    return record;
+  }
+
+  async function evaluate(record) {
+    if (record.evaluated) return;
+    record.evaluated = true;
+    for (const dep of record.deps) await evaluate(dep);
+    await record.execute();
+  }
+
+  async function load(id) {
+    const record = getRecord(id);
+    await evaluate(record);
+    return record.namespace;
+  }
+
+  return load(path.resolve(dir, entry));
+}
+
+/**
+ * Requires `entry` from `dir` as an AMD module and returns its exports.
+ * `externals` maps module ids to the values they resolve to.
+ */
+export function requireAmd(dir, entry, externals = {}) {
+  const modules = new Map();
+
+  function resolve(id, parentFile) {
+    if (id in externals) return id;
+    return path.resolve(path.dirname(parentFile), `${id}.js`);
+  }
+
+  function load(file) {
+    if (modules.has(file)) return modules.get(file);
+    if (file in externals) return externals[file];
+    const module = { exports: {} };
+    modules.set(file, module.exports);
+    let definition;
+    const define = (...args) => (definition = args.length === 3 ? args.slice(1) : args);
+    define.amd = {};
+    runScript(file, { define });
+    const [deps, factory] = definition;
+    const localRequire = (ids, onLoad, onError) => {
+      if (typeof ids === 'string') return load(resolve(ids, file));
+      setTimeout(() => {
+        try {
+          onLoad(...ids.map((id) => load(resolve(id, file))));
+        } catch (error) {
+          onError(error);
+        }
+      });
+    };
+    const args = deps.map((dep) => {
+      if (dep === 'require') return localRequire;
+      if (dep === 'exports') return module.exports;
+      return load(resolve(dep, file));
+    });
+    const returned = factory(...args);
+    if (returned !== undefined) modules.set(file, returned);
+    return modules.get(file);
+  }
+
+  return load(path.resolve(dir, entry));
+}
@@ -0,0 +1,3 @@
+import value from './value.ts' with { type: 'json' };
+
+console.log(value);
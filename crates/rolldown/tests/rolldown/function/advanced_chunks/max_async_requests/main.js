@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+
+const [x, y, z] = await Promise.all([import('./x.js'), import('./y.js'), import('./z.js')])
+assert.strictEqual(x.default, 'xy-xyz')
+assert.strictEqual(y.default, 'xy-yz-xyz')
+assert.strictEqual(z.default, 'yz-xyz')
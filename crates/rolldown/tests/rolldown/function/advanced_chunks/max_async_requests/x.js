@@ -0,0 +1,4 @@
+import { xy } from './xy.js'
+import { xyz } from './xyz.js'
+
+export default [xy(), xyz()].join('-')
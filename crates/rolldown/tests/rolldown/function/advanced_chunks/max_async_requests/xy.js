@@ -0,0 +1,3 @@
+export function xy() {
+  return 'xy'
+}
@@ -0,0 +1,3 @@
+export function xyz() {
+  return 'xyz'
+}
@@ -0,0 +1,5 @@
+import { xy } from './xy.js'
+import { yz } from './yz.js'
+import { xyz } from './xyz.js'
+
+export default [xy(), yz(), xyz()].join('-')
@@ -0,0 +1,3 @@
+export function yz() {
+  return 'yz'
+}
@@ -0,0 +1,4 @@
+import { yz } from './yz.js'
+import { xyz } from './xyz.js'
+
+export default [yz(), xyz()].join('-')
@@ -0,0 +1,4 @@
+import { ab } from './ab.js'
+import { abc } from './abc.js'
+
+console.log('a', ab(), abc())
@@ -0,0 +1,3 @@
+export function ab() {
+  return 'ab'
+}
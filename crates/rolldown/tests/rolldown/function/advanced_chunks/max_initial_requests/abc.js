@@ -0,0 +1,3 @@
+export function abc() {
+  return 'abc'
+}
@@ -0,0 +1,5 @@
+import { ab } from './ab.js'
+import { bc } from './bc.js'
+import { abc } from './abc.js'
+
+console.log('b', ab(), bc(), abc())
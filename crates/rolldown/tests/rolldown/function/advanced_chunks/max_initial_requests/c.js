@@ -0,0 +1,4 @@
+import { bc } from './bc.js'
+import { abc } from './abc.js'
+
+console.log('c', bc(), abc())
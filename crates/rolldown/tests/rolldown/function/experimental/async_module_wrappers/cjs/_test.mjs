@@ -0,0 +1,9 @@
+import assert from 'node:assert';
+import { createRequire } from 'node:module';
+
+const require = createRequire(import.meta.url);
+const exports = require('./dist/main.js');
+
+await exports.__tla;
+assert.deepStrictEqual(globalThis.logs, ['config', 'loaded app']);
+assert.strictEqual(exports.name, 'app');
@@ -0,0 +1,5 @@
+import { log } from './log.js';
+
+log('config');
+
+export const config = await Promise.resolve({ name: 'app' });
@@ -0,0 +1,5 @@
+export const logs = (globalThis.logs ??= []);
+
+export function log(message) {
+  logs.push(message);
+}
@@ -0,0 +1,6 @@
+import { config } from './config.js';
+import { log } from './log.js';
+
+log(`loaded ${config.name}`);
+
+export const name = config.name;
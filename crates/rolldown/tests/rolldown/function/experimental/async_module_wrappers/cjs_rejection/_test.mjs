@@ -0,0 +1,8 @@
+import assert from 'node:assert';
+import { createRequire } from 'node:module';
+
+const require = createRequire(import.meta.url);
+const exports = require('./dist/main.js');
+
+await assert.rejects(exports.__tla, /boom/);
+assert.strictEqual(exports.name, undefined);
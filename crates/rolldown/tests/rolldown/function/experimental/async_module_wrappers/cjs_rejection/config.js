@@ -0,0 +1 @@
+export const config = await Promise.reject(new Error('boom'));
@@ -0,0 +1,7 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import path from 'node:path';
+
+const code = fs.readFileSync(path.resolve(import.meta.dirname, './dist/main.js'), 'utf-8');
+(0, eval)(code);
+assert.strictEqual(await globalThis.app, 42);
@@ -0,0 +1,9 @@
+import { helper } from './util.js'
+
+export function used() {
+  return helper() + 1
+}
+
+export function unused() {
+  return 0
+}
@@ -0,0 +1,4 @@
+import { used } from './lib.js'
+import './polyfill.js'
+
+console.log(used())
@@ -0,0 +1,3 @@
+globalThis.polyfilled = true
+
+const noop = () => {}
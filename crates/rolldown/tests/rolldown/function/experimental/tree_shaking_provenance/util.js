@@ -0,0 +1,3 @@
+export const helper = () => 1
+
+export const dropped = () => 2
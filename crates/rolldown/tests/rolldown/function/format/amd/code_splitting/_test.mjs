@@ -0,0 +1,12 @@
+import assert from 'node:assert';
+import { requireAmd } from '../../../../_test_helpers/module-loaders.mjs';
+
+const dist = `${import.meta.dirname}/dist`;
+const main = requireAmd(dist, 'main.js');
+assert.strictEqual(main.read(), 'initial');
+main.update('updated');
+assert.strictEqual(main.read(), 'updated');
+assert.strictEqual((await main.loadLazy()).lazy, 'lazy');
+
+const other = requireAmd(dist, 'other.js');
+assert.strictEqual(other.otherValue, 'initial');
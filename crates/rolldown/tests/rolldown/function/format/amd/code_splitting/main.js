@@ -0,0 +1,9 @@
+import { update, value } from './shared.js';
+
+export function read() {
+  return value;
+}
+
+export { update };
+
+export const loadLazy = () => import('./lazy.js');
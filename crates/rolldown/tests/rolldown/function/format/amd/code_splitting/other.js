@@ -0,0 +1,3 @@
+import { value } from './shared.js';
+
+export const otherValue = value;
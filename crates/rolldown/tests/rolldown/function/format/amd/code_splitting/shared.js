@@ -0,0 +1,5 @@
+export let value = 'initial';
+
+export function update(next) {
+  value = next;
+}
@@ -0,0 +1,5 @@
+import assert from 'node:assert';
+import { requireAmd } from '../../../../_test_helpers/module-loaders.mjs';
+
+const greet = requireAmd(`${import.meta.dirname}/dist`, 'main.js');
+assert.strictEqual(greet('amd'), 'hello amd');
@@ -0,0 +1,10 @@
+import assert from 'node:assert';
+import { requireAmd } from '../../../../_test_helpers/module-loaders.mjs';
+
+const ns = requireAmd(`${import.meta.dirname}/dist`, 'main.js', {
+  ext: { __esModule: true, default: 'default', named: 'named' },
+  'ext-star': { star: 'star', fromExternal: 'shadowed' },
+  'ext-side-effect': {},
+});
+assert.deepStrictEqual([...ns.fromExternal], ['default', 'named']);
+assert.strictEqual(ns.star, 'star');
@@ -0,0 +1,5 @@
+import 'ext-side-effect';
+import ext, { named } from 'ext';
+
+export * from 'ext-star';
+export const fromExternal = [ext, named];
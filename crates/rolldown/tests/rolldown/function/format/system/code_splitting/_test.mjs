@@ -0,0 +1,14 @@
+import assert from 'node:assert';
+import { importSystem } from '../../../../_test_helpers/module-loaders.mjs';
+
+const dist = `${import.meta.dirname}/dist`;
+const main = await importSystem(dist, 'main.js');
+assert.strictEqual(main.read(), 'initial');
+assert.strictEqual(main.sharedValue, 'initial');
+main.update('updated');
+assert.strictEqual(main.read(), 'updated');
+assert.strictEqual(main.sharedValue, 'updated');
+assert.strictEqual((await main.loadLazy()).lazy, 'lazy');
+
+const other = await importSystem(dist, 'other.js');
+assert.strictEqual(other.otherValue, 'initial');
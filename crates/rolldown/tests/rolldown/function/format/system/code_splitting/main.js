@@ -0,0 +1,9 @@
+import { update, value } from './shared.js';
+
+export function read() {
+  return value;
+}
+
+export { update, value as sharedValue };
+
+export const loadLazy = () => import('./lazy.js');
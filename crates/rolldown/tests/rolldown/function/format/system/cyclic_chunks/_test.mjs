@@ -0,0 +1,12 @@
+import assert from 'node:assert';
+import { importSystem } from '../../../../_test_helpers/module-loaders.mjs';
+
+const main = await importSystem(`${import.meta.dirname}/dist`, 'main.js');
+// `other.js` runs first and calls back into `main.js` before its body has run.
+assert.strictEqual(main.result, 'main');
+main.bump();
+assert.strictEqual(main.counter, 1);
+assert.strictEqual(main.aliased, 2);
+main.swap();
+assert.strictEqual(main.first, 'b');
+assert.strictEqual(main.second, 'a');
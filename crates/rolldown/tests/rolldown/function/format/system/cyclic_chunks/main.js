@@ -0,0 +1,9 @@
+import { callMain } from './other.js';
+
+export function mainValue() {
+  return 'main';
+}
+
+export const result = callMain();
+
+export { aliased, bump, counter, first, second, swap } from './other.js';
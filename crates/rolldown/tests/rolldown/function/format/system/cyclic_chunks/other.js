@@ -0,0 +1,20 @@
+import { mainValue } from './main.js';
+
+export function callMain() {
+  return mainValue();
+}
+
+export let counter = 0;
+let hidden = 1;
+export { hidden as aliased };
+export let first = 'a';
+export let second = 'b';
+
+export function bump() {
+  counter += 1;
+  hidden++;
+}
+
+export function swap() {
+  [first, second] = [second, first];
+}
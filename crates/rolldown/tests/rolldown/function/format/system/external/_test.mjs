@@ -0,0 +1,10 @@
+import assert from 'node:assert';
+import { importSystem } from '../../../../_test_helpers/module-loaders.mjs';
+
+const ns = await importSystem(`${import.meta.dirname}/dist`, 'main.js', {
+  ext: { default: 'default', named: 'named' },
+  'ext-star': { default: 'ignored', star: 'star', fromExternal: 'shadowed' },
+});
+assert.deepStrictEqual([...ns.fromExternal], ['default', 'named']);
+assert.strictEqual(ns.star, 'star');
+assert.strictEqual(ns.default, undefined);
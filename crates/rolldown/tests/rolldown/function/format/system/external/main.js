@@ -0,0 +1,4 @@
+import ext, { named } from 'ext';
+
+export * from 'ext-star';
+export const fromExternal = [ext, named];
@@ -0,0 +1,12 @@
+import assert from 'node:assert';
+import { importSystem } from '../../../../_test_helpers/module-loaders.mjs';
+
+const ns = await importSystem(`${import.meta.dirname}/dist`, 'main.js');
+assert.strictEqual(ns.label, 'counter');
+assert.strictEqual(ns.count, 0);
+ns.increment();
+ns.increment();
+assert.strictEqual(ns.count, 2);
+assert.strictEqual(ns.total, 4);
+ns.default();
+assert.strictEqual(ns.count, 0);
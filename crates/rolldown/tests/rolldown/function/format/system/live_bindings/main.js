@@ -0,0 +1,12 @@
+export let count = 0;
+export var total = 0;
+export const label = 'counter';
+
+export function increment() {
+  count++;
+  total += 2;
+}
+
+export default function reset() {
+  count = 0;
+}
@@ -0,0 +1,5 @@
+import assert from 'node:assert';
+import { importSystem } from '../../../../_test_helpers/module-loaders.mjs';
+
+const ns = await importSystem(`${import.meta.dirname}/dist`, 'main.js');
+assert.strictEqual(ns.value, true);
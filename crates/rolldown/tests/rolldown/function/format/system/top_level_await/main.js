@@ -0,0 +1 @@
+export const value = await Promise.resolve(import.meta.url.endsWith('main.js'));
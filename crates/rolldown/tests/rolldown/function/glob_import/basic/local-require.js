@@ -0,0 +1,2 @@
+// A local `require` is not the global one, so its calls are left alone.
+export const viaLocalRequire = (require, lang) => require('./locales/' + lang + '.js');
@@ -0,0 +1,12 @@
+import assert from 'node:assert';
+import { viaLocalRequire } from './local-require.js';
+
+const load = (lang) => import(`./locales/${lang}.js`);
+const loadSync = (lang) => require('./locales/' + lang + '.js');
+
+assert.strictEqual((await load('en')).default, 'hello');
+assert.strictEqual((await load('nested/fr')).default, 'bonjour');
+assert.strictEqual(loadSync('en').default, 'hello');
+assert.throws(() => load('de'), /Module not found in bundle: \.\/locales\/de\.js/);
+
+assert.strictEqual(viaLocalRequire((id) => id, 'en'), './locales/en.js');
@@ -0,0 +1,3 @@
+import { a } from './a.js';
+
+console.log('other', a());
@@ -0,0 +1,3 @@
+export function loadLazy() {
+  return import('./lazy.js')
+}
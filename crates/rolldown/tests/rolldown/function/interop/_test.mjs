@@ -0,0 +1,27 @@
+import assert from 'node:assert';
+import fs from 'node:fs';
+import { createRequire } from 'node:module';
+import path from 'node:path';
+
+// Every external is an ES module transpiled to CommonJS, found next to the output.
+const nodeModules = path.join(import.meta.dirname, 'dist/node_modules');
+fs.mkdirSync(nodeModules, { recursive: true });
+for (const name of ['auto', 'es-module', 'default', 'default-only', 'compat', 'unset']) {
+  fs.writeFileSync(
+    path.join(nodeModules, `${name}.js`),
+    `exports.__esModule = true; exports.default = 'default'; exports.named = 'named';`,
+  );
+}
+
+const { auto, esModule, defaultMode, defaultOnly, compat, unset } = createRequire(
+  import.meta.url,
+)('./dist/main.js');
+
+assert.deepStrictEqual(auto, ['default', 'named']);
+assert.deepStrictEqual(esModule, ['default', 'named', 'named']);
+assert.strictEqual(defaultMode[0].default, 'default');
+assert.strictEqual(defaultMode[1], 'named');
+assert.strictEqual(defaultOnly[0].default, 'default');
+assert.strictEqual(defaultOnly[1], undefined);
+assert.deepStrictEqual(compat, ['default', 'named']);
+assert.strictEqual(unset, 'default');
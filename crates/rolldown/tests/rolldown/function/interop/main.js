@@ -0,0 +1,14 @@
+import autoDefault, * as autoNs from 'auto';
+import esModuleDefault, * as esModuleNs from 'es-module';
+import { named as esModuleNamed } from 'es-module';
+import defaultDefault, * as defaultNs from 'default';
+import defaultOnlyDefault, * as defaultOnlyNs from 'default-only';
+import compatDefault, * as compatNs from 'compat';
+import unsetDefault from 'unset';
+
+export const auto = [autoDefault, autoNs.named];
+export const esModule = [esModuleDefault, esModuleNs.named, esModuleNamed];
+export const defaultMode = [defaultDefault, defaultNs.named];
+export const defaultOnly = [defaultOnlyDefault, defaultOnlyNs.named];
+export const compat = [compatDefault, compatNs.named];
+export const unset = unsetDefault;
@@ -0,0 +1,9 @@
+/**
+ * @license Shared v1.0.0
+ * MIT License
+ */
+
+/*! dep v2.0.0 | MIT */
+export function dep() {
+  return 'dep';
+}
@@ -0,0 +1,10 @@
+/**
+ * @license Shared v1.0.0
+ * MIT License
+ */
+import { dep } from './dep.js';
+
+//! Credit to Astro | MIT License
+export function hello() {
+  return dep();
+}
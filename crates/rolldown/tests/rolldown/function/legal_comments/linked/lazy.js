@@ -0,0 +1,2 @@
+/*! lazy v3.0.0 | MIT */
+export const lazy = 'lazy';
@@ -0,0 +1,3 @@
+import { add } from './math.js';
+
+export const lazy = add(3, 4);
@@ -0,0 +1,11 @@
+import { join } from 'node:path';
+import { add } from './math.js';
+import legacy from './legacy.cjs';
+
+export const value = add(1, 2);
+export const file = join('a', 'b');
+export { legacy };
+
+export function loadLazy() {
+  return import('./lazy.js');
+}
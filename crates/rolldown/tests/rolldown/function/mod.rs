@@ -1,4 +1,7 @@
 pub mod asset_dedup_filename;
 pub mod chunk_filenames_function;
 pub mod external_interop_invariants;
+pub mod persistent_cache;
+pub mod records;
 pub mod strict_execution_order_invariants;
+pub mod worker_watch_files;
@@ -0,0 +1,15 @@
+import assert from 'node:assert';
+import json from './data.json';
+import jsonWithType from './data.json' with { type: 'json' };
+import text from './data.json' with { type: 'text' };
+import bytes from './data.json' with { type: 'bytes' };
+import foo from './foo.js';
+import fooText from './foo.js' with { type: 'text' };
+
+assert.strictEqual(json, jsonWithType);
+assert.deepStrictEqual(json, { name: 'data' });
+assert.strictEqual(text, '{ "name": "data" }\n');
+assert.ok(bytes instanceof Uint8Array);
+assert.strictEqual(new TextDecoder().decode(bytes), text);
+assert.strictEqual(foo, 'js');
+assert.strictEqual(fooText, "export default 'js';\n");
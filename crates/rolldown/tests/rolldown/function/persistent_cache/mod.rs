@@ -0,0 +1,247 @@
+use std::{
+  borrow::Cow,
+  path::{Path, PathBuf},
+  sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+  },
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{EmittedAsset, ExperimentalOptions, Output, RUNTIME_MODULE_KEY};
+use rolldown_plugin::{
+  __inner::SharedPluginable, HookTransformArgs, HookTransformOutput, HookTransformReturn,
+  HookUsage, Plugin, SharedTransformPluginContext,
+};
+
+/// Replaces `__VALUE__`, emits an asset for every `__ASSET__` and counts how many user modules it
+/// was called for.
+#[derive(Debug, Default)]
+struct CountingTransformPlugin {
+  cache_key: Option<&'static str>,
+  calls: AtomicUsize,
+}
+
+impl Plugin for CountingTransformPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "counting-transform".into()
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Transform
+  }
+
+  fn cache_key(&self) -> Option<Cow<'_, str>> {
+    self.cache_key.map(Cow::Borrowed)
+  }
+
+  async fn transform(
+    &self,
+    ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if args.id == RUNTIME_MODULE_KEY {
+      return Ok(None);
+    }
+    self.calls.fetch_add(1, Ordering::SeqCst);
+    let mut code = args.code.replace("__VALUE__", "42");
+    if code.contains("__ASSET__") {
+      let file_name = Path::new(args.id).file_stem().unwrap().to_string_lossy();
+      let reference_id = ctx.emit_file(
+        EmittedAsset {
+          name: Some(format!("{file_name}.txt")),
+          source: file_name.to_string().into(),
+          ..Default::default()
+        },
+        None,
+        None,
+      )?;
+      code = code.replace("__ASSET__", &format!("import.meta.ROLLDOWN_FILE_URL_{reference_id}"));
+      ctx.add_watch_file(&format!("{}.txt", args.id));
+    }
+    Ok(Some(HookTransformOutput { code: Some(code), ..Default::default() }))
+  }
+}
+
+/// Creates a fresh project with an entry importing `dep.js`.
+fn create_project(name: &str) -> PathBuf {
+  let project_dir =
+    std::env::temp_dir().join(format!("rolldown-persistent-cache-{}-{name}", std::process::id()));
+  let _ = std::fs::remove_dir_all(&project_dir);
+  std::fs::create_dir_all(&project_dir).unwrap();
+  std::fs::write(
+    project_dir.join("main.js"),
+    "import { value } from './dep.js';\nconsole.log(value);\n",
+  )
+  .unwrap();
+  std::fs::write(project_dir.join("dep.js"), "export const value = __VALUE__;\n").unwrap();
+  project_dir
+}
+
+struct BundleResult {
+  code: String,
+  assets: Vec<String>,
+  watch_files: Vec<String>,
+  /// The size the metafile reports for `dep.js`.
+  dep_bytes: usize,
+  calls: usize,
+}
+
+/// Bundles the project with a new `Bundler`, as a new process would, and returns the output along
+/// with the number of modules the transform hook ran for.
+async fn bundle(project_dir: &Path, cache_key: Option<&'static str>) -> BundleResult {
+  let plugin = Arc::new(CountingTransformPlugin { cache_key, ..Default::default() });
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(project_dir.to_path_buf()),
+      experimental: Some(ExperimentalOptions {
+        persistent_cache_dir: Some(".cache".to_string()),
+        ..Default::default()
+      }),
+      metafile: Some(true),
+      ..Default::default()
+    },
+    vec![Arc::clone(&plugin) as SharedPluginable],
+  )
+  .expect("failed to create bundler");
+
+  let output = bundler.generate().await.expect("build should succeed");
+  let code = output
+    .assets
+    .iter()
+    .find_map(|output| match output {
+      Output::Chunk(chunk) => Some(chunk.code.clone()),
+      Output::Asset(_) => None,
+    })
+    .expect("should emit a chunk");
+  let mut assets = output
+    .assets
+    .iter()
+    .filter_map(|output| match output {
+      Output::Asset(asset) => Some(asset.filename.to_string()),
+      Output::Chunk(_) => None,
+    })
+    .collect::<Vec<_>>();
+  assets.sort_unstable();
+  let mut watch_files = bundler
+    .watch_files()
+    .iter()
+    .filter(|file| file.ends_with(".txt"))
+    .map(|file| Path::new(file.as_str()).file_name().unwrap().to_string_lossy().into_owned())
+    .collect::<Vec<_>>();
+  watch_files.sort_unstable();
+  let dep_bytes =
+    output.metafile.as_ref().expect("metafile should be enabled").inputs["dep.js"].bytes;
+  BundleResult { code, assets, watch_files, dep_bytes, calls: plugin.calls.load(Ordering::SeqCst) }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rehydrates_transformed_sources_in_a_new_bundler() {
+  let project_dir = create_project("rehydrate");
+
+  let cold = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(cold.calls, 2);
+  assert!(cold.code.contains("42"));
+
+  let warm = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(warm.calls, 0, "every module should be served from the persistent cache");
+  assert_eq!(warm.code, cold.code);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn metafile_reports_the_loaded_source_size() {
+  let project_dir = create_project("metafile");
+  let loaded_len = std::fs::read(project_dir.join("dep.js")).unwrap().len();
+
+  let cold = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(cold.dep_bytes, loaded_len, "the size before `__VALUE__` is replaced");
+
+  let warm = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(warm.calls, 0);
+  assert_eq!(warm.dep_bytes, loaded_len);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn changed_files_miss_the_persistent_cache() {
+  let project_dir = create_project("invalidate");
+
+  let cold = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(cold.calls, 2);
+
+  std::fs::write(project_dir.join("dep.js"), "export const value = __VALUE__ + 1;\n").unwrap();
+  let warm = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(warm.calls, 1, "only the changed module should be transformed again");
+  assert!(warm.code.contains("43"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn changed_plugin_cache_keys_miss_the_persistent_cache() {
+  let project_dir = create_project("cache-key");
+
+  let cold = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(cold.calls, 2);
+
+  let warm = bundle(&project_dir, Some("v2")).await;
+  assert_eq!(warm.calls, 2, "entries of other plugin options should not be reused");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn plugins_without_cache_keys_bypass_the_persistent_cache() {
+  let project_dir = create_project("no-cache-key");
+
+  let cold = bundle(&project_dir, None).await;
+  assert_eq!(cold.calls, 2);
+
+  let warm = bundle(&project_dir, None).await;
+  assert_eq!(warm.calls, 2);
+  assert!(!project_dir.join(".cache").exists());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn replays_emitted_files_and_watch_files_on_a_cache_hit() {
+  let project_dir = create_project("replay");
+  std::fs::write(
+    project_dir.join("main.js"),
+    "import { value } from './dep.js';\nconsole.log(value, __ASSET__);\n",
+  )
+  .unwrap();
+  std::fs::write(project_dir.join("dep.js"), "export const value = __ASSET__;\n").unwrap();
+
+  let cold = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(cold.calls, 2);
+  assert_eq!(cold.assets.len(), 2);
+  assert_eq!(cold.watch_files, ["dep.js.txt", "main.js.txt"]);
+
+  let warm = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(warm.calls, 0, "every module should be served from the persistent cache");
+  assert_eq!(warm.assets, cold.assets);
+  assert_eq!(warm.watch_files, cold.watch_files);
+  assert_eq!(warm.code, cold.code);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn changed_watch_files_miss_the_persistent_cache() {
+  let project_dir = create_project("watch-files");
+  std::fs::write(
+    project_dir.join("main.js"),
+    "import { value } from './dep.js';\nconsole.log(value, __ASSET__);\n",
+  )
+  .unwrap();
+  std::fs::write(project_dir.join("dep.js"), "export const value = __ASSET__;\n").unwrap();
+  std::fs::write(project_dir.join("main.js.txt"), "main").unwrap();
+
+  let cold = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(cold.calls, 2);
+
+  std::fs::write(project_dir.join("main.js.txt"), "changed").unwrap();
+  std::fs::write(project_dir.join("dep.js.txt"), "created").unwrap();
+  let warm = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(warm.calls, 2, "modules whose watch files changed should be transformed again");
+
+  let hot = bundle(&project_dir, Some("v1")).await;
+  assert_eq!(hot.calls, 0);
+}
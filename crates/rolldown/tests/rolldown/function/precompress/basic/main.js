@@ -0,0 +1,10 @@
+export const messages = [
+  'The quick brown fox jumps over the lazy dog.',
+  'The quick brown fox jumps over the lazy cat.',
+  'The quick brown fox jumps over the lazy bird.',
+  'The quick brown fox jumps over the lazy fish.',
+  'The quick brown fox jumps over the lazy horse.',
+  'The quick brown fox jumps over the lazy sheep.',
+];
+
+export const small = import('./small.js');
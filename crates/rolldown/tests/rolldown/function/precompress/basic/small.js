@@ -0,0 +1 @@
+export default 'below the threshold';
@@ -0,0 +1,5 @@
+export const messages = [
+  'The quick brown fox jumps over the lazy dog.',
+  'The quick brown fox jumps over the lazy cat.',
+  'The quick brown fox jumps over the lazy bird.',
+];
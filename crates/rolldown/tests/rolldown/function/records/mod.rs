@@ -0,0 +1,115 @@
+use std::path::{Path, PathBuf};
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::{ExperimentalOptions, Output};
+use rolldown_error::EventKind;
+
+const ENTRY_SOURCE: &str =
+  "import { shared } from './shared.js';\nimport { util } from './util.js';\n";
+
+/// Creates a fresh project whose two entries share `shared.js` and `util.js`.
+fn create_project(name: &str) -> PathBuf {
+  let project_dir =
+    std::env::temp_dir().join(format!("rolldown-records-{}-{name}", std::process::id()));
+  let _ = std::fs::remove_dir_all(&project_dir);
+  std::fs::create_dir_all(&project_dir).unwrap();
+  for entry in ["a", "b"] {
+    std::fs::write(
+      project_dir.join(format!("{entry}.js")),
+      format!("{ENTRY_SOURCE}export const {entry} = [shared(), util()];\n"),
+    )
+    .unwrap();
+  }
+  std::fs::write(project_dir.join("shared.js"), "export const shared = () => 'shared';\n").unwrap();
+  std::fs::write(project_dir.join("util.js"), "export const util = () => 'util';\n").unwrap();
+  project_dir
+}
+
+/// Bundles the project with a new `Bundler`, as a new process would, and returns the filename and
+/// code of every common chunk.
+async fn bundle(project_dir: &Path) -> Vec<(String, String)> {
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(
+      ["a", "b"]
+        .into_iter()
+        .map(|entry| InputItem { name: Some(entry.to_string()), import: format!("./{entry}.js") })
+        .collect(),
+    ),
+    cwd: Some(project_dir.to_path_buf()),
+    chunk_filenames: Some("[name]-[hash].js".to_string().into()),
+    experimental: Some(ExperimentalOptions {
+      records_path: Some("records.json".to_string()),
+      ..Default::default()
+    }),
+    ..Default::default()
+  })
+  .expect("failed to create bundler");
+
+  let output = bundler.generate().await.expect("build should succeed");
+  let mut chunks = output
+    .assets
+    .iter()
+    .filter_map(|output| match output {
+      Output::Chunk(chunk) if !chunk.is_entry => {
+        Some((chunk.filename.to_string(), chunk.code.clone()))
+      }
+      _ => None,
+    })
+    .collect::<Vec<_>>();
+  chunks.sort_unstable();
+  chunks
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn recorded_chunks_keep_their_filenames_and_modules_across_builds() {
+  let project_dir = create_project("stable");
+
+  let [(recorded_filename, recorded_code)] = bundle(&project_dir).await.try_into().unwrap();
+  assert!(project_dir.join("records.json").exists(), "the records file should be written");
+
+  // Without records, a module shared by the same entries would join the recorded chunk.
+  std::fs::write(project_dir.join("added.js"), "export const added = () => 'added';\n").unwrap();
+  for entry in ["a", "b"] {
+    std::fs::write(
+      project_dir.join(format!("{entry}.js")),
+      format!(
+        "{ENTRY_SOURCE}import {{ added }} from './added.js';\nexport const {entry} = [shared(), util(), added()];\n"
+      ),
+    )
+    .unwrap();
+  }
+  let chunks = bundle(&project_dir).await;
+  assert_eq!(chunks.len(), 2, "the new module should get a chunk of its own: {chunks:#?}");
+  assert!(
+    chunks.contains(&(recorded_filename.clone(), recorded_code.clone())),
+    "{recorded_filename} should be left untouched: {chunks:#?}"
+  );
+
+  assert_eq!(bundle(&project_dir).await, chunks, "a rebuild without changes should be identical");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn failing_to_write_the_records_is_a_warning() {
+  let project_dir = create_project("unwritable");
+  // A file stands where the directory of the records file should be created.
+  std::fs::write(project_dir.join("blocker"), "").unwrap();
+
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem { name: Some("a".to_string()), import: "./a.js".to_string() }]),
+    cwd: Some(project_dir.clone()),
+    experimental: Some(ExperimentalOptions {
+      records_path: Some("blocker/records.json".to_string()),
+      ..Default::default()
+    }),
+    ..Default::default()
+  })
+  .expect("failed to create bundler");
+
+  let output = bundler.generate().await.expect("build should succeed");
+  let warning = output
+    .warnings
+    .iter()
+    .find(|warning| matches!(warning.kind(), EventKind::CouldNotWriteRecords))
+    .expect("should warn about the records file");
+  assert!(warning.to_diagnostic().to_string().contains("records.json"));
+}
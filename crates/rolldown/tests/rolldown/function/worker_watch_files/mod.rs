@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use rolldown::{Bundler, BundlerOptions, InputItem};
+use rolldown_common::WorkerOptions;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn watches_the_files_of_nested_worker_builds() {
+  let project_dir =
+    std::env::temp_dir().join(format!("rolldown-worker-watch-files-{}", std::process::id()));
+  let _ = std::fs::remove_dir_all(&project_dir);
+  std::fs::create_dir_all(&project_dir).unwrap();
+  std::fs::write(
+    project_dir.join("main.js"),
+    "export const start = () => new Worker(new URL('./worker.js', import.meta.url));\n",
+  )
+  .unwrap();
+  std::fs::write(
+    project_dir.join("worker.js"),
+    "import { value } from './dep.js';\nself.postMessage(value);\n",
+  )
+  .unwrap();
+  std::fs::write(project_dir.join("dep.js"), "export const value = 42;\n").unwrap();
+
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(project_dir.clone()),
+    worker: Some(WorkerOptions::default()),
+    ..Default::default()
+  })
+  .expect("failed to create bundler");
+  bundler.generate().await.expect("build should succeed");
+
+  let mut watch_files = bundler
+    .watch_files()
+    .iter()
+    .filter_map(|file| {
+      let path = Path::new(file.as_str());
+      path
+        .starts_with(&project_dir)
+        .then(|| path.file_name().unwrap().to_string_lossy().into_owned())
+    })
+    .collect::<Vec<_>>();
+  watch_files.sort_unstable();
+  assert_eq!(watch_files, ["dep.js", "main.js", "worker.js"]);
+}
@@ -0,0 +1,2 @@
+exports.__esModule = true;
+exports.helper = (value) => value;
@@ -0,0 +1,5 @@
+import assert from 'node:assert';
+import { add, name } from './math.js';
+
+assert.strictEqual(add(1, 2), 3);
+assert.strictEqual(name, 'math');
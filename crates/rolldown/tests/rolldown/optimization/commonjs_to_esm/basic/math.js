@@ -0,0 +1,5 @@
+Object.defineProperty(exports, '__esModule', { value: true });
+const { helper } = require('./helper.js');
+exports.add = (a, b) => helper(a + b);
+exports.name = 'math';
+exports.unused = () => 'unused';
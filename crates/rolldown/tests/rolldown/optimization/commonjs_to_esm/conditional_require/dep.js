@@ -0,0 +1,2 @@
+globalThis.depLoaded = true;
+export const dep = true;
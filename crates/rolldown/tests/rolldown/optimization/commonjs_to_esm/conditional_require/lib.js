@@ -0,0 +1,5 @@
+exports.__esModule = true;
+if (globalThis.loadDep) {
+  require('./dep.js');
+}
+exports.value = 1;
@@ -0,0 +1,5 @@
+import assert from 'node:assert';
+import { value } from './lib.js';
+
+assert.strictEqual(value, 1);
+assert.strictEqual(globalThis.depLoaded, undefined);
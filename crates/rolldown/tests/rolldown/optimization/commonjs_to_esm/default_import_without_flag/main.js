@@ -0,0 +1,4 @@
+import assert from 'node:assert';
+import lib from './lib.js';
+
+assert.deepStrictEqual(lib, { value: 1 });
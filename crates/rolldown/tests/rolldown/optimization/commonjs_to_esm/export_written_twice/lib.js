@@ -0,0 +1,3 @@
+exports.__esModule = true;
+exports.value = 1;
+exports.value = 2;
@@ -0,0 +1,4 @@
+import assert from 'node:assert';
+import { value } from './lib.js';
+
+assert.strictEqual(value, 2);
@@ -0,0 +1,3 @@
+exports.__esModule = true;
+exports.value = 1;
+exports.double = exports.value * 2;
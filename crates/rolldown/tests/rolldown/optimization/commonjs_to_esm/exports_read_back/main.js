@@ -0,0 +1,4 @@
+import assert from 'node:assert';
+import { double } from './lib.js';
+
+assert.strictEqual(double, 2);
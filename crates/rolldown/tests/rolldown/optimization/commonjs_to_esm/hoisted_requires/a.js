@@ -0,0 +1,2 @@
+globalThis.order.push('a');
+export const a = 1;
@@ -0,0 +1,2 @@
+globalThis.order.push('b');
+export const b = 2;
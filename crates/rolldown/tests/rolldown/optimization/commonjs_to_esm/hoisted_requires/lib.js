@@ -0,0 +1,7 @@
+exports.__esModule = true;
+require('./setup.js');
+const { a } = require('./a.js');
+globalThis.order.push('lib');
+// Runs after a side effect of this module, so it's still evaluated lazily.
+const { b } = require('./b.js');
+exports.value = a + b;
@@ -0,0 +1,5 @@
+import assert from 'node:assert';
+import { value } from './lib.js';
+
+assert.strictEqual(value, 3);
+assert.deepStrictEqual(globalThis.order, ['setup', 'a', 'lib', 'b']);
@@ -0,0 +1,2 @@
+globalThis.order = ['setup'];
+export const setup = true;
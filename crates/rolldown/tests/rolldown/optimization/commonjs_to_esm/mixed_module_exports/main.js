@@ -0,0 +1,6 @@
+import assert from 'node:assert';
+import { a, b } from './lib.js';
+
+assert.strictEqual(a, 1);
+// `exports.b` is written to the object that `module.exports` replaced.
+assert.strictEqual(b, undefined);
@@ -0,0 +1,5 @@
+import assert from 'node:assert';
+import lib from './lib.js';
+
+// Under Node.js semantics the default import is `module.exports` even with the `__esModule` flag.
+assert.strictEqual(lib.default, 'default');
@@ -0,0 +1,10 @@
+const name = 'greet';
+
+function greet(who) {
+  return `hello ${who}`;
+}
+
+module.exports = {
+  greet,
+  name,
+};
@@ -0,0 +1,5 @@
+import assert from 'node:assert';
+import { greet } from './greet.js';
+
+const name = 'main';
+assert.strictEqual(greet(name), 'hello main');
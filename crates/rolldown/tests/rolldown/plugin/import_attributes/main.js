@@ -0,0 +1,9 @@
+import assert from 'node:assert';
+import foo from './foo.js';
+import fooText from './foo.js' with { type: 'text' };
+// A real `?type=text` query must not be mistaken for the attribute.
+import fooQuery from './foo.js?type=text';
+
+assert.strictEqual(foo, 'js');
+assert.strictEqual(fooText, "export default 'js';\n");
+assert.strictEqual(fooQuery, 'query');
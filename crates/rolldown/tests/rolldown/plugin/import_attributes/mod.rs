@@ -0,0 +1,147 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use arcstr::ArcStr;
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_common::{ImportAttribute, ModuleInfo, NormalModule};
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookNoopReturn, HookResolveIdArgs,
+  HookResolveIdOutput, HookResolveIdReturn, HookTransformArgs, HookTransformReturn, HookUsage,
+  Plugin, PluginContext, PluginContextResolveOptions, SharedLoadPluginContext,
+  SharedTransformPluginContext,
+};
+use rolldown_testing::{manual_integration_test, test_config::TestMeta};
+
+#[derive(Debug, Default)]
+struct TestPlugin {
+  loaded: Mutex<Vec<(String, Option<String>)>>,
+  transformed: Mutex<Vec<String>>,
+  parsed: Mutex<Vec<String>>,
+}
+
+impl Plugin for TestPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "TestPlugin".into()
+  }
+
+  async fn resolve_id(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    if let Some(specifier) = args.specifier.strip_suffix("?type=text") {
+      // Resolves through the branch below, which only sees the attributes if `this.resolve`
+      // forwards them.
+      let attributes = ImportAttribute::from_iter([("custom".into(), "query".into())]);
+      let resolved = ctx
+        .resolve(
+          specifier,
+          args.importer,
+          Some(PluginContextResolveOptions {
+            skip_self: false,
+            attributes: Some(attributes),
+            ..Default::default()
+          }),
+        )
+        .await??;
+      return Ok(Some(HookResolveIdOutput {
+        id: resolved.id.as_arc_str().clone(),
+        ..Default::default()
+      }));
+    }
+    if args
+      .attributes
+      .is_some_and(|attributes| attributes.iter().any(|entry| entry == ("custom", "query")))
+    {
+      let resolved = ctx.resolve(args.specifier, args.importer, None).await??;
+      return Ok(Some(HookResolveIdOutput {
+        id: ArcStr::from(format!("{}?type=text", resolved.id)),
+        ..Default::default()
+      }));
+    }
+    Ok(None)
+  }
+
+  async fn load(&self, _ctx: SharedLoadPluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    self
+      .loaded
+      .lock()
+      .unwrap()
+      .push((args.id.to_string(), args.attributes.map(ToString::to_string)));
+    if args.id.ends_with("foo.js?type=text") {
+      return Ok(Some(HookLoadOutput {
+        code: arcstr::literal!("export default 'query';"),
+        ..Default::default()
+      }));
+    }
+    Ok(None)
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    self.transformed.lock().unwrap().push(args.id.to_string());
+    Ok(None)
+  }
+
+  async fn module_parsed(
+    &self,
+    _ctx: &PluginContext,
+    module_info: Arc<ModuleInfo>,
+    _normal_module: &NormalModule,
+  ) -> HookNoopReturn {
+    self.parsed.lock().unwrap().push(module_info.id.to_string());
+    Ok(())
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::ResolveId | HookUsage::Load | HookUsage::Transform | HookUsage::ModuleParsed
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn import_attributes_in_hooks() {
+  let plugin = Arc::new(TestPlugin::default());
+  manual_integration_test!()
+    .build(TestMeta::default())
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        ..Default::default()
+      },
+      vec![Arc::<TestPlugin>::clone(&plugin)],
+    )
+    .await;
+
+  let mut loaded = plugin.loaded.lock().unwrap().clone();
+  loaded.sort();
+  let mut transformed = plugin.transformed.lock().unwrap().clone();
+  transformed.sort();
+  let mut parsed = plugin.parsed.lock().unwrap().clone();
+  parsed.sort();
+
+  // Every hook sees the same id for a module. The runtime module isn't loaded through plugins.
+  transformed.retain(|id| !id.starts_with("\0rolldown/runtime"));
+  parsed.retain(|id| !id.starts_with("\0rolldown/runtime"));
+  assert_eq!(loaded.len(), 4);
+  assert_eq!(loaded.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>(), transformed);
+  assert_eq!(transformed, parsed);
+
+  // The module typed by the attribute is separate from the one with a `?type=text` query, and its
+  // `load` hook receives the attributes as written.
+  assert!(loaded.iter().any(|(id, attributes)| {
+    id.starts_with("\0rolldown/with-type/text?")
+      && id.ends_with("foo.js")
+      && attributes.as_deref() == Some(r#"with { type: "text" }"#)
+  }));
+  assert!(
+    loaded.iter().any(|(id, attributes)| id.ends_with("foo.js?type=text") && attributes.is_none())
+  );
+}
@@ -1 +1,2 @@
+mod import_attributes;
 mod plugin_context;
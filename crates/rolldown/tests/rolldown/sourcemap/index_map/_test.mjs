@@ -0,0 +1,25 @@
+import fs from 'node:fs';
+import assert from 'node:assert';
+import path from 'node:path';
+import { SourceMap } from 'node:module';
+
+const code = fs.readFileSync(path.resolve(import.meta.dirname, 'dist/main.js'), 'utf8');
+const payload = JSON.parse(
+  fs.readFileSync(path.resolve(import.meta.dirname, 'dist/main.js.map'), 'utf8'),
+);
+assert.equal(payload.file, 'main.js');
+assert.deepEqual(
+  payload.sections.map((section) => section.map.sources),
+  [['../foo.js'], ['../bar.js'], ['../main.js']],
+);
+
+const map = new SourceMap(payload);
+function originalPositionOf(text) {
+  const lines = code.split('\n');
+  const line = lines.findIndex((line) => line.includes(text));
+  const { originalSource, originalLine } = map.findEntry(line, lines[line].indexOf(text));
+  return [path.basename(originalSource), originalLine];
+}
+assert.deepEqual(originalPositionOf('return "foo"'), ['foo.js', 1]);
+assert.deepEqual(originalPositionOf('return "bar"'), ['bar.js', 2]);
+assert.deepEqual(originalPositionOf('console.log'), ['main.js', 3]);
@@ -0,0 +1,4 @@
+export const bar = () => {
+
+  return 'bar';
+};
@@ -0,0 +1,79 @@
+import fs from 'node:fs';
+import assert from 'node:assert';
+import path from 'node:path';
+
+const map = JSON.parse(fs.readFileSync(path.resolve(import.meta.dirname, 'dist/main.js.map'), 'utf8'));
+assert.equal(typeof map.scopes, 'string');
+
+const BASE64 = 'ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/';
+function decodeUnsigned(item) {
+  const values = [];
+  let value = 0;
+  let shift = 0;
+  for (const char of item) {
+    const digit = BASE64.indexOf(char);
+    value += (digit & 0b11111) << shift;
+    if (digit & 0b100000) {
+      shift += 5;
+    } else {
+      values.push(value);
+      value = 0;
+      shift = 0;
+    }
+  }
+  return values;
+}
+const toSigned = (value) => (value & 1 ? -(value >> 1) : value >> 1);
+
+// Decodes the original scopes (in definition order) and, for every generated range, the name of
+// its original scope and its bindings.
+const originalScopes = [];
+const ranges = [];
+const state = { name: 0, kind: 0, variable: 0, definition: 0 };
+for (const item of map.scopes.split(',')) {
+  if (item === '') continue;
+  const [tag, ...values] = decodeUnsigned(item);
+  switch (tag) {
+    case 1: {
+      const [flags, , , ...rest] = values;
+      const scope = { variables: [] };
+      if (flags & 1) scope.name = map.names[(state.name += toSigned(rest.shift()))];
+      if (flags & 2) scope.kind = map.names[(state.kind += toSigned(rest.shift()))];
+      originalScopes.push(scope);
+      break;
+    }
+    case 3:
+      originalScopes.at(-1).variables = values.map(
+        (value) => map.names[(state.variable += toSigned(value))],
+      );
+      break;
+    case 4: {
+      const definition = (state.definition += toSigned(values.at(-1)));
+      ranges.push({ scope: originalScopes[definition], bindings: {} });
+      break;
+    }
+    case 6: {
+      const range = ranges.at(-1);
+      values.forEach((value, index) => {
+        range.bindings[range.scope.variables[index]] = value === 0 ? null : map.names[value - 1];
+      });
+      break;
+    }
+  }
+}
+
+const findRange = (name, kind) =>
+  ranges.find((range) => range.scope.name === name && range.scope.kind === kind);
+
+assert.deepEqual(
+  ranges.filter((range) => range.scope.kind === 'module').map((range) => range.bindings),
+  [
+    // `message` of `greet.js` is renamed to avoid the conflict with the one of `main.js`.
+    { message: 'message$1', greet: 'greet' },
+    { message: 'message', run: 'run' },
+  ],
+);
+assert.deepEqual(findRange('greet', 'function').bindings, { name: 'name' });
+assert.deepEqual(findRange('run', 'function').bindings, { name: 'name', handler: 'handler' });
+// The arrow function is named after the variable it's assigned to.
+assert.ok(findRange('handler', 'function'));
@@ -0,0 +1,5 @@
+const message = 'hello';
+
+export function greet(name) {
+  return `${message}, ${name}`;
+}
@@ -0,0 +1,10 @@
+import { greet } from './greet.js';
+
+const message = 'main';
+
+export function run(name) {
+  const handler = () => greet(name);
+  return handler();
+}
+
+export { message };
@@ -0,0 +1,2 @@
+import './lazy.css';
+console.log('lazy');
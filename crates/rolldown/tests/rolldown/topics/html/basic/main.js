@@ -0,0 +1,3 @@
+import { greet } from './shared.js';
+
+greet('main');
@@ -0,0 +1 @@
+export const preloaded = true;
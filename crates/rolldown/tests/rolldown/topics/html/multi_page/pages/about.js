@@ -0,0 +1,3 @@
+import { render } from '../shared.js';
+
+render('about');
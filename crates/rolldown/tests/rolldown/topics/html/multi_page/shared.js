@@ -0,0 +1,3 @@
+export function render(page) {
+  document.title = page;
+}
@@ -0,0 +1,10 @@
+import assert from "node:assert";
+import defer * as dep from './dep.js';
+import { log } from './log.js';
+
+log.push('main');
+assert.deepStrictEqual(log, ['main']);
+assert.strictEqual(dep.value, 1);
+assert.deepStrictEqual(log, ['main', 'dep']);
+assert.strictEqual(dep.value, 1);
+assert.deepStrictEqual(log, ['main', 'dep']);
@@ -0,0 +1,5 @@
+import { log } from './log.js';
+
+log.push('dep');
+
+export const value = 1;
@@ -0,0 +1,9 @@
+import assert from "node:assert";
+import defer * as dep from './dep.js';
+import { log } from './log.js';
+import './other.js';
+
+// `other.js` imports `dep.js` eagerly, so it's already evaluated before the deferred namespace is used.
+assert.deepStrictEqual(log, ['dep', 'other']);
+assert.strictEqual(dep.value, 1);
+assert.deepStrictEqual(log, ['dep', 'other']);
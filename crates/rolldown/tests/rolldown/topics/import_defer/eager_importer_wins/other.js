@@ -0,0 +1,6 @@
+import { log } from './log.js';
+import { value } from './dep.js';
+
+log.push('other');
+
+export { value };
@@ -0,0 +1,3 @@
+import defer * as ext from 'ext';
+
+export const read = () => ext.value;
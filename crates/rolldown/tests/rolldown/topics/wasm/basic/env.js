@@ -0,0 +1,4 @@
+export let logged;
+export function log(value) {
+  logged = value;
+}
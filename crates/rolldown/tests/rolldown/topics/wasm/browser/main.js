@@ -0,0 +1,7 @@
+import assert from 'node:assert';
+import { add, answer } from './math.wasm';
+import { logged } from './env.js';
+
+assert.strictEqual(answer, 42);
+assert.strictEqual(add(1, 2), 3);
+assert.strictEqual(logged, 1);
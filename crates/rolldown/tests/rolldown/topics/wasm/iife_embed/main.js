@@ -0,0 +1,6 @@
+import { add, answer } from './math.wasm';
+import { logged } from './env.js';
+
+if (answer !== 42 || add(1, 2) !== 3 || logged !== 1) {
+  throw new Error('Unexpected wasm exports');
+}
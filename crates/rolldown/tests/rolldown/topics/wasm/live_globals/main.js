@@ -0,0 +1,9 @@
+import assert from 'node:assert';
+import { counter, inc } from './counter.wasm';
+import { logged } from './env.js';
+
+assert.strictEqual(counter, 0);
+inc();
+inc();
+assert.strictEqual(counter, 2);
+assert.strictEqual(logged, 2);
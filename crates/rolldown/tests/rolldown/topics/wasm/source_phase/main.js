@@ -0,0 +1,7 @@
+import assert from 'node:assert';
+import source mathModule from './math.wasm';
+import * as env from './env.js';
+
+assert.ok(mathModule instanceof WebAssembly.Module);
+const { exports } = await WebAssembly.instantiate(mathModule, { './env.js': env });
+assert.strictEqual(exports.add(1, 2), 3);
@@ -0,0 +1,10 @@
+import assert from 'node:assert';
+import fs from 'node:fs/promises';
+import { importSystem } from '../../../_test_helpers/module-loaders.mjs';
+
+const main = await importSystem(`${import.meta.dirname}/dist`, 'main.js', {
+  'node:fs/promises': fs,
+});
+assert.strictEqual(main.answer, 42);
+assert.strictEqual(main.sum, 3);
+assert.strictEqual(main.logged, 1);
@@ -0,0 +1,5 @@
+import { add, answer } from './math.wasm';
+import { logged } from './env.js';
+
+export const sum = add(1, 2);
+export { answer, logged };
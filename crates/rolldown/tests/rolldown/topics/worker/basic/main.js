@@ -0,0 +1,11 @@
+export function startWorker() {
+  return new Worker(new URL('./worker.js', import.meta.url))
+}
+
+export function startSharedWorker() {
+  return new SharedWorker(new URL('./worker.js', import.meta.url), { name: 'shared' })
+}
+
+export function registerServiceWorker() {
+  return navigator.serviceWorker.register(new URL('./sw.js', import.meta.url))
+}
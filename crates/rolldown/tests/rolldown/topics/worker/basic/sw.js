@@ -0,0 +1,5 @@
+import { greet } from './shared.js'
+
+self.addEventListener('install', () => {
+  console.log(greet('service worker'))
+})
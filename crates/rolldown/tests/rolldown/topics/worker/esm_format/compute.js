@@ -0,0 +1,3 @@
+export function compute(n) {
+  return n * 2
+}
@@ -0,0 +1,3 @@
+export function startWorker() {
+  return new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })
+}
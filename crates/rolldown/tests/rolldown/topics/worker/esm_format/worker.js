@@ -0,0 +1,4 @@
+self.onmessage = async (e) => {
+  const { compute } = await import('./compute.js')
+  self.postMessage(compute(e.data))
+}
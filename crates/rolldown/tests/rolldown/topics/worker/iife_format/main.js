@@ -0,0 +1,5 @@
+globalThis.startWorker = () => {
+  const worker = new Worker(new URL('./worker.js', import.meta.url))
+  worker.postMessage('ping')
+  return worker
+}
@@ -0,0 +1,3 @@
+// Spawning itself can't point at its own hashed output
+const again = new Worker(new URL('./inner.js', import.meta.url))
+self.postMessage(again)
@@ -0,0 +1,3 @@
+export function startWorker() {
+  return new Worker(new URL('./outer.js', import.meta.url))
+}
@@ -0,0 +1,2 @@
+const inner = new Worker(new URL('./inner.js', import.meta.url))
+inner.onmessage = (e) => self.postMessage(e.data)
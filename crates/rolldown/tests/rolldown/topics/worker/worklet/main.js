@@ -0,0 +1,7 @@
+export function registerPainter() {
+  return CSS.paintWorklet.addModule(new URL('./painter.js', import.meta.url))
+}
+
+export function registerProcessor(audioContext) {
+  return audioContext.audioWorklet.addModule(new URL('./processor.js', import.meta.url))
+}
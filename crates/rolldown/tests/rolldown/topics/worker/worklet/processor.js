@@ -0,0 +1,6 @@
+registerProcessor('passthrough', class extends AudioWorkletProcessor {
+  process(inputs, outputs) {
+    outputs[0].forEach((channel, i) => channel.set(inputs[0][i]))
+    return true
+  }
+})
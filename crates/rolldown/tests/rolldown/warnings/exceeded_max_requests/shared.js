@@ -0,0 +1,5 @@
+export function shared() {
+  return 'shared'
+}
+
+globalThis.loaded = true
@@ -1,3 +1,3 @@
 console.log('side effect');
 
-export const value = 1;
+exports.value = 1;
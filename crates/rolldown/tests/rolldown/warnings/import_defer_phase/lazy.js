@@ -0,0 +1,3 @@
+console.log('lazy side effect');
+
+export const value = 2;
@@ -1,3 +1,5 @@
-import defer * as dep from './dep.js';
+import defer * as dep from './dep.cjs';
 
 console.log(dep.value);
+
+import.defer('./lazy.js').then((lazy) => console.log(lazy.value));
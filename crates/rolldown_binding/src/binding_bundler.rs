@@ -80,7 +80,9 @@ impl BindingBundler {
         return Ok(napi::Either::A(BindingErrors::new(vec![error])));
       }
 
-      Ok(napi::Either::B(bundle_output.assets.into()))
+      Ok(napi::Either::B(
+        BindingOutputs::from(bundle_output.assets).with_metafile(bundle_output.metafile),
+      ))
     };
     spawn_boxed_future(env, fut)
   }
@@ -128,7 +130,9 @@ impl BindingBundler {
         return Ok(napi::Either::A(BindingErrors::new(vec![error])));
       }
 
-      Ok(napi::Either::B(bundle_output.assets.into()))
+      Ok(napi::Either::B(
+        BindingOutputs::from(bundle_output.assets).with_metafile(bundle_output.metafile),
+      ))
     };
     spawn_boxed_future(env, fut)
   }
@@ -28,6 +28,9 @@ pub struct BindingChecksOptions {
   pub large_barrel_modules: Option<bool>,
   pub sourcemap_broken: Option<bool>,
   pub namespace_conflict: Option<bool>,
+  pub empty_glob_import: Option<bool>,
+  pub exceeded_max_requests: Option<bool>,
+  pub could_not_write_records: Option<bool>,
 }
 impl From<BindingChecksOptions> for rolldown_common::ChecksOptions {
   fn from(value: BindingChecksOptions) -> Self {
@@ -56,6 +59,9 @@ impl From<BindingChecksOptions> for rolldown_common::ChecksOptions {
       large_barrel_modules: value.large_barrel_modules,
       sourcemap_broken: value.sourcemap_broken,
       namespace_conflict: value.namespace_conflict,
+      empty_glob_import: value.empty_glob_import,
+      exceeded_max_requests: value.exceeded_max_requests,
+      could_not_write_records: value.could_not_write_records,
     }
   }
 }
@@ -1,5 +1,9 @@
 use napi::bindgen_prelude::Either;
 
+use crate::types::binding_string_or_regex::{
+  BindingStringOrRegex, bindingify_string_or_regex_array,
+};
+
 #[napi_derive::napi(object, object_to_js = false)]
 #[derive(Debug, Default)]
 pub struct BindingExperimentalOptions {
@@ -14,6 +18,10 @@ pub struct BindingExperimentalOptions {
   pub native_magic_string: Option<bool>,
   pub chunk_optimization: Option<Either<bool, BindingChunkOptimizationOptions>>,
   pub lazy_barrel: Option<bool>,
+  pub precompress: Option<Either<bool, BindingPrecompressOptions>>,
+  pub async_module_wrappers: Option<bool>,
+  pub persistent_cache_dir: Option<String>,
+  pub records_path: Option<String>,
 }
 
 impl TryFrom<BindingExperimentalOptions> for rolldown_common::ExperimentalOptions {
@@ -38,6 +46,18 @@ impl TryFrom<BindingExperimentalOptions> for rolldown_common::ExperimentalOption
         Either::B(v) => rolldown_common::ChunkOptimizationOption::Options(v.into()),
       }),
       lazy_barrel: value.lazy_barrel,
+      persistent_cache_dir: value.persistent_cache_dir,
+      tree_shaking_provenance: None,
+      records_path: value.records_path,
+      precompress: value
+        .precompress
+        .map(|v| match v {
+          Either::A(v) => Ok(v.then_some(rolldown_common::PrecompressOptions::default())),
+          Either::B(v) => v.try_into().map(Some),
+        })
+        .transpose()?
+        .flatten(),
+      async_module_wrappers: value.async_module_wrappers,
     })
   }
 }
@@ -127,3 +147,47 @@ impl From<BindingChunkImportMap> for rolldown_common::ChunkImportMap {
     Self { base_url: value.base_url, file_name: value.file_name }
   }
 }
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug, Default)]
+pub struct BindingPrecompressOptions {
+  #[napi(ts_type = "Array<'gzip' | 'brotli' | 'zstd'>")]
+  pub algorithms: Option<Vec<String>>,
+  pub threshold: Option<u32>,
+  pub level: Option<u32>,
+  pub include: Option<Vec<BindingStringOrRegex>>,
+  pub exclude: Option<Vec<BindingStringOrRegex>>,
+}
+
+impl TryFrom<BindingPrecompressOptions> for rolldown_common::PrecompressOptions {
+  type Error = napi::Error;
+
+  fn try_from(value: BindingPrecompressOptions) -> Result<Self, Self::Error> {
+    let algorithms = value
+      .algorithms
+      .map(|algorithms| {
+        algorithms
+          .iter()
+          .map(|algorithm| match algorithm.as_str() {
+            "gzip" => Ok(rolldown_common::PrecompressAlgorithm::Gzip),
+            "brotli" => Ok(rolldown_common::PrecompressAlgorithm::Brotli),
+            "zstd" => Ok(rolldown_common::PrecompressAlgorithm::Zstd),
+            _ => Err(napi::Error::new(
+              napi::Status::InvalidArg,
+              format!(
+                "Invalid value for `experimental.precompress.algorithms` option: {algorithm}, expected one of 'gzip', 'brotli' or 'zstd'"
+              ),
+            )),
+          })
+          .collect::<Result<Vec<_>, _>>()
+      })
+      .transpose()?;
+    Ok(Self {
+      algorithms,
+      threshold: value.threshold,
+      level: value.level,
+      include: value.include.map(bindingify_string_or_regex_array),
+      exclude: value.exclude.map(bindingify_string_or_regex_array),
+    })
+  }
+}
@@ -13,6 +13,7 @@ pub struct BindingInlineConstConfig {
 pub struct BindingOptimization {
   pub inline_const: Option<Either<bool, BindingInlineConstConfig>>,
   pub pife_for_module_wrappers: Option<bool>,
+  pub commonjs_to_esm: Option<bool>,
 }
 
 impl TryFrom<BindingOptimization> for rolldown_common::OptimizationOption {
@@ -46,6 +47,10 @@ impl TryFrom<BindingOptimization> for rolldown_common::OptimizationOption {
       None => None,
     };
 
-    Ok(Self { inline_const, pife_for_module_wrappers: value.pife_for_module_wrappers })
+    Ok(Self {
+      inline_const,
+      pife_for_module_wrappers: value.pife_for_module_wrappers,
+      commonjs_to_esm: value.commonjs_to_esm,
+    })
   }
 }
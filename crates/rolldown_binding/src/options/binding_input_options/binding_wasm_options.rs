@@ -0,0 +1,5 @@
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug, Default)]
+pub struct BindingWasmOptions {
+  pub embed: Option<bool>,
+}
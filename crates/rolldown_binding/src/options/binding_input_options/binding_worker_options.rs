@@ -0,0 +1,6 @@
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug, Default)]
+pub struct BindingWorkerOptions {
+  #[napi(ts_type = "'es' | 'cjs' | 'iife' | 'umd'")]
+  pub format: Option<String>,
+}
@@ -5,7 +5,9 @@ mod binding_make_absolute_externals_relative;
 mod binding_optimization;
 mod binding_resolve_options;
 mod binding_treeshake;
+mod binding_wasm_options;
 mod binding_watch_option;
+mod binding_worker_options;
 
 pub mod binding_inject_import;
 
@@ -21,7 +23,9 @@ use std::collections::HashMap;
 use binding_inject_import::BindingInjectImport;
 use binding_input_item::BindingInputItem;
 use binding_resolve_options::BindingResolveOptions;
+use binding_wasm_options::BindingWasmOptions;
 use binding_watch_option::BindingWatchOption;
+use binding_worker_options::BindingWorkerOptions;
 
 use super::plugin::BindingPluginOrParallelJsPluginPlaceholder;
 use crate::generated::binding_checks_options;
@@ -111,4 +115,6 @@ pub struct BindingInputOptions<'env> {
   pub optimization: Option<BindingOptimization>,
   pub context: Option<String>,
   pub tsconfig: Option<Either<bool, String>>,
+  pub worker: Option<BindingWorkerOptions>,
+  pub wasm: Option<BindingWasmOptions>,
 }
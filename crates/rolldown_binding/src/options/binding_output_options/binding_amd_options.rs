@@ -0,0 +1,6 @@
+#[napi_derive::napi(object)]
+#[derive(Debug)]
+pub struct BindingAmdOptions {
+  pub id: Option<String>,
+  pub define: Option<String>,
+}
@@ -17,6 +17,8 @@ pub struct BindingManualCodeSplittingOptions {
   pub max_size: Option<f64>,
   pub min_module_size: Option<f64>,
   pub max_module_size: Option<f64>,
+  pub max_initial_requests: Option<u32>,
+  pub max_async_requests: Option<u32>,
 }
 
 type BindingMatchGroupTest =
@@ -1,3 +1,4 @@
+mod binding_amd_options;
 mod binding_comments_options;
 mod binding_generated_code_options;
 pub mod binding_manual_code_splitting_options;
@@ -9,6 +10,7 @@ use napi::Either;
 use napi::bindgen_prelude::{Either3, FnArgs};
 use rustc_hash::FxHashMap;
 
+pub use binding_amd_options::BindingAmdOptions;
 pub use binding_comments_options::BindingCommentsOptions;
 pub use binding_generated_code_options::BindingGeneratedCodeOptions;
 use binding_manual_code_splitting_options::BindingManualCodeSplittingOptions;
@@ -31,6 +33,8 @@ pub type GlobalsOutputOption =
   Either<FxHashMap<String, String>, JsCallback<FnArgs<(String,)>, String>>;
 pub type PathsOutputOption =
   Either<FxHashMap<String, String>, JsCallback<FnArgs<(String,)>, String>>;
+pub type InteropOutputOption =
+  Either3<String, FxHashMap<String, String>, JsCallback<FnArgs<(String,)>, Option<String>>>;
 pub type SanitizeFileName = Either<bool, JsCallback<FnArgs<(String,)>, String>>;
 pub type SourcemapIgnoreListOutputOption =
   Either3<bool, BindingStringOrRegex, JsCallback<FnArgs<(String, String)>, bool>>;
@@ -53,9 +57,15 @@ pub struct BindingOutputOptions<'env> {
   #[napi(ts_type = "string | ((chunk: PreRenderedChunk) => string)")]
   pub chunk_file_names: Option<ChunkFileNamesOutputOption>,
   #[debug(skip)]
+  #[napi(ts_type = "string | ((chunk: PreRenderedChunk) => string)")]
+  pub css_entry_file_names: Option<ChunkFileNamesOutputOption>,
+  #[debug(skip)]
+  #[napi(ts_type = "string | ((chunk: PreRenderedChunk) => string)")]
+  pub css_chunk_file_names: Option<ChunkFileNamesOutputOption>,
+  #[debug(skip)]
   #[napi(ts_type = "boolean | ((name: string) => string)")]
   pub sanitize_file_name: Option<SanitizeFileName>,
-  // amd: NormalizedAmdOptions;
+  pub amd: Option<BindingAmdOptions>,
   #[debug(skip)]
   #[napi(
     ts_type = "string | ((chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>)"
@@ -85,7 +95,7 @@ pub struct BindingOutputOptions<'env> {
   pub exports: Option<String>,
   pub extend: Option<bool>,
   pub external_live_bindings: Option<bool>,
-  #[napi(ts_type = "'es' | 'cjs' | 'iife' | 'umd'")]
+  #[napi(ts_type = "'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'system'")]
   pub format: Option<String>,
   // freeze: boolean;
   pub generated_code: Option<BindingGeneratedCodeOptions>,
@@ -94,11 +104,16 @@ pub struct BindingOutputOptions<'env> {
   pub globals: Option<GlobalsOutputOption>,
   #[napi(ts_type = "'base64' | 'base36' | 'hex'")]
   pub hash_characters: Option<String>,
-  // hoistTransitiveImports: boolean;
+  #[napi(ts_type = "boolean | 'modulepreload'")]
+  pub hoist_transitive_imports: Option<Either<bool, String>>,
   // indent: true | string;
   pub inline_dynamic_imports: Option<bool>,
   pub dynamic_import_in_cjs: Option<bool>,
-  // interop: GetInterop;
+  #[debug(skip)]
+  #[napi(
+    ts_type = "'auto' | 'esModule' | 'default' | 'defaultOnly' | 'compat' | Record<string, string> | ((id: string) => VoidNullable<string>)"
+  )]
+  pub interop: Option<InteropOutputOption>,
   #[debug(skip)]
   #[napi(
     ts_type = "string | ((chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>)"
@@ -131,6 +146,8 @@ pub struct BindingOutputOptions<'env> {
   )]
   pub sourcemap_ignore_list: Option<SourcemapIgnoreListOutputOption>,
   pub sourcemap_debug_ids: Option<bool>,
+  pub sourcemap_scopes: Option<bool>,
+  pub sourcemap_index_map: Option<bool>,
   #[debug(skip)]
   #[napi(ts_type = "(source: string, sourcemapPath: string) => string")]
   pub sourcemap_path_transform: Option<JsCallback<FnArgs<(String, String)>, String>>,
@@ -146,7 +163,7 @@ pub struct BindingOutputOptions<'env> {
   #[napi(ts_type = "boolean | 'dce-only' | MinifyOptions")]
   pub minify: Option<Either3<bool, String, oxc_minify_napi::MinifyOptions>>,
   pub manual_code_splitting: Option<BindingManualCodeSplittingOptions>,
-  #[napi(ts_type = "'none' | 'inline'")]
+  #[napi(ts_type = "'none' | 'inline' | 'eof' | 'linked' | 'external'")]
   pub legal_comments: Option<String>,
   pub comments: Option<Either<bool, BindingCommentsOptions>>,
   pub polyfill_require: Option<bool>,
@@ -156,5 +173,8 @@ pub struct BindingOutputOptions<'env> {
   pub top_level_var: Option<bool>,
   pub minify_internal_exports: Option<bool>,
   pub clean_dir: Option<bool>,
+  pub metafile: Option<bool>,
+  #[napi(ts_type = "'sha256' | 'sha384' | 'sha512'")]
+  pub integrity: Option<String>,
   pub strict_execution_order: Option<bool>,
 }
@@ -105,6 +105,7 @@ impl BindingCallableBuiltinPlugin {
             is_entry: options.as_ref().is_some_and(|options| options.is_entry.unwrap_or_default()),
             kind,
             custom: options.map(Into::into).unwrap_or_default(),
+            attributes: None,
           },
         )
         .await
@@ -131,7 +132,10 @@ impl BindingCallableBuiltinPlugin {
       let module_idx = rolldown_common::ModuleIdx::new(0);
       let load_ctx = Arc::new(LoadPluginContext::new(context.inner.clone(), module_idx));
       plugin
-        .call_load(load_ctx, &HookLoadArgs { id: &id, module_idx, asserted_module_type: None })
+        .call_load(
+          load_ctx,
+          &HookLoadArgs { id: &id, module_idx, asserted_module_type: None, attributes: None },
+        )
         .await
         .map_err(AnyHowMaybeNapiError::into_napi_error)
         .map(|result| result.map(Into::into))
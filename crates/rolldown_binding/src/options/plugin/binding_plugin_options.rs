@@ -26,6 +26,7 @@ use super::{
     binding_hook_resolve_id_output::BindingHookResolveIdOutput,
     binding_hook_transform_output::BindingHookTransformOutput,
     binding_hot_update_args::BindingHotUpdateArgs,
+    binding_plugin_load_extra_args::BindingLoadHookExtraArgs,
     binding_plugin_transform_extra_args::BindingTransformHookExtraArgs,
     binding_render_chunk_meta_chunks::BindingRenderedChunkMeta,
     binding_shared_string::BindingSharedString,
@@ -41,6 +42,7 @@ pub type BindingPluginOrParallelJsPluginPlaceholder<'env> =
 pub struct BindingPluginOptions {
   pub name: String,
   pub hook_usage: u32,
+  pub cache_key: Option<String>,
   #[napi(
     ts_type = "(ctx: BindingPluginContext, opts: BindingNormalizedOptions) => MaybePromise<VoidNullable>"
   )]
@@ -72,10 +74,13 @@ pub struct BindingPluginOptions {
   pub resolve_dynamic_import_meta: Option<BindingPluginHookMeta>,
 
   #[napi(
-    ts_type = "(ctx: BindingLoadPluginContext, id: string) => MaybePromise<VoidNullable<BindingHookLoadOutput>>"
+    ts_type = "(ctx: BindingLoadPluginContext, id: string, meta: BindingLoadHookExtraArgs) => MaybePromise<VoidNullable<BindingHookLoadOutput>>"
   )]
   pub load: Option<
-    MaybeAsyncJsCallback<FnArgs<(BindingLoadPluginContext, String)>, Option<BindingHookLoadOutput>>,
+    MaybeAsyncJsCallback<
+      FnArgs<(BindingLoadPluginContext, String, BindingLoadHookExtraArgs)>,
+      Option<BindingHookLoadOutput>,
+    >,
   >,
   pub load_meta: Option<BindingPluginHookMeta>,
   pub load_filter: Option<BindingHookFilter>,
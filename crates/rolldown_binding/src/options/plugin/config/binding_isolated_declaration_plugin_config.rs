@@ -4,10 +4,11 @@ use rolldown_plugin_isolated_declaration::IsolatedDeclarationPlugin;
 #[derive(Debug, Default)]
 pub struct BindingIsolatedDeclarationPluginConfig {
   pub strip_internal: Option<bool>,
+  pub bundle: Option<bool>,
 }
 
 impl From<BindingIsolatedDeclarationPluginConfig> for IsolatedDeclarationPlugin {
   fn from(value: BindingIsolatedDeclarationPluginConfig) -> Self {
-    Self { strip_internal: value.strip_internal.unwrap_or_default() }
+    Self::new(value.strip_internal.unwrap_or_default(), value.bundle.unwrap_or_default())
   }
 }
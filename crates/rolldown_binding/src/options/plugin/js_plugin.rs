@@ -21,6 +21,7 @@ use super::{
     binding_hook_resolve_file_url_args::BindingHookResolveFileUrlArgs,
     binding_hook_resolve_id_extra_args::BindingHookResolveIdExtraArgs,
     binding_hot_update_args::BindingHotUpdateArgs,
+    binding_plugin_load_extra_args::BindingLoadHookExtraArgs,
     binding_plugin_transform_extra_args::BindingTransformHookExtraArgs,
     binding_render_chunk_meta_chunks::BindingRenderedChunkMeta,
     binding_shared_string::BindingSharedString,
@@ -128,6 +129,12 @@ impl Plugin for JsPlugin {
         .custom
         .get::<JsPluginContextResolveCustomArgId>(&JsPluginContextResolveCustomArgId)
         .copied(),
+      attributes: args
+        .attributes
+        .map(|attributes| {
+          attributes.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+        })
+        .unwrap_or_default(),
     };
 
     let span =
@@ -216,10 +223,18 @@ impl Plugin for JsPlugin {
     }
 
     let binding_ctx = BindingLoadPluginContext::new(Arc::clone(&ctx));
+    let extra_args = BindingLoadHookExtraArgs {
+      attributes: args
+        .attributes
+        .map(|attributes| {
+          attributes.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+        })
+        .unwrap_or_default(),
+    };
     let span =
       debug_span!("load_hook", plugin_name = self.name, result_kind = tracing::field::Empty);
     let result = cb
-      .await_call((binding_ctx, args.id.to_string()).into())
+      .await_call((binding_ctx, args.id.to_string(), extra_args).into())
       .instrument(span.clone())
       .await?
       .map(TryInto::try_into)
@@ -735,4 +750,8 @@ impl Plugin for JsPlugin {
   fn register_hook_usage(&self) -> HookUsage {
     HookUsage::from_bits(self.inner.hook_usage).expect("Failed to register hook usage")
   }
+
+  fn cache_key(&self) -> Option<Cow<'_, str>> {
+    self.inner.cache_key.as_deref().map(Cow::Borrowed)
+  }
 }
@@ -409,4 +409,8 @@ impl Plugin for ParallelJsPlugin {
   fn register_hook_usage(&self) -> HookUsage {
     Plugin::register_hook_usage(self.first_plugin())
   }
+
+  fn cache_key(&self) -> Option<Cow<'_, str>> {
+    Plugin::cache_key(self.first_plugin())
+  }
 }
@@ -6,7 +6,7 @@ pub struct BindingHookResolveFileUrlArgs {
   pub chunk_id: String,
   /// Filename of the emitted file, relative to the output directory.
   pub file_name: String,
-  #[napi(ts_type = "'es' | 'cjs' | 'iife' | 'umd'")]
+  #[napi(ts_type = "'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'system'")]
   pub format: String,
   /// Id of the module containing the `import.meta.ROLLDOWN_FILE_URL_*` reference.
   pub module_id: String,
@@ -1,3 +1,5 @@
+use rustc_hash::FxHashMap;
+
 // This struct is used to both pass to JS and receive from JS:
 // - Pass to JS: Created in js_plugin.rs:106 and passed to JS plugin resolve_id hooks
 // - Receive from JS: Received in binding_callable_builtin_plugin.rs as parameter (unused currently)
@@ -18,4 +20,6 @@ pub struct BindingHookResolveIdExtraArgs {
     ts_type = "'import-statement' | 'dynamic-import' | 'require-call' | 'import-rule' | 'url-token' | 'new-url' | 'hot-accept'"
   )]
   pub kind: String,
+  /// The import attributes of the import, e.g. `{ type: 'json' }` for `with { type: 'json' }`.
+  pub attributes: FxHashMap<String, String>,
 }
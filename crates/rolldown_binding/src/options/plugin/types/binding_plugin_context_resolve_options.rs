@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use rolldown_plugin::{CustomField, PluginContextResolveOptions};
 use rolldown_plugin_utils::constants::{ViteImportGlob, ViteImportGlobValue};
+use rustc_hash::FxHashMap;
 
 use crate::options::plugin::{
   JsPluginContextResolveCustomArgId, types::binding_vite_plugin_custom::BindingVitePluginCustom,
@@ -26,6 +27,7 @@ pub struct BindingPluginContextResolveOptions {
   pub skip_self: Option<bool>,
   pub custom: Option<u32>,
   pub vite_plugin_custom: Option<BindingVitePluginCustom>,
+  pub attributes: Option<FxHashMap<String, String>>,
 }
 
 impl TryFrom<BindingPluginContextResolveOptions> for PluginContextResolveOptions {
@@ -47,6 +49,7 @@ impl TryFrom<BindingPluginContextResolveOptions> for PluginContextResolveOptions
       is_entry: value.is_entry.unwrap_or_default(),
       skip_self: value.skip_self.unwrap_or(true),
       custom: Arc::new(custom),
+      attributes: value.attributes.map(|attributes| attributes.into_iter().collect()),
     })
   }
 }
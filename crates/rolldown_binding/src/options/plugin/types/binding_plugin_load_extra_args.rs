@@ -0,0 +1,10 @@
+use rustc_hash::FxHashMap;
+
+// This struct is passed to JS plugin load hooks, created in js_plugin.rs
+#[napi_derive::napi(object)]
+#[derive(Default, Debug)]
+pub struct BindingLoadHookExtraArgs {
+  /// The import attributes of the import that created the module, e.g. `{ type: 'text' }` for
+  /// `with { type: 'text' }`. Empty if the import has none.
+  pub attributes: FxHashMap<String, String>,
+}
@@ -15,6 +15,7 @@ pub mod binding_hook_transform_output;
 pub mod binding_hot_update_args;
 pub mod binding_limited_boolean;
 pub mod binding_plugin_context_resolve_options;
+pub mod binding_plugin_load_extra_args;
 pub mod binding_plugin_transform_extra_args;
 pub mod binding_render_chunk_meta_chunks;
 pub mod binding_resolved_external;
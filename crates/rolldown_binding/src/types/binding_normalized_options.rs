@@ -90,6 +90,22 @@ impl BindingNormalizedOptions {
     }
   }
 
+  #[napi(getter)]
+  pub fn css_entry_filenames(&self) -> Either<&str, Undefined> {
+    match &self.inner.css_entry_filenames {
+      rolldown::ChunkFilenamesOutputOption::String(inner) => Either::A(inner),
+      rolldown::ChunkFilenamesOutputOption::Fn(_) => Either::B(()),
+    }
+  }
+
+  #[napi(getter)]
+  pub fn css_chunk_filenames(&self) -> Either<&str, Undefined> {
+    match &self.inner.css_chunk_filenames {
+      rolldown::ChunkFilenamesOutputOption::String(inner) => Either::A(inner),
+      rolldown::ChunkFilenamesOutputOption::Fn(_) => Either::B(()),
+    }
+  }
+
   #[napi(getter)]
   pub fn sourcemap_filenames(&self) -> Either<&str, Undefined> {
     match &self.inner.sourcemap_filenames {
@@ -116,7 +132,7 @@ impl BindingNormalizedOptions {
     self.inner.file.as_deref()
   }
 
-  #[napi(getter, ts_return_type = "'es' | 'cjs' | 'iife' | 'umd'")]
+  #[napi(getter, ts_return_type = "'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'system'")]
   pub fn format(&self) -> &'static str {
     self.inner.format.as_str()
   }
@@ -257,6 +273,16 @@ impl BindingNormalizedOptions {
     self.inner.sourcemap_debug_ids
   }
 
+  #[napi(getter)]
+  pub fn sourcemap_scopes(&self) -> bool {
+    self.inner.sourcemap_scopes
+  }
+
+  #[napi(getter)]
+  pub fn sourcemap_index_map(&self) -> bool {
+    self.inner.sourcemap_index_map
+  }
+
   #[napi(getter)]
   pub fn sourcemap_exclude_sources(&self) -> bool {
     self.inner.sourcemap_exclude_sources
@@ -289,11 +315,14 @@ impl BindingNormalizedOptions {
     }
   }
 
-  #[napi(getter, ts_return_type = "'none' | 'inline'")]
+  #[napi(getter, ts_return_type = "'none' | 'inline' | 'eof' | 'linked' | 'external'")]
   pub fn legal_comments(&self) -> &'static str {
     match self.inner.legal_comments {
       rolldown::LegalComments::None => "none",
       rolldown::LegalComments::Inline => "inline",
+      rolldown::LegalComments::Eof => "eof",
+      rolldown::LegalComments::Linked => "linked",
+      rolldown::LegalComments::External => "external",
     }
   }
 
@@ -73,6 +73,11 @@ impl BindingOutputAsset {
     Ok(self.try_get_inner()?.original_file_names.iter().map(AsRef::as_ref).collect())
   }
 
+  #[napi]
+  pub fn get_integrity(&self) -> napi::Result<Option<&str>> {
+    Ok(self.try_get_inner()?.integrity.as_deref())
+  }
+
   #[napi(ts_return_type = "BindingAssetSource")]
   pub fn get_source<'env>(&self, env: &'env Env) -> napi::Result<Object<'env>> {
     let mut source = Object::new(env)?;
@@ -113,6 +118,7 @@ impl From<JsOutputAsset> for rolldown_common::OutputAsset {
       original_file_names: asset.original_file_names,
       filename: asset.filename.into(),
       source: asset.source.into(),
+      integrity: None,
     }
   }
 }
@@ -102,6 +102,16 @@ impl BindingOutputChunk {
     Ok(self.try_get_inner()?.dynamic_imports.iter().map(AsRef::as_ref).collect())
   }
 
+  #[napi]
+  pub fn get_transitive_imports(&self) -> napi::Result<Vec<&str>> {
+    Ok(self.try_get_inner()?.transitive_imports.iter().map(AsRef::as_ref).collect())
+  }
+
+  #[napi]
+  pub fn get_imported_css(&self) -> napi::Result<Vec<&str>> {
+    Ok(self.try_get_inner()?.imported_css.iter().map(AsRef::as_ref).collect())
+  }
+
   // OutputChunk
   #[napi]
   pub fn get_code(&self) -> napi::Result<&str> {
@@ -124,6 +134,11 @@ impl BindingOutputChunk {
     Ok(&self.try_get_inner()?.preliminary_filename)
   }
 
+  #[napi]
+  pub fn get_integrity(&self) -> napi::Result<Option<&str>> {
+    Ok(self.try_get_inner()?.integrity.as_deref())
+  }
+
   #[napi]
   pub fn get_name(&self) -> napi::Result<&str> {
     Ok(&self.try_get_inner()?.name)
@@ -180,10 +195,13 @@ pub fn update_output_chunk(
       modules: old_chunk.modules.clone(),
       imports,
       dynamic_imports,
+      transitive_imports: old_chunk.transitive_imports.clone(),
+      imported_css: old_chunk.imported_css.clone(),
       code: js_chunk.code,
       map,
       sourcemap_filename: old_chunk.sourcemap_filename.clone(),
       preliminary_filename: old_chunk.preliminary_filename.clone(),
+      integrity: None,
     });
   }
   Ok(())
@@ -17,6 +17,15 @@ use rustc_hash::FxBuildHasher;
 pub struct BindingOutputs {
   pub chunks: Vec<BindingOutputChunk>,
   pub assets: Vec<BindingOutputAsset>,
+  /// The metafile serialized as JSON, only present when `output.metafile` is enabled.
+  pub metafile: Option<String>,
+}
+
+impl BindingOutputs {
+  pub fn with_metafile(mut self, metafile: Option<rolldown_common::Metafile>) -> Self {
+    self.metafile = metafile.map(|metafile| metafile.to_json_string());
+    self
+  }
 }
 
 impl From<Vec<rolldown_common::Output>> for BindingOutputs {
@@ -31,7 +40,7 @@ impl From<Vec<rolldown_common::Output>> for BindingOutputs {
         assets.push(BindingOutputAsset::new(asset));
       }
     });
-    Self { chunks, assets }
+    Self { chunks, assets, metafile: None }
   }
 }
 
@@ -26,12 +26,12 @@ use crate::{
 use napi::bindgen_prelude::{Either, Either3, FnArgs};
 use oxc::transformer::EngineTargets;
 use rolldown::{
-  AddonOutputOption, AssetFilenamesOutputOption, BundlerConfig, BundlerOptions,
+  AddonOutputOption, AmdOptions, AssetFilenamesOutputOption, BundlerConfig, BundlerOptions,
   ChunkFilenamesOutputOption, CodeSplittingMode, DeferSyncScanDataOption, HashCharacters,
-  IsExternal, ManualCodeSplittingOptions, MatchGroup, MatchGroupName, ModuleType,
-  OptimizationOption, OutputExports, OutputFormat, Platform, PluginTimingsOption,
+  HoistTransitiveImports, IsExternal, ManualCodeSplittingOptions, MatchGroup, MatchGroupName,
+  ModuleType, OptimizationOption, OutputExports, OutputFormat, Platform, PluginTimingsOption,
   RawCompressOptions, RawMangleOptions, RawMinifyOptions, RawMinifyOptionsDetailed,
-  SanitizeFilename, StrictMode, TsConfig,
+  SanitizeFilename, StrictMode, TsConfig, WasmOptions, WorkerOptions,
 };
 use rolldown_common::DeferSyncScanData;
 use rolldown_common::GeneratedCodeOptions;
@@ -179,6 +179,43 @@ fn normalize_paths_option(
   })
 }
 
+fn normalize_interop_option(
+  option: Option<crate::options::InteropOutputOption>,
+) -> napi::Result<Option<rolldown_common::InteropOutputOption>> {
+  let parse = |value: &str| {
+    rolldown_common::InteropType::try_from(value)
+      .map_err(|err| napi::Error::new(napi::Status::InvalidArg, err))
+  };
+  option
+    .map(|value| {
+      Ok(match value {
+        Either3::A(value) => rolldown_common::InteropOutputOption::InteropType(parse(&value)?),
+        Either3::B(hash_map) => rolldown_common::InteropOutputOption::FxHashMap(
+          hash_map
+            .into_iter()
+            .map(|(id, value)| Ok((id, parse(&value)?)))
+            .collect::<napi::Result<_>>()?,
+        ),
+        Either3::C(func) => rolldown_common::InteropOutputOption::Fn(Arc::new(move |id| {
+          let func = Arc::clone(&func);
+          let id = id.to_string();
+          Box::pin(async move {
+            let value = func
+              .invoke_async((id,).into())
+              .await
+              .context("interop option")
+              .map_err(anyhow::Error::from)?;
+            value
+              .map(|value| rolldown_common::InteropType::try_from(value.as_str()))
+              .transpose()
+              .map_err(anyhow::Error::msg)
+          })
+        })),
+      })
+    })
+    .transpose()
+}
+
 fn napi_compress_options_to_raw_compress_options(
   o: &oxc_minify_napi::CompressOptions,
 ) -> Result<RawCompressOptions, String> {
@@ -442,6 +479,8 @@ fn normalize_code_splitting(
           })
           .transpose()?,
         include_dependencies_recursively: inner.include_dependencies_recursively,
+        max_initial_requests: inner.max_initial_requests,
+        max_async_requests: inner.max_async_requests,
       })
     })
     .transpose()?;
@@ -520,6 +559,14 @@ pub fn normalize_binding_options(
       output_options.chunk_file_names,
       "chunkFileNames option",
     )?,
+    css_entry_filenames: normalize_chunk_file_names_option(
+      output_options.css_entry_file_names,
+      "cssEntryFileNames option",
+    )?,
+    css_chunk_filenames: normalize_chunk_file_names_option(
+      output_options.css_chunk_file_names,
+      "cssChunkFileNames option",
+    )?,
     sanitize_filename: normalize_sanitize_filename(output_options.sanitize_file_name)?,
     dir: output_options.dir,
     file: output_options.file,
@@ -528,6 +575,15 @@ pub fn normalize_binding_options(
       Either::A(es_module_bool) => es_module_bool.into(),
       Either::B(es_module_string) => es_module_string.into(),
     }),
+    hoist_transitive_imports: output_options
+      .hoist_transitive_imports
+      .map(|value| match value {
+        Either::A(value) => Ok(HoistTransitiveImports::from(value)),
+        Either::B(value) => {
+          HoistTransitiveImports::try_from(value).map_err(napi::Error::from_reason)
+        }
+      })
+      .transpose()?,
     banner: normalize_addon_option(output_options.banner, "banner option"),
     footer: normalize_addon_option(output_options.footer, "footer option"),
     post_banner: normalize_addon_option(output_options.post_banner, "post_banner option"),
@@ -561,6 +617,8 @@ pub fn normalize_binding_options(
     sourcemap_ignore_list,
     sourcemap_path_transform,
     sourcemap_debug_ids: output_options.sourcemap_debug_ids,
+    sourcemap_scopes: output_options.sourcemap_scopes,
+    sourcemap_index_map: output_options.sourcemap_index_map,
     sourcemap_exclude_sources: output_options.sourcemap_exclude_sources,
     exports: output_options
       .exports
@@ -587,10 +645,12 @@ pub fn normalize_binding_options(
           "cjs" => OutputFormat::Cjs,
           "iife" => OutputFormat::Iife,
           "umd" => OutputFormat::Umd,
+          "amd" => OutputFormat::Amd,
+          "system" => OutputFormat::System,
           _ => {
             return Err(napi::Error::new(
               napi::Status::InvalidArg,
-              format!("Invalid value \"{format_str}\" for option \"output.format\" - valid values are \"es\", \"cjs\", \"iife\", and \"umd\"."),
+              format!("Invalid value \"{format_str}\" for option \"output.format\" - valid values are \"es\", \"cjs\", \"iife\", \"umd\", \"amd\", and \"system\"."),
             ));
           }
         })
@@ -614,6 +674,7 @@ pub fn normalize_binding_options(
       .transpose()?,
     globals: normalize_globals_option(output_options.globals),
     paths: normalize_paths_option(output_options.paths),
+    interop: normalize_interop_option(output_options.interop)?,
     generated_code: output_options
       .generated_code
       .map(normalize_generated_code_option)
@@ -675,6 +736,7 @@ pub fn normalize_binding_options(
       })
       .transpose()?,
     extend: output_options.extend,
+    amd: output_options.amd.map(|amd| AmdOptions { id: amd.id, define: amd.define }),
     define: input_options.define.map(FxIndexMap::from_iter),
     inject: input_options
       .inject
@@ -693,6 +755,9 @@ pub fn normalize_binding_options(
       .map(|inner| match inner.as_str() {
         "none" => Ok(rolldown::LegalComments::None),
         "inline" => Ok(rolldown::LegalComments::Inline),
+        "eof" => Ok(rolldown::LegalComments::Eof),
+        "linked" => Ok(rolldown::LegalComments::Linked),
+        "external" => Ok(rolldown::LegalComments::External),
         _ => Err(napi::Error::new(
           napi::Status::GenericFailure,
           format!("Invalid value for `legalComments` option: {inner}"),
@@ -731,6 +796,19 @@ pub fn normalize_binding_options(
     top_level_var: output_options.top_level_var,
     minify_internal_exports: output_options.minify_internal_exports,
     clean_dir: output_options.clean_dir,
+    metafile: output_options.metafile,
+    integrity: output_options
+      .integrity
+      .map(|inner| match inner.as_str() {
+        "sha256" => Ok(rolldown::IntegrityAlgorithm::Sha256),
+        "sha384" => Ok(rolldown::IntegrityAlgorithm::Sha384),
+        "sha512" => Ok(rolldown::IntegrityAlgorithm::Sha512),
+        _ => Err(napi::Error::new(
+          napi::Status::GenericFailure,
+          format!("Invalid value for `integrity` option: {inner}"),
+        )),
+      })
+      .transpose()?,
     strict_execution_order: output_options.strict_execution_order,
     strict: output_options
       .strict
@@ -746,7 +824,31 @@ pub fn normalize_binding_options(
     tsconfig: input_options.tsconfig.map(|v| match v {
       Either::A(v) => TsConfig::Auto(v),
       Either::B(s) => TsConfig::Manual(s.into()),
-    })
+    }),
+    worker: input_options
+      .worker
+      .map(|worker| {
+        let format = worker
+          .format
+          .map(|format_str| {
+            Ok(match format_str.as_str() {
+              "es" => OutputFormat::Esm,
+              "cjs" => OutputFormat::Cjs,
+              "iife" => OutputFormat::Iife,
+              "umd" => OutputFormat::Umd,
+              _ => {
+                return Err(napi::Error::new(
+                  napi::Status::InvalidArg,
+                  format!("Invalid value \"{format_str}\" for option \"worker.format\" - valid values are \"es\", \"cjs\", \"iife\", and \"umd\"."),
+                ));
+              }
+            })
+          })
+          .transpose()?;
+        Ok::<_, napi::Error>(WorkerOptions { format })
+      })
+      .transpose()?,
+    wasm: input_options.wasm.map(|wasm| WasmOptions { embed: wasm.embed }),
   };
 
   #[cfg(not(target_family = "wasm"))]
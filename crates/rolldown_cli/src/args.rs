@@ -0,0 +1,93 @@
+use std::path::PathBuf;
+
+pub const HELP: &str = "\
+Usage: rolldown [options]
+
+Options:
+  -c, --config <path>  Use this config file (defaults to rolldown.config.{json,yaml,yml} in the current directory)
+  -w, --watch          Rebuild when the input files change
+      --color          Force colored diagnostics
+      --no-color       Disable colored diagnostics
+  -h, --help           Show this help message
+  -v, --version        Show the version number";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CliArgs {
+  pub config: Option<PathBuf>,
+  pub watch: bool,
+  /// `None` means auto-detect based on whether stderr is a terminal.
+  pub color: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CliCommand {
+  Run(CliArgs),
+  Help,
+  Version,
+}
+
+impl CliCommand {
+  pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+    let mut cli_args = CliArgs::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+      match arg.as_str() {
+        "-h" | "--help" => return Ok(Self::Help),
+        "-v" | "--version" => return Ok(Self::Version),
+        "-w" | "--watch" => cli_args.watch = true,
+        "--color" => cli_args.color = Some(true),
+        "--no-color" => cli_args.color = Some(false),
+        "-c" | "--config" => {
+          let path = args.next().ok_or_else(|| format!("Missing value for `{arg}`"))?;
+          cli_args.config = Some(path.into());
+        }
+        _ => {
+          if let Some(path) = arg.strip_prefix("--config=") {
+            cli_args.config = Some(path.into());
+          } else {
+            return Err(format!("Unknown argument `{arg}`"));
+          }
+        }
+      }
+    }
+    Ok(Self::Run(cli_args))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(args: &[&str]) -> Result<CliCommand, String> {
+    CliCommand::parse(args.iter().map(ToString::to_string))
+  }
+
+  #[test]
+  fn test_parse_defaults() {
+    assert_eq!(parse(&[]), Ok(CliCommand::Run(CliArgs::default())));
+  }
+
+  #[test]
+  fn test_parse_config_and_watch() {
+    let expected = || {
+      CliCommand::Run(CliArgs {
+        config: Some("build/rolldown.yaml".into()),
+        watch: true,
+        color: Some(false),
+      })
+    };
+    assert_eq!(parse(&["-c", "build/rolldown.yaml", "--watch", "--no-color"]), Ok(expected()));
+    assert_eq!(parse(&["--config=build/rolldown.yaml", "-w", "--no-color"]), Ok(expected()));
+  }
+
+  #[test]
+  fn test_parse_errors() {
+    assert_eq!(parse(&["--config"]), Err("Missing value for `--config`".to_string()));
+    assert_eq!(parse(&["--minify"]), Err("Unknown argument `--minify`".to_string()));
+  }
+
+  #[test]
+  fn test_parse_help_wins() {
+    assert_eq!(parse(&["--watch", "--help"]), Ok(CliCommand::Help));
+  }
+}
@@ -0,0 +1,137 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use rolldown::BundlerOptions;
+
+/// Looked up in the current directory when no `--config` is given, in this order.
+pub const DEFAULT_CONFIG_FILES: [&str; 3] =
+  ["rolldown.config.json", "rolldown.config.yaml", "rolldown.config.yml"];
+
+pub fn find_default_config(dir: &Path) -> Option<PathBuf> {
+  DEFAULT_CONFIG_FILES.iter().map(|name| dir.join(name)).find(|path| path.is_file())
+}
+
+/// Loads a config file containing either a single `BundlerOptions` object or an array of them.
+///
+/// The format is picked from the extension: `.yaml` and `.yml` are parsed as YAML, everything
+/// else as JSON with comments. A missing or relative `cwd` is resolved against the directory of
+/// the config file, so the build doesn't depend on where the CLI is invoked from.
+///
+/// Like Rollup, `input` may also be a string, an array of strings or an object mapping entry
+/// names to modules.
+pub fn load_config(config_path: &Path) -> anyhow::Result<Vec<BundlerOptions>> {
+  let mut content = std::fs::read_to_string(config_path)
+    .with_context(|| format!("Failed to read config file {}", config_path.display()))?;
+
+  let is_yaml = config_path
+    .extension()
+    .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"));
+  let value: serde_json::Value = if is_yaml {
+    serde_yaml::from_str(&content)
+      .with_context(|| format!("Failed to parse YAML config file {}", config_path.display()))?
+  } else {
+    json_strip_comments::strip(&mut content)
+      .with_context(|| format!("Failed to strip comments of {}", config_path.display()))?;
+    serde_json::from_str(&content)
+      .with_context(|| format!("Failed to parse JSON config file {}", config_path.display()))?
+  };
+
+  let config_dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+  let values = match value {
+    serde_json::Value::Array(values) => values,
+    value => vec![value],
+  };
+  values
+    .into_iter()
+    .enumerate()
+    .map(|(index, mut value)| {
+      normalize_input(&mut value);
+      let mut options: BundlerOptions = serde_json::from_value(value).with_context(|| {
+        format!("Invalid options at index {index} of config file {}", config_path.display())
+      })?;
+      options.cwd = Some(match options.cwd.take() {
+        Some(cwd) if cwd.is_absolute() => cwd,
+        Some(cwd) => config_dir.join(cwd),
+        None => config_dir.to_path_buf(),
+      });
+      Ok(options)
+    })
+    .collect()
+}
+
+/// Rewrites the shorthand forms of `input` into the `{ name, import }` items `BundlerOptions` expects.
+fn normalize_input(value: &mut serde_json::Value) {
+  use serde_json::{Value, json};
+
+  let Some(input) = value.get_mut("input") else {
+    return;
+  };
+  *input = match input.take() {
+    Value::String(import) => json!([{ "import": import }]),
+    Value::Array(items) => Value::Array(
+      items
+        .into_iter()
+        .map(|item| match item {
+          Value::String(import) => json!({ "import": import }),
+          item => item,
+        })
+        .collect(),
+    ),
+    Value::Object(entries) => Value::Array(
+      entries.into_iter().map(|(name, import)| json!({ "name": name, "import": import })).collect(),
+    ),
+    input => input,
+  };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn write_config(name: &str, content: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rolldown-cli-config-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::write(&path, content).unwrap();
+    path
+  }
+
+  #[test]
+  fn test_load_json_config_with_comments() {
+    let path = write_config(
+      "single.json",
+      r#"{
+        // Entry of the app
+        "input": ["./src/main.js"],
+        "dir": "dist"
+      }"#,
+    );
+    let options = load_config(&path).unwrap();
+    assert_eq!(options.len(), 1);
+    assert_eq!(options[0].dir.as_deref(), Some("dist"));
+    assert_eq!(options[0].input.as_ref().unwrap()[0].import, "./src/main.js");
+    assert_eq!(options[0].cwd.as_deref(), path.parent());
+  }
+
+  #[test]
+  fn test_load_yaml_config_with_multiple_builds() {
+    let path = write_config(
+      "multiple.yaml",
+      "- input: ./a.js\n  cwd: packages/a\n- input:\n    b: ./b.js\n  format: cjs\n",
+    );
+    let options = load_config(&path).unwrap();
+    let config_dir = path.parent().unwrap();
+    assert_eq!(options.len(), 2);
+    assert_eq!(options[0].cwd, Some(config_dir.join("packages/a")));
+    assert_eq!(options[1].cwd.as_deref(), Some(config_dir));
+    let input = options[1].input.as_ref().unwrap();
+    assert_eq!((input[0].name.as_deref(), input[0].import.as_str()), (Some("b"), "./b.js"));
+  }
+
+  #[test]
+  fn test_load_config_rejects_unknown_options() {
+    let path = write_config("unknown.json", r#"{ "inputs": ["./main.js"] }"#);
+    let err = load_config(&path).unwrap_err();
+    assert!(format!("{err:#}").contains("unknown field `inputs`"));
+  }
+}
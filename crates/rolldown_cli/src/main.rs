@@ -0,0 +1,119 @@
+#![expect(clippy::print_stdout, clippy::print_stderr, reason = "The CLI reports to the terminal")]
+
+mod args;
+mod config;
+mod reporter;
+mod watch;
+
+use std::{io::IsTerminal, process::ExitCode, time::Instant};
+
+use rolldown::{Bundler, BundlerOptions};
+
+use crate::{
+  args::{CliArgs, CliCommand, HELP},
+  reporter::Reporter,
+};
+
+/// The build finished with errors.
+const EXIT_BUILD_FAILED: u8 = 1;
+/// The CLI arguments or the config file are invalid.
+const EXIT_INVALID_CONFIG: u8 = 2;
+
+#[tokio::main]
+async fn main() -> ExitCode {
+  let args = match CliCommand::parse(std::env::args().skip(1)) {
+    Ok(CliCommand::Run(args)) => args,
+    Ok(CliCommand::Help) => {
+      println!("{HELP}");
+      return ExitCode::SUCCESS;
+    }
+    Ok(CliCommand::Version) => {
+      println!("rolldown v{}", env!("CARGO_PKG_VERSION"));
+      return ExitCode::SUCCESS;
+    }
+    Err(message) => {
+      eprintln!("{message}\n\n{HELP}");
+      return ExitCode::from(EXIT_INVALID_CONFIG);
+    }
+  };
+
+  let reporter = Reporter {
+    color: args
+      .color
+      .unwrap_or_else(|| std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none()),
+  };
+
+  let options = match load_options(&args) {
+    Ok(options) => options,
+    Err(err) => {
+      eprintln!("{err:#}");
+      return ExitCode::from(EXIT_INVALID_CONFIG);
+    }
+  };
+
+  if args.watch {
+    watch::watch(options, reporter).await
+  } else {
+    Box::pin(build(options, reporter)).await
+  }
+}
+
+fn load_options(args: &CliArgs) -> anyhow::Result<Vec<BundlerOptions>> {
+  let cwd = std::env::current_dir()?;
+  let config_path = match &args.config {
+    Some(path) => std::path::absolute(path)?,
+    None => config::find_default_config(&cwd).ok_or_else(|| {
+      anyhow::format_err!(
+        "No config file found in {}. Expected one of {} or pass `--config <path>`.",
+        cwd.display(),
+        config::DEFAULT_CONFIG_FILES.join(", ")
+      )
+    })?,
+  };
+  config::load_config(&config_path)
+}
+
+/// Builds every config in order and writes the output to disk.
+async fn build(options: Vec<BundlerOptions>, reporter: Reporter) -> ExitCode {
+  let mut failed = false;
+  for options in options {
+    if !build_one(options, reporter).await {
+      failed = true;
+    }
+  }
+  if failed { ExitCode::from(EXIT_BUILD_FAILED) } else { ExitCode::SUCCESS }
+}
+
+async fn build_one(options: BundlerOptions, reporter: Reporter) -> bool {
+  let start = Instant::now();
+  let fallback_cwd = options.cwd.clone().unwrap_or_default();
+  let mut bundler = match Bundler::new(options) {
+    Ok(bundler) => bundler,
+    Err(errs) => {
+      reporter.diagnostics(&errs.into_vec(), &fallback_cwd);
+      return false;
+    }
+  };
+  let cwd = bundler.options().cwd.clone();
+  let out_dir = cwd.join(&bundler.options().out_dir);
+
+  let written = match bundler.write().await {
+    Ok(output) => {
+      reporter.diagnostics(&output.warnings, &cwd);
+      reporter.outputs(&out_dir, &output.assets, &cwd, start.elapsed());
+      true
+    }
+    Err(errs) => {
+      reporter.diagnostics(&errs.into_vec(), &cwd);
+      false
+    }
+  };
+  // `closeBundle` runs even for failed builds, like in Rollup.
+  match bundler.close().await {
+    Ok(()) => written,
+    Err(errs) => {
+      reporter.diagnostics(&errs.into_vec(), &cwd);
+      false
+    }
+  }
+}
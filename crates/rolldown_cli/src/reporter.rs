@@ -0,0 +1,36 @@
+use std::{path::Path, time::Duration};
+
+use rolldown_common::Output;
+use rolldown_error::{BuildDiagnostic, DiagnosticOptions};
+
+/// Writes build results and diagnostics to the terminal.
+#[derive(Debug, Clone, Copy)]
+pub struct Reporter {
+  pub color: bool,
+}
+
+impl Reporter {
+  pub fn diagnostics(self, diagnostics: &[BuildDiagnostic], cwd: &Path) {
+    let opts = DiagnosticOptions { cwd: cwd.to_path_buf() };
+    for diagnostic in diagnostics {
+      eprintln!("{}", diagnostic.to_diagnostic_with(&opts).convert_to_string(self.color));
+    }
+  }
+
+  pub fn outputs(self, out_dir: &Path, outputs: &[Output], cwd: &Path, elapsed: Duration) {
+    let out_dir = out_dir.strip_prefix(cwd).unwrap_or(out_dir);
+    for output in outputs {
+      println!(
+        "{:<40} {}",
+        out_dir.join(output.filename()).display(),
+        format_size(output.content_as_bytes().len())
+      );
+    }
+    println!("Finished in {}ms", elapsed.as_millis());
+  }
+}
+
+#[expect(clippy::cast_precision_loss)]
+fn format_size(bytes: usize) -> String {
+  if bytes < 1000 { format!("{bytes} B") } else { format!("{:.2} kB", bytes as f64 / 1000.0) }
+}
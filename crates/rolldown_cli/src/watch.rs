@@ -0,0 +1,78 @@
+use std::{process::ExitCode, sync::Arc};
+
+use rolldown::{BundlerConfig, BundlerOptions};
+use rolldown_common::{LogLevel, OnLog, WatcherChangeKind};
+use rolldown_watcher::{WatchEvent, Watcher, WatcherConfig, WatcherEventHandler};
+
+use crate::{EXIT_BUILD_FAILED, reporter::Reporter};
+
+struct CliWatcherEventHandler {
+  reporter: Reporter,
+}
+
+impl WatcherEventHandler for CliWatcherEventHandler {
+  async fn on_event(&self, event: WatchEvent) {
+    match event {
+      WatchEvent::BundleEnd(data) => println!("Built {} in {}ms", data.output, data.duration),
+      WatchEvent::Error(data) => self.reporter.diagnostics(&data.diagnostics, &data.cwd),
+      WatchEvent::End => println!("Waiting for changes..."),
+      WatchEvent::Start | WatchEvent::BundleStart(_) => {}
+    }
+  }
+
+  async fn on_change(&self, path: &str, kind: WatcherChangeKind) {
+    println!("{kind}: {path}");
+  }
+
+  async fn on_restart(&self) {}
+
+  async fn on_close(&self) {}
+}
+
+/// Rebuilds on file changes until the process receives Ctrl+C.
+///
+/// Failed rebuilds are reported but don't stop watching, so the exit code only reflects whether
+/// the watcher could be started.
+pub async fn watch(options: Vec<BundlerOptions>, reporter: Reporter) -> ExitCode {
+  let configs = options
+    .into_iter()
+    .map(|mut options| {
+      // Watch builds don't return their warnings, they are only reported through `onLog`.
+      options.on_log.get_or_insert_with(|| {
+        OnLog::new(Arc::new(|level, log| {
+          Box::pin(async move {
+            if level != LogLevel::Debug {
+              let code = log.code.map(|code| format!("[{code}] ")).unwrap_or_default();
+              eprintln!("{code}{}", log.message);
+            }
+            Ok(())
+          })
+        }))
+      });
+      BundlerConfig::new(options, vec![])
+    })
+    .collect();
+
+  let watcher =
+    match Watcher::new(configs, CliWatcherEventHandler { reporter }, &WatcherConfig::default()) {
+      Ok(watcher) => watcher,
+      Err(errs) => {
+        let cwd = std::env::current_dir().unwrap_or_default();
+        reporter.diagnostics(&errs.into_vec(), &cwd);
+        return ExitCode::from(EXIT_BUILD_FAILED);
+      }
+    };
+  watcher.run();
+
+  tokio::select! {
+    _ = tokio::signal::ctrl_c() => {}
+    () = watcher.wait_for_close() => {}
+  }
+  match watcher.close().await {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(err) => {
+      eprintln!("{err:#}");
+      ExitCode::from(EXIT_BUILD_FAILED)
+    }
+  }
+}
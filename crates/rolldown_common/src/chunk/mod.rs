@@ -79,6 +79,9 @@ pub struct Chunk {
   pub preliminary_filename: Option<PreliminaryFilename>,
   pub preliminary_sourcemap_filename: Option<PreliminaryFilename>,
   pub absolute_preliminary_filename: Option<String>,
+  /// Only set for chunks that contain CSS modules, see [`crate::CssView`].
+  pub css_preliminary_filename: Option<PreliminaryFilename>,
+  pub absolute_css_preliminary_filename: Option<String>,
   pub canonical_names: FxHashMap<SymbolRef, CompactStr>,
   /// For mixed-mode externals: maps external `namespace_ref` to the node-mode binding name.
   /// Only populated when an external has both ESM and non-ESM importers needing interop.
@@ -86,6 +89,9 @@ pub struct Chunk {
   // Sorted by Chunk#exec_order of the imported chunks
   pub cross_chunk_imports: Vec<ChunkIdx>,
   pub cross_chunk_dynamic_imports: Vec<ChunkIdx>,
+  /// Chunks only imported by the dependencies of this entry chunk, sorted by `Chunk#exec_order`.
+  /// Only populated when `output.hoistTransitiveImports` is enabled.
+  pub transitive_imports: Vec<ChunkIdx>,
   pub dynamic_imports_from_external_modules: Vec<ModuleIdx>,
   pub bits: BitSet,
   pub imports_from_other_chunks: FxIndexMap<ChunkIdx, Vec<CrossChunkImportItem>>,
@@ -230,6 +236,66 @@ impl Chunk {
 
     Ok(PreliminaryFilename::new(name, hash_placeholder))
   }
+  pub async fn generate_css_preliminary_filename(
+    &self,
+    options: &NormalizedBundlerOptions,
+    rollup_pre_rendered_chunk: &RollupPreRenderedChunk,
+    chunk_name: &ArcStr,
+    hash_placeholder_generator: &mut HashPlaceholderGenerator,
+    used_name_counts: &FxDashMap<ArcStr, u32>,
+  ) -> anyhow::Result<PreliminaryFilename> {
+    // The stylesheet sits next to its js chunk when the js filename is fixed.
+    if let Some(file) = options.file.as_ref().map(PathBuf::from) {
+      let basename = file
+        .with_extension("css")
+        .file_name()
+        .expect("The file should have basename")
+        .to_string_lossy()
+        .to_string();
+      return Ok(PreliminaryFilename::new(basename.into(), None));
+    }
+    if let Some(file_name) = &self.file_name {
+      let filename = PathBuf::from(file_name.as_str()).with_extension("css");
+      return Ok(PreliminaryFilename::new(path_buf_to_slash(filename).into(), None));
+    }
+
+    let is_entry = matches!(self.kind, ChunkKind::EntryPoint { meta, .. } if meta.contains(ChunkMeta::UserDefinedEntry) && !meta.contains(ChunkMeta::EmittedChunk))
+      || options.preserve_modules;
+    let filename_template = if is_entry {
+      FilenameTemplate::new(
+        options.css_entry_filenames.call(rollup_pre_rendered_chunk).await?,
+        "output.cssEntryFileNames",
+      )
+    } else {
+      FilenameTemplate::new(
+        options.css_chunk_filenames.call(rollup_pre_rendered_chunk).await?,
+        "output.cssChunkFileNames",
+      )
+    };
+    let has_hash_pattern = filename_template.has_hash_pattern();
+
+    let mut hash_placeholder = has_hash_pattern.then_some(vec![]);
+    let hash_replacer = has_hash_pattern.then(|| {
+      let pattern_name = filename_template.pattern_name();
+      |len: Option<usize>| {
+        let hash = hash_placeholder_generator.generate(len, pattern_name)?;
+        if let Some(hash_placeholder) = hash_placeholder.as_mut() {
+          hash_placeholder.push(hash.clone());
+        }
+        Ok(hash)
+      }
+    });
+    let chunk_name = self.get_preserve_modules_chunk_name(options, chunk_name.as_str());
+
+    let filename = filename_template
+      .render(Some(&chunk_name), Some(options.format.as_str()), None, None, hash_replacer)?
+      .into();
+
+    let name = make_unique_name(&filename, used_name_counts);
+
+    Ok(PreliminaryFilename::new(name, hash_placeholder))
+  }
+
   pub async fn generate_preliminary_sourcemap_filename(
     &self,
     options: &NormalizedBundlerOptions,
@@ -319,6 +385,14 @@ impl Chunk {
     Cow::Owned(p)
   }
 
+  /// Whether this chunk contains CSS modules and therefore emits a stylesheet.
+  pub fn has_css_modules(&self, module_table: &ModuleTable) -> bool {
+    self
+      .modules
+      .iter()
+      .any(|&idx| module_table[idx].as_normal().is_some_and(|module| module.css_view.is_some()))
+  }
+
   pub fn user_defined_entry_module_idx(&self) -> Option<ModuleIdx> {
     match &self.kind {
       ChunkKind::EntryPoint { module, meta, .. } if meta.contains(ChunkMeta::UserDefinedEntry) => {
@@ -7,6 +7,8 @@ pub enum ChunkReasonType {
   Entry,
   #[default]
   Common,
+  /// A common chunk recreated from `experimental.recordsPath`.
+  ChunkRecord,
 }
 
 impl std::fmt::Display for ChunkReasonType {
@@ -29,6 +31,7 @@ impl ChunkReasonType {
       ChunkReasonType::PreserveModules => "preserve-modules",
       ChunkReasonType::Entry => "entry",
       ChunkReasonType::Common => "common",
+      ChunkReasonType::ChunkRecord => "chunk-record",
     }
   }
 }
@@ -0,0 +1,96 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+use oxc_index::IndexVec;
+use oxc_str::CompactStr;
+use rustc_hash::FxHashMap;
+use string_wizard::MagicString;
+
+use crate::{ArcSourceMutation, ImportRecordIdx, ResolvedImportRecord, SourceMutation};
+
+/// The stylesheet side of a module whose type is [`crate::ModuleType::Css`].
+///
+/// A CSS module is still a [`crate::NormalModule`] so it can be imported from JavaScript and placed
+/// into chunks like any other module. Its `EcmaView` is an empty program, while the actual
+/// stylesheet, and the `@import`/`url()` dependencies found in it, live here.
+#[derive(Debug, Clone)]
+pub struct CssView {
+  pub source: ArcStr,
+  /// Records created from `@import` rules (`ImportKind::AtImport`) and `url()` tokens (`ImportKind::UrlImport`).
+  ///
+  /// The `importer_span` of an `@import` record covers the whole rule including the trailing `;`,
+  /// while for `url()` it covers the token to be replaced, either `url(...)` or the quoted string.
+  pub import_records: IndexVec<ImportRecordIdx, ResolvedImportRecord>,
+  /// `layer(..)`, `supports(..)` and media query conditions of conditional `@import` rules.
+  pub import_conditions: FxHashMap<ImportRecordIdx, CssImportConditions>,
+  /// `@import` rules that point to absolute urls, such as `@import "https://example.com/font.css";`.
+  /// They are kept as-is and hoisted to the top of the output stylesheet.
+  pub external_at_imports: Vec<Span>,
+  /// Static edits that don't depend on the output, such as removing `@import` and `@charset` rules.
+  pub mutations: Vec<ArcSourceMutation>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CssImportConditions {
+  /// `Some("")` for an anonymous `layer`.
+  pub layer: Option<CompactStr>,
+  pub supports: Option<CompactStr>,
+  pub media: Option<CompactStr>,
+}
+
+impl CssImportConditions {
+  pub fn is_empty(&self) -> bool {
+    self.layer.is_none() && self.supports.is_none() && self.media.is_none()
+  }
+
+  /// Wraps `content` with the at-rules that an `@import` with these conditions implies.
+  ///
+  /// `@import "a.css" layer(base) supports(display: grid) screen;` is equivalent to
+  /// `@media screen { @supports (display: grid) { @layer base { ... } } }`.
+  pub fn wrap(&self, content: &str) -> String {
+    let mut opening = String::new();
+    let mut closing = String::new();
+    if let Some(media) = &self.media {
+      opening.push_str("@media ");
+      opening.push_str(media.trim());
+      opening.push_str(" {\n");
+      closing.push_str("}\n");
+    }
+    if let Some(supports) = &self.supports {
+      opening.push_str("@supports (");
+      opening.push_str(supports.trim());
+      opening.push_str(") {\n");
+      closing.push_str("}\n");
+    }
+    if let Some(layer) = &self.layer {
+      if layer.is_empty() {
+        opening.push_str("@layer {\n");
+      } else {
+        opening.push_str("@layer ");
+        opening.push_str(layer.trim());
+        opening.push_str(" {\n");
+      }
+      closing.push_str("}\n");
+    }
+    let mut ret = opening;
+    ret.push_str(content);
+    if !content.ends_with('\n') {
+      ret.push('\n');
+    }
+    ret.push_str(&closing);
+    ret
+  }
+}
+
+/// Removes a range of the stylesheet, used for rules that are hoisted or inlined elsewhere.
+#[derive(Debug)]
+pub struct RemoveCssRange {
+  pub span: Span,
+}
+
+impl SourceMutation for RemoveCssRange {
+  fn apply(&self, magic_string: &mut MagicString<'_>) {
+    magic_string
+      .remove(self.span.start, self.span.end)
+      .expect("the removed range should be within the stylesheet");
+  }
+}
@@ -0,0 +1 @@
+pub mod css_view;
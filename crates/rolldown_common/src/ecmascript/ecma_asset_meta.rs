@@ -12,7 +12,12 @@ pub struct EcmaAssetMeta {
   // The updated fields of rendered_chunk after the final render
   pub imports: Vec<ArcStr>,
   pub dynamic_imports: Vec<ArcStr>,
+  pub transitive_imports: Vec<ArcStr>,
   pub sourcemap_filename: Option<String>,
+  /// The encoded `scopes` field of the sourcemap, see `output.sourcemapScopes`.
+  pub sourcemap_scopes: Option<String>,
   pub file_dir: PathBuf,
   pub preliminary_filename: PreliminaryFilename,
+  /// Deduplicated legal comments to write to the chunk's `.LEGAL.txt` file.
+  pub legal_comments: Vec<String>,
 }
@@ -7,6 +7,7 @@ use oxc::{
 };
 use oxc_index::IndexVec;
 use oxc_str::CompactStr;
+use rolldown_sourcemap::SourceScopes;
 use rolldown_utils::indexmap::{FxIndexMap, FxIndexSet};
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -30,6 +31,9 @@ bitflags! {
         const TopExportedSideEffectsFreeFunction = 1 << 5;
         /// Module evaluation reads at least one imported binding.
         const TopLevelImportRead = 1 << 6;
+        /// A CommonJS module that `optimization.commonjsToEsm` rewrites to ESM bindings. Set by the
+        /// scanner on every candidate and cleared in the link stage on the ones that stay CommonJS.
+        const CommonjsToEsm = 1 << 7;
     }
 }
 
@@ -113,6 +117,9 @@ impl EcmaView {
 #[derive(Debug, Clone)]
 pub struct EcmaView {
   pub dummy_record_set: FxHashSet<NodeId>,
+  /// `__glob` callees generated for glob `import()` / `require()` calls, which are bound to
+  /// the runtime helper.
+  pub glob_callee_set: FxHashSet<NodeId>,
   pub source: ArcStr,
   pub def_format: ModuleDefFormat,
   /// Represents [Module Namespace Object](https://tc39.es/ecma262/#sec-module-namespace-exotic-objects)
@@ -138,6 +145,8 @@ pub struct EcmaView {
   pub dynamic_importers_idx: FxIndexSet<ModuleIdx>,
   // the module ids statically imported by this module
   pub imported_ids: FxIndexSet<ModuleId>,
+  /// `./foo` in `import { foo } from './foo'` -> its resolved id, for the imports in `imported_ids`
+  pub imported_ids_by_specifier: FxHashMap<CompactStr, ModuleId>,
   // the module ids imported by this module via dynamic import()
   pub dynamically_imported_ids: FxIndexSet<ModuleId>,
   pub side_effects: DeterminedSideEffects,
@@ -150,6 +159,9 @@ pub struct EcmaView {
   pub mutations: Vec<ArcSourceMutation>,
   /// `NodeId` of `new URL('path', import.meta.url)` -> `ImportRecordIdx`
   pub new_url_references: FxHashMap<NodeId, ImportRecordIdx>,
+  /// Import records marked with `ImportRecordMeta::IsWorker` -> resolved id of the worker entry.
+  /// Worker entries are bundled separately, so these records never resolve to a module.
+  pub worker_references: FxHashMap<ImportRecordIdx, ModuleId>,
   /// Occurrences of `import.meta.ROLLDOWN_FILE_URL_<referenceId>[_<urlId>]`, in source order.
   /// One entry per occurrence: the `resolveFileUrl` hook is called per occurrence,
   /// matching Rollup, so duplicates are meaningful.
@@ -168,6 +180,13 @@ pub struct EcmaView {
   pub json_module_none_self_reference_included_symbol: Option<Box<FxHashSet<SymbolRef>>>,
   /// Import record indices for `module.exports = require(...)` patterns.
   pub cjs_reexport_import_record_ids: Vec<ImportRecordIdx>,
+  /// Facade exports for the keys of a `module.exports = { ... }` object literal. Only set on
+  /// candidates of `optimization.commonjsToEsm`, and merged into `named_exports` once the module is
+  /// converted.
+  pub commonjs_object_exports: Option<Box<FxHashMap<CompactStr, LocalExport>>>,
+  /// The scope tree emitted in the `scopes` field of sourcemaps. Only collected when
+  /// `output.sourcemapScopes` is enabled.
+  pub original_scopes: Option<Box<SourceScopes>>,
 }
 
 impl EcmaView {
@@ -17,7 +17,7 @@ use std::sync::Arc;
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct EmittedAsset {
   pub name: Option<String>,
   pub original_file_name: Option<String>,
@@ -352,6 +352,7 @@ impl FileEmitter {
         names: std::mem::take(&mut file.name).map_or(vec![], |name| vec![name]),
         original_file_names: std::mem::take(&mut file.original_file_name)
           .map_or(vec![], |original_file_name| vec![original_file_name]),
+        integrity: None,
       },
     );
     Ok(())
@@ -398,6 +399,7 @@ impl FileEmitter {
         names,
         original_file_names,
         source: std::mem::take(&mut value.source),
+        integrity: None,
       })));
     });
     // Sort to ensure deterministic output order regardless of DashMap iteration order
@@ -431,10 +433,13 @@ impl FileEmitter {
         modules: Modules { keys: vec![], values: vec![] },
         imports: vec![],
         dynamic_imports: vec![],
+        transitive_imports: vec![],
+        imported_css: vec![],
         code: value.code.clone(),
         map: value.map.clone(),
         sourcemap_filename: value.sourcemap_filename.clone(),
         preliminary_filename: value.file_name.to_string(),
+        integrity: None,
       })));
     });
   }
@@ -36,6 +36,9 @@ pub struct ChecksOptions {
   pub large_barrel_modules: Option<bool>,
   pub sourcemap_broken: Option<bool>,
   pub namespace_conflict: Option<bool>,
+  pub empty_glob_import: Option<bool>,
+  pub exceeded_max_requests: Option<bool>,
+  pub could_not_write_records: Option<bool>,
 }
 impl From<ChecksOptions> for rolldown_error::EventKindSwitcher {
   fn from(value: ChecksOptions) -> Self {
@@ -128,6 +131,18 @@ impl From<ChecksOptions> for rolldown_error::EventKindSwitcher {
       rolldown_error::EventKindSwitcher::NamespaceConflict,
       value.namespace_conflict.unwrap_or(true),
     );
+    flag.set(
+      rolldown_error::EventKindSwitcher::EmptyGlobImport,
+      value.empty_glob_import.unwrap_or(true),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::ExceededMaxRequests,
+      value.exceeded_max_requests.unwrap_or(true),
+    );
+    flag.set(
+      rolldown_error::EventKindSwitcher::CouldNotWriteRecords,
+      value.could_not_write_records.unwrap_or(true),
+    );
     flag
   }
 }
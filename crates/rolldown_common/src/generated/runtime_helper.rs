@@ -25,7 +25,9 @@ bitflags! {
     const ToCommonJs = 1 << 15;
     const ToBinaryNode = 1 << 16;
     const ToBinary = 1 << 17;
-    const Require = 1 << 18;
+    const ImportDefer = 1 << 18;
+    const Glob = 1 << 19;
+    const Require = 1 << 20;
   }
 }
 
@@ -72,7 +74,7 @@ impl DependedRuntimeHelperMap {
   }
 }
 
-pub const RUNTIME_HELPER_NAMES: [&str; 19] = [
+pub const RUNTIME_HELPER_NAMES: [&str; 21] = [
   "__create",
   "__defProp",
   "__name",
@@ -91,5 +93,7 @@ pub const RUNTIME_HELPER_NAMES: [&str; 19] = [
   "__toCommonJS",
   "__toBinaryNode",
   "__toBinary",
+  "__importDefer",
+  "__glob",
   "__require",
 ];
@@ -1,11 +1,13 @@
 use rolldown_utils::indexmap::FxIndexMap;
 use rustc_hash::FxHashMap;
 use std::{fmt::Debug, path::PathBuf};
+use types::amd_options::AmdOptions;
 use types::code_splitting_mode::CodeSplittingMode;
 use types::comments::CommentsOptions;
 use types::devtools_options::DevtoolsOptions;
 use types::generated_code_options::GeneratedCodeOptions;
 use types::inject_import::InjectImport;
+use types::integrity_algorithm::IntegrityAlgorithm;
 use types::invalidate_js_side_cache::InvalidateJsSideCache;
 use types::legal_comments::LegalComments;
 use types::log_level::LogLevel;
@@ -14,10 +16,13 @@ use types::minify_options::RawMinifyOptions;
 use types::on_log::OnLog;
 use types::optimization::OptimizationOption;
 use types::output_option::{
-  AssetFilenamesOutputOption, GlobalsOutputOption, PathsOutputOption, PreserveEntrySignatures,
+  AssetFilenamesOutputOption, GlobalsOutputOption, InteropOutputOption, PathsOutputOption,
+  PreserveEntrySignatures,
 };
 use types::sanitize_filename::SanitizeFilename;
+use types::wasm_options::WasmOptions;
 use types::watch_option::WatchOption;
+use types::worker_options::WorkerOptions;
 
 #[cfg(feature = "deserialize_bundler_options")]
 use schemars::JsonSchema;
@@ -28,13 +33,16 @@ use serde_json::Value;
 use types::experimental_options::ExperimentalOptions;
 #[cfg(feature = "deserialize_bundler_options")]
 use types::minify_options::SimpleMinifyOptions;
+#[cfg(feature = "deserialize_bundler_options")]
+use types::output_option::SimpleInteropOption;
 
 use self::types::treeshake::TreeshakeOptions;
 use self::types::{
   defer_sync_scan_data_option::DeferSyncScanDataOption, es_module_flag::EsModuleFlag,
-  hash_characters::HashCharacters, input_item::InputItem, is_external::IsExternal,
-  output_exports::OutputExports, output_format::OutputFormat, output_option::AddonOutputOption,
-  platform::Platform, plugin_timings_option::PluginTimingsOption, resolve_options::ResolveOptions,
+  hash_characters::HashCharacters, hoist_transitive_imports::HoistTransitiveImports,
+  input_item::InputItem, is_external::IsExternal, output_exports::OutputExports,
+  output_format::OutputFormat, output_option::AddonOutputOption, platform::Platform,
+  plugin_timings_option::PluginTimingsOption, resolve_options::ResolveOptions,
   source_map_type::SourceMapType, sourcemap_path_transform::SourceMapPathTransform,
   strict_mode::StrictMode, tsconfig::TsConfig,
 };
@@ -78,6 +86,18 @@ pub struct BundlerOptions {
     schemars(with = "Option<String>")
   )]
   pub chunk_filenames: Option<ChunkFilenamesOutputOption>,
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_chunk_filenames"),
+    schemars(with = "Option<String>")
+  )]
+  pub css_entry_filenames: Option<ChunkFilenamesOutputOption>,
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_chunk_filenames"),
+    schemars(with = "Option<String>")
+  )]
+  pub css_chunk_filenames: Option<ChunkFilenamesOutputOption>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, deserialize_with = "deserialize_asset_filenames"),
@@ -106,9 +126,24 @@ pub struct BundlerOptions {
     schemars(with = "Option<FxHashMap<String, String>>")
   )]
   pub paths: Option<PathsOutputOption>,
+  /// Controls how default and namespace imports of each external module are read from `require()`
+  /// in the cjs, iife, umd and amd formats. See [`InteropType`](crate::InteropType).
+  ///
+  /// - Defaults to picking between `auto` and `default` from the format of the importing module.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_interop"),
+    schemars(with = "Option<SimpleInteropOption>")
+  )]
+  pub interop: Option<InteropOutputOption>,
   pub generated_code: Option<GeneratedCodeOptions>,
   pub sourcemap: Option<SourceMapType>,
   pub es_module: Option<EsModuleFlag>,
+  /// Reference the chunks entry chunks only depend on transitively in the esm format. See
+  /// [`HoistTransitiveImports`](crate::HoistTransitiveImports).
+  ///
+  /// - Defaults to `import`, like rollup.
+  pub hoist_transitive_imports: Option<HoistTransitiveImports>,
   pub drop_labels: Option<Vec<String>>,
   pub hash_characters: Option<HashCharacters>,
   #[cfg_attr(
@@ -167,6 +202,14 @@ pub struct BundlerOptions {
   )]
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
   pub sourcemap_debug_ids: Option<bool>,
+  /// Emit the `scopes` field of the [ECMA-426 scopes proposal](https://github.com/tc39/ecma426/blob/main/proposals/scopes.md)
+  /// in sourcemaps, so original function and variable names can be recovered from generated code.
+  pub sourcemap_scopes: Option<bool>,
+  /// Emit an [index map](https://tc39.es/ecma426/#sec-index-source-map) with a section per joined
+  /// source instead of merging the sourcemaps of a chunk into one. Chunks whose maps have to be
+  /// combined with others, e.g. by `renderChunk` hooks, minification (including the default
+  /// dce-only pass) or `sourcemap_scopes`, are still emitted as regular sourcemaps.
+  pub sourcemap_index_map: Option<bool>,
   pub sourcemap_exclude_sources: Option<bool>,
 
   /// Key is the file extension. The extension should start with a `.`. E.g. `".txt"`.
@@ -191,6 +234,7 @@ pub struct BundlerOptions {
   )]
   pub define: Option<FxIndexMap<String, String>>,
   pub extend: Option<bool>,
+  pub amd: Option<AmdOptions>,
   pub profiler_names: Option<bool>,
   pub keep_names: Option<bool>,
   pub inject: Option<Vec<InjectImport>>,
@@ -246,10 +290,16 @@ pub struct BundlerOptions {
   pub top_level_var: Option<bool>,
   pub minify_internal_exports: Option<bool>,
   pub clean_dir: Option<bool>,
+  /// Attach an esbuild-compatible metafile to the bundle output.
+  pub metafile: Option<bool>,
+  /// Attach a Subresource Integrity digest of the final bytes to each output.
+  pub integrity: Option<IntegrityAlgorithm>,
   pub context: Option<String>,
   pub tsconfig: Option<TsConfig>,
   pub strict_execution_order: Option<bool>,
   pub strict: Option<StrictMode>,
+  pub worker: Option<WorkerOptions>,
+  pub wasm: Option<WasmOptions>,
 }
 
 #[cfg(feature = "deserialize_bundler_options")]
@@ -321,6 +371,15 @@ where
   Ok(deserialized.map(From::from))
 }
 
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_interop<'de, D>(deserializer: D) -> Result<Option<InteropOutputOption>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<SimpleInteropOption>::deserialize(deserializer)?;
+  Ok(deserialized.map(From::from))
+}
+
 #[cfg(feature = "deserialize_bundler_options")]
 fn deserialize_minify<'de, D>(deserializer: D) -> Result<Option<RawMinifyOptions>, D::Error>
 where
@@ -0,0 +1,26 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Options for the `amd` output format. The AMD branch of `umd` output respects them as well.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct AmdOptions {
+  /// The id to register the module with, e.g. `define('my-lib', [...], factory)`. Anonymous
+  /// modules are emitted if it's not set.
+  pub id: Option<String>,
+  /// The function to call instead of `define`, e.g. `requirejs.define`.
+  pub define: Option<String>,
+}
+
+impl AmdOptions {
+  #[inline]
+  pub fn define(&self) -> &str {
+    self.define.as_deref().unwrap_or("define")
+  }
+}
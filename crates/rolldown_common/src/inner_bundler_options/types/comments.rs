@@ -32,7 +32,7 @@ impl Default for CommentsOptions {
 
 impl From<CommentsOptions> for PrintCommentsOptions {
   fn from(opts: CommentsOptions) -> Self {
-    Self { legal: opts.legal, annotation: opts.annotation, jsdoc: opts.jsdoc }
+    Self { legal: opts.legal, extract_legal: false, annotation: opts.annotation, jsdoc: opts.jsdoc }
   }
 }
 
@@ -7,6 +7,7 @@ use super::attach_debug_info::AttachDebugInfo;
 use super::chunk_import_map::ChunkImportMap;
 use super::chunk_modules_order::ChunkModulesOrderBy;
 use super::dev_mode_options::DevModeOptions;
+use super::precompress_options::PrecompressOptions;
 
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(
@@ -80,6 +81,24 @@ pub struct ExperimentalOptions {
   pub native_magic_string: Option<bool>,
   pub chunk_optimization: Option<ChunkOptimizationOption>,
   pub lazy_barrel: Option<bool>,
+  /// Directory, relative to `cwd`, where the results of the `load` and `transform` hooks are
+  /// persisted across builds and processes. Disabled when unset, or when a plugin with one of these
+  /// hooks has no `cache_key`.
+  pub persistent_cache_dir: Option<String>,
+  /// Record why each module and statement survived tree shaking. See
+  /// [`crate::TreeShakingProvenance`].
+  pub tree_shaking_provenance: Option<bool>,
+  /// File, relative to `cwd`, recording the common chunks of the previous build. Code splitting
+  /// recreates them where still valid and the file is rewritten after each build. See
+  /// [`crate::ChunkRecords`].
+  pub records_path: Option<String>,
+  /// Emit `.gz`, `.br` or `.zst` siblings of the outputs. See [`PrecompressOptions`].
+  pub precompress: Option<PrecompressOptions>,
+  /// Allow top-level await with the `cjs`, `iife`, `umd` and `amd` output formats. Modules that
+  /// contain top-level await, and everything their entry imports, are wrapped in async `init_*`
+  /// functions that the entry awaits in order. The entry exposes the promise of its wrapper as a
+  /// non-enumerable `__tla` export, or as its default export with `output.exports: 'default'`.
+  pub async_module_wrappers: Option<bool>,
 }
 
 impl ExperimentalOptions {
@@ -125,4 +144,12 @@ impl ExperimentalOptions {
   pub fn is_lazy_barrel_enabled(&self) -> bool {
     self.lazy_barrel.unwrap_or(false)
   }
+
+  pub fn is_tree_shaking_provenance_enabled(&self) -> bool {
+    self.tree_shaking_provenance.unwrap_or(false)
+  }
+
+  pub fn is_async_module_wrappers_enabled(&self) -> bool {
+    self.async_module_wrappers.unwrap_or(false)
+  }
 }
@@ -0,0 +1,53 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Whether entry chunks in the ESM format should reference the chunks they only depend on transitively.
+/// It is rollup-compatible, see [`output.hoistTransitiveImports`](https://rollupjs.org/configuration-options/#output-hoisttransitiveimports).
+///
+/// A browser only discovers the imports of a chunk once it has fetched it, so a deep chain of chunks is
+/// loaded one round-trip at a time. Knowing every static dependency of an entry chunk upfront lets them
+/// be fetched in parallel.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "kebab-case", deny_unknown_fields)
+)]
+pub enum HoistTransitiveImports {
+  /// Entry chunks only import the chunks they depend on directly.
+  Disabled,
+  /// Add an empty import, such as `import "./chunk.js";`, of each transitive dependency to entry chunks.
+  /// The hoisted chunks are also listed in `OutputChunk#imports`.
+  #[default]
+  Import,
+  /// Leave the code untouched and only list the transitive dependencies in `OutputChunk#transitive_imports`,
+  /// so they can be emitted as `<link rel="modulepreload">` tags.
+  #[cfg_attr(feature = "deserialize_bundler_options", serde(rename = "modulepreload"))]
+  ModulePreload,
+}
+
+impl HoistTransitiveImports {
+  #[inline]
+  pub fn is_enabled(self) -> bool {
+    !matches!(self, Self::Disabled)
+  }
+}
+
+impl From<bool> for HoistTransitiveImports {
+  fn from(value: bool) -> Self {
+    if value { Self::Import } else { Self::Disabled }
+  }
+}
+
+impl TryFrom<String> for HoistTransitiveImports {
+  type Error = String;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    match value.as_str() {
+      "modulepreload" => Ok(Self::ModulePreload),
+      _ => Err(format!("Unknown hoist transitive imports type: {value:?}")),
+    }
+  }
+}
@@ -0,0 +1,43 @@
+use std::fmt::Display;
+
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+/// The hash function of the [Subresource Integrity](https://www.w3.org/TR/SRI/) digests attached
+/// to the outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "kebab-case", deny_unknown_fields)
+)]
+pub enum IntegrityAlgorithm {
+  Sha256,
+  Sha384,
+  Sha512,
+}
+
+impl IntegrityAlgorithm {
+  /// Returns the integrity metadata of `content`, such as `sha384-<base64 digest>`.
+  pub fn digest(self, content: &[u8]) -> String {
+    let digest = match self {
+      Self::Sha256 => rolldown_utils::base64::to_standard_base64(Sha256::digest(content)),
+      Self::Sha384 => rolldown_utils::base64::to_standard_base64(Sha384::digest(content)),
+      Self::Sha512 => rolldown_utils::base64::to_standard_base64(Sha512::digest(content)),
+    };
+    format!("{self}-{digest}")
+  }
+}
+
+impl Display for IntegrityAlgorithm {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      IntegrityAlgorithm::Sha256 => write!(f, "sha256"),
+      IntegrityAlgorithm::Sha384 => write!(f, "sha384"),
+      IntegrityAlgorithm::Sha512 => write!(f, "sha512"),
+    }
+  }
+}
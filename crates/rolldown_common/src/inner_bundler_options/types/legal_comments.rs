@@ -5,7 +5,8 @@ use schemars::JsonSchema;
 #[cfg(feature = "deserialize_bundler_options")]
 use serde::Deserialize;
 
-#[derive(Debug, Clone, Copy)]
+/// See <https://esbuild.github.io/api/#legal-comments>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(
   feature = "deserialize_bundler_options",
   derive(Deserialize, JsonSchema),
@@ -16,6 +17,24 @@ pub enum LegalComments {
   None,
   /// Preserve all legal comments
   Inline,
+  /// Move all legal comments of a chunk to the end of the chunk
+  Eof,
+  /// Move all legal comments of a chunk to a `.LEGAL.txt` file and link to it with a comment
+  Linked,
+  /// Move all legal comments of a chunk to a `.LEGAL.txt` file without linking to it
+  External,
+}
+
+impl LegalComments {
+  /// Whether legal comments are collected per chunk instead of being printed in place.
+  pub fn is_extracted(self) -> bool {
+    matches!(self, Self::Eof | Self::Linked | Self::External)
+  }
+
+  /// Whether the collected legal comments are written to a separate `.LEGAL.txt` asset.
+  pub fn emits_legal_file(self) -> bool {
+    matches!(self, Self::Linked | Self::External)
+  }
 }
 
 impl Display for LegalComments {
@@ -23,6 +42,9 @@ impl Display for LegalComments {
     match self {
       LegalComments::None => write!(f, "none"),
       LegalComments::Inline => write!(f, "inline"),
+      LegalComments::Eof => write!(f, "eof"),
+      LegalComments::Linked => write!(f, "linked"),
+      LegalComments::External => write!(f, "external"),
     }
   }
 }
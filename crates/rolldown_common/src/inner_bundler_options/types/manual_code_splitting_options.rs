@@ -36,6 +36,11 @@ pub struct ManualCodeSplittingOptions {
   pub max_module_size: Option<f64>,
   pub include_dependencies_recursively: Option<bool>,
   pub groups: Option<Vec<MatchGroup>>,
+  /// Maximum number of chunks, the entry chunk included, that loading a user-defined entry
+  /// requests. Shared chunks are merged, least-shared first, until the limit is met.
+  pub max_initial_requests: Option<u32>,
+  /// Like `max_initial_requests`, but for the chunks a dynamic `import()` requests.
+  pub max_async_requests: Option<u32>,
 }
 
 #[derive(Default, Debug, Clone)]
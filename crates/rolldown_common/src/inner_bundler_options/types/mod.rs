@@ -1,3 +1,4 @@
+pub mod amd_options;
 pub mod attach_debug_info;
 pub mod chunk_import_map;
 pub mod chunk_modules_order;
@@ -11,8 +12,10 @@ pub mod experimental_options;
 pub mod filename_template;
 pub mod generated_code_options;
 pub mod hash_characters;
+pub mod hoist_transitive_imports;
 pub mod inject_import;
 pub mod input_item;
+pub mod integrity_algorithm;
 pub mod invalidate_js_side_cache;
 pub mod is_external;
 pub mod legal_comments;
@@ -29,6 +32,7 @@ pub mod output_format;
 pub mod output_option;
 pub mod platform;
 pub mod plugin_timings_option;
+pub mod precompress_options;
 pub mod resolve_options;
 pub mod sanitize_filename;
 pub mod source_map_type;
@@ -41,4 +45,6 @@ pub mod transform_options;
 pub mod treeshake;
 pub mod tsconfig;
 pub mod tsconfig_merge;
+pub mod wasm_options;
 pub mod watch_option;
+pub mod worker_options;
@@ -25,6 +25,8 @@ pub enum ModuleType {
   Css,
   Asset,
   Copy,
+  Wasm,
+  Html,
   Custom(String),
 }
 
@@ -44,6 +46,8 @@ impl ModuleType {
       "css" => Ok(Self::Css),
       "asset" => Ok(Self::Asset),
       "copy" => Ok(Self::Copy),
+      "wasm" => Ok(Self::Wasm),
+      "html" => Ok(Self::Html),
       _ => Err(anyhow::format_err!("Unknown module type: {s}")),
     }
   }
@@ -65,6 +69,8 @@ impl ModuleType {
       "css" => Self::Css,
       "asset" => Self::Asset,
       "copy" => Self::Copy,
+      "wasm" => Self::Wasm,
+      "html" => Self::Html,
       _ => Self::Custom(s.as_ref().to_string()),
     }
   }
@@ -86,6 +92,8 @@ impl Display for ModuleType {
       ModuleType::Css => write!(f, "css"),
       ModuleType::Asset => write!(f, "asset"),
       ModuleType::Copy => write!(f, "copy"),
+      ModuleType::Wasm => write!(f, "wasm"),
+      ModuleType::Html => write!(f, "html"),
       ModuleType::Custom(custom_type) => write!(f, "{custom_type}"),
     }
   }
@@ -10,20 +10,24 @@ use oxc::transformer_plugins::InjectGlobalVariablesConfig;
 use rolldown_error::EventKindSwitcher;
 use rustc_hash::{FxHashMap, FxHashSet};
 
+use super::amd_options::AmdOptions;
 use super::code_splitting_mode::CodeSplittingMode;
 use super::comments::CommentsOptions;
 use super::experimental_options::ExperimentalOptions;
 use super::generated_code_options::GeneratedCodeOptions;
+use super::integrity_algorithm::IntegrityAlgorithm;
 use super::legal_comments::LegalComments;
 use super::manual_code_splitting_options::ManualCodeSplittingOptions;
 use super::minify_options::MinifyOptions;
 use super::output_option::{
-  AssetFilenamesOutputOption, ChunkFilenamesOutputOption, PathsOutputOption,
+  AssetFilenamesOutputOption, ChunkFilenamesOutputOption, InteropOutputOption, PathsOutputOption,
   PreserveEntrySignatures,
 };
 use super::sanitize_filename::SanitizeFilename;
 use super::treeshake::NormalizedTreeshakeOptions;
+use super::wasm_options::WasmOptions;
 use super::watch_option::WatchOption;
+use super::worker_options::WorkerOptions;
 use super::{
   is_external::IsExternal, output_exports::OutputExports, output_format::OutputFormat,
   output_option::AddonOutputOption, platform::Platform, source_map_type::SourceMapType,
@@ -32,7 +36,7 @@ use super::{
 use crate::inner_bundler_options::types::optimization::NormalizedOptimizationConfig;
 use crate::{
   DeferSyncScanDataOption, EmittedAsset, EsModuleFlag, FilenameTemplate, GlobalsOutputOption,
-  HashCharacters, InjectImport, InputItem, InvalidateJsSideCache, LogLevel,
+  HashCharacters, HoistTransitiveImports, InjectImport, InputItem, InvalidateJsSideCache, LogLevel,
   MakeAbsoluteExternalsRelative, ModuleType, OnLog, PluginTimingsOption, RollupPreRenderedAsset,
   StrictMode, TransformOptions,
 };
@@ -55,6 +59,8 @@ pub struct NormalizedBundlerOptions {
   pub name: Option<String>,
   pub entry_filenames: ChunkFilenamesOutputOption,
   pub chunk_filenames: ChunkFilenamesOutputOption,
+  pub css_entry_filenames: ChunkFilenamesOutputOption,
+  pub css_chunk_filenames: ChunkFilenamesOutputOption,
   pub asset_filenames: AssetFilenamesOutputOption,
   pub sanitize_filename: SanitizeFilename,
   // The user specified output directory config
@@ -65,9 +71,11 @@ pub struct NormalizedBundlerOptions {
   pub format: OutputFormat,
   pub exports: OutputExports,
   pub es_module: EsModuleFlag,
+  pub hoist_transitive_imports: HoistTransitiveImports,
   pub hash_characters: HashCharacters,
   pub globals: GlobalsOutputOption,
   pub paths: Option<PathsOutputOption>,
+  pub interop: Option<InteropOutputOption>,
   pub generated_code: GeneratedCodeOptions,
   pub sourcemap: Option<SourceMapType>,
   pub banner: Option<AddonOutputOption>,
@@ -79,12 +87,15 @@ pub struct NormalizedBundlerOptions {
   pub sourcemap_ignore_list: Option<SourceMapIgnoreList>,
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
   pub sourcemap_debug_ids: bool,
+  pub sourcemap_scopes: bool,
+  pub sourcemap_index_map: bool,
   pub sourcemap_exclude_sources: bool,
   pub sourcemap_base_url: Option<String>,
   pub sourcemap_filenames: Option<ChunkFilenamesOutputOption>,
   pub experimental: ExperimentalOptions,
   pub minify: MinifyOptions,
   pub extend: bool,
+  pub amd: AmdOptions,
   pub define: Vec<(/* Target to be replaced */ String, /* Replacement */ String)>,
   pub keep_names: bool,
   pub inject: Vec<InjectImport>,
@@ -117,9 +128,14 @@ pub struct NormalizedBundlerOptions {
   pub top_level_var: bool,
   pub minify_internal_exports: bool,
   pub clean_dir: bool,
+  pub metafile: bool,
+  pub integrity: Option<IntegrityAlgorithm>,
   pub context: String,
   pub strict_execution_order: bool,
   pub strict: StrictMode,
+  /// `None` disables native worker bundling.
+  pub worker: Option<WorkerOptions>,
+  pub wasm: WasmOptions,
 }
 
 // This is only used for testing
@@ -138,6 +154,8 @@ impl Default for NormalizedBundlerOptions {
       name: Default::default(),
       entry_filenames: ChunkFilenamesOutputOption::String(String::new()),
       chunk_filenames: ChunkFilenamesOutputOption::String(String::new()),
+      css_entry_filenames: ChunkFilenamesOutputOption::String(String::new()),
+      css_chunk_filenames: ChunkFilenamesOutputOption::String(String::new()),
       asset_filenames: AssetFilenamesOutputOption::String(String::new()),
       sanitize_filename: Default::default(),
       dir: Default::default(),
@@ -146,9 +164,11 @@ impl Default for NormalizedBundlerOptions {
       format: OutputFormat::Esm,
       exports: Default::default(),
       es_module: Default::default(),
+      hoist_transitive_imports: Default::default(),
       hash_characters: Default::default(),
       globals: GlobalsOutputOption::FxHashMap(FxHashMap::default()),
       paths: Default::default(),
+      interop: Default::default(),
       generated_code: Default::default(),
       sourcemap: Default::default(),
       banner: Default::default(),
@@ -160,12 +180,15 @@ impl Default for NormalizedBundlerOptions {
       sourcemap_ignore_list: Default::default(),
       sourcemap_path_transform: Default::default(),
       sourcemap_debug_ids: Default::default(),
+      sourcemap_scopes: false,
+      sourcemap_index_map: false,
       sourcemap_exclude_sources: false,
       sourcemap_base_url: Default::default(),
       sourcemap_filenames: None,
       experimental: Default::default(),
       minify: MinifyOptions::Disabled,
       extend: Default::default(),
+      amd: AmdOptions::default(),
       define: Default::default(),
       keep_names: Default::default(),
       inject: Default::default(),
@@ -197,9 +220,13 @@ impl Default for NormalizedBundlerOptions {
       top_level_var: false,
       minify_internal_exports: Default::default(),
       clean_dir: false,
+      metafile: false,
+      integrity: None,
       context: Default::default(),
       strict_execution_order: false,
       strict: StrictMode::default(),
+      worker: None,
+      wasm: WasmOptions::default(),
     }
   }
 }
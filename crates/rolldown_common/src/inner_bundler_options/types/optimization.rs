@@ -95,6 +95,11 @@ pub struct OptimizationOption {
   /// This improves the initial execution performance.
   /// See <https://v8.dev/blog/preparser#pife> for more details about the optimization.
   pub pife_for_module_wrappers: Option<bool>,
+  /// Rewrite CommonJS modules whose exports are only written by top-level `exports.foo = ...` or
+  /// `module.exports = { ... }` statements, and whose `require` calls are all top-level and
+  /// unconditional, to ESM bindings instead of wrapping them with `__commonJS`. A module is only
+  /// rewritten when every importer reads it in a way the ESM form preserves.
+  pub commonjs_to_esm: Option<bool>,
 }
 
 pub fn normalize_optimization_option(
@@ -123,6 +128,7 @@ pub fn normalize_optimization_option(
     pife_for_module_wrappers: option
       .pife_for_module_wrappers
       .unwrap_or(!matches!(platform, Platform::Neutral)),
+    commonjs_to_esm: option.commonjs_to_esm.unwrap_or(false),
   }
 }
 
@@ -152,6 +158,7 @@ where
 pub struct NormalizedOptimizationConfig {
   pub inline_const: Option<NormalizedInlineConstConfig>,
   pub pife_for_module_wrappers: bool,
+  pub commonjs_to_esm: bool,
 }
 
 #[derive(Debug, Clone, Default, Copy)]
@@ -180,4 +187,9 @@ impl NormalizedOptimizationConfig {
   pub fn is_pife_for_module_wrappers_enabled(&self) -> bool {
     self.pife_for_module_wrappers
   }
+
+  #[inline]
+  pub fn is_commonjs_to_esm_enabled(&self) -> bool {
+    self.commonjs_to_esm
+  }
 }
@@ -16,6 +16,8 @@ pub enum OutputFormat {
   Cjs,
   Iife,
   Umd,
+  Amd,
+  System,
 }
 
 impl OutputFormat {
@@ -27,6 +29,8 @@ impl OutputFormat {
       Self::Cjs => "cjs",
       Self::Iife => "iife",
       Self::Umd => "umd",
+      Self::Amd => "amd",
+      Self::System => "system",
     }
   }
 
@@ -40,6 +44,25 @@ impl OutputFormat {
     matches!(self, Self::Esm | Self::Cjs)
   }
 
+  /// Formats that can split code into several chunks, which then import each other through
+  /// namespace bindings like `require_chunk.foo` instead of `import` statements.
+  #[inline]
+  pub fn uses_chunk_namespace_bindings(&self) -> bool {
+    matches!(self, Self::Cjs | Self::Amd | Self::System)
+  }
+
+  /// Formats whose module body runs in a context that can `await` at the top level.
+  #[inline]
+  pub fn supports_top_level_await(&self) -> bool {
+    matches!(self, Self::Esm | Self::System)
+  }
+
+  /// Formats that can express `import.meta`, which SystemJS provides as `module.meta`.
+  #[inline]
+  pub fn supports_import_meta(&self) -> bool {
+    matches!(self, Self::Esm | Self::System)
+  }
+
   #[inline]
   pub fn keep_esm_import_export_syntax(&self) -> bool {
     matches!(self, Self::Esm)
@@ -57,7 +80,7 @@ impl OutputFormat {
     match self {
       Self::Esm => SourceType::mjs(),
       Self::Cjs => SourceType::cjs(),
-      Self::Iife | Self::Umd => SourceType::cjs().with_script(true),
+      Self::Iife | Self::Umd | Self::Amd | Self::System => SourceType::cjs().with_script(true),
     }
   }
 }
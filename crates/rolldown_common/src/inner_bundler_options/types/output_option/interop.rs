@@ -0,0 +1,125 @@
+use derive_more::Debug;
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use rustc_hash::FxHashMap;
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// How the default and namespace imports of an external module are read from the value `require()`
+/// returns in the cjs, iife, umd and amd formats.
+///
+/// It is rollup-compatible, see [`output.interop`](https://rollupjs.org/configuration-options/#output-interop).
+/// Named imports are always read from the required value directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum InteropType {
+  /// The default import is `.default` of a value flagged with `__esModule`, and the value itself otherwise.
+  Auto,
+  /// The required value is a transpiled ES module: the default import is `.default` and the namespace
+  /// is the value itself. No helper is needed.
+  EsModule,
+  /// The default import is always the required value, as in Node.js importing a CommonJS module.
+  Default,
+  /// Like `default`, but the namespace only has a `default` property and named imports are an error.
+  /// No helper is needed.
+  DefaultOnly,
+  /// The default import is `.default` of an object owning a `default` property, and the value itself otherwise.
+  Compat,
+}
+
+impl InteropType {
+  /// Whether reading a default or namespace import in this mode calls the `__toESM` runtime helper.
+  pub fn needs_to_esm_helper(self) -> bool {
+    matches!(self, Self::Auto | Self::Default | Self::Compat)
+  }
+
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Self::Auto => "auto",
+      Self::EsModule => "esModule",
+      Self::Default => "default",
+      Self::DefaultOnly => "defaultOnly",
+      Self::Compat => "compat",
+    }
+  }
+}
+
+impl TryFrom<&str> for InteropType {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "auto" => Ok(Self::Auto),
+      "esModule" => Ok(Self::EsModule),
+      "default" => Ok(Self::Default),
+      "defaultOnly" => Ok(Self::DefaultOnly),
+      "compat" => Ok(Self::Compat),
+      _ => Err(format!(
+        r#"Invalid value "{value}" for option "output.interop" - valid values are "auto", "esModule", "default", "defaultOnly", and "compat"."#
+      )),
+    }
+  }
+}
+
+pub type InteropFunction = dyn Fn(&str) -> Pin<Box<dyn Future<Output = anyhow::Result<Option<InteropType>>> + Send + 'static>>
+  + Send
+  + Sync;
+
+#[derive(Clone, Debug)]
+pub enum InteropOutputOption {
+  #[debug("InteropOutputOption::InteropType({_0:?})")]
+  InteropType(InteropType),
+  #[debug("InteropOutputOption::FxHashMap({_0:?})")]
+  FxHashMap(FxHashMap<String, InteropType>),
+  #[debug("InteropOutputOption::Fn(...)")]
+  Fn(Arc<InteropFunction>),
+}
+
+impl InteropOutputOption {
+  /// The interop of the external module `id`. `None` keeps the default behavior, which picks
+  /// between `auto` and `default` from the format of the importing module.
+  pub async fn call(&self, id: &str) -> anyhow::Result<Option<InteropType>> {
+    match self {
+      Self::InteropType(value) => Ok(Some(*value)),
+      Self::FxHashMap(value) => Ok(value.get(id).copied()),
+      Self::Fn(value) => value(id).await,
+    }
+  }
+}
+
+impl From<InteropType> for InteropOutputOption {
+  fn from(value: InteropType) -> Self {
+    Self::InteropType(value)
+  }
+}
+
+impl From<FxHashMap<String, InteropType>> for InteropOutputOption {
+  fn from(value: FxHashMap<String, InteropType>) -> Self {
+    Self::FxHashMap(value)
+  }
+}
+
+/// An interop option that can be either a single interop type or a map of external ids, used for rolldown rust testing.
+#[cfg(feature = "deserialize_bundler_options")]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SimpleInteropOption {
+  InteropType(InteropType),
+  FxHashMap(FxHashMap<String, InteropType>),
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+impl From<SimpleInteropOption> for InteropOutputOption {
+  fn from(value: SimpleInteropOption) -> Self {
+    match value {
+      SimpleInteropOption::InteropType(value) => Self::InteropType(value),
+      SimpleInteropOption::FxHashMap(value) => Self::FxHashMap(value),
+    }
+  }
+}
@@ -2,6 +2,7 @@ mod addon;
 mod asset_filenames;
 mod chunk_filenames;
 mod globals;
+mod interop;
 mod paths;
 mod preserve_entry_signatures;
 
@@ -9,5 +10,8 @@ pub use addon::{AddonFunction, AddonOutputOption};
 pub use asset_filenames::AssetFilenamesOutputOption;
 pub use chunk_filenames::ChunkFilenamesOutputOption;
 pub use globals::GlobalsOutputOption;
+#[cfg(feature = "deserialize_bundler_options")]
+pub use interop::SimpleInteropOption;
+pub use interop::{InteropFunction, InteropOutputOption, InteropType};
 pub use paths::PathsOutputOption;
 pub use preserve_entry_signatures::PreserveEntrySignatures;
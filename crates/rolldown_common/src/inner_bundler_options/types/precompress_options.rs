@@ -0,0 +1,81 @@
+use rolldown_utils::pattern_filter::StringOrRegex;
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::{Deserialize, Deserializer};
+
+/// Emits pre-compressed siblings, such as `main.js.gz`, next to the outputs, for static hosting
+/// that serves them as-is.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct PrecompressOptions {
+  /// Defaults to `["gzip", "brotli"]`.
+  pub algorithms: Option<Vec<PrecompressAlgorithm>>,
+  /// Outputs smaller than this many bytes are skipped. Defaults to `1024`.
+  pub threshold: Option<u32>,
+  /// Clamped to the range each algorithm supports. Defaults to `9` for gzip, `11` for brotli and
+  /// `19` for zstd.
+  pub level: Option<u32>,
+  /// Matched against the output file names. When unset, all chunks and the assets with a
+  /// text-like extension are compressed.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_string_or_regex"),
+    schemars(with = "Option<Vec<String>>")
+  )]
+  pub include: Option<Vec<StringOrRegex>>,
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_string_or_regex"),
+    schemars(with = "Option<Vec<String>>")
+  )]
+  pub exclude: Option<Vec<StringOrRegex>>,
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_string_or_regex<'de, D>(
+  deserializer: D,
+) -> Result<Option<Vec<StringOrRegex>>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<Vec<String>>::deserialize(deserializer)?;
+  Ok(deserialized.map(|v| v.into_iter().map(StringOrRegex::String).collect::<Vec<_>>()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum PrecompressAlgorithm {
+  Gzip,
+  Brotli,
+  Zstd,
+}
+
+impl PrecompressAlgorithm {
+  /// The extension appended to the file name of the compressed sibling.
+  pub fn extension(self) -> &'static str {
+    match self {
+      Self::Gzip => ".gz",
+      Self::Brotli => ".br",
+      Self::Zstd => ".zst",
+    }
+  }
+
+  /// Clamps `level` to the range supported by the algorithm, or returns its highest practical
+  /// level when unset.
+  pub fn level(self, level: Option<u32>) -> u32 {
+    match self {
+      Self::Gzip => level.map_or(9, |level| level.min(9)),
+      Self::Brotli => level.map_or(11, |level| level.min(11)),
+      Self::Zstd => level.map_or(19, |level| level.clamp(1, 22)),
+    }
+  }
+}
@@ -0,0 +1,24 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Options for modules with type `wasm`, see `ModuleType::Wasm`.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct WasmOptions {
+  /// Inline the binary as base64 instead of emitting it as an asset that is fetched
+  /// (browser) or read from disk (node) at runtime. Defaults to `false`.
+  pub embed: Option<bool>,
+}
+
+impl WasmOptions {
+  #[inline]
+  pub fn embed(&self) -> bool {
+    self.embed.unwrap_or(false)
+  }
+}
@@ -0,0 +1,31 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use super::output_format::OutputFormat;
+
+/// Enables bundling of `new Worker(new URL('./w.js', import.meta.url))`,
+/// `new SharedWorker(...)`, `navigator.serviceWorker.register(...)` and
+/// `*.{paint,audio,layout,animation}Worklet.addModule(...)` references.
+///
+/// Each referenced module is bundled as the entry of its own chunk graph and the URL
+/// is rewritten to point at the emitted worker chunk.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct WorkerOptions {
+  /// Output format of worker bundles. Defaults to `iife`, which works for both
+  /// classic and module workers.
+  pub format: Option<OutputFormat>,
+}
+
+impl WorkerOptions {
+  #[inline]
+  pub fn format(&self) -> OutputFormat {
+    self.format.unwrap_or(OutputFormat::Iife)
+  }
+}
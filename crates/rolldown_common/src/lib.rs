@@ -1,4 +1,5 @@
 mod chunk;
+mod css;
 mod ecmascript;
 mod file_emitter;
 mod generated;
@@ -25,6 +26,7 @@ pub mod bundler_options {
   pub use crate::inner_bundler_options::{
     BundlerOptions,
     types::{
+      amd_options::AmdOptions,
       attach_debug_info::AttachDebugInfo,
       chunk_import_map::ChunkImportMap,
       chunk_modules_order::ChunkModulesOrderBy,
@@ -40,8 +42,10 @@ pub mod bundler_options {
       filename_template::{FilenameTemplate, is_path_fragment},
       generated_code_options::GeneratedCodeOptions,
       hash_characters::HashCharacters,
+      hoist_transitive_imports::HoistTransitiveImports,
       inject_import::InjectImport,
       input_item::InputItem,
+      integrity_algorithm::IntegrityAlgorithm,
       invalidate_js_side_cache::InvalidateJsSideCache,
       is_external::IsExternal,
       legal_comments::LegalComments,
@@ -65,10 +69,12 @@ pub mod bundler_options {
       output_format::OutputFormat,
       output_option::{
         AddonFunction, AddonOutputOption, AssetFilenamesOutputOption, ChunkFilenamesOutputOption,
-        GlobalsOutputOption, PathsOutputOption, PreserveEntrySignatures,
+        GlobalsOutputOption, InteropFunction, InteropOutputOption, InteropType, PathsOutputOption,
+        PreserveEntrySignatures,
       },
       platform::Platform,
       plugin_timings_option::PluginTimingsOption,
+      precompress_options::{PrecompressAlgorithm, PrecompressOptions},
       resolve_options::ResolveOptions,
       sanitize_filename::SanitizeFilename,
       source_map_type::SourceMapType,
@@ -91,7 +97,9 @@ pub mod bundler_options {
       },
       tsconfig::TsConfig,
       tsconfig_merge::merge_transform_options_with_tsconfig as merge_tsconfig,
+      wasm_options::WasmOptions,
       watch_option::{OnInvalidate, WatchOption},
+      worker_options::WorkerOptions,
     },
   };
 
@@ -114,6 +122,7 @@ pub use crate::{
       preliminary_filename::PreliminaryFilename,
     },
   },
+  css::css_view::{CssImportConditions, CssView, RemoveCssRange},
   ecmascript::{
     comment_annotation::get_leading_comment,
     dynamic_import_usage,
@@ -154,6 +163,7 @@ pub use crate::{
   types::bundle_mode::BundleMode,
   types::chunk_idx::ChunkIdx,
   types::chunk_kind::ChunkKind,
+  types::chunk_records::{ChunkRecord, ChunkRecords},
   types::concatenate_wrapped_module::{
     ConcatenateWrappedModuleKind, RenderedConcatenatedModuleParts,
   },
@@ -182,6 +192,7 @@ pub use crate::{
   },
   types::member_expr_ref::{MemberExprObjectReferencedType, MemberExprProp, MemberExprRef},
   types::member_expr_ref_resolution::MemberExprRefResolution,
+  types::metafile::{Metafile, MetafileImport, MetafileInput, MetafileOutput},
   types::module_def_format::ModuleDefFormat,
   types::module_id::{ModuleId, ModuleIdKind},
   types::module_info::ModuleInfo,
@@ -206,7 +217,7 @@ pub use crate::{
   types::rollup_rendered_chunk::RollupRenderedChunk,
   types::scan_mode::ScanMode,
   types::side_effects,
-  types::source_mutation::SourceMutation,
+  types::source_mutation::{ArcSourceMutation, SourceMutation},
   types::sourcemap_chain_element::SourcemapChainElement,
   types::stable_module_id::StableModuleId,
   types::stmt_eval_flags::StmtEvalFlags,
@@ -219,6 +230,10 @@ pub use crate::{
   types::symbol_ref_db::{
     GetLocalDb, GetLocalDbMut, SymbolRefDb, SymbolRefDbForModule, SymbolRefFlags,
   },
+  types::tree_shaking_provenance::{
+    InclusionReason, InclusionReasonKind, InclusionSite, ModuleProvenance, SideEffectKind,
+    StatementProvenance, TreeShakingProvenance,
+  },
   types::used_external_symbols::{ExternalInteropUse, UsedExternalSymbols},
   types::used_symbol_refs::{UsedSymbolRefs, UsedSymbolRefsBuilder},
   types::watch::WatcherChangeKind,
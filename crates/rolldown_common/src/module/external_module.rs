@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use crate::inner_bundler_options::types::output_option::PathsOutputOption;
+use crate::inner_bundler_options::types::output_option::{InteropType, PathsOutputOption};
 use crate::side_effects::DeterminedSideEffects;
 use crate::{
   Chunk, ImportRecordIdx, ModuleId, ModuleIdx, ResolvedImportRecord, StableModuleId, SymbolRef,
@@ -28,9 +28,13 @@ pub struct ExternalModule {
   pub import_records: IndexVec<ImportRecordIdx, ResolvedImportRecord>,
   pub side_effects: DeterminedSideEffects,
   pub need_renormalize_render_path: bool,
+  /// The `output.interop` resolved for this module. `None` picks the interop from the format of
+  /// each importer, see `chunk_external_interop_modes`.
+  pub interop: Option<InteropType>,
 }
 
 impl ExternalModule {
+  #[expect(clippy::too_many_arguments)]
   pub fn new(
     idx: ModuleIdx,
     id: ModuleId,
@@ -39,6 +43,7 @@ impl ExternalModule {
     side_effects: DeterminedSideEffects,
     namespace_ref: SymbolRef,
     need_renormalize_render_path: bool,
+    interop: Option<InteropType>,
   ) -> Self {
     Self {
       idx,
@@ -52,9 +57,15 @@ impl ExternalModule {
       import_records: IndexVec::default(),
       side_effects,
       need_renormalize_render_path,
+      interop,
     }
   }
 
+  /// Whether reading this module as an ES module calls the `__toESM` runtime helper.
+  pub fn needs_to_esm_helper(&self) -> bool {
+    self.interop.is_none_or(InteropType::needs_to_esm_helper)
+  }
+
   pub fn get_file_name(&self, paths: Option<&PathsOutputOption>) -> ArcStr {
     // Try to apply paths mapping first
     if let Some(paths_option) = paths {
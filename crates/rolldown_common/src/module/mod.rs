@@ -96,7 +96,11 @@ impl Module {
 
   pub fn set_import_records(&mut self, records: IndexVec<ImportRecordIdx, ResolvedImportRecord>) {
     match self {
-      Module::Normal(v) => v.ecma_view.import_records = records,
+      Module::Normal(v) => match &mut v.css_view {
+        // A css module has no js imports, all of its records come from `@import` and `url()`.
+        Some(css_view) => css_view.import_records = records,
+        None => v.ecma_view.import_records = records,
+      },
       Module::External(v) => v.import_records = records,
     }
   }
@@ -1,12 +1,12 @@
 use std::{fmt::Debug, sync::Arc};
 
 use crate::types::module_render_output::ModuleRenderOutput;
+use crate::{CssView, EcmaView, IndexModules, Interop, Module, ModuleType};
 use crate::{
   DebugStmtInfoForTreeShaking, EcmaModuleAstUsage, ExportsKind, ImportRecordIdx, ImportRecordMeta,
   ModuleId, ModuleIdx, ModuleInfo, NormalizedBundlerOptions, RawImportRecord, ResolvedId,
   StableModuleId, StmtInfoIdx,
 };
-use crate::{EcmaView, IndexModules, Interop, Module, ModuleType};
 use std::ops::{Deref, DerefMut};
 
 use itertools::Itertools;
@@ -30,7 +30,11 @@ pub struct NormalModule {
   pub repr_name: String,
   pub module_type: ModuleType,
   pub ecma_view: EcmaView,
+  /// Only exists for modules with `ModuleType::Css`.
+  pub css_view: Option<CssView>,
   pub originative_resolved_id: ResolvedId,
+  /// Byte length of the source returned by the `load` hook, before the `transform` hooks ran.
+  pub loaded_source_len: usize,
 }
 
 impl NormalModule {
@@ -76,7 +80,12 @@ impl NormalModule {
     is_entry: bool,
   ) -> ModuleInfo {
     ModuleInfo {
-      code: Some(self.ecma_view.source.clone()),
+      code: Some(
+        self
+          .css_view
+          .as_ref()
+          .map_or_else(|| self.ecma_view.source.clone(), |css_view| css_view.source.clone()),
+      ),
       id: self.id.clone(),
       is_entry,
       importers: {
@@ -90,6 +99,7 @@ impl NormalModule {
         value
       },
       imported_ids: self.ecma_view.imported_ids.clone(),
+      imported_ids_by_specifier: self.ecma_view.imported_ids_by_specifier.clone(),
       dynamically_imported_ids: self.ecma_view.dynamically_imported_ids.clone(),
       // https://github.com/rollup/rollup/blob/7a8ac460c62b0406a749e367dbd0b74973282449/src/Module.ts#L331
       exports: {
@@ -217,11 +227,19 @@ impl NormalModule {
               // Only `annotation` needs this override — `legal` and `jsdoc` comments
               // have no effect on DCE behaviour.
               c.annotation = true;
+              // Legal comments of the whole chunk are moved to its end or to a `.LEGAL.txt`
+              // file, so they are collected here instead of being printed in place.
+              c.extract_legal = options.legal_comments.is_extracted();
               c
             },
             initial_indent,
           },
         );
+        let legal_comments = render_output
+          .legal_comments
+          .iter()
+          .map(|comment| comment.span.source_text(ast.source()).to_string())
+          .collect::<Vec<_>>();
         if !self.ecma_view.mutations.is_empty() {
           let original_code: Arc<str> = render_output.code.into();
           let mut magic_string = string_wizard::MagicString::new(&*original_code);
@@ -241,11 +259,12 @@ impl NormalModule {
           // keeps from it, so no `into_owned` detach is needed.
           let map =
             render_output.map.map(|original| collapse_sourcemaps(&[&original, &mutated_map]));
-          return ModuleRenderOutput { code, map };
+          return ModuleRenderOutput { code, map, legal_comments };
         }
         ModuleRenderOutput {
           code: render_output.code,
           map: render_output.map.map(oxc_sourcemap::SourceMap::into_owned),
+          legal_comments,
         }
       }
     }
@@ -1,5 +1,5 @@
 use arcstr::ArcStr;
-use rolldown_sourcemap::SourceMap;
+use rolldown_sourcemap::{IndexSourceMap, SourceMap};
 
 use crate::{ChunkIdx, InstantiationKind, StrOrBytes};
 
@@ -16,6 +16,7 @@ pub struct Asset {
   pub originate_from: Option<ChunkIdx>,
   pub content: StrOrBytes,
   pub map: Option<SourceMap>,
+  pub index_map: Option<IndexSourceMap>,
   pub meta: InstantiationKind,
   pub filename: ArcStr,
 }
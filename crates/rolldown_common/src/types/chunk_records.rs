@@ -0,0 +1,63 @@
+use serde_json::{Value, json};
+
+/// Bumped whenever the layout of the records file changes. Records of another version are ignored.
+const CHUNK_RECORDS_VERSION: u64 = 1;
+
+/// The common chunks of a previous build, read from and written to `experimental.recordsPath`.
+///
+/// Code splitting recreates a recorded chunk before assigning the remaining modules, as long as
+/// its modules are still loaded by the same set of entries. The chunk keeps its name, and modules
+/// new to the build never join it, so its content, and with it its `[hash]`, only changes when
+/// one of its modules does.
+#[derive(Debug, Default, Clone)]
+pub struct ChunkRecords {
+  pub chunks: Vec<ChunkRecord>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChunkRecord {
+  /// The `[name]` of the chunk.
+  pub name: String,
+  /// Stable ids of the modules of the chunk, in the order they were rendered.
+  pub modules: Vec<String>,
+}
+
+impl ChunkRecords {
+  pub fn to_json(&self) -> Value {
+    let chunks = self
+      .chunks
+      .iter()
+      .map(|chunk| json!({ "name": chunk.name, "modules": chunk.modules }))
+      .collect::<Vec<_>>();
+    json!({ "version": CHUNK_RECORDS_VERSION, "chunks": chunks })
+  }
+
+  pub fn to_json_string(&self) -> String {
+    serde_json::to_string_pretty(&self.to_json()).expect("Chunk records should be valid JSON")
+  }
+
+  /// Returns `None` for malformed records or records of another version.
+  pub fn from_json_str(raw: &str) -> Option<Self> {
+    let value = serde_json::from_str::<Value>(raw).ok()?;
+    if value.get("version")?.as_u64()? != CHUNK_RECORDS_VERSION {
+      return None;
+    }
+    let chunks = value
+      .get("chunks")?
+      .as_array()?
+      .iter()
+      .map(|chunk| {
+        Some(ChunkRecord {
+          name: chunk.get("name")?.as_str()?.to_string(),
+          modules: chunk
+            .get("modules")?
+            .as_array()?
+            .iter()
+            .map(|module| module.as_str().map(ToString::to_string))
+            .collect::<Option<_>>()?,
+        })
+      })
+      .collect::<Option<_>>()?;
+    Some(Self { chunks })
+  }
+}
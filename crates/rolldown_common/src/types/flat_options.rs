@@ -36,6 +36,9 @@ bitflags! {
     /// If set, lazy barrel optimization is enabled.
     /// Usage: `self.options.experimental.is_lazy_barrel_enabled()`
     const LazyBarrelEnabled = 1 << 10;
+    /// If set, worker constructors referencing `new URL()` are bundled as separate entries.
+    /// Usage: `self.options.worker.is_some()`
+    const WorkerEnabled = 1 << 11;
   }
 }
 
@@ -71,6 +74,7 @@ impl FlatOptions {
     );
     flags.set(Self::InlineConstEnabled, options.optimization.is_inline_const_enabled());
     flags.set(Self::LazyBarrelEnabled, options.experimental.is_lazy_barrel_enabled());
+    flags.set(Self::WorkerEnabled, options.worker.is_some());
     flags
   }
 
@@ -128,4 +132,9 @@ impl FlatOptions {
   pub fn is_lazy_barrel_enabled(self) -> bool {
     self.contains(Self::LazyBarrelEnabled)
   }
+
+  #[inline]
+  pub fn worker_enabled(self) -> bool {
+    self.contains(Self::WorkerEnabled)
+  }
 }
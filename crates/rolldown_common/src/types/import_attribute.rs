@@ -4,6 +4,8 @@ use oxc::ast::ast;
 use oxc_str::CompactStr;
 use rolldown_utils::indexmap::FxIndexMap;
 
+use crate::ModuleType;
+
 #[derive(Debug, Clone, Default)]
 pub struct ImportAttribute {
   kind: ImportAttributeKind,
@@ -36,6 +38,36 @@ impl ImportAttribute {
     self.entries.contains_key(key)
   }
 
+  /// The value of the `type` attribute, e.g. `json` for `with { type: 'json' }`.
+  pub fn type_value(&self) -> Option<&str> {
+    self
+      .entries
+      .iter()
+      .find(|(key, _)| match key {
+        ImportAttributeKey::String(name) | ImportAttributeKey::Identifier(name) => name == "type",
+      })
+      .map(|(_, value)| value.as_str())
+  }
+
+  /// The module type the `type` attribute asks for, if it's one rolldown can load.
+  pub fn module_type(&self) -> Option<ModuleType> {
+    match self.type_value()? {
+      "json" => Some(ModuleType::Json),
+      "text" => Some(ModuleType::Text),
+      "bytes" => Some(ModuleType::Binary),
+      "css" => Some(ModuleType::Css),
+      _ => None,
+    }
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+    self.entries.iter().map(|(key, value)| match key {
+      ImportAttributeKey::String(name) | ImportAttributeKey::Identifier(name) => {
+        (name.as_str(), value.as_str())
+      }
+    })
+  }
+
   pub fn from_with_clause(with_clause: &ast::WithClause) -> Self {
     let kind = match with_clause.keyword {
       ast::WithClauseKeyword::With => ImportAttributeKind::With,
@@ -60,6 +92,16 @@ impl ImportAttribute {
   }
 }
 
+impl FromIterator<(String, String)> for ImportAttribute {
+  fn from_iter<T: IntoIterator<Item = (String, String)>>(iter: T) -> Self {
+    let entries = iter
+      .into_iter()
+      .map(|(key, value)| (ImportAttributeKey::String(key.into()), value))
+      .collect();
+    Self { kind: ImportAttributeKind::With, entries }
+  }
+}
+
 impl Display for ImportAttribute {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     let with_entries = self
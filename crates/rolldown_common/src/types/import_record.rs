@@ -78,6 +78,18 @@ bitflags::bitflags! {
     /// The import record is solely for re-export purposes, created by
     /// `export { .. } from '..'` or `export * as ns from '..'`
     const IsReExportOnly = 1 << 10;
+    /// `import defer * as ns from 'mod'`. Cleared in the link stage when the deferred evaluation
+    /// can't be honored, so the record falls back to a regular import.
+    const IsDeferred = 1 << 11;
+    /// `new URL()` passed to a worker constructor; the importee is bundled as a separate worker entry.
+    const IsWorker = 1 << 12;
+    /// `import source mod from './mod.wasm'`, the importee is the module's source object.
+    const IsSourcePhase = 1 << 13;
+    /// A top-level `require` of a module converted by `optimization.commonjsToEsm` that runs
+    /// before any other side effect of the module. It's evaluated like a static import, so an ES
+    /// module importee is hoisted along with the importer instead of staying behind `init_*`.
+    /// Cleared in the link stage unless both the importer and the importee end up as ES modules.
+    const HoistedRequire = 1 << 14;
 
     const TopLevelPureDynamicImport = Self::IsTopLevel.bits() | Self::PureDynamicImport.bits();
   }
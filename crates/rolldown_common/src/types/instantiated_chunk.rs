@@ -1,5 +1,5 @@
 use arcstr::ArcStr;
-use rolldown_sourcemap::SourceMap;
+use rolldown_sourcemap::{IndexSourceMap, SourceMap};
 
 use crate::{Asset, ChunkIdx, InstantiationKind, PreliminaryFilename, StrOrBytes};
 
@@ -10,6 +10,8 @@ pub struct InstantiatedChunk {
   pub originate_from: ChunkIdx,
   pub content: StrOrBytes,
   pub map: Option<SourceMap>,
+  /// Set instead of `map` when the sourcemap is kept as an index map, see `output.sourcemapIndexMap`.
+  pub index_map: Option<IndexSourceMap>,
   pub kind: InstantiationKind,
   pub augment_chunk_hash: Option<String>,
   pub preliminary_filename: PreliminaryFilename,
@@ -25,8 +27,17 @@ impl InstantiatedChunk {
       originate_from: Some(self.originate_from),
       content: self.content,
       map: self.map,
+      index_map: self.index_map,
       meta: self.kind,
       filename,
     }
   }
+
+  /// Merges the index map, if any, into `map` for steps that need to combine it with other
+  /// sourcemaps.
+  pub fn flatten_index_map(&mut self) {
+    if let Some(index_map) = self.index_map.take() {
+      self.map = Some(index_map.flatten());
+    }
+  }
 }
@@ -0,0 +1,108 @@
+use rolldown_utils::indexmap::FxIndexMap;
+use serde_json::{Map, Value, json};
+
+use super::import_kind::ImportKind;
+
+/// Machine-readable build report following esbuild's metafile schema, so tools written for
+/// esbuild's `metafile` can consume it unchanged.
+///
+/// See https://esbuild.github.io/api/#metafile
+#[derive(Debug, Default, Clone)]
+pub struct Metafile {
+  /// Keyed by the stable id of the module, which is relative to `cwd`.
+  pub inputs: FxIndexMap<String, MetafileInput>,
+  /// Keyed by the path of the output file, relative to `cwd`.
+  pub outputs: FxIndexMap<String, MetafileOutput>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MetafileInput {
+  pub bytes: usize,
+  pub imports: Vec<MetafileImport>,
+  /// `"esm"` or `"cjs"`. `None` for modules without any module syntax.
+  pub format: Option<&'static str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MetafileImport {
+  pub path: String,
+  /// Rendered with esbuild's names, e.g. `"import-statement"` or `"dynamic-import"`.
+  pub kind: ImportKind,
+  pub external: bool,
+  /// The specifier as written in the source, if it differs from `path`.
+  pub original: Option<String>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MetafileOutput {
+  pub bytes: usize,
+  /// Number of bytes each input contributes to the output, keyed like [`Metafile::inputs`].
+  pub inputs: FxIndexMap<String, usize>,
+  pub imports: Vec<MetafileImport>,
+  pub exports: Vec<String>,
+  pub entry_point: Option<String>,
+}
+
+impl Metafile {
+  pub fn to_json(&self) -> Value {
+    let inputs = self
+      .inputs
+      .iter()
+      .map(|(path, input)| {
+        let mut value = Map::new();
+        value.insert("bytes".to_string(), json!(input.bytes));
+        value.insert("imports".to_string(), imports_to_json(&input.imports));
+        if let Some(format) = input.format {
+          value.insert("format".to_string(), json!(format));
+        }
+        (path.clone(), Value::Object(value))
+      })
+      .collect::<Map<_, _>>();
+    let outputs = self
+      .outputs
+      .iter()
+      .map(|(path, output)| {
+        let mut value = Map::new();
+        value.insert("bytes".to_string(), json!(output.bytes));
+        value.insert(
+          "inputs".to_string(),
+          output
+            .inputs
+            .iter()
+            .map(|(path, bytes)| (path.clone(), json!({ "bytesInOutput": bytes })))
+            .collect::<Map<_, _>>()
+            .into(),
+        );
+        value.insert("imports".to_string(), imports_to_json(&output.imports));
+        value.insert("exports".to_string(), json!(output.exports));
+        if let Some(entry_point) = &output.entry_point {
+          value.insert("entryPoint".to_string(), json!(entry_point));
+        }
+        (path.clone(), Value::Object(value))
+      })
+      .collect::<Map<_, _>>();
+    json!({ "inputs": inputs, "outputs": outputs })
+  }
+
+  pub fn to_json_string(&self) -> String {
+    self.to_json().to_string()
+  }
+}
+
+fn imports_to_json(imports: &[MetafileImport]) -> Value {
+  imports
+    .iter()
+    .map(|import| {
+      let mut value = Map::new();
+      value.insert("path".to_string(), json!(import.path));
+      value.insert("kind".to_string(), json!(import.kind.to_string()));
+      if import.external {
+        value.insert("external".to_string(), json!(true));
+      }
+      if let Some(original) = &import.original {
+        value.insert("original".to_string(), json!(original));
+      }
+      Value::Object(value)
+    })
+    .collect()
+}
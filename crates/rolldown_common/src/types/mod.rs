@@ -5,6 +5,7 @@ pub mod ast_scopes;
 pub mod bundle_mode;
 pub mod chunk_idx;
 pub mod chunk_kind;
+pub mod chunk_records;
 pub mod concatenate_wrapped_module;
 pub mod constant_value;
 pub mod deconflict;
@@ -27,6 +28,7 @@ pub mod interop;
 pub mod lazy_barrel;
 pub mod member_expr_ref;
 pub mod member_expr_ref_resolution;
+pub mod metafile;
 pub mod module_def_format;
 pub mod module_id;
 pub mod module_info;
@@ -60,6 +62,7 @@ pub mod str_or_bytes;
 pub mod symbol_or_member_expr_ref;
 pub mod symbol_ref;
 pub mod symbol_ref_db;
+pub mod tree_shaking_provenance;
 pub mod used_external_symbols;
 pub mod used_symbol_refs;
 pub mod watch;
@@ -12,6 +12,7 @@ use sugar_path::SugarPath as _;
 use super::stable_module_id::StableModuleId;
 
 const EMPTY_MODULE_PREFIX: &str = "\0rolldown/empty.js?";
+const WITH_TYPE_MODULE_PREFIX: &str = "\0rolldown/with-type/";
 
 /// Classification of a [`ModuleId`]'s string identity.
 ///
@@ -72,6 +73,14 @@ impl ModuleId {
     Self::new(format!("{EMPTY_MODULE_PREFIX}{original}"))
   }
 
+  /// Construct the id of a module whose type is selected by import attributes, e.g.
+  /// `with { type: 'text' }` on a `.js` file. It wraps the original resolved id, so importing the
+  /// same file with and without the attribute creates two modules. The `\0` prefix guarantees it
+  /// never collides with a real path or a query like `?type=text`.
+  pub fn new_with_type(original: &str, module_type: &str) -> Self {
+    Self::new(format!("{WITH_TYPE_MODULE_PREFIX}{module_type}?{original}"))
+  }
+
   fn classify(inner: ArcStr) -> Repr {
     if inner.starts_with('\0') {
       Repr::Virtual(inner)
@@ -104,6 +113,12 @@ impl ModuleId {
     self.as_str().strip_prefix(EMPTY_MODULE_PREFIX)
   }
 
+  /// For an id created via `new_with_type`, returns the original id portion.
+  pub fn strip_with_type_prefix(&self) -> Option<&str> {
+    let rest = self.as_str().strip_prefix(WITH_TYPE_MODULE_PREFIX)?;
+    rest.split_once('?').map(|(_, original)| original)
+  }
+
   pub fn as_str(&self) -> &str {
     self.as_arc_str().as_str()
   }
@@ -1,6 +1,7 @@
 use arcstr::ArcStr;
 use oxc_str::CompactStr;
 use rolldown_utils::indexmap::FxIndexSet;
+use rustc_hash::FxHashMap;
 
 use crate::{ExportsKind, ModuleId};
 
@@ -12,6 +13,8 @@ pub struct ModuleInfo {
   pub importers: FxIndexSet<ModuleId>,
   pub dynamic_importers: FxIndexSet<ModuleId>,
   pub imported_ids: FxIndexSet<ModuleId>,
+  /// Specifier of each static import -> the id in `imported_ids` it resolved to.
+  pub imported_ids_by_specifier: FxHashMap<CompactStr, ModuleId>,
   pub dynamically_imported_ids: FxIndexSet<ModuleId>,
   pub exports: Vec<CompactStr>,
   pub input_format: ExportsKind,
@@ -4,4 +4,7 @@ use rolldown_sourcemap::SourceMap;
 pub struct ModuleRenderOutput {
   pub code: String,
   pub map: Option<SourceMap>,
+  /// Legal comments moved out of `code`, only collected when `legalComments` is `eof`, `linked`
+  /// or `external`.
+  pub legal_comments: Vec<String>,
 }
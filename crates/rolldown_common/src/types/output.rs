@@ -10,6 +10,8 @@ pub struct OutputAsset {
   pub original_file_names: Vec<String>,
   pub filename: ArcStr,
   pub source: StrOrBytes,
+  /// The Subresource Integrity digest of `source`, only present when `output.integrity` is set.
+  pub integrity: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -23,11 +23,18 @@ pub struct OutputChunk {
   pub modules: Modules,
   pub imports: Vec<ArcStr>,
   pub dynamic_imports: Vec<ArcStr>,
+  /// Chunks only imported by the dependencies of this entry chunk, see `output.hoistTransitiveImports`.
+  pub transitive_imports: Vec<ArcStr>,
+  /// The stylesheets generated from the CSS modules of this chunk, which need to be loaded
+  /// along with it.
+  pub imported_css: Vec<ArcStr>,
   // OutputChunk
   pub code: String,
   pub map: Option<SourceMap>,
   pub sourcemap_filename: Option<String>,
   pub preliminary_filename: String,
+  /// The Subresource Integrity digest of `code`, only present when `output.integrity` is set.
+  pub integrity: Option<String>,
 }
 
 #[derive(Debug, Clone)]
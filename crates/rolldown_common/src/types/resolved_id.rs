@@ -4,7 +4,9 @@ use arcstr::ArcStr;
 use rolldown_utils::{dataurl::is_data_url, stabilize_id::stabilize_id};
 
 use super::module_id::ModuleId;
-use crate::{ModuleDefFormat, PackageJson, side_effects::HookSideEffects};
+use crate::{
+  ImportAttribute, ModuleDefFormat, ModuleType, PackageJson, side_effects::HookSideEffects,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub enum ResolvedExternal {
@@ -44,6 +46,12 @@ pub struct ResolvedId {
   pub package_json: Option<Arc<PackageJson>>,
   pub side_effects: Option<HookSideEffects>,
   pub is_external_without_side_effects: bool,
+  /// The import attributes of the import this id was resolved for, as written in the source, e.g.
+  /// `with { type: 'text' }`. They are passed to the `resolveId` and `load` hooks.
+  pub attributes: Option<Arc<ImportAttribute>>,
+  /// The module type selected by the `type` attribute, when it differs from the one the id would be
+  /// loaded as. Such an import creates a separate module, see [`ResolvedId::module_id`].
+  pub attributes_module_type: Option<ModuleType>,
 }
 
 impl ResolvedId {
@@ -58,6 +66,17 @@ impl ResolvedId {
       package_json: None,
       side_effects: None,
       is_external_without_side_effects: false,
+      attributes: None,
+      attributes_module_type: None,
+    }
+  }
+
+  /// The id of the module created for this resolution. Importing a file with attributes that select
+  /// another module type creates a separate module, see [`ModuleId::new_with_type`].
+  pub fn module_id(&self) -> ModuleId {
+    match &self.attributes_module_type {
+      Some(module_type) => ModuleId::new_with_type(&self.id, &module_type.to_string()),
+      None => self.id.clone(),
     }
   }
 
@@ -85,6 +104,8 @@ impl ResolvedId {
       package_json: None,
       side_effects: None,
       is_external_without_side_effects: true,
+      attributes: None,
+      attributes_module_type: None,
     }
   }
 }
@@ -2,6 +2,7 @@ use std::{borrow::Borrow, path::Path};
 
 use arcstr::ArcStr;
 use rolldown_std_utils::relative_path_to_slash;
+use rolldown_utils::concat_string;
 
 #[cfg(test)]
 use rolldown_std_utils::PathExt as _;
@@ -31,8 +32,16 @@ impl StableModuleId {
     let inner: ArcStr = match id.kind() {
       // Absolute path → relative to cwd, slashed (stable across machines/OSes).
       ModuleIdKind::Path => relative_path_to_slash(id.as_str(), cwd).into(),
-      // Virtual module → escape the `\0` prefix.
-      ModuleIdKind::Virtual => id.as_str().replace('\0', "\\0").into(),
+      // Virtual module → escape the `\0` prefix. A module typed by import attributes wraps a
+      // resolved id, which is stabilized on its own.
+      ModuleIdKind::Virtual => match id.strip_with_type_prefix() {
+        Some(original) => {
+          let prefix = &id.as_str()[..id.len() - original.len()];
+          let original = Self::new(&ModuleId::new(original), cwd);
+          concat_string!(prefix.replace('\0', "\\0"), original.as_str()).into()
+        }
+        None => id.as_str().replace('\0', "\\0").into(),
+      },
       // Bare specifier / URL / … → as-is (cheap `Arc` clone).
       ModuleIdKind::Bare => id.as_arc_str().clone(),
     };
@@ -112,5 +121,15 @@ mod tests {
 
     // virtual module
     assert_eq!(StableModuleId::with_str("\0foo", &cwd).as_str(), "\\0foo");
+
+    // module typed by import attributes
+    assert_eq!(
+      StableModuleId::new(
+        &ModuleId::new_with_type(cwd.join("src").join("foo.js").expect_to_str(), "text"),
+        &cwd
+      )
+      .as_str(),
+      "\\0rolldown/with-type/text?src/foo.js"
+    );
   }
 }
@@ -132,6 +132,11 @@ bitflags! {
         const ReExportDynamicExports = 1 << 2;
         /// Statement contains non-static dynamic import like `import(foo)` or `import('a' + 'b')`
         const NonStaticDynamicImport = 1 << 3;
+        /// If this statement needs to reference `__glob` runtime
+        const GlobImport = 1 << 4;
+        /// `exports.__esModule = true` or `Object.defineProperty(exports, "__esModule", { value: true })`
+        /// in a module that `optimization.commonjsToEsm` may convert. Dropped once it is converted.
+        const CommonjsEsModuleFlag = 1 << 5;
     }
 }
 
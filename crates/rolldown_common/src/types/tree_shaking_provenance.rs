@@ -0,0 +1,155 @@
+use rolldown_utils::indexmap::FxIndexMap;
+
+/// Why each included module and top-level statement survived tree shaking. Only collected when
+/// `experimental.treeShakingProvenance` is enabled.
+///
+/// Every reason links to the module or statement whose inclusion caused it, so following
+/// [`InclusionReason::by`] from any site ends at a root such as an entry module. Only the first
+/// reason a site was included for is kept.
+#[derive(Debug, Default, Clone)]
+pub struct TreeShakingProvenance {
+  /// Keyed by the stable id of the module, in the order modules were included.
+  pub modules: FxIndexMap<String, ModuleProvenance>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModuleProvenance {
+  pub reason: InclusionReason,
+  /// Included statements, sorted by [`StatementProvenance::index`].
+  pub statements: Vec<StatementProvenance>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StatementProvenance {
+  /// `0` is the module's namespace object, which has no source. `n` is the `n`-th top-level
+  /// statement of the module.
+  pub index: usize,
+  /// The first line of the statement, shortened for display.
+  pub code: String,
+  pub reason: InclusionReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InclusionSite {
+  Module(String),
+  /// A module and the [`StatementProvenance::index`] of one of its statements.
+  Statement(String, usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InclusionReason {
+  pub kind: InclusionReasonKind,
+  /// The used symbol, named as declared in its module.
+  pub symbol: Option<String>,
+  /// The site whose inclusion caused this one. `None` for roots.
+  pub by: Option<InclusionSite>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InclusionReasonKind {
+  /// Root: a user-defined entry module.
+  Entry,
+  /// Root: a dynamic entry reached by a live `import()`, and what it exports.
+  DynamicEntry,
+  /// Root: an export of an entry module.
+  EntryExport,
+  /// Root: a runtime helper required by the generated code.
+  RuntimeHelper,
+  /// Root: an export of a CommonJS module whose `exports` object escapes, so none of its
+  /// exports can be removed.
+  CommonJsBailout,
+  /// Root: a re-export kept under `preserveModules` to mirror the module's export interface.
+  PreservedReexport,
+  /// The importer is included and this module has side effects.
+  SideEffectfulImport,
+  /// [`InclusionReason::symbol`] is used by the `by` site. The module owns it, or the statement
+  /// declares it.
+  UsedSymbol,
+  /// The statement has a side effect of its own.
+  SideEffect(SideEffectKind),
+  /// A side effect of a module declared side-effect free, which runs once the module's
+  /// [`InclusionReason::symbol`] is used.
+  BodyDemand,
+  /// The statement writes a property of [`InclusionReason::symbol`], which is used. Only occurs
+  /// with `treeshake.propertyWriteSideEffects: false`.
+  PropertyWrite,
+  /// Tree shaking is disabled for the module.
+  NoTreeshake,
+  /// The module calls `eval`, which can observe any of its bindings.
+  Eval,
+  /// In dev mode, the namespace object of every included ES module is registered with the HMR
+  /// runtime.
+  DevModeNamespace,
+}
+
+/// What makes a statement side-effectful, as far as can be told from its syntax.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SideEffectKind {
+  /// Assigns to or updates a property, e.g. `window.foo = 1`.
+  PropertyWrite,
+  /// Reads or writes a global binding that isn't declared anywhere in the bundle, e.g.
+  /// `console.log()`.
+  GlobalAccess(String),
+  /// Calls or constructs something not known to be pure.
+  Call,
+  Other,
+}
+
+impl InclusionReasonKind {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Entry => "entry",
+      Self::DynamicEntry => "dynamic-entry",
+      Self::EntryExport => "entry-export",
+      Self::RuntimeHelper => "runtime-helper",
+      Self::CommonJsBailout => "commonjs-bailout",
+      Self::PreservedReexport => "preserved-reexport",
+      Self::SideEffectfulImport => "side-effectful-import",
+      Self::UsedSymbol => "used-symbol",
+      Self::SideEffect(_) => "side-effect",
+      Self::BodyDemand => "body-demand",
+      Self::PropertyWrite => "property-write",
+      Self::NoTreeshake => "no-treeshake",
+      Self::Eval => "eval",
+      Self::DevModeNamespace => "dev-mode-namespace",
+    }
+  }
+}
+
+impl SideEffectKind {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::PropertyWrite => "property-write",
+      Self::GlobalAccess(_) => "global-access",
+      Self::Call => "call",
+      Self::Other => "other",
+    }
+  }
+}
+
+impl TreeShakingProvenance {
+  pub fn reason(&self, site: &InclusionSite) -> Option<&InclusionReason> {
+    match site {
+      InclusionSite::Module(module_id) => self.modules.get(module_id).map(|module| &module.reason),
+      InclusionSite::Statement(module_id, index) => {
+        let statements = &self.modules.get(module_id)?.statements;
+        let position = statements.binary_search_by_key(index, |stmt| stmt.index).ok()?;
+        Some(&statements[position].reason)
+      }
+    }
+  }
+
+  /// The reasons from `site` back to its root, starting with `site` itself. Empty if `site` was
+  /// not included.
+  pub fn chain(&self, site: &InclusionSite) -> Vec<(InclusionSite, &InclusionReason)> {
+    let mut chain = Vec::new();
+    let mut next = Some(site.clone());
+    // A site only ever points to a site included before it, so the walk always ends.
+    while let Some(site) = next.take() {
+      let Some(reason) = self.reason(&site) else { break };
+      next.clone_from(&reason.by);
+      chain.push((site, reason));
+    }
+    chain
+  }
+}
@@ -109,9 +109,4 @@ impl UsedExternalSymbols {
   ) -> Option<&FxHashMap<ModuleIdx, ExternalInteropUse>> {
     self.interop_uses.get(namespace_ref)
   }
-
-  #[inline]
-  pub fn has_interop_use(&self) -> bool {
-    !self.interop_uses.is_empty()
-  }
 }
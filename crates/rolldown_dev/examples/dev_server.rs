@@ -0,0 +1,49 @@
+#![expect(clippy::print_stdout, clippy::print_stderr)]
+
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, ExperimentalOptions};
+use rolldown_dev::{BundlerConfig, DevOptions, DevServer, DevServerOptions, DevWatchOptions};
+use sugar_path::SugarPath;
+
+// cargo run -p rolldown_dev --features server --example dev_server
+
+#[tokio::main]
+async fn main() {
+  let bundler_config = BundlerConfig::new(
+    BundlerOptions {
+      input: Some(vec!["./entry.js".to_string().into()]),
+      cwd: Some(
+        rolldown_workspace::crate_dir("rolldown").join("./examples/basic").normalize().into_owned(),
+      ),
+      experimental: Some(ExperimentalOptions {
+        incremental_build: Some(true),
+        ..Default::default()
+      }),
+      ..Default::default()
+    },
+    vec![],
+  );
+  let server = DevServer::new(
+    bundler_config,
+    DevOptions {
+      // Everything is served from memory.
+      watch: Some(DevWatchOptions { skip_write: Some(true), ..Default::default() }),
+      on_hmr_updates: Some(Arc::new(|result| match result {
+        Ok((updates, changed_files)) => {
+          println!("HMR updates: {updates:#?} due to {changed_files:#?}");
+        }
+        Err(e) => {
+          eprintln!("HMR error: {e:#?}");
+        }
+      })),
+      ..Default::default()
+    },
+    DevServerOptions { port: Some(3000), ..Default::default() },
+  )
+  .await
+  .unwrap();
+  server.run().await.unwrap();
+  println!("Serving on http://{}", server.local_addr());
+  server.engine().wait_for_close().await.unwrap();
+}
@@ -0,0 +1,312 @@
+use std::{
+  path::Path,
+  sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+  },
+};
+
+use futures::{SinkExt, StreamExt};
+use percent_encoding::percent_decode_str;
+use rolldown_utils::{concat_string, light_guess::mime_type_by_extension};
+use tokio::{
+  net::TcpStream,
+  sync::{mpsc::unbounded_channel, watch},
+};
+use tokio_tungstenite::{
+  WebSocketStream,
+  tungstenite::{Message, protocol::Role},
+};
+
+use super::{
+  http::{self, Request, Response},
+  server_message::ServerMessage,
+  server_state::{ServedFile, ServerState},
+};
+use crate::DevEngine;
+
+/// Requested by the proxy modules of `rolldown_plugin_lazy_compilation` (see its
+/// `proxy-module-template.js`) to compile a lazy entry.
+const LAZY_COMPILATION_PATH: &str = "/@vite/lazy";
+
+static NEXT_CONNECTION_ID: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Clone)]
+pub struct ConnectionContext {
+  pub engine: Arc<DevEngine>,
+  pub state: Arc<ServerState>,
+  /// Flips to `true` once the initial build is done. Requests wait for it, so a client never
+  /// sees a missing bundle while the server is starting.
+  pub ready: watch::Receiver<bool>,
+  pub shutdown: watch::Receiver<bool>,
+}
+
+pub async fn handle_connection(mut stream: TcpStream, mut ctx: ConnectionContext) {
+  let mut buf = Vec::new();
+  loop {
+    let request = tokio::select! {
+      request = http::read_request(&mut stream, &mut buf) => request,
+      _ = ctx.shutdown.changed() => return,
+    };
+    let Ok(Some(request)) = request else { return };
+    tokio::select! {
+      _ = ctx.ready.wait_for(|ready| *ready) => {}
+      _ = ctx.shutdown.changed() => return,
+    }
+
+    if let Some(websocket_key) = &request.websocket_key {
+      let websocket_key = websocket_key.clone();
+      return serve_websocket(stream, buf, &request, &websocket_key, ctx).await;
+    }
+    let served = if !matches!(request.method.as_str(), "GET" | "HEAD") {
+      http::write_response(&mut stream, &request, &Response::error("405 Method Not Allowed", ""))
+        .await
+    } else if request.path == LAZY_COMPILATION_PATH {
+      serve_lazy_entry(&mut stream, &request, &ctx).await
+    } else {
+      serve_file(&mut stream, &request, &ctx).await
+    };
+    if served.is_err() || !request.keep_alive {
+      return;
+    }
+  }
+}
+
+async fn serve_file(
+  stream: &mut TcpStream,
+  request: &Request,
+  ctx: &ConnectionContext,
+) -> std::io::Result<()> {
+  let path = percent_decode_str(&request.path).decode_utf8_lossy();
+  let filename = match path.trim_start_matches('/') {
+    "" => "index.html",
+    filename => filename,
+  };
+  let Some(file) = ctx.state.file(filename) else {
+    return http::write_response(stream, request, &Response::error("404 Not Found", "Not Found"))
+      .await;
+  };
+  let content_type = content_type_of(filename);
+  match file {
+    ServedFile::Bundle(content) => {
+      http::write_response(stream, request, &Response::ok(&content_type, &content)).await
+    }
+    ServedFile::Payload(content) => {
+      http::write_response(stream, request, &Response::ok(&content_type, &content)).await?;
+      ctx.state.remove_payload(filename);
+      ctx.engine.notify_payload_delivered(filename).await;
+      Ok(())
+    }
+  }
+}
+
+async fn serve_lazy_entry(
+  stream: &mut TcpStream,
+  request: &Request,
+  ctx: &ConnectionContext,
+) -> std::io::Result<()> {
+  let (Some(proxy_module_id), Some(client_id)) =
+    (request.query_param("id"), request.query_param("clientId"))
+  else {
+    return http::write_response(
+      stream,
+      request,
+      &Response::error("400 Bad Request", "Missing `id` or `clientId`"),
+    )
+    .await;
+  };
+  match ctx.engine.compile_lazy_entry(proxy_module_id, client_id).await {
+    Ok(output) => {
+      if let (Some(sourcemap), Some(sourcemap_filename)) =
+        (output.sourcemap, output.sourcemap_filename)
+      {
+        ctx.state.add_payload(sourcemap_filename, sourcemap.as_bytes());
+      }
+      let response = Response::ok("text/javascript; charset=utf-8", output.code.as_bytes());
+      http::write_response(stream, request, &response).await?;
+      ctx.engine.notify_payload_delivered(&output.filename).await;
+      Ok(())
+    }
+    Err(errors) => {
+      let message = errors.to_string();
+      http::write_response(stream, request, &Response::error("500 Internal Server Error", &message))
+        .await
+    }
+  }
+}
+
+/// Keeps a client's WebSocket open for its whole session. Inbound messages are dropped, as no
+/// server-side state depends on them.
+async fn serve_websocket(
+  mut stream: TcpStream,
+  read_ahead: Vec<u8>,
+  request: &Request,
+  websocket_key: &str,
+  mut ctx: ConnectionContext,
+) {
+  let Some(client_id) = request.query_param("clientId") else {
+    let response = Response::error("400 Bad Request", "Missing `clientId`");
+    let _ = http::write_response(&mut stream, request, &response).await;
+    return;
+  };
+  if http::write_switching_protocols(&mut stream, websocket_key).await.is_err() {
+    return;
+  }
+  let socket = WebSocketStream::from_partially_read(stream, read_ahead, Role::Server, None).await;
+  let (mut sink, mut inbound) = socket.split();
+
+  let connection_id = NEXT_CONNECTION_ID.fetch_add(1, Ordering::Relaxed);
+  let (sender, mut outbound) = unbounded_channel();
+  ctx.state.connect_client(client_id.clone(), connection_id, sender);
+  ctx.engine.register_client(client_id.clone()).await;
+  ctx.state.send(&client_id, ServerMessage::Connected);
+
+  loop {
+    tokio::select! {
+      message = outbound.recv() => {
+        // `None` means a newer connection of the same client replaced this one.
+        let Some(message) = message else { break };
+        let Ok(text) = serde_json::to_string(&message) else { continue };
+        if sink.send(Message::text(text)).await.is_err() {
+          break;
+        }
+      }
+      message = inbound.next() => {
+        if !matches!(message, Some(Ok(message)) if !message.is_close()) {
+          break;
+        }
+      }
+      _ = ctx.shutdown.changed() => break,
+    }
+  }
+
+  let _ = sink.close().await;
+  if ctx.state.disconnect_client(&client_id, connection_id) {
+    ctx.engine.remove_client(&client_id).await;
+  }
+}
+
+fn content_type_of(filename: &str) -> String {
+  let mime =
+    Path::new(filename).extension().and_then(|ext| ext.to_str()).and_then(mime_type_by_extension);
+  match mime {
+    Some(mime) if mime.is_utf8_encoded => concat_string!(mime.mime_str, "; charset=utf-8"),
+    Some(mime) => mime.mime_str.to_string(),
+    None => "application/octet-stream".to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use rolldown::BundlerOptions;
+  use rolldown_common::WatcherChangeKind;
+  use rolldown_utils::indexmap::FxIndexMap;
+  use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+  use super::*;
+  use crate::{BundlerConfig, DevOptions, DevServer, DevServerOptions, DevWatchOptions};
+
+  /// Starts a server for a project whose entry accepts updates of `dep.js`.
+  async fn start_server(name: &str) -> (DevServer, PathBuf) {
+    let cwd =
+      std::env::temp_dir().join(format!("rolldown-dev-server-{}-{name}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&cwd);
+    std::fs::create_dir_all(&cwd).unwrap();
+    std::fs::write(
+      cwd.join("main.js"),
+      "import { value } from './dep.js';\nconsole.log(value);\nimport.meta.hot.accept('./dep.js', () => {});\n",
+    )
+    .unwrap();
+    std::fs::write(cwd.join("dep.js"), "export const value = 'initial';\n").unwrap();
+
+    let server = DevServer::new(
+      BundlerConfig::new(
+        BundlerOptions {
+          input: Some(vec!["./main.js".to_string().into()]),
+          cwd: Some(cwd.clone()),
+          ..Default::default()
+        },
+        vec![],
+      ),
+      DevOptions {
+        watch: Some(DevWatchOptions {
+          disable_watcher: Some(true),
+          skip_write: Some(true),
+          ..Default::default()
+        }),
+        ..Default::default()
+      },
+      DevServerOptions { host: Some("127.0.0.1".to_string()), ..Default::default() },
+    )
+    .await
+    .unwrap();
+    server.run().await.unwrap();
+    (server, cwd)
+  }
+
+  /// Returns the status line and the body of the response to a one-off request.
+  async fn request(server: &DevServer, method: &str, target: &str) -> (String, String) {
+    let mut stream = TcpStream::connect(server.local_addr()).await.unwrap();
+    let head = format!("{method} {target} HTTP/1.1\r\nConnection: close\r\n\r\n");
+    stream.write_all(head.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    (head.lines().next().unwrap().to_string(), body.to_string())
+  }
+
+  async fn next_message(
+    socket: &mut WebSocketStream<TcpStream>,
+  ) -> serde_json::Map<String, serde_json::Value> {
+    let message = socket.next().await.unwrap().unwrap();
+    serde_json::from_str(message.to_text().unwrap()).unwrap()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn serves_the_bundle_and_rejects_other_requests() {
+    let (server, _) = start_server("http").await;
+
+    let (status, body) = request(&server, "GET", "/main.js").await;
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert!(body.contains("initial"), "{body}");
+    assert_eq!(
+      request(&server, "HEAD", "/main.js").await,
+      ("HTTP/1.1 200 OK".to_string(), String::new())
+    );
+    assert_eq!(request(&server, "GET", "/missing.js").await.0, "HTTP/1.1 404 Not Found");
+    assert_eq!(request(&server, "POST", "/main.js").await.0, "HTTP/1.1 405 Method Not Allowed");
+    assert_eq!(request(&server, "GET", LAZY_COMPILATION_PATH).await.0, "HTTP/1.1 400 Bad Request");
+
+    server.close().await.unwrap();
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn pushes_hmr_updates_and_evicts_delivered_patches() {
+    let (server, cwd) = start_server("hmr").await;
+
+    let stream = TcpStream::connect(server.local_addr()).await.unwrap();
+    let url = format!("ws://{}/?clientId=client", server.local_addr());
+    let (mut socket, _) = tokio_tungstenite::client_async(url, stream).await.unwrap();
+    assert_eq!(next_message(&mut socket).await["type"], "connected");
+
+    let dep = cwd.join("dep.js");
+    std::fs::write(&dep, "export const value = 'updated';\n").unwrap();
+    server
+      .engine()
+      .ensure_task_with_changed_files(FxIndexMap::from_iter([(dep, WatcherChangeKind::Update)]))
+      .await;
+
+    let update = next_message(&mut socket).await;
+    assert_eq!(update["type"], "hmr:update");
+    let patch_url = update["url"].as_str().unwrap();
+    let (status, body) = request(&server, "GET", patch_url).await;
+    assert_eq!(status, "HTTP/1.1 200 OK");
+    assert!(body.contains("updated"), "{body}");
+    assert_eq!(request(&server, "GET", patch_url).await.0, "HTTP/1.1 404 Not Found");
+
+    socket.close(None).await.unwrap();
+    server.close().await.unwrap();
+  }
+}
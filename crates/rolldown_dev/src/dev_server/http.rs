@@ -0,0 +1,249 @@
+//! Just enough HTTP/1.1 to serve files to a browser and accept WebSocket upgrades. Request bodies
+//! are read and dropped, as every route is a `GET`.
+
+use std::io;
+
+use rolldown_utils::concat_string;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Request heads larger than this are rejected, as nothing the server expects comes close.
+const MAX_HEAD_SIZE: usize = 64 * 1024;
+const MAX_HEADERS: usize = 64;
+
+pub struct Request {
+  pub method: String,
+  pub path: String,
+  pub query: Option<String>,
+  /// The `Sec-WebSocket-Key` of a WebSocket upgrade request.
+  pub websocket_key: Option<String>,
+  pub keep_alive: bool,
+}
+
+impl Request {
+  pub fn query_param(&self, name: &str) -> Option<String> {
+    url::form_urlencoded::parse(self.query.as_deref()?.as_bytes())
+      .find_map(|(key, value)| (key == name).then(|| value.into_owned()))
+  }
+}
+
+/// Reads the next request head from `stream`. `buf` carries bytes read past the previous head
+/// between calls. Returns `Ok(None)` when the peer closes the connection between requests.
+pub async fn read_request(
+  stream: &mut TcpStream,
+  buf: &mut Vec<u8>,
+) -> io::Result<Option<Request>> {
+  loop {
+    let mut headers = [httparse::EMPTY_HEADER; MAX_HEADERS];
+    let mut parsed = httparse::Request::new(&mut headers);
+    let status =
+      parsed.parse(buf).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    if let httparse::Status::Complete(head_len) = status {
+      let (request, body_len) = to_request(&parsed);
+      buf.drain(..head_len);
+      discard_body(stream, buf, body_len).await?;
+      return Ok(Some(request));
+    }
+    if buf.len() > MAX_HEAD_SIZE {
+      return Err(io::Error::new(io::ErrorKind::InvalidData, "request head is too large"));
+    }
+    if stream.read_buf(buf).await? == 0 {
+      return if buf.is_empty() { Ok(None) } else { Err(io::ErrorKind::UnexpectedEof.into()) };
+    }
+  }
+}
+
+fn to_request(parsed: &httparse::Request<'_, '_>) -> (Request, usize) {
+  let header = |name: &str| {
+    parsed
+      .headers
+      .iter()
+      .find(|header| header.name.eq_ignore_ascii_case(name))
+      .and_then(|header| std::str::from_utf8(header.value).ok())
+  };
+  let target = parsed.path.unwrap_or("/");
+  let (path, query) = match target.split_once('?') {
+    Some((path, query)) => (path, Some(query.to_string())),
+    None => (target, None),
+  };
+  let is_upgrade = header("upgrade").is_some_and(|value| value.eq_ignore_ascii_case("websocket"));
+  let request = Request {
+    method: parsed.method.unwrap_or_default().to_string(),
+    path: path.to_string(),
+    query,
+    websocket_key: is_upgrade.then(|| header("sec-websocket-key")).flatten().map(str::to_string),
+    // HTTP/1.1 connections are persistent unless the client says otherwise.
+    keep_alive: parsed.version == Some(1)
+      && !header("connection").is_some_and(|value| value.eq_ignore_ascii_case("close")),
+  };
+  let body_len = header("content-length").and_then(|value| value.parse().ok()).unwrap_or(0);
+  (request, body_len)
+}
+
+async fn discard_body(
+  stream: &mut TcpStream,
+  buf: &mut Vec<u8>,
+  body_len: usize,
+) -> io::Result<()> {
+  let buffered = body_len.min(buf.len());
+  buf.drain(..buffered);
+  let mut remaining = (body_len - buffered) as u64;
+  if remaining > 0 {
+    remaining -=
+      tokio::io::copy(&mut (&mut *stream).take(remaining), &mut tokio::io::sink()).await?;
+  }
+  if remaining > 0 { Err(io::ErrorKind::UnexpectedEof.into()) } else { Ok(()) }
+}
+
+pub struct Response<'a> {
+  pub status: &'static str,
+  pub content_type: Option<&'a str>,
+  pub body: &'a [u8],
+}
+
+impl<'a> Response<'a> {
+  pub fn ok(content_type: &'a str, body: &'a [u8]) -> Self {
+    Self { status: "200 OK", content_type: Some(content_type), body }
+  }
+
+  pub fn error(status: &'static str, message: &'a str) -> Self {
+    Self { status, content_type: Some("text/plain; charset=utf-8"), body: message.as_bytes() }
+  }
+}
+
+/// Writes `response` and flushes it, so a returned `Ok` means the whole response left the server.
+/// The body is left out for `HEAD` requests.
+pub async fn write_response(
+  stream: &mut TcpStream,
+  request: &Request,
+  response: &Response<'_>,
+) -> io::Result<()> {
+  let content_length = response.body.len().to_string();
+  let head = concat_string!(
+    "HTTP/1.1 ",
+    response.status,
+    "\r\n",
+    response
+      .content_type
+      .map(|ty| concat_string!("Content-Type: ", ty, "\r\n"))
+      .unwrap_or_default(),
+    "Content-Length: ",
+    content_length,
+    "\r\nCache-Control: no-cache\r\nAccess-Control-Allow-Origin: *\r\nConnection: ",
+    if request.keep_alive { "keep-alive" } else { "close" },
+    "\r\n\r\n"
+  );
+  stream.write_all(head.as_bytes()).await?;
+  if request.method != "HEAD" {
+    stream.write_all(response.body).await?;
+  }
+  stream.flush().await
+}
+
+/// Accepts a WebSocket upgrade, after which the stream speaks the WebSocket protocol.
+pub async fn write_switching_protocols(
+  stream: &mut TcpStream,
+  websocket_key: &str,
+) -> io::Result<()> {
+  let accept =
+    tokio_tungstenite::tungstenite::handshake::derive_accept_key(websocket_key.as_bytes());
+  let head = concat_string!(
+    "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: ",
+    accept,
+    "\r\n\r\n"
+  );
+  stream.write_all(head.as_bytes()).await?;
+  stream.flush().await
+}
+
+#[cfg(test)]
+mod tests {
+  use tokio::net::TcpListener;
+
+  use super::*;
+
+  /// Returns both ends of a local connection, after the client end sent `input`.
+  async fn connect(input: &[u8]) -> (TcpStream, TcpStream) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let mut client = TcpStream::connect(listener.local_addr().unwrap()).await.unwrap();
+    client.write_all(input).await.unwrap();
+    let (server, _) = listener.accept().await.unwrap();
+    (server, client)
+  }
+
+  async fn respond(request_head: &[u8], response: &Response<'_>) -> String {
+    let (mut server, mut client) = connect(request_head).await;
+    let request = read_request(&mut server, &mut Vec::new()).await.unwrap().unwrap();
+    write_response(&mut server, &request, response).await.unwrap();
+    drop(server);
+    let mut written = String::new();
+    client.read_to_string(&mut written).await.unwrap();
+    written
+  }
+
+  #[tokio::test]
+  async fn reads_pipelined_requests_and_skips_their_bodies() {
+    let (mut server, client) = connect(
+      b"POST /a?clientId=a%20b HTTP/1.1\r\nContent-Length: 5\r\n\r\nhelloGET /b HTTP/1.1\r\nConnection: close\r\n\r\n",
+    )
+    .await;
+    let mut buf = Vec::new();
+
+    let first = read_request(&mut server, &mut buf).await.unwrap().unwrap();
+    assert_eq!(first.method, "POST");
+    assert_eq!(first.path, "/a");
+    assert_eq!(first.query_param("clientId").as_deref(), Some("a b"));
+    assert_eq!(first.query_param("id"), None);
+    assert!(first.keep_alive);
+
+    let second = read_request(&mut server, &mut buf).await.unwrap().unwrap();
+    assert_eq!(second.method, "GET");
+    assert_eq!(second.path, "/b");
+    assert_eq!(second.query, None);
+    assert!(!second.keep_alive);
+
+    drop(client);
+    assert!(read_request(&mut server, &mut buf).await.unwrap().is_none());
+  }
+
+  #[tokio::test]
+  async fn reads_websocket_upgrades() {
+    let (mut server, _client) = connect(
+      b"GET /?clientId=a HTTP/1.1\r\nUpgrade: WebSocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\r\n",
+    )
+    .await;
+    let request = read_request(&mut server, &mut Vec::new()).await.unwrap().unwrap();
+    assert_eq!(request.websocket_key.as_deref(), Some("dGhlIHNhbXBsZSBub25jZQ=="));
+  }
+
+  #[tokio::test]
+  async fn rejects_truncated_and_oversized_heads() {
+    let (mut server, client) = connect(b"GET / HTTP/1.1\r\nHost: localhost\r\n").await;
+    drop(client);
+    let err = read_request(&mut server, &mut Vec::new()).await.err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+    let mut head = b"GET / HTTP/1.1\r\nX-Padding: ".to_vec();
+    head.resize(head.len() + MAX_HEAD_SIZE, b'a');
+    let (mut server, _client) = connect(&head).await;
+    let err = read_request(&mut server, &mut Vec::new()).await.err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+  }
+
+  #[tokio::test]
+  async fn writes_responses_without_bodies_for_head_requests() {
+    let response = Response::ok("text/plain", b"hello");
+
+    let written = respond(b"GET / HTTP/1.1\r\n\r\n", &response).await;
+    assert!(written.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(written.contains("Content-Type: text/plain\r\n"));
+    assert!(written.contains("Content-Length: 5\r\n"));
+    assert!(written.contains("Connection: keep-alive\r\n"));
+    assert!(written.ends_with("\r\n\r\nhello"));
+
+    let written = respond(b"HEAD / HTTP/1.0\r\n\r\n", &response).await;
+    assert!(written.contains("Content-Length: 5\r\n"));
+    assert!(written.contains("Connection: close\r\n"));
+    assert!(written.ends_with("\r\n\r\n"));
+  }
+}
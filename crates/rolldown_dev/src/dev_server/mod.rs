@@ -0,0 +1,158 @@
+mod connection;
+mod http;
+mod server_message;
+mod server_state;
+
+use std::{net::SocketAddr, sync::Arc};
+
+use anyhow::Context;
+use rolldown_error::{BuildResult, ResultExt};
+use rolldown_plugin_hmr::default_dev_runtime_implement;
+use tokio::{net::TcpListener, sync::watch};
+
+use self::{connection::ConnectionContext, server_state::ServerState};
+use crate::{BundlerConfig, DevEngine, DevOptions};
+
+#[derive(Debug, Default)]
+pub struct DevServerOptions {
+  /// Host to listen on. Defaults to `localhost`.
+  pub host: Option<String>,
+  /// Port to listen on. Defaults to `0`, which lets the OS pick a free port.
+  pub port: Option<u16>,
+}
+
+/// Serves a [`DevEngine`] over HTTP and WebSocket, so HMR works without a JavaScript server:
+///
+/// - `GET /<filename>` serves the latest build output and HMR patches from memory.
+/// - `GET /@vite/lazy?id=..&clientId=..` compiles a lazy entry for
+///   `rolldown_plugin_lazy_compilation`.
+/// - A WebSocket upgrade with a `clientId` query parameter opens a client session, which
+///   receives `hmr:update` / `hmr:reload` messages.
+///
+/// Responses of HMR patches and lazy entries are reported to the engine with
+/// [`DevEngine::notify_payload_delivered`] once they are written.
+pub struct DevServer {
+  engine: Arc<DevEngine>,
+  local_addr: SocketAddr,
+  ready: watch::Sender<bool>,
+  shutdown: watch::Sender<bool>,
+}
+
+impl DevServer {
+  /// Binds the server, then creates the engine. The listener is bound first so the bound port is
+  /// known when `experimental.devMode` is filled in: the HMR runtime connects to the `host` and
+  /// `port` given there, and gets the default `DevRuntime` implementation unless `implement` is
+  /// set.
+  ///
+  /// The callbacks in `dev_options` are still called, after the server has handled them.
+  pub async fn new(
+    mut config: BundlerConfig,
+    mut dev_options: DevOptions,
+    server_options: DevServerOptions,
+  ) -> BuildResult<Self> {
+    let host = server_options.host.unwrap_or_else(|| "localhost".to_string());
+    let listener = TcpListener::bind((host.as_str(), server_options.port.unwrap_or(0)))
+      .await
+      .map_err_to_unhandleable()
+      .context("DevServer: failed to bind the listener")?;
+    let local_addr = listener.local_addr().map_err_to_unhandleable()?;
+    let addr = format!("{host}:{}", local_addr.port());
+
+    let dev_mode =
+      config.options.experimental.get_or_insert_default().dev_mode.get_or_insert_default();
+    dev_mode.host.get_or_insert(host);
+    dev_mode.port = Some(local_addr.port());
+    dev_mode.implement.get_or_insert_with(|| default_dev_runtime_implement(&addr));
+
+    let state = Arc::new(ServerState::default());
+    hook_dev_options(&mut dev_options, &state, format!("http://{addr}"));
+    let engine = Arc::new(DevEngine::new(config, dev_options)?);
+
+    let (ready, ready_receiver) = watch::channel(false);
+    let (shutdown, shutdown_receiver) = watch::channel(false);
+    let ctx = ConnectionContext {
+      engine: Arc::clone(&engine),
+      state,
+      ready: ready_receiver,
+      shutdown: shutdown_receiver,
+    };
+    tokio::spawn(accept_connections(listener, ctx));
+
+    Ok(Self { engine, local_addr, ready, shutdown })
+  }
+
+  pub fn local_addr(&self) -> SocketAddr {
+    self.local_addr
+  }
+
+  pub fn engine(&self) -> &Arc<DevEngine> {
+    &self.engine
+  }
+
+  /// Runs the initial build. Requests that arrive earlier are answered once it is done, whether
+  /// it succeeded or not.
+  pub async fn run(&self) -> BuildResult<()> {
+    let result = self.engine.run().await;
+    self.ready.send_replace(true);
+    result
+  }
+
+  /// Stops accepting connections, closes the open ones and closes the engine.
+  pub async fn close(&self) -> BuildResult<()> {
+    self.shutdown.send_replace(true);
+    self.engine.close().await
+  }
+}
+
+async fn accept_connections(listener: TcpListener, mut ctx: ConnectionContext) {
+  loop {
+    tokio::select! {
+      accepted = listener.accept() => {
+        match accepted {
+          Ok((stream, _)) => {
+            tokio::spawn(connection::handle_connection(stream, ctx.clone()));
+          }
+          Err(err) => tracing::warn!("[DevServer] failed to accept a connection: {err}"),
+        }
+      }
+      _ = ctx.shutdown.changed() => break,
+    }
+  }
+}
+
+/// Wraps the engine callbacks so the server keeps its files up to date and pushes HMR updates
+/// before the user callbacks run.
+fn hook_dev_options(dev_options: &mut DevOptions, state: &Arc<ServerState>, origin: String) {
+  let on_output = dev_options.on_output.take();
+  let output_state = Arc::clone(state);
+  dev_options.on_output = Some(Arc::new(move |result| {
+    if let Ok(output) = &result {
+      output_state.replace_bundle(&output.assets);
+    }
+    if let Some(on_output) = &on_output {
+      on_output(result);
+    }
+  }));
+
+  let on_additional_assets = dev_options.on_additional_assets.take();
+  let assets_state = Arc::clone(state);
+  dev_options.on_additional_assets = Some(Arc::new(move |output| {
+    for asset in &output.assets {
+      assets_state.add_payload(asset.filename().to_string(), asset.content_as_bytes());
+    }
+    if let Some(on_additional_assets) = &on_additional_assets {
+      on_additional_assets(output);
+    }
+  }));
+
+  let on_hmr_updates = dev_options.on_hmr_updates.take();
+  let hmr_state = Arc::clone(state);
+  dev_options.on_hmr_updates = Some(Arc::new(move |result| {
+    if let Ok((updates, _)) = &result {
+      hmr_state.dispatch_hmr_updates(updates, &origin);
+    }
+    if let Some(on_hmr_updates) = &on_hmr_updates {
+      on_hmr_updates(result);
+    }
+  }));
+}
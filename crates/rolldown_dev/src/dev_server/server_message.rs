@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+/// Messages pushed to the client over the WebSocket, in the shape the default `DevRuntime`
+/// implementation (`runtime-extra-dev-default.js`) reads.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum ServerMessage {
+  /// Sent once the client's session is registered.
+  #[serde(rename = "connected")]
+  Connected,
+  /// A patch is ready to be loaded from `url`.
+  #[serde(rename = "hmr:update", rename_all = "camelCase")]
+  HmrUpdate {
+    /// Path of the patch on this server, loaded by browsers.
+    url: String,
+    /// Absolute URL of the patch, imported by non-browser clients.
+    path: String,
+    seq: u32,
+    changed_ids: Vec<String>,
+  },
+  #[serde(rename = "hmr:reload")]
+  HmrReload { reason: String },
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use parking_lot::{Mutex, RwLock};
+use rolldown_common::{ClientHmrUpdate, HmrUpdate, Output};
+use rustc_hash::FxHashMap;
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::server_message::ServerMessage;
+
+/// State shared by the engine callbacks, which are synchronous, and the connection tasks. The
+/// locks are never held across an `.await`.
+#[derive(Default)]
+pub struct ServerState {
+  /// Files of the latest full build, replaced on every `on_output`.
+  bundle: RwLock<FxHashMap<String, Arc<[u8]>>>,
+  /// Files that arrive between full builds: HMR patches, sourcemaps of lazy chunks and
+  /// additional assets. Patches are never overwritten, as their filenames are unique, so each
+  /// payload is removed once it is delivered.
+  payloads: RwLock<FxHashMap<String, Arc<[u8]>>>,
+  /// Outgoing message queue of every connected client, tagged with the id of its connection.
+  /// A client that reconnects with the same id replaces its previous connection.
+  sockets: Mutex<FxHashMap<String, (u64, UnboundedSender<ServerMessage>)>>,
+}
+
+pub enum ServedFile {
+  Bundle(Arc<[u8]>),
+  /// A file from `payloads`. Once delivered, it must be removed with
+  /// [`ServerState::remove_payload`] and reported to the engine, which ignores files that aren't
+  /// pending payloads.
+  Payload(Arc<[u8]>),
+}
+
+impl ServerState {
+  pub fn replace_bundle(&self, assets: &[Output]) {
+    let bundle = assets
+      .iter()
+      .map(|asset| (asset.filename().to_string(), Arc::from(asset.content_as_bytes())))
+      .collect();
+    *self.bundle.write() = bundle;
+  }
+
+  pub fn add_payload(&self, filename: String, content: &[u8]) {
+    self.payloads.write().insert(filename, Arc::from(content));
+  }
+
+  pub fn remove_payload(&self, filename: &str) {
+    self.payloads.write().remove(filename);
+  }
+
+  pub fn file(&self, filename: &str) -> Option<ServedFile> {
+    if let Some(content) = self.payloads.read().get(filename) {
+      return Some(ServedFile::Payload(Arc::clone(content)));
+    }
+    self.bundle.read().get(filename).map(|content| ServedFile::Bundle(Arc::clone(content)))
+  }
+
+  pub fn connect_client(
+    &self,
+    client_id: String,
+    connection_id: u64,
+    sender: UnboundedSender<ServerMessage>,
+  ) {
+    // Dropping the previous sender ends the replaced connection.
+    self.sockets.lock().insert(client_id, (connection_id, sender));
+  }
+
+  /// Returns `false` if the client has reconnected since, in which case its session must be kept.
+  pub fn disconnect_client(&self, client_id: &str, connection_id: u64) -> bool {
+    let mut sockets = self.sockets.lock();
+    if sockets.get(client_id).is_some_and(|(id, _)| *id == connection_id) {
+      sockets.remove(client_id);
+      true
+    } else {
+      false
+    }
+  }
+
+  pub fn send(&self, client_id: &str, message: ServerMessage) {
+    if let Some((_, sender)) = self.sockets.lock().get(client_id) {
+      // The receiver is gone only while the connection is shutting down.
+      let _ = sender.send(message);
+    }
+  }
+
+  /// Stores the patches so they can be requested, then tells each client what to load.
+  pub fn dispatch_hmr_updates(&self, updates: &[ClientHmrUpdate], origin: &str) {
+    for ClientHmrUpdate { client_id, update } in updates {
+      let message = match update {
+        HmrUpdate::Patch(patch) => {
+          self.add_payload(patch.filename.clone(), patch.code.as_bytes());
+          if let (Some(sourcemap), Some(sourcemap_filename)) =
+            (&patch.sourcemap, &patch.sourcemap_filename)
+          {
+            self.add_payload(sourcemap_filename.clone(), sourcemap.as_bytes());
+          }
+          let url = format!("/{}", patch.filename);
+          ServerMessage::HmrUpdate {
+            path: format!("{origin}{url}"),
+            url,
+            seq: patch.seq,
+            changed_ids: patch.changed_ids.clone(),
+          }
+        }
+        HmrUpdate::FullReload { reason } => ServerMessage::HmrReload { reason: reason.clone() },
+        HmrUpdate::Noop => continue,
+      };
+      self.send(client_id, message);
+    }
+  }
+}
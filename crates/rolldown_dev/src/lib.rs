@@ -2,6 +2,8 @@ mod bundle_coordinator;
 mod bundling_task;
 mod dev_context;
 mod dev_engine;
+#[cfg(feature = "server")]
+mod dev_server;
 mod type_aliases;
 mod types;
 mod watcher_event_handler;
@@ -24,6 +26,8 @@ pub use {
   },
 };
 
+#[cfg(feature = "server")]
+pub use crate::dev_server::{DevServer, DevServerOptions};
 use crate::types::client_session::ClientSession;
 
 // Multiple clients are not accessed from multiple threads simultaneously
@@ -1,8 +1,14 @@
-use rolldown_common::Output;
+use rolldown_common::{ChunkRecords, Metafile, Output, TreeShakingProvenance};
 use rolldown_error::BuildDiagnostic;
 
 #[derive(Default)]
 pub struct BundleOutput {
   pub warnings: Vec<BuildDiagnostic>,
   pub assets: Vec<Output>,
+  /// Only present when `output.metafile` is enabled.
+  pub metafile: Option<Metafile>,
+  /// Only present when `experimental.treeShakingProvenance` is enabled.
+  pub tree_shaking_provenance: Option<TreeShakingProvenance>,
+  /// Only present when `experimental.recordsPath` is set.
+  pub chunk_records: Option<ChunkRecords>,
 }
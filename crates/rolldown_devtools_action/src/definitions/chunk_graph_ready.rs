@@ -57,7 +57,7 @@ pub struct Chunk {
   pub is_async_entry: bool,
   pub entry_module: Option<String>,
   pub modules: Vec<String>,
-  #[ts(type = "'advanced-chunks' | 'preserve-modules' | 'entry' | 'common'")]
+  #[ts(type = "'advanced-chunks' | 'preserve-modules' | 'entry' | 'common' | 'chunk-record'")]
   pub reason: &'static str,
   pub imports: Vec<ChunkImport>,
 }
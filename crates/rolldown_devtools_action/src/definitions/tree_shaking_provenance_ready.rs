@@ -0,0 +1,51 @@
+/// Emitted after tree shaking when `experimental.treeShakingProvenance` is enabled.
+#[derive(ts_rs::TS, serde::Serialize)]
+#[ts(export)]
+pub struct TreeShakingProvenanceReady {
+  #[ts(type = "'TreeShakingProvenanceReady'")]
+  pub action: &'static str,
+  /// Included modules, in the order they were included.
+  pub modules: Vec<IncludedModule>,
+}
+
+#[derive(ts_rs::TS, serde::Serialize)]
+#[ts(export)]
+pub struct IncludedModule {
+  pub module_id: String,
+  pub reason: InclusionReason,
+  pub statements: Vec<IncludedStatement>,
+}
+
+#[derive(ts_rs::TS, serde::Serialize)]
+#[ts(export)]
+pub struct IncludedStatement {
+  /// `0` is the module's namespace object. `n` is the `n`-th top-level statement of the module.
+  pub stmt_index: u32,
+  /// The first line of the statement.
+  pub code: String,
+  pub reason: InclusionReason,
+}
+
+#[derive(ts_rs::TS, serde::Serialize)]
+#[ts(export)]
+pub struct InclusionReason {
+  /// Refer to crates/rolldown_common/src/types/tree_shaking_provenance.rs
+  /// - `entry`, `dynamic-entry`, `entry-export`, `runtime-helper`, `commonjs-bailout` and
+  ///   `preserved-reexport` are roots, which have no `by_module_id`.
+  /// - `side-effectful-import`: `by_module_id` imports this module, which has side effects.
+  /// - `used-symbol`: `symbol` is used by the `by_*` statement.
+  /// - `side-effect`: the statement has side effects, described by `side_effect`.
+  #[ts(
+    type = "'entry' | 'dynamic-entry' | 'entry-export' | 'runtime-helper' | 'commonjs-bailout' | 'preserved-reexport' | 'side-effectful-import' | 'used-symbol' | 'side-effect' | 'body-demand' | 'property-write' | 'no-treeshake' | 'eval' | 'dev-mode-namespace'"
+  )]
+  pub kind: &'static str,
+  pub symbol: Option<String>,
+  #[ts(type = "'property-write' | 'global-access' | 'call' | 'other' | null")]
+  pub side_effect: Option<&'static str>,
+  /// Name of the accessed global, for `global-access` side effects.
+  pub global_name: Option<String>,
+  /// The module whose inclusion caused this one.
+  pub by_module_id: Option<String>,
+  /// Set if it was a statement of `by_module_id`.
+  pub by_stmt_index: Option<u32>,
+}
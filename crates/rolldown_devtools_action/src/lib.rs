@@ -19,6 +19,9 @@ pub use {
     module_graph_ready::{Module, ModuleGraphReady, ModuleImport},
     package_graph_ready::{PackageGraphReady, PackageInfo},
     session_meta::SessionMeta,
+    tree_shaking_provenance_ready::{
+      IncludedModule, IncludedStatement, InclusionReason, TreeShakingProvenanceReady,
+    },
   },
   types::{InputItem, PluginItem},
 };
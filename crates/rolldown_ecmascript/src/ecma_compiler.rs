@@ -88,7 +88,11 @@ impl EcmaCompiler {
   }
 
   pub fn print_with(ast: &EcmaAst, options: PrintOptions) -> CodegenReturn<'_> {
-    let legal = if options.comments.legal { LegalComment::Inline } else { LegalComment::None };
+    let legal = match (options.comments.legal, options.comments.extract_legal) {
+      (false, _) => LegalComment::None,
+      (true, false) => LegalComment::Inline,
+      (true, true) => LegalComment::External,
+    };
     Codegen::new()
       .with_options(CodegenOptions {
         comments: CommentOptions {
@@ -156,8 +160,11 @@ pub struct PrintOptions {
 }
 
 #[derive(Debug, Default, Clone, Copy)]
+#[expect(clippy::struct_excessive_bools)]
 pub struct PrintCommentsOptions {
   pub legal: bool,
+  /// Return legal comments in `CodegenReturn::legal_comments` instead of printing them in place.
+  pub extract_legal: bool,
   pub annotation: bool,
   pub jsdoc: bool,
 }
@@ -19,7 +19,7 @@ use oxc::{
   allocator::{self, GetAllocator, IntoIn},
   ast::{
     ast::{
-      Argument, ArrowFunctionBody, ArrowFunctionExpression, AssignmentOperator, AssignmentTarget,
+      Argument, ArrayExpressionElement, ArrowFunctionBody, ArrowFunctionExpression, AssignmentOperator, AssignmentTarget,
       BindingIdentifier, BindingPattern, CallExpression, ClassElement,
       ExportDefaultDeclarationKind, ExportSpecifier, Expression, FormalParameter,
       FormalParameterKind, FormalParameters, FunctionBody, FunctionType, IdentifierName,
@@ -146,6 +146,90 @@ pub trait ExpressionFactoryExt<'ast> {
     )
   }
 
+  /// `new Promise((resolve, reject) => require([<source>], resolve, reject))`, the AMD
+  /// counterpart of a dynamic `import(<source>)`.
+  fn new_amd_require_promise<B: GetAstBuilder<'ast> + GetAllocator<'ast>>(
+    source: Expression<'ast>,
+    builder: &B,
+  ) -> Expression<'ast> {
+    let param = |name: &'static str| {
+      FormalParameter::new(
+        SPAN,
+        [],
+        BindingPattern::new_binding_identifier(SPAN, name, builder),
+        None,
+        None,
+        false,
+        None,
+        false,
+        false,
+        builder,
+      )
+    };
+    let require_call = Expression::new_call_expression(
+      SPAN,
+      Expression::new_identifier(SPAN, "require", builder),
+      None,
+      [
+        Argument::new_array_expression(
+          SPAN,
+          oxc::allocator::Vec::from_value_in(ArrayExpressionElement::from(source), builder),
+          builder,
+        ),
+        Argument::new_identifier(SPAN, "resolve", builder),
+        Argument::new_identifier(SPAN, "reject", builder),
+      ],
+      false,
+      builder,
+    );
+    let executor = Argument::new_arrow_function_expression(
+      SPAN,
+      false,
+      None,
+      FormalParameters::boxed(
+        SPAN,
+        FormalParameterKind::ArrowFormalParameters,
+        [param("resolve"), param("reject")],
+        None,
+        builder,
+      ),
+      None,
+      ArrowFunctionBody::from(require_call),
+      builder,
+    );
+    Expression::new_new_expression(
+      SPAN,
+      Expression::new_identifier(SPAN, "Promise", builder),
+      None,
+      [executor],
+      builder,
+    )
+  }
+
+  /// `exports("<name>", <value>)`, which publishes a binding of a SystemJS module.
+  fn new_system_exports_call<B: GetAstBuilder<'ast> + GetAllocator<'ast>>(
+    name: &str,
+    value: Expression<'ast>,
+    builder: &B,
+  ) -> Expression<'ast> {
+    Expression::new_call_expression(
+      SPAN,
+      Expression::new_identifier(SPAN, "exports", builder),
+      None,
+      [
+        Argument::new_string_literal(
+          SPAN,
+          oxc::ast::ast::Str::from_str_in(name, builder),
+          None,
+          builder,
+        ),
+        Argument::from(value),
+      ],
+      false,
+      builder,
+    )
+  }
+
   /// `None` → `<call_expr>`; `Babel` → `__toESM(<call_expr>)`; `Node` → `__toESM(<call_expr>, 1)`.
   fn new_to_esm_call_with_interop<B: GetAstBuilder<'ast> + GetAllocator<'ast>>(
     to_esm_fn_name: &str,
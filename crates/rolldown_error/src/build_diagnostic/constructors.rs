@@ -21,6 +21,7 @@ use super::events::bundler_initialize_error::BundlerInitializeError;
 use super::events::cannot_call_namespace::CannotCallNamespace;
 use super::events::configuration_field_conflict::ConfigurationFieldConflict;
 use super::events::could_not_clean_directory::CouldNotCleanDirectory;
+use super::events::could_not_write_records::CouldNotWriteRecords;
 use super::events::duplicate_shebang::DuplicateShebang;
 use super::events::filename_conflict::FilenameConflict;
 use super::events::filename_outside_output_directory::FilenameOutsideOutputDirectory;
@@ -50,15 +51,19 @@ use super::events::{
   circular_dependency::CircularDependency,
   circular_reexport::CircularReexport,
   commonjs_variable_in_esm::{CjsExportSpan, CommonJsVariableInEsm},
+  empty_glob_import::EmptyGlobImport,
   eval::Eval,
+  exceeded_max_requests::ExceededMaxRequests,
   external_entry::ExternalEntry,
   file_not_found::FileNotFound,
   forbid_const_assign::ForbidConstAssign,
+  import_attribute_mismatch::ImportAttributeMismatch,
   invalid_export_option::InvalidExportOption,
   missing_export::MissingExport,
   mixed_exports::MixedExports,
   namespace_conflict::{NamespaceConflict, NamespaceConflictExporter},
   oxc_error::OxcError,
+  unexpected_named_import::UnexpectedNamedImport,
   unresolved_entry::UnresolvedEntry,
 };
 
@@ -350,6 +355,55 @@ impl BuildDiagnostic {
     Self::new_inner(Eval { span, source, filename })
   }
 
+  pub fn empty_glob_import(filename: String, source: ArcStr, span: Span, glob: String) -> Self {
+    Self::new_inner(EmptyGlobImport { filename, source, span, glob })
+  }
+
+  pub fn unexpected_named_import(
+    importer: String,
+    stable_importer: String,
+    importer_source: ArcStr,
+    stable_importee: String,
+    imported_specifier: String,
+    imported_specifier_span: Span,
+  ) -> Self {
+    Self::new_inner(UnexpectedNamedImport {
+      importer,
+      stable_importer,
+      importer_source,
+      stable_importee,
+      imported_specifier,
+      imported_specifier_span,
+    })
+  }
+
+  pub fn import_attribute_mismatch(
+    importer: ArcStr,
+    importer_source: ArcStr,
+    span: Span,
+    attribute_type: String,
+    importee: String,
+    module_type: String,
+  ) -> Self {
+    Self::new_inner(ImportAttributeMismatch {
+      importer,
+      importer_source,
+      span,
+      attribute_type,
+      importee,
+      module_type,
+    })
+  }
+
+  pub fn exceeded_max_requests(
+    entry_module_id: String,
+    option_name: &'static str,
+    limit: u32,
+    requests: u32,
+  ) -> Self {
+    Self::new_inner(ExceededMaxRequests { entry_module_id, option_name, limit, requests })
+  }
+
   pub fn configuration_field_conflict(
     a_config_name: &str,
     a_field_name: &str,
@@ -443,6 +497,10 @@ impl BuildDiagnostic {
     Self::new_inner(CouldNotCleanDirectory { dir, reason })
   }
 
+  pub fn could_not_write_records(path: String, reason: String) -> Self {
+    Self::new_inner(CouldNotWriteRecords { path, reason })
+  }
+
   pub fn plugin_timings(timings: PluginTimings) -> Self {
     Self::new_inner(timings)
   }
@@ -0,0 +1,22 @@
+use super::BuildEvent;
+use crate::{types::diagnostic_options::DiagnosticOptions, types::event_kind::EventKind};
+
+#[derive(Debug)]
+pub struct CouldNotWriteRecords {
+  pub path: String,
+  pub reason: String,
+}
+
+impl BuildEvent for CouldNotWriteRecords {
+  fn kind(&self) -> EventKind {
+    EventKind::CouldNotWriteRecords
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Could not write chunk records to {}. Reason: {}",
+      opts.stabilize_path(&self.path),
+      self.reason
+    )
+  }
+}
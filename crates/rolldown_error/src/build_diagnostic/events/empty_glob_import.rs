@@ -0,0 +1,44 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct EmptyGlobImport {
+  pub filename: String,
+  pub source: ArcStr,
+  pub span: Span,
+  /// The call with its path turned into a glob, e.g. `import("./src/**/*.js")`.
+  pub glob: String,
+}
+
+impl BuildEvent for EmptyGlobImport {
+  fn kind(&self) -> crate::types::event_kind::EventKind {
+    crate::types::event_kind::EventKind::EmptyGlobImport
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("The glob pattern {} did not match any files.", self.glob)
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+    let file_id = diagnostic.add_file(filename, self.source.clone());
+
+    diagnostic.title = format!("The glob pattern {} did not match any files.", self.glob);
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      String::from("This call is kept as-is and will fail at runtime if the path doesn't exist."),
+    );
+  }
+}
@@ -0,0 +1,31 @@
+use super::BuildEvent;
+use crate::{types::diagnostic_options::DiagnosticOptions, types::event_kind::EventKind};
+
+#[derive(Debug)]
+pub struct ExceededMaxRequests {
+  pub entry_module_id: String,
+  /// `maxInitialRequests` or `maxAsyncRequests`.
+  pub option_name: &'static str,
+  pub limit: u32,
+  pub requests: u32,
+}
+
+impl BuildEvent for ExceededMaxRequests {
+  fn kind(&self) -> EventKind {
+    EventKind::ExceededMaxRequests
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Loading {} requests {} chunks, more than `codeSplitting.{}` ({}) allows. The chunks it loads can't be merged further without changing an entry signature, running a module some entry doesn't need or creating a circular chunk import.",
+      opts.stabilize_path(&self.entry_module_id),
+      self.requests,
+      self.option_name,
+      self.limit,
+    )
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.entry_module_id.clone())
+  }
+}
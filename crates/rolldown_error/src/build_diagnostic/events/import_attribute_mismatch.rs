@@ -0,0 +1,55 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use super::BuildEvent;
+use crate::{
+  build_diagnostic::diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions,
+  types::event_kind::EventKind,
+};
+
+#[derive(Debug)]
+pub struct ImportAttributeMismatch {
+  pub importer: ArcStr,
+  pub importer_source: ArcStr,
+  pub span: Span,
+  pub attribute_type: String,
+  pub importee: String,
+  pub module_type: String,
+}
+
+impl BuildEvent for ImportAttributeMismatch {
+  fn kind(&self) -> EventKind {
+    EventKind::ImportAttributeMismatchError
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.importer.to_string())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      r#""{}" is imported with `type: "{}"` in "{}", but it is a {} module."#,
+      opts.stabilize_path(&self.importee),
+      self.attribute_type,
+      opts.stabilize_path(self.importer.as_str()),
+      self.module_type,
+    )
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.title = format!(
+      r#""{}" is imported with `type: "{}"`, but it is a {} module."#,
+      opts.stabilize_path(&self.importee),
+      self.attribute_type,
+      self.module_type,
+    );
+
+    let file_id = diagnostic
+      .add_file(opts.stabilize_path(self.importer.as_str()), self.importer_source.clone());
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      format!("This import asks for a {} module", self.attribute_type),
+    );
+  }
+}
@@ -6,6 +6,7 @@ pub enum InvalidOptionType {
   UnsupportedInlineDynamicFormat(String),
   UnsupportedCodeSplittingFormat(String),
   InvalidOutputFile,
+  AmdIdWithMultipleChunks,
   OutputFileWithoutName(String),
   InvalidOutputDirOption,
   NoEntryPoint,
@@ -21,6 +22,7 @@ pub enum InvalidOptionType {
   HashLengthTooShort { pattern_name: String, received: usize, min: usize, chunk_count: u32 },
   InvalidEmittedFileName(String),
   NulByteInFilename { pattern_name: String },
+  PrecompressUnavailable,
 }
 
 #[derive(Debug)]
@@ -42,6 +44,7 @@ impl BuildEvent for InvalidOption {
           format!("Invalid value \"{format}\" for option \"output.format\" - UMD and IIFE are not supported for code-splitting builds. For single entry builds, you can set `output.codeSplitting` to `false` to disable code-splitting.")
         }
         InvalidOptionType::InvalidOutputFile => "Invalid value for option \"output.file\" - When building multiple chunks, the \"output.dir\" option must be used, not \"output.file\". You may set `output.codeSplitting` to `false` when using dynamic imports.".to_string(),
+        InvalidOptionType::AmdIdWithMultipleChunks => "Invalid value for option \"output.amd.id\" - this option is only supported for single-file builds.".to_string(),
         InvalidOptionType::OutputFileWithoutName(file) => {
           format!("Invalid value \"{file}\" for option \"output.file\" - it does not contain a file name. Please provide a path that ends with a file name.")
         }
@@ -108,6 +111,9 @@ impl BuildEvent for InvalidOption {
         InvalidOptionType::NulByteInFilename { pattern_name } => {
           format!("The \"{pattern_name}\" pattern (or the value returned from the function) would result in a filename with invalid null byte(s) (\\0). This is usually caused by using virtual module IDs (which start with \\0) directly in filenames. Use the module ID without the \\0 prefix, or filter out virtual modules from chunk.moduleIds.")
         }
+        InvalidOptionType::PrecompressUnavailable => {
+          "Option \"experimental.precompress\" is ignored - this build of rolldown does not include the precompress plugin.".to_string()
+        }
     }
   }
 }
@@ -21,15 +21,19 @@ pub mod circular_reexport;
 pub mod commonjs_variable_in_esm;
 pub mod configuration_field_conflict;
 pub mod could_not_clean_directory;
+pub mod could_not_write_records;
 pub mod duplicate_shebang;
+pub mod empty_glob_import;
 pub mod empty_import_meta;
 pub mod eval;
+pub mod exceeded_max_requests;
 pub mod external_entry;
 pub mod file_not_found;
 pub mod filename_conflict;
 pub mod filename_outside_output_directory;
 pub mod forbid_const_assign;
 pub mod illegal_identifier_as_name;
+pub mod import_attribute_mismatch;
 pub mod import_is_undefined;
 pub mod ineffective_dynamic_import;
 pub mod invalid_annotation;
@@ -52,6 +56,7 @@ pub mod resolve_error;
 pub mod runtime_module_symbol_not_found;
 pub mod sourcemap_broken;
 pub mod tsconfig_error;
+pub mod unexpected_named_import;
 pub mod unhandleable_error;
 pub mod unloadable_dependency;
 pub mod unresolved_entry;
@@ -0,0 +1,51 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use super::BuildEvent;
+use crate::{types::diagnostic_options::DiagnosticOptions, types::event_kind::EventKind};
+
+#[derive(Debug)]
+pub struct UnexpectedNamedImport {
+  pub importer: String,
+  pub stable_importer: String,
+  pub importer_source: ArcStr,
+  pub stable_importee: String,
+  pub imported_specifier: String,
+  pub imported_specifier_span: Span,
+}
+
+impl BuildEvent for UnexpectedNamedImport {
+  fn kind(&self) -> EventKind {
+    EventKind::UnexpectedNamedImportError
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.importer.clone())
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      r#"The named export "{}" was imported from the external module "{}" in "{}" even though its interop type is "defaultOnly". Either remove or change this import or change the value of the "output.interop" option."#,
+      self.imported_specifier, self.stable_importee, self.stable_importer
+    )
+  }
+
+  fn on_diagnostic(
+    &self,
+    diagnostic: &mut crate::build_diagnostic::diagnostic::Diagnostic,
+    _opts: &DiagnosticOptions,
+  ) {
+    let file_id = diagnostic.add_file(&self.stable_importer, &self.importer_source);
+
+    diagnostic.title = format!(
+      r#"The named export "{}" was imported from the external module "{}" even though its interop type is "defaultOnly"."#,
+      self.imported_specifier, self.stable_importee
+    );
+
+    diagnostic.add_label(
+      &file_id,
+      self.imported_specifier_span.start..self.imported_specifier_span.end,
+      String::from("Named import of a `defaultOnly` external"),
+    );
+  }
+}
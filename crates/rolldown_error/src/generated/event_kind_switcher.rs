@@ -54,5 +54,10 @@ bitflags! {
     const LargeBarrelModules = 1 << 47;
     const SourcemapBroken = 1 << 48;
     const NamespaceConflict = 1 << 49;
+    const EmptyGlobImport = 1 << 50;
+    const ExceededMaxRequests = 1 << 51;
+    const UnexpectedNamedImportError = 1 << 52;
+    const ImportAttributeMismatchError = 1 << 53;
+    const CouldNotWriteRecords = 1 << 54;
   }
 }
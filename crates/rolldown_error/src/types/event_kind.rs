@@ -134,6 +134,20 @@ pub enum EventKind {
   SourcemapBroken = 48,
   /// Whether to emit warnings when multiple star re-exports provide the same name from different modules.
   NamespaceConflict = 49,
+  /// Whether to emit warnings when the path of an `import()` or `require()` built at runtime, like `` import(`./locales/${lang}.js`) ``, matches no file.
+  ///
+  /// Such calls are kept as-is, so they will fail at runtime unless the file exists next to the output.
+  EmptyGlobImport = 50,
+  /// Whether to emit warnings when an entry loads more chunks than `codeSplitting.maxInitialRequests` or `codeSplitting.maxAsyncRequests` allow.
+  ///
+  /// Shared chunks are only merged when that changes no entry signature and runs no module an entry doesn't need.
+  ExceededMaxRequests = 51,
+  UnexpectedNamedImportError = 52,
+  ImportAttributeMismatchError = 53,
+  /// Whether to emit warnings when Rolldown could not write the file of `experimental.recordsPath`.
+  ///
+  /// The next build then starts without the recorded chunks.
+  CouldNotWriteRecords = 54,
 }
 
 impl Display for EventKind {
@@ -198,6 +212,11 @@ impl Display for EventKind {
       EventKind::RequireTlaError => write!(f, "REQUIRE_TLA"),
       EventKind::LargeBarrelModules => write!(f, "LARGE_BARREL_MODULES"),
       EventKind::SourcemapBroken => write!(f, "SOURCEMAP_BROKEN"),
+      EventKind::EmptyGlobImport => write!(f, "EMPTY_GLOB_IMPORT"),
+      EventKind::ExceededMaxRequests => write!(f, "EXCEEDED_MAX_REQUESTS"),
+      EventKind::UnexpectedNamedImportError => write!(f, "UNEXPECTED_NAMED_IMPORT"),
+      EventKind::ImportAttributeMismatchError => write!(f, "IMPORT_ATTRIBUTE_MISMATCH"),
+      EventKind::CouldNotWriteRecords => write!(f, "COULD_NOT_WRITE_RECORDS"),
     }
   }
 }
@@ -55,6 +55,7 @@ pub use crate::{
   types::hook_transform_ast_args::HookTransformAstArgs,
   types::hook_transform_output::{HookTransformOutput, HookTransformOutputMap},
   types::hook_write_bundle_args::HookWriteBundleArgs,
+  types::module_emitted_file::ModuleEmittedFile,
   types::plugin_context_resolve_options::PluginContextResolveOptions,
   types::plugin_hook_meta::{PluginHookMeta, PluginOrder},
 };
@@ -322,4 +322,13 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
   }
 
   fn register_hook_usage(&self) -> HookUsage;
+
+  /// Identifies everything besides the module itself that affects the output of the `load`,
+  /// `transform` and `transform_ast` hooks of this plugin, such as its version and options.
+  ///
+  /// `experimental.persistentCacheDir` mixes it into the keys of its entries, and is bypassed
+  /// when a plugin using any of these hooks returns `None`.
+  fn cache_key(&self) -> Option<Cow<'_, str>> {
+    None
+  }
 }
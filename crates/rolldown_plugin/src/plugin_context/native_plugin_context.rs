@@ -8,8 +8,8 @@ use anyhow::Context;
 use arcstr::ArcStr;
 use derive_more::Debug;
 use rolldown_common::{
-  FilenameTemplate, LogLevel, LogWithoutPlugin, ModuleDefFormat, ModuleId, ModuleLoaderMsg,
-  PackageJson, PluginIdx, ResolvedId, SharedFileEmitter, SharedModuleInfoDashMap,
+  FilenameTemplate, LogLevel, LogWithoutPlugin, ModuleDefFormat, ModuleId, ModuleIdx,
+  ModuleLoaderMsg, PackageJson, PluginIdx, ResolvedId, SharedFileEmitter, SharedModuleInfoDashMap,
   SharedNormalizedBundlerOptions, side_effects::HookSideEffects,
 };
 use rolldown_resolver::{ResolveError, Resolver};
@@ -20,7 +20,7 @@ use crate::{
   PluginDriver,
   plugin_context::PluginContextMeta,
   types::{
-    hook_resolve_id_skipped::HookResolveIdSkipped,
+    hook_resolve_id_skipped::HookResolveIdSkipped, module_emitted_file::ModuleEmittedFile,
     plugin_context_resolve_options::PluginContextResolveOptions,
   },
   utils::resolve_id_check_external::resolve_id_check_external,
@@ -124,6 +124,7 @@ impl NativePluginContextImpl {
         normalized_extra_options.import_kind,
         skipped_resolve_calls,
         normalized_extra_options.custom,
+        normalized_extra_options.attributes.as_ref(),
         false,
         &self.options,
       )
@@ -170,6 +171,59 @@ impl NativePluginContextImpl {
     self.file_emitter.emit_file(file, asset_filename, sanitized_file_name)
   }
 
+  /// Emits an asset from the `load` or `transform` hook of a module, recording it when
+  /// `experimental.persistentCacheDir` is set so that it can be emitted again on a cache hit.
+  pub(crate) fn emit_module_file(
+    &self,
+    module_idx: ModuleIdx,
+    file: rolldown_common::EmittedAsset,
+    fn_asset_filename: Option<String>,
+    fn_sanitized_file_name: Option<String>,
+  ) -> anyhow::Result<ArcStr> {
+    let recorded = self
+      .should_record_module_emitted_files()
+      .then(|| (file.clone(), Some((fn_asset_filename.clone(), fn_sanitized_file_name.clone()))));
+    let reference_id = self.emit_file(file, fn_asset_filename, fn_sanitized_file_name)?;
+    if let Some((file, file_name_args)) = recorded {
+      self.record_module_emitted_file(module_idx, &reference_id, file, file_name_args);
+    }
+    Ok(reference_id)
+  }
+
+  pub(crate) async fn emit_module_file_async(
+    &self,
+    module_idx: ModuleIdx,
+    file: rolldown_common::EmittedAsset,
+  ) -> anyhow::Result<ArcStr> {
+    let recorded = self.should_record_module_emitted_files().then(|| file.clone());
+    let reference_id = self.emit_file_async(file).await?;
+    if let Some(file) = recorded {
+      self.record_module_emitted_file(module_idx, &reference_id, file, None);
+    }
+    Ok(reference_id)
+  }
+
+  fn should_record_module_emitted_files(&self) -> bool {
+    self.options.experimental.persistent_cache_dir.is_some()
+  }
+
+  fn record_module_emitted_file(
+    &self,
+    module_idx: ModuleIdx,
+    reference_id: &ArcStr,
+    file: rolldown_common::EmittedAsset,
+    file_name_args: Option<(Option<String>, Option<String>)>,
+  ) {
+    if let Some(plugin_driver) = self.plugin_driver.upgrade() {
+      plugin_driver.module_emitted_files.entry(module_idx).or_default().push(ModuleEmittedFile {
+        plugin_idx: self.plugin_idx,
+        reference_id: reference_id.clone(),
+        file,
+        file_name_args,
+      });
+    }
+  }
+
   pub fn get_file_name(&self, reference_id: &str) -> anyhow::Result<ArcStr> {
     self.file_emitter.get_file_name(reference_id)
   }
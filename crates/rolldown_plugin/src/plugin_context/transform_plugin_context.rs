@@ -2,7 +2,7 @@ use std::{ops::Deref, sync::Arc};
 
 use crate::PluginContext;
 use arcstr::ArcStr;
-use rolldown_common::{ModuleIdx, PluginIdx, SourceMapGenMsg, SourcemapChainElement};
+use rolldown_common::{EmittedAsset, ModuleIdx, PluginIdx, SourceMapGenMsg, SourcemapChainElement};
 use rolldown_sourcemap::{SourceMap, collapse_sourcemaps, empty_sourcemap};
 use rolldown_utils::unique_arc::WeakRef;
 use std::sync::mpsc;
@@ -83,6 +83,32 @@ impl TransformPluginContext {
     }
   }
 
+  /// Emit an asset. Unlike [`PluginContext::emit_file`], the asset is tied to this module, so it is
+  /// emitted again when the module is served from `experimental.persistentCacheDir`.
+  pub fn emit_file(
+    &self,
+    file: EmittedAsset,
+    fn_asset_filename: Option<String>,
+    fn_sanitized_file_name: Option<String>,
+  ) -> anyhow::Result<ArcStr> {
+    match &self.inner {
+      PluginContext::Native(ctx) => {
+        ctx.emit_module_file(self.module_idx, file, fn_asset_filename, fn_sanitized_file_name)
+      }
+      PluginContext::Napi(_) => {
+        self.inner.emit_file(file, fn_asset_filename, fn_sanitized_file_name)
+      }
+    }
+  }
+
+  /// See [`Self::emit_file`].
+  pub async fn emit_file_async(&self, file: EmittedAsset) -> anyhow::Result<ArcStr> {
+    match &self.inner {
+      PluginContext::Native(ctx) => ctx.emit_module_file_async(self.module_idx, file).await,
+      PluginContext::Napi(_) => self.inner.emit_file_async(file).await,
+    }
+  }
+
   pub fn send_magic_string(
     &self,
     magic_string: MagicString<'static>,
@@ -18,7 +18,7 @@ use rolldown_common::{
   ModuleId, ModuleIdx, ModuleInfo, ModuleLoaderMsg, PluginIdx, SharedFileEmitter,
   SharedModuleInfoDashMap,
 };
-use rolldown_utils::dashmap::FxDashSet;
+use rolldown_utils::dashmap::{FxDashMap, FxDashSet};
 use sugar_path::SugarPath;
 use tokio::sync::broadcast;
 
@@ -27,7 +27,7 @@ use crate::{
   PluginContext,
   plugin_driver::hook_orders::PluginHookOrders,
   type_aliases::{IndexPluginContext, IndexPluginable},
-  types::build_timings::BuildTimings,
+  types::{build_timings::BuildTimings, module_emitted_file::ModuleEmittedFile},
 };
 
 pub type SharedPluginDriver = Arc<PluginDriver>;
@@ -43,6 +43,8 @@ pub struct PluginDriver {
   pub module_infos: SharedModuleInfoDashMap,
   /// Module dependencies tracked during load/transform hooks for HMR invalidation
   pub transform_dependencies: Arc<DashMap<ModuleIdx, Arc<FxDashSet<ArcStr>>>>,
+  /// Assets emitted by the `load` and `transform` hooks of each module, see [`ModuleEmittedFile`].
+  pub module_emitted_files: FxDashMap<ModuleIdx, Vec<ModuleEmittedFile>>,
   context_load_completion_manager: ContextLoadCompletionManager,
   pub(crate) tx: Arc<Mutex<Option<tokio::sync::mpsc::UnboundedSender<ModuleLoaderMsg>>>>,
   /// Outlives the `Bundle` through `BundleHandle`, which is how the binding reads them
@@ -54,6 +56,7 @@ impl PluginDriver {
   pub fn clear(&self) {
     self.watch_files.clear();
     self.module_infos.clear();
+    self.module_emitted_files.clear();
     // Note: transform_dependencies is NOT cleared here - it's preserved across incremental builds
     // by BundleFactory which manages its lifecycle (reset on full builds only)
     self.context_load_completion_manager.clear();
@@ -109,6 +112,18 @@ impl PluginDriver {
       .or_insert_with(|| Arc::new(FxDashSet::default()))
       .insert(dependency);
   }
+
+  /// Emits an asset recorded in [`ModuleEmittedFile`] again, on behalf of the plugin that emitted
+  /// it, and returns its new reference id.
+  pub async fn reemit_module_file(&self, emitted: ModuleEmittedFile) -> anyhow::Result<ArcStr> {
+    let ctx = &self.contexts[emitted.plugin_idx];
+    match emitted.file_name_args {
+      Some((fn_asset_filename, fn_sanitized_file_name)) => {
+        ctx.emit_file(emitted.file, fn_asset_filename, fn_sanitized_file_name)
+      }
+      None => ctx.emit_file_async(emitted.file).await,
+    }
+  }
 }
 
 impl Deref for PluginDriver {
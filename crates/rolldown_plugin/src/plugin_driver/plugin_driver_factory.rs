@@ -7,7 +7,7 @@ use rolldown_common::{
   ModuleIdx, PluginIdx, SharedFileEmitter, SharedModuleInfoDashMap, SharedNormalizedBundlerOptions,
 };
 use rolldown_resolver::Resolver;
-use rolldown_utils::dashmap::FxDashSet;
+use rolldown_utils::dashmap::{FxDashMap, FxDashSet};
 
 use crate::{
   __inner::SharedPluginable,
@@ -96,6 +96,7 @@ impl PluginDriverFactory {
         watch_files,
         module_infos,
         transform_dependencies,
+        module_emitted_files: FxDashMap::default(),
         context_load_completion_manager: ContextLoadCompletionManager::default(),
         tx,
         // The JavaScript side registers this callback only when it is measuring, so its
@@ -244,6 +244,8 @@ pub trait Pluginable: Any + Send + Sync + 'static {
   }
 
   fn call_hook_usage(&self) -> HookUsage;
+
+  fn call_cache_key(&self) -> Option<Cow<'_, str>>;
 }
 
 impl fmt::Debug for dyn Pluginable {
@@ -535,4 +537,8 @@ impl<T: Plugin> Pluginable for T {
   fn call_hook_usage(&self) -> HookUsage {
     Plugin::register_hook_usage(self)
   }
+
+  fn call_cache_key(&self) -> Option<Cow<'_, str>> {
+    Plugin::cache_key(self)
+  }
 }
@@ -1,8 +1,10 @@
-use rolldown_common::{ModuleIdx, ModuleType};
+use rolldown_common::{ImportAttribute, ModuleIdx, ModuleType};
 
 #[derive(Debug)]
 pub struct HookLoadArgs<'a> {
   pub id: &'a str,
   pub module_idx: ModuleIdx,
   pub asserted_module_type: Option<&'a ModuleType>,
+  /// The import attributes that selected the type of the module, see `ResolvedId::attributes`.
+  pub attributes: Option<&'a ImportAttribute>,
 }
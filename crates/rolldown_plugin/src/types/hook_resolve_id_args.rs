@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use rolldown_common::ImportKind;
+use rolldown_common::{ImportAttribute, ImportKind};
 
 use super::custom_field::CustomField;
 
@@ -13,4 +13,6 @@ pub struct HookResolveIdArgs<'a> {
   // field to determine the import kind.
   pub kind: ImportKind,
   pub custom: Arc<CustomField>,
+  /// The import attributes of the import, e.g. `with { type: 'json' }`.
+  pub attributes: Option<&'a ImportAttribute>,
 }
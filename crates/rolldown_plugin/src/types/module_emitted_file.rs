@@ -0,0 +1,15 @@
+use arcstr::ArcStr;
+use rolldown_common::{EmittedAsset, PluginIdx};
+
+/// An asset emitted by the `load` or `transform` hook of a module. Only recorded with
+/// `experimental.persistentCacheDir`, so the asset can be emitted again when the module is served
+/// from the cache.
+#[derive(Debug, Clone)]
+pub struct ModuleEmittedFile {
+  pub plugin_idx: PluginIdx,
+  pub reference_id: ArcStr,
+  pub file: EmittedAsset,
+  /// The `fn_asset_filename` and `fn_sanitized_file_name` passed to `emit_file`. `None` if the
+  /// asset was emitted by `emit_file_async`, which computes them itself.
+  pub file_name_args: Option<(Option<String>, Option<String>)>,
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use rolldown_common::ImportKind;
+use rolldown_common::{ImportAttribute, ImportKind};
 
 use super::custom_field::CustomField;
 
@@ -10,6 +10,8 @@ pub struct PluginContextResolveOptions {
   pub is_entry: bool,
   pub skip_self: bool,
   pub custom: Arc<CustomField>,
+  /// The import attributes passed to `resolveId` hooks.
+  pub attributes: Option<ImportAttribute>,
 }
 
 impl Default for PluginContextResolveOptions {
@@ -19,6 +21,7 @@ impl Default for PluginContextResolveOptions {
       is_entry: false,
       skip_self: true,
       custom: Arc::default(),
+      attributes: None,
     }
   }
 }
@@ -5,8 +5,8 @@ use crate::{
 use arcstr::ArcStr;
 use nodejs_built_in_modules::is_nodejs_builtin_module;
 use rolldown_common::{
-  ImportKind, MakeAbsoluteExternalsRelative, ModuleId, NormalizedBundlerOptions, ResolvedExternal,
-  ResolvedId,
+  ImportAttribute, ImportKind, MakeAbsoluteExternalsRelative, ModuleId, NormalizedBundlerOptions,
+  ResolvedExternal, ResolvedId,
 };
 use rolldown_fs::FileSystem;
 use rolldown_resolver::{ResolveError, Resolver};
@@ -29,6 +29,7 @@ pub async fn resolve_id_check_external<Fs: FileSystem>(
   import_kind: ImportKind,
   skipped_resolve_calls: Option<Vec<Arc<HookResolveIdSkipped>>>,
   custom: Arc<CustomField>,
+  attributes: Option<&ImportAttribute>,
   is_user_defined_entry: bool,
   bundle_options: &NormalizedBundlerOptions,
 ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
@@ -46,6 +47,7 @@ pub async fn resolve_id_check_external<Fs: FileSystem>(
     import_kind,
     skipped_resolve_calls,
     custom,
+    attributes,
     is_user_defined_entry,
   )
   .await?;
@@ -3,7 +3,9 @@ use crate::{
   types::{custom_field::CustomField, hook_resolve_id_skipped::HookResolveIdSkipped},
 };
 use nodejs_built_in_modules::is_nodejs_builtin_module;
-use rolldown_common::{ImportKind, ModuleDefFormat, ModuleId, PackageJson, ResolvedId};
+use rolldown_common::{
+  ImportAttribute, ImportKind, ModuleDefFormat, ModuleId, PackageJson, ResolvedId,
+};
 use rolldown_fs::FileSystem;
 use rolldown_resolver::{ResolveError, Resolver};
 use rolldown_utils::dataurl::is_data_url;
@@ -57,6 +59,7 @@ pub async fn resolve_id_with_plugins<Fs: FileSystem>(
   import_kind: ImportKind,
   skipped_resolve_calls: Option<Vec<Arc<HookResolveIdSkipped>>>,
   custom: Arc<CustomField>,
+  attributes: Option<&ImportAttribute>,
   is_user_defined_entry: bool,
 ) -> anyhow::Result<Result<ResolvedId, ResolveError>> {
   if matches!(import_kind, ImportKind::DynamicImport) {
@@ -68,6 +71,7 @@ pub async fn resolve_id_with_plugins<Fs: FileSystem>(
           is_entry,
           kind: import_kind,
           custom: Arc::clone(&custom),
+          attributes,
         },
         skipped_resolve_calls.as_ref(),
       )
@@ -98,6 +102,7 @@ pub async fn resolve_id_with_plugins<Fs: FileSystem>(
         is_entry,
         kind: import_kind,
         custom: Arc::clone(&custom),
+        attributes,
       },
       skipped_resolve_calls.as_ref(),
     )
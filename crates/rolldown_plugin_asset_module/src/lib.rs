@@ -38,6 +38,12 @@ impl Plugin for AssetModulePlugin {
     HookUsage::Load | HookUsage::RenderChunk
   }
 
+  fn cache_key(&self) -> Option<Cow<'_, str>> {
+    let mut extensions = self.asset_extensions.iter().map(String::as_str).collect::<Vec<_>>();
+    extensions.sort_unstable();
+    Some(format!("{}:{}", env!("CARGO_PKG_VERSION"), extensions.join(",")).into())
+  }
+
   fn load_meta(&self) -> Option<PluginHookMeta> {
     // Run after user plugins so they can override asset loading
     Some(PluginHookMeta { order: Some(PluginOrder::Post) })
@@ -112,6 +112,8 @@ impl Plugin for ChunkImportMapPlugin {
 
     let base_url = self.base_url.as_deref().unwrap_or("/");
     let mut chunk_import_map = FxHashMap::with_capacity(self.chunk_import_map.len() / 2);
+    // Filled when `output.integrity` is set, see https://html.spec.whatwg.org/multipage/webappapis.html#concept-import-map-integrity.
+    let mut integrity_map = FxHashMap::default();
     for output in args.bundle.iter() {
       let Output::Chunk(chunk) = output else { continue };
       if let Some(v) = self.chunk_import_map.get(chunk.preliminary_filename.as_str()) {
@@ -120,15 +122,24 @@ impl Plugin for ChunkImportMapPlugin {
           rolldown_utils::concat_string!(base_url, chunk.filename),
         );
       }
+      if let Some(integrity) = &chunk.integrity {
+        integrity_map
+          .insert(rolldown_utils::concat_string!(base_url, chunk.filename), integrity.clone());
+      }
     }
 
+    let import_map = if integrity_map.is_empty() {
+      serde_json::json!({ "imports": chunk_import_map })
+    } else {
+      serde_json::json!({ "imports": chunk_import_map, "integrity": integrity_map })
+    };
+
     ctx
       .emit_file_async(EmittedAsset {
         file_name: Some(
           self.file_name.as_ref().map_or(arcstr::literal!("importmap.json"), ArcStr::from),
         ),
-        source: (serde_json::to_string(&serde_json::json!({ "imports": chunk_import_map }))?)
-          .into(),
+        source: (serde_json::to_string(&import_map)?).into(),
         ..Default::default()
       })
       .await?;
@@ -33,6 +33,10 @@ impl Plugin for DataUrlPlugin {
     HookUsage::ResolveId | HookUsage::Load
   }
 
+  fn cache_key(&self) -> Option<Cow<'_, str>> {
+    Some(Cow::Borrowed(env!("CARGO_PKG_VERSION")))
+  }
+
   fn resolve_id_meta(&self) -> Option<PluginHookMeta> {
     // Users might have other plugins to handle data URLs, we should give them a chance to do so by resolving data URLs as late as possible.
     Some(PluginHookMeta { order: Some(PluginOrder::PinPost) })
@@ -4,6 +4,12 @@ use rolldown_plugin::{
   SharedTransformPluginContext,
 };
 
+/// The default `DevRuntime` implementation that the JS API injects, connecting to the WebSocket
+/// server at `addr` (`host:port`). Rust consumers can pass it as `DevModeOptions::implement`.
+pub fn default_dev_runtime_implement(addr: &str) -> String {
+  include_str!("./runtime/runtime-extra-dev-default.js").replace("$ADDR", addr)
+}
+
 #[derive(Debug)]
 pub struct HmrPlugin;
 
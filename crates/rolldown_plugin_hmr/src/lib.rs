@@ -1,3 +1,3 @@
 mod hmr_plugin;
 
-pub use hmr_plugin::HmrPlugin;
+pub use hmr_plugin::{HmrPlugin, default_dev_runtime_implement};
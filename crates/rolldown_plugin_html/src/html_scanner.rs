@@ -0,0 +1,164 @@
+use std::ops::Range;
+
+/// An element found by [`scan_html`]. Only the start tag is parsed, except for raw text elements
+/// like `<script>` whose content is recorded as well.
+#[derive(Debug)]
+pub struct HtmlElement {
+  /// Lowercased tag name.
+  pub name: String,
+  pub attrs: Vec<HtmlAttribute>,
+  /// From the `<` of the start tag to the end of the end tag for raw text elements, or to the end
+  /// of the start tag otherwise.
+  pub span: Range<usize>,
+  /// The content of a raw text element.
+  pub content: Option<Range<usize>>,
+}
+
+#[derive(Debug)]
+pub struct HtmlAttribute {
+  /// Lowercased attribute name.
+  pub name: String,
+  /// The raw value, without the quotes, and its span in the source.
+  pub value: Option<(String, Range<usize>)>,
+}
+
+impl HtmlElement {
+  pub fn attr(&self, name: &str) -> Option<&HtmlAttribute> {
+    self.attrs.iter().find(|attr| attr.name == name)
+  }
+
+  pub fn attr_value(&self, name: &str) -> Option<&str> {
+    self.attr(name).and_then(|attr| attr.value.as_ref()).map(|(value, _)| value.as_str())
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct ScannedHtml {
+  pub elements: Vec<HtmlElement>,
+  /// Offset of the `</head>` end tag, if any.
+  pub head_end: Option<usize>,
+}
+
+/// Elements whose content is not markup and runs until the matching end tag.
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// A small, forgiving scanner for the subset of HTML needed to find the resources of a page. It
+/// skips comments, doctypes and end tags, and does not decode character references in attribute
+/// values.
+pub fn scan_html(source: &str) -> ScannedHtml {
+  let bytes = source.as_bytes();
+  let mut scanned = ScannedHtml::default();
+  let mut pos = 0;
+
+  while let Some(offset) = memchr::memchr(b'<', &bytes[pos..]) {
+    let start = pos + offset;
+    let rest = &source[start..];
+    if let Some(comment) = rest.strip_prefix("<!--") {
+      pos = comment.find("-->").map_or(bytes.len(), |end| start + 4 + end + 3);
+      continue;
+    }
+    if rest.starts_with("<!") || rest.starts_with("<?") {
+      pos = memchr::memchr(b'>', &bytes[start..]).map_or(bytes.len(), |end| start + end + 1);
+      continue;
+    }
+    if let Some(name) = rest.strip_prefix("</") {
+      if scanned.head_end.is_none() && name.get(..4).is_some_and(|n| n.eq_ignore_ascii_case("head"))
+      {
+        scanned.head_end = Some(start);
+      }
+      pos = memchr::memchr(b'>', &bytes[start..]).map_or(bytes.len(), |end| start + end + 1);
+      continue;
+    }
+    if !rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+      pos = start + 1;
+      continue;
+    }
+
+    let (mut element, tag_end) = scan_start_tag(source, start);
+    pos = tag_end;
+    if RAW_TEXT_ELEMENTS.contains(&element.name.as_str()) {
+      let content_end = find_end_tag(source, tag_end, &element.name).unwrap_or(bytes.len());
+      pos = memchr::memchr(b'>', &bytes[content_end..])
+        .map_or(bytes.len(), |end| content_end + end + 1);
+      element.content = Some(tag_end..content_end);
+    }
+    element.span = start..pos;
+    scanned.elements.push(element);
+  }
+
+  scanned
+}
+
+/// Scans the start tag beginning at `start`, returning the element and the offset after `>`.
+fn scan_start_tag(source: &str, start: usize) -> (HtmlElement, usize) {
+  let bytes = source.as_bytes();
+  let mut pos = start + 1;
+  let name_end = take_while(bytes, pos, |b| !b.is_ascii_whitespace() && b != b'/' && b != b'>');
+  let name = source[pos..name_end].to_ascii_lowercase();
+  pos = name_end;
+
+  let mut attrs = vec![];
+  loop {
+    pos = take_while(bytes, pos, |b| b.is_ascii_whitespace() || b == b'/');
+    if pos >= bytes.len() {
+      break;
+    }
+    if bytes[pos] == b'>' {
+      pos += 1;
+      break;
+    }
+
+    let attr_name_end =
+      take_while(bytes, pos + 1, |b| !b.is_ascii_whitespace() && !matches!(b, b'=' | b'>' | b'/'));
+    let attr_name = source[pos..attr_name_end].to_ascii_lowercase();
+    pos = take_while(bytes, attr_name_end, |b| b.is_ascii_whitespace());
+
+    let mut value = None;
+    if bytes.get(pos) == Some(&b'=') {
+      pos = take_while(bytes, pos + 1, |b| b.is_ascii_whitespace());
+      let value_span = match bytes.get(pos) {
+        Some(&quote @ (b'"' | b'\'')) => {
+          let value_start = pos + 1;
+          let value_end = memchr::memchr(quote, &bytes[value_start..])
+            .map_or(bytes.len(), |end| value_start + end);
+          pos = (value_end + 1).min(bytes.len());
+          value_start..value_end
+        }
+        _ => {
+          let value_start = pos;
+          pos = take_while(bytes, pos, |b| !b.is_ascii_whitespace() && b != b'>');
+          value_start..pos
+        }
+      };
+      value = Some((source[value_span.clone()].to_string(), value_span));
+    }
+    attrs.push(HtmlAttribute { name: attr_name, value });
+  }
+
+  (HtmlElement { name, attrs, span: start..pos, content: None }, pos)
+}
+
+/// Finds the offset of `</name`, matched case-insensitively, from `from`.
+fn find_end_tag(source: &str, from: usize, name: &str) -> Option<usize> {
+  let bytes = source.as_bytes();
+  let mut pos = from;
+  while let Some(offset) = memchr::memmem::find(&bytes[pos..], b"</") {
+    let start = pos + offset;
+    let name_start = start + 2;
+    if bytes
+      .get(name_start..name_start + name.len())
+      .is_some_and(|tag| tag.eq_ignore_ascii_case(name.as_bytes()))
+    {
+      return Some(start);
+    }
+    pos = name_start;
+  }
+  None
+}
+
+fn take_while(bytes: &[u8], from: usize, predicate: impl Fn(u8) -> bool) -> usize {
+  bytes[from.min(bytes.len())..]
+    .iter()
+    .position(|&b| !predicate(b))
+    .map_or(bytes.len(), |p| from + p)
+}
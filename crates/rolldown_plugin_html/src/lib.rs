@@ -0,0 +1,435 @@
+mod html_scanner;
+
+use std::{borrow::Cow, fmt::Write as _, io, ops::Range, path::Path};
+
+use arcstr::ArcStr;
+use rolldown_common::{EmittedAsset, EmittedChunk, ModuleType, Output, OutputChunk, StrOrBytes};
+use rolldown_fs::FileSystem;
+use rolldown_plugin::{
+  HookBuildStartArgs, HookGenerateBundleArgs, HookLoadArgs, HookLoadOutput, HookLoadReturn,
+  HookNoopReturn, HookResolveIdArgs, HookResolveIdOutput, HookResolveIdReturn, HookUsage, Plugin,
+  PluginContext, PluginHookMeta, PluginOrder, SharedLoadPluginContext,
+};
+use rolldown_utils::{concat_string, dashmap::FxDashMap, url::clean_url};
+use rustc_hash::{FxHashMap, FxHashSet};
+use sugar_path::SugarPath as _;
+
+use crate::html_scanner::{HtmlElement, scan_html};
+
+/// Query of the virtual modules holding the inline module scripts of a page.
+pub const HTML_PROXY_QUERY: &str = "?html-proxy&index=";
+
+/// What is needed to rewrite a page once its outputs are known.
+#[derive(Debug)]
+struct HtmlPage {
+  source: String,
+  /// Spans of the module scripts, which are replaced by the entry chunk of the page.
+  scripts: Vec<Range<usize>>,
+  /// Spans of the attribute values to replace with the file name of an emitted chunk or asset.
+  references: Vec<(Range<usize>, ArcStr)>,
+  /// Offset of `</head>`, where the tags loading the entry chunk are inserted.
+  head_end: Option<usize>,
+}
+
+/// Bundles modules with type `html`.
+///
+/// A page is loaded as a js module importing its module scripts in document order, so they are
+/// bundled into the entry chunk of the page. `<link rel="modulepreload">` targets are emitted as
+/// entry chunks of their own and `<img src>` files as assets. Once the bundle is generated, the page
+/// is emitted next to its entry chunk with the module scripts replaced by a single script loading
+/// that chunk, preceded by a `modulepreload` link for each chunk it statically imports.
+#[derive(Debug)]
+pub struct HtmlPlugin<Fs> {
+  fs: Fs,
+  html_extensions: FxHashSet<String>,
+  /// Keyed by the id of the html module.
+  pages: FxDashMap<String, HtmlPage>,
+  /// Content of the inline module scripts, keyed by their virtual module id.
+  inline_scripts: FxDashMap<String, String>,
+}
+
+impl<Fs: FileSystem + Clone + 'static> HtmlPlugin<Fs> {
+  pub fn new(module_types: &FxHashMap<Cow<'static, str>, ModuleType>, fs: Fs) -> Self {
+    let mut html_extensions = FxHashSet::default();
+    for (ext, module_type) in module_types {
+      if matches!(module_type, ModuleType::Html) {
+        let ext = ext.strip_prefix('.').unwrap_or(ext);
+        html_extensions.insert(ext.to_string());
+      }
+    }
+    Self { fs, html_extensions, pages: FxDashMap::default(), inline_scripts: FxDashMap::default() }
+  }
+}
+
+impl<Fs: FileSystem + Clone + std::fmt::Debug + 'static> Plugin for HtmlPlugin<Fs> {
+  fn name(&self) -> Cow<'static, str> {
+    Cow::Borrowed("builtin:html")
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::BuildStart | HookUsage::ResolveId | HookUsage::Load | HookUsage::GenerateBundle
+  }
+
+  fn cache_key(&self) -> Option<Cow<'_, str>> {
+    let mut extensions = self.html_extensions.iter().map(String::as_str).collect::<Vec<_>>();
+    extensions.sort_unstable();
+    Some(format!("{}:{}", env!("CARGO_PKG_VERSION"), extensions.join(",")).into())
+  }
+
+  async fn build_start(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookBuildStartArgs<'_>,
+  ) -> HookNoopReturn {
+    // The plugin outlives a build in watch mode, so pages removed since the last build must not
+    // be emitted again.
+    self.pages.clear();
+    self.inline_scripts.clear();
+    Ok(())
+  }
+
+  async fn resolve_id(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveIdArgs<'_>,
+  ) -> HookResolveIdReturn {
+    Ok(
+      self
+        .inline_scripts
+        .contains_key(args.specifier)
+        .then(|| HookResolveIdOutput::from_id(args.specifier)),
+    )
+  }
+
+  fn load(
+    &self,
+    ctx: SharedLoadPluginContext,
+    args: &HookLoadArgs<'_>,
+  ) -> impl std::future::Future<Output = HookLoadReturn> + Send {
+    self.load_impl(ctx, args)
+  }
+
+  fn load_meta(&self) -> Option<PluginHookMeta> {
+    // Run after user plugins so they can override html loading
+    Some(PluginHookMeta { order: Some(PluginOrder::Post) })
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &PluginContext,
+    args: &mut HookGenerateBundleArgs<'_>,
+  ) -> HookNoopReturn {
+    if self.pages.is_empty() {
+      return Ok(());
+    }
+
+    let chunks = args
+      .bundle
+      .iter()
+      .filter_map(|output| match output {
+        Output::Chunk(chunk) => Some((chunk.filename.as_str(), chunk.as_ref())),
+        Output::Asset(_) => None,
+      })
+      .collect::<FxHashMap<_, _>>();
+
+    let mut html_files = vec![];
+    let mut empty_entries = FxHashSet::default();
+    for output in args.bundle.iter() {
+      let Output::Chunk(chunk) = output else {
+        continue;
+      };
+      if !chunk.is_entry {
+        continue;
+      }
+      let Some(page) = chunk.facade_module_id.as_ref().and_then(|id| self.pages.get(id.as_str()))
+      else {
+        continue;
+      };
+      let file_name = concat_string!(chunk.name, ".html");
+
+      let mut replacements = Vec::with_capacity(page.references.len() + page.scripts.len() + 1);
+      for (span, reference_id) in &page.references {
+        let target = ctx.get_file_name(reference_id)?;
+        replacements.push((span.clone(), relative_url(&file_name, &target)));
+      }
+      if let Some(first_script) = page.scripts.first() {
+        // Insert at the end of `<head>`, or in place of the first module script.
+        let (inject_at, indent) = match page.head_end {
+          Some(head_end) => {
+            let last_child = page.source[..head_end].trim_end().len().saturating_sub(1);
+            (head_end, line_indent(&page.source, last_child))
+          }
+          None => (first_script.start, line_indent(&page.source, first_script.start)),
+        };
+        let inject_at = line_start_if_blank(&page.source, inject_at);
+        let mut tags = String::new();
+        if !page.source[..inject_at].ends_with('\n') && inject_at != 0 {
+          tags.push('\n');
+        }
+        for import in collect_static_imports(chunk, &chunks) {
+          writeln!(
+            tags,
+            r#"{indent}<link rel="modulepreload" crossorigin href="{}">"#,
+            relative_url(&file_name, import)
+          )?;
+        }
+        writeln!(
+          tags,
+          r#"{indent}<script type="module" crossorigin src="{}"></script>"#,
+          relative_url(&file_name, &chunk.filename)
+        )?;
+        replacements.push((inject_at..inject_at, tags));
+        replacements.extend(
+          page.scripts.iter().map(|span| (line_span(&page.source, span.clone()), String::new())),
+        );
+      } else {
+        // A page without module scripts only produces an empty chunk.
+        empty_entries.insert(chunk.filename.clone());
+      }
+
+      // Insertions come before the removal of a script starting at the same offset.
+      replacements.sort_by_key(|(span, _)| (span.start, span.end));
+      let mut html = String::with_capacity(page.source.len());
+      let mut last = 0;
+      for (span, replacement) in replacements {
+        html.push_str(&page.source[last..span.start]);
+        html.push_str(&replacement);
+        last = span.end;
+      }
+      html.push_str(&page.source[last..]);
+      html_files.push((file_name, html));
+    }
+
+    args.bundle.retain(
+      |output| !matches!(output, Output::Chunk(chunk) if empty_entries.contains(&chunk.filename)),
+    );
+    for (file_name, html) in html_files {
+      ctx
+        .emit_file_async(EmittedAsset {
+          file_name: Some(file_name.into()),
+          source: StrOrBytes::Str(html),
+          ..Default::default()
+        })
+        .await?;
+    }
+
+    Ok(())
+  }
+}
+
+impl<Fs: FileSystem + Clone + 'static> HtmlPlugin<Fs> {
+  async fn load_impl(
+    &self,
+    ctx: SharedLoadPluginContext,
+    args: &HookLoadArgs<'_>,
+  ) -> HookLoadReturn {
+    if let Some(content) = self.inline_scripts.get(args.id) {
+      return Ok(Some(HookLoadOutput {
+        code: content.as_str().into(),
+        module_type: Some(ModuleType::Js),
+        ..Default::default()
+      }));
+    }
+
+    let clean_id = clean_url(args.id);
+    let is_html = args.asserted_module_type.is_some_and(|ty| matches!(ty, ModuleType::Html))
+      || self.is_html_by_extension(clean_id);
+    if !is_html {
+      return Ok(None);
+    }
+
+    let source = self
+      .read_file(clean_id)
+      .await
+      .and_then(|bytes| String::from_utf8(bytes).map_err(io::Error::other))
+      .map_err(|e| anyhow::anyhow!("Failed to read html module {clean_id}: {e}"))?;
+    ctx.add_watch_file(clean_id);
+    let html_dir = Path::new(clean_id).parent().unwrap_or(Path::new(""));
+    let scanned = scan_html(&source);
+
+    let mut code = String::new();
+    let mut scripts = vec![];
+    let mut references = vec![];
+    for element in &scanned.elements {
+      match element.name.as_str() {
+        "script" if is_module_script(element) => {
+          let specifier = match element.attr_value("src") {
+            Some(src) if !is_local_url(src) => continue,
+            Some(src) => resolve_local_url(src, html_dir, ctx.cwd()),
+            None => {
+              let content =
+                element.content.clone().map(|span| source[span].to_string()).unwrap_or_default();
+              let proxy_id = concat_string!(
+                args.id,
+                HTML_PROXY_QUERY,
+                itoa::Buffer::new().format(scripts.len()),
+                ".js"
+              );
+              self.inline_scripts.insert(proxy_id.clone(), content);
+              proxy_id
+            }
+          };
+          writeln!(code, "import {};", quote(&specifier))?;
+          scripts.push(element.span.clone());
+        }
+        "link"
+          if element
+            .attr_value("rel")
+            .is_some_and(|rel| rel.eq_ignore_ascii_case("modulepreload")) =>
+        {
+          let Some((href, span)) = element.attr("href").and_then(|attr| attr.value.as_ref()) else {
+            continue;
+          };
+          if !is_local_url(href) {
+            continue;
+          }
+          let reference_id = ctx.emit_chunk(EmittedChunk {
+            id: resolve_local_url(href, html_dir, ctx.cwd()),
+            importer: Some(args.id.to_string()),
+            ..Default::default()
+          })?;
+          references.push((span.clone(), reference_id));
+        }
+        "img" => {
+          let Some((src, span)) = element.attr("src").and_then(|attr| attr.value.as_ref()) else {
+            continue;
+          };
+          if !is_local_url(src) {
+            continue;
+          }
+          let path = resolve_local_url(clean_url(src), html_dir, ctx.cwd());
+          // Files that do not exist, e.g. ones served from a public directory, are left as is.
+          let Ok(bytes) = self.read_file(&path).await else {
+            continue;
+          };
+          ctx.add_watch_file(&path);
+          let path = Path::new(&path);
+          let reference_id = ctx
+            .emit_file_async(EmittedAsset {
+              name: path.file_name().map(|name| name.to_string_lossy().into_owned()),
+              original_file_name: Some(
+                path.strip_prefix(ctx.cwd()).unwrap_or(path).to_string_lossy().into_owned(),
+              ),
+              source: StrOrBytes::Bytes(bytes),
+              ..Default::default()
+            })
+            .await?;
+          references.push((span.clone(), reference_id));
+        }
+        _ => {}
+      }
+    }
+
+    self.pages.insert(
+      args.id.to_string(),
+      HtmlPage { source, scripts, references, head_end: scanned.head_end },
+    );
+
+    Ok(Some(HookLoadOutput {
+      code: code.into(),
+      module_type: Some(ModuleType::Js),
+      ..Default::default()
+    }))
+  }
+
+  async fn read_file(&self, path: &str) -> io::Result<Vec<u8>> {
+    #[cfg(not(target_family = "wasm"))]
+    {
+      let fs = self.fs.clone();
+      let path = path.to_string();
+      tokio::task::spawn_blocking(move || fs.read(Path::new(&path)))
+        .await
+        .map_err(io::Error::other)?
+    }
+    #[cfg(target_family = "wasm")]
+    {
+      self.fs.read(Path::new(path))
+    }
+  }
+
+  fn is_html_by_extension(&self, id: &str) -> bool {
+    Path::new(id)
+      .extension()
+      .and_then(|e| e.to_str())
+      .is_some_and(|ext| self.html_extensions.contains(ext))
+  }
+}
+
+/// Extends `span` to the whole line when nothing else is on it, so that removing it does not
+/// leave a blank line behind.
+fn line_span(source: &str, span: Range<usize>) -> Range<usize> {
+  let line_start = source[..span.start].rfind('\n').map_or(0, |i| i + 1);
+  let line_end = source[span.end..].find('\n').map_or(source.len(), |i| span.end + i + 1);
+  if source[line_start..span.start].trim().is_empty()
+    && source[span.end..line_end].trim().is_empty()
+  {
+    line_start..line_end
+  } else {
+    span
+  }
+}
+
+/// The start of the line containing `offset` when only whitespace precedes it, `offset` otherwise.
+fn line_start_if_blank(source: &str, offset: usize) -> usize {
+  let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+  if source[line_start..offset].trim().is_empty() { line_start } else { offset }
+}
+
+/// The leading whitespace of the line containing `offset`.
+fn line_indent(source: &str, offset: usize) -> &str {
+  let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+  let line = &source[line_start..];
+  &line[..line.len() - line.trim_start().len()]
+}
+
+fn is_module_script(element: &HtmlElement) -> bool {
+  element.attr_value("type").is_some_and(|ty| ty.eq_ignore_ascii_case("module"))
+}
+
+/// Whether `url` points to a file of the project rather than to another origin or into the page.
+fn is_local_url(url: &str) -> bool {
+  !(url.is_empty()
+    || url.starts_with('#')
+    || url.starts_with("//")
+    || url.starts_with("data:")
+    || url.contains("://"))
+}
+
+/// Urls starting with `/` are relative to the cwd, others to the directory of the page.
+fn resolve_local_url(url: &str, html_dir: &Path, cwd: &Path) -> String {
+  let path = match url.strip_prefix('/') {
+    Some(root_relative) => cwd.join(root_relative),
+    None => html_dir.join(url),
+  };
+  path.normalize().to_string_lossy().into_owned()
+}
+
+/// The url of `to_file` from the page `from_file`, both relative to the output directory.
+fn relative_url(from_file: &str, to_file: &str) -> String {
+  let from_dir = Path::new(from_file).parent().unwrap_or(Path::new(""));
+  Path::new(to_file).relative(from_dir).to_slash_lossy().into_owned()
+}
+
+/// The chunks statically imported by `chunk`, directly or not, in depth-first order.
+fn collect_static_imports<'a>(
+  chunk: &'a OutputChunk,
+  chunks: &FxHashMap<&str, &'a OutputChunk>,
+) -> Vec<&'a str> {
+  let mut visited = FxHashSet::default();
+  let mut imports = vec![];
+  let mut stack = chunk.imports.iter().rev().map(ArcStr::as_str).collect::<Vec<_>>();
+  while let Some(file_name) = stack.pop() {
+    if file_name == chunk.filename.as_str() || !visited.insert(file_name) {
+      continue;
+    }
+    imports.push(file_name);
+    if let Some(imported) = chunks.get(file_name) {
+      stack.extend(imported.imports.iter().rev().map(ArcStr::as_str));
+    }
+  }
+  imports
+}
+
+fn quote(s: &str) -> String {
+  serde_json::to_string(s).expect("a string always serializes")
+}
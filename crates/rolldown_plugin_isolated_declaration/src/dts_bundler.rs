@@ -0,0 +1,846 @@
+use std::{path::Path, sync::Arc};
+
+use anyhow::bail;
+use arcstr::ArcStr;
+use oxc::{
+  allocator::Allocator,
+  ast::ast::{
+    BindingIdentifier, Declaration, ExportDefaultDeclarationKind, Expression,
+    ImportDeclarationSpecifier, ModuleExportName, Statement,
+  },
+  parser::Parser,
+  semantic::{Scoping, SemanticBuilder, SymbolId},
+  span::{GetSpan, SourceType, Span},
+};
+use rolldown_common::{ModuleId, ModuleInfo};
+use rolldown_plugin_utils::to_string_literal;
+use rolldown_utils::{
+  concat_string,
+  dashmap::FxDashMap,
+  ecmascript::{legitimize_identifier_name, to_module_import_export_name},
+  indexmap::{FxIndexMap, FxIndexSet},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The declaration generated for a module, kept until the entry chunks are known.
+#[derive(Debug, Clone)]
+pub struct ModuleDeclaration {
+  pub source: String,
+  /// Specifiers of the internal `import type` and `export type` -> resolved id. The other imports
+  /// are looked up in the module graph.
+  pub type_imports: FxHashMap<String, ArcStr>,
+}
+
+type ModuleIdx = usize;
+
+#[derive(Debug, Clone)]
+enum Target {
+  Internal(ModuleIdx),
+  External(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ImportedName {
+  Named(String),
+  Namespace,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ExternalBinding {
+  specifier: String,
+  imported: ImportedName,
+}
+
+/// A top level binding of the rolled-up declaration.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Binding {
+  Local(ModuleIdx, SymbolId),
+  /// `export default function(): void`, which has no symbol.
+  AnonymousDefault(ModuleIdx),
+  /// `import * as ns from './foo'`, rendered as a `declare namespace`.
+  Namespace(ModuleIdx),
+  External(ExternalBinding),
+}
+
+#[derive(Debug)]
+enum ExportEntry {
+  Local(SymbolId),
+  AnonymousDefault,
+  ReExport { target: Target, imported: ImportedName, is_type: bool },
+}
+
+#[derive(Debug)]
+struct ImportEntry {
+  target: Target,
+  imported: ImportedName,
+  is_type: bool,
+}
+
+#[derive(Debug)]
+struct DeclarationStatement {
+  /// Span of the declaration, without its `export` or `export default` prefix.
+  span: Span,
+  /// Leading comments, such as JSDoc.
+  comments: Vec<Span>,
+  /// `export default function foo(): void` needs `declare` once `export default` is removed.
+  needs_declare: bool,
+  /// Where the generated name goes for `export default function(): void`.
+  anonymous_default_name_at: Option<u32>,
+  /// `declare global {}` and `declare module 'foo' {}` are kept as long as the module is reached.
+  is_augmentation: bool,
+}
+
+#[derive(Debug)]
+struct Module {
+  id: ArcStr,
+  source: String,
+  scoping: Scoping,
+  statements: Vec<DeclarationStatement>,
+  /// Statements declaring each top level symbol. Declarations merge, so there can be several.
+  declared_by: FxHashMap<SymbolId, Vec<usize>>,
+  anonymous_default: Option<usize>,
+  exports: FxIndexMap<String, ExportEntry>,
+  star_exports: Vec<Target>,
+  imports: FxHashMap<SymbolId, ImportEntry>,
+  /// Identifiers declaring or referencing top level symbols, sorted by position.
+  occurrences: Vec<(Span, SymbolId)>,
+  /// Internal modules imported by this one, in source order.
+  dependencies: FxIndexSet<ModuleIdx>,
+}
+
+/// Rolls up the declarations reachable from `entry` into a single declaration file.
+///
+/// Imports are followed through the module graph of the bundle, `module_info` returns the
+/// `ModuleInfo` of a module id. As for the js chunk, only what the entry exports is kept, top level names colliding across
+/// modules get a `$n` suffix and external modules stay imports. Returns `None` if `entry` has no
+/// declaration, e.g. it's a js module.
+pub fn bundle_declarations(
+  entry: &str,
+  declarations: &FxDashMap<ArcStr, ModuleDeclaration>,
+  module_info: impl Fn(&str) -> Option<Arc<ModuleInfo>>,
+) -> anyhow::Result<Option<String>> {
+  let Some(modules) = collect_modules(entry, declarations, module_info)? else {
+    return Ok(None);
+  };
+  let mut bundler = DtsBundler {
+    modules,
+    included: FxIndexSet::default(),
+    included_statements: FxHashSet::default(),
+    names: FxHashMap::default(),
+  };
+  bundler.include_entry();
+  bundler.assign_names();
+  Ok(Some(bundler.render()))
+}
+
+fn collect_modules(
+  entry: &str,
+  declarations: &FxDashMap<ArcStr, ModuleDeclaration>,
+  module_info: impl Fn(&str) -> Option<Arc<ModuleInfo>>,
+) -> anyhow::Result<Option<Vec<Module>>> {
+  let Some(entry_declaration) = declarations.get(entry).map(|decl| decl.clone()) else {
+    return Ok(None);
+  };
+  let mut module_indices: FxHashMap<ArcStr, ModuleIdx> = FxHashMap::default();
+  module_indices.insert(entry.into(), 0);
+  let mut queue = vec![(0, ArcStr::from(entry), entry_declaration)];
+  let mut modules: Vec<Option<Module>> = vec![];
+
+  while let Some((idx, id, declaration)) = queue.pop() {
+    let ModuleDeclaration { source, type_imports } = declaration;
+    let info = module_info(&id);
+    let mut resolve = |specifier: &str| {
+      let resolved = info
+        .as_ref()
+        .and_then(|info| info.imported_ids_by_specifier.get(specifier))
+        .map(ModuleId::as_arc_str)
+        .or_else(|| type_imports.get(specifier));
+      // Externals and modules without a declaration, like js ones, are left as imports.
+      let Some(resolved) = resolved else {
+        return Target::External(specifier.to_string());
+      };
+      if let Some(idx) = module_indices.get(resolved) {
+        return Target::Internal(*idx);
+      }
+      let Some(declaration) = declarations.get(resolved).map(|decl| decl.clone()) else {
+        return Target::External(specifier.to_string());
+      };
+      let idx = module_indices.len();
+      module_indices.insert(resolved.clone(), idx);
+      queue.push((idx, resolved.clone(), declaration));
+      Target::Internal(idx)
+    };
+    let module = analyze_module(id, source, &mut resolve)?;
+    if modules.len() <= idx {
+      modules.resize_with(idx + 1, || None);
+    }
+    modules[idx] = Some(module);
+  }
+
+  Ok(Some(modules.into_iter().map(|module| module.expect("every module is analyzed")).collect()))
+}
+
+fn analyze_module(
+  id: ArcStr,
+  source: String,
+  resolve: &mut impl FnMut(&str) -> Target,
+) -> anyhow::Result<Module> {
+  let allocator = Allocator::default();
+  let ret = Parser::new(&allocator, &source, SourceType::d_ts()).parse();
+  if let Some(error) = ret.diagnostics.first() {
+    bail!("Failed to parse the generated declaration of {id}: {error}");
+  }
+  let program = ret.program;
+  let semantic = SemanticBuilder::new().with_build_nodes(true).build(&program).semantic;
+  let (scoping, nodes) = semantic.into_scoping_and_nodes();
+
+  let mut statements = vec![];
+  let mut anonymous_default = None;
+  let mut exports = FxIndexMap::default();
+  let mut star_exports = vec![];
+  let mut imports = FxHashMap::default();
+  let mut dependencies = FxIndexSet::default();
+  let mut resolve = |specifier: &str| {
+    let target = resolve(specifier);
+    if let Target::Internal(idx) = target {
+      dependencies.insert(idx);
+    }
+    target
+  };
+  let reference_symbol = |ident: &oxc::ast::ast::IdentifierReference| {
+    ident
+      .reference_id
+      .get()
+      .and_then(|reference_id| scoping.get_reference(reference_id).symbol_id())
+  };
+
+  for stmt in &program.body {
+    let comments = program
+      .comments
+      .iter()
+      .filter(|comment| comment.is_leading() && comment.attached_to == stmt.span().start)
+      .map(|comment| comment.span)
+      .collect::<Vec<_>>();
+    match stmt {
+      Statement::ImportDeclaration(decl) => {
+        let target = resolve(&decl.source.value);
+        for specifier in decl.specifiers.iter().flatten() {
+          let mut is_type = decl.import_kind.is_type();
+          let (local, imported) = match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(spec) => {
+              is_type |= spec.import_kind.is_type();
+              (&spec.local, ImportedName::Named(spec.imported.name().to_string()))
+            }
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(spec) => {
+              (&spec.local, ImportedName::Named("default".to_string()))
+            }
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(spec) => {
+              (&spec.local, ImportedName::Namespace)
+            }
+          };
+          imports
+            .insert(local.symbol_id(), ImportEntry { target: target.clone(), imported, is_type });
+        }
+      }
+      Statement::ExportDeclaration(decl) => {
+        for ident in declared_identifiers(&decl.declaration) {
+          exports.insert(ident.name.to_string(), ExportEntry::Local(ident.symbol_id()));
+        }
+        statements.push(declaration_statement(&decl.declaration, comments));
+      }
+      Statement::ExportNamedDeclaration(decl) => {
+        for spec in &decl.specifiers {
+          if let ModuleExportName::IdentifierReference(ident) = &spec.local
+            && let Some(symbol_id) = reference_symbol(ident)
+          {
+            exports.insert(spec.exported.name().to_string(), ExportEntry::Local(symbol_id));
+          }
+        }
+      }
+      Statement::ExportFromDeclaration(decl) => {
+        let target = resolve(&decl.source.value);
+        for spec in &decl.specifiers {
+          exports.insert(
+            spec.exported.name().to_string(),
+            ExportEntry::ReExport {
+              target: target.clone(),
+              imported: ImportedName::Named(spec.local.name().to_string()),
+              is_type: decl.export_kind.is_type() || spec.export_kind.is_type(),
+            },
+          );
+        }
+      }
+      Statement::ExportDefaultDeclaration(decl) => {
+        let (span, id, needs_declare, keyword) = match &decl.declaration {
+          ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+            (func.span, func.id.as_ref(), !func.declare, "function")
+          }
+          ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+            (class.span, class.id.as_ref(), !class.declare, "class")
+          }
+          ExportDefaultDeclarationKind::TSInterfaceDeclaration(interface) => {
+            (interface.span, Some(&interface.id), false, "interface")
+          }
+          kind => {
+            // `export default foo`
+            if let Some(Expression::Identifier(ident)) = kind.as_expression()
+              && let Some(symbol_id) = reference_symbol(ident)
+            {
+              exports.insert("default".to_string(), ExportEntry::Local(symbol_id));
+            }
+            continue;
+          }
+        };
+        let anonymous_default_name_at = id.is_none().then(|| {
+          let offset = span.source_text(&source).find(keyword).unwrap_or_default() + keyword.len();
+          span.start + u32::try_from(offset).unwrap_or_default()
+        });
+        exports.insert(
+          "default".to_string(),
+          id.map_or(ExportEntry::AnonymousDefault, |id| ExportEntry::Local(id.symbol_id())),
+        );
+        if anonymous_default_name_at.is_some() {
+          anonymous_default = Some(statements.len());
+        }
+        statements.push(DeclarationStatement {
+          span,
+          comments,
+          needs_declare,
+          anonymous_default_name_at,
+          is_augmentation: false,
+        });
+      }
+      Statement::ExportAllDeclaration(decl) => {
+        let target = resolve(&decl.source.value);
+        match &decl.exported {
+          Some(exported) => {
+            exports.insert(
+              exported.name().to_string(),
+              ExportEntry::ReExport {
+                target,
+                imported: ImportedName::Namespace,
+                is_type: decl.export_kind.is_type(),
+              },
+            );
+          }
+          None => star_exports.push(target),
+        }
+      }
+      Statement::TSExportAssignment(_) => {
+        bail!("`export =` in the declaration of {id} can't be bundled");
+      }
+      _ => {
+        if let Some(declaration) = stmt.as_declaration() {
+          statements.push(declaration_statement(declaration, comments));
+        }
+      }
+    }
+  }
+
+  let root_scope_id = scoping.root_scope_id();
+  let mut occurrences = vec![];
+  let mut declared_by: FxHashMap<SymbolId, Vec<usize>> = FxHashMap::default();
+  for symbol_id in scoping.symbol_ids() {
+    if scoping.symbol_scope_id(symbol_id) != root_scope_id {
+      continue;
+    }
+    let declaration_spans = std::iter::once(scoping.symbol_span(symbol_id))
+      .chain(
+        scoping.symbol_redeclarations(symbol_id).iter().map(|redeclaration| redeclaration.span),
+      )
+      .collect::<FxIndexSet<_>>();
+    for span in declaration_spans {
+      occurrences.push((span, symbol_id));
+      let idx = statements.partition_point(|stmt| stmt.span.start <= span.start);
+      if let Some(stmt_idx) = idx.checked_sub(1)
+        && statements[stmt_idx].span.end >= span.end
+      {
+        declared_by.entry(symbol_id).or_default().push(stmt_idx);
+      }
+    }
+    for reference_id in scoping.get_resolved_reference_ids(symbol_id) {
+      let node_id = scoping.get_reference(*reference_id).node_id();
+      occurrences.push((nodes.get_node(node_id).span(), symbol_id));
+    }
+  }
+  occurrences.sort_unstable_by_key(|(span, _)| span.start);
+
+  Ok(Module {
+    id,
+    source,
+    scoping,
+    statements,
+    declared_by,
+    anonymous_default,
+    exports,
+    star_exports,
+    imports,
+    occurrences,
+    dependencies,
+  })
+}
+
+fn declared_identifiers<'a>(declaration: &'a Declaration<'a>) -> Vec<&'a BindingIdentifier<'a>> {
+  match declaration {
+    Declaration::VariableDeclaration(decl) => decl
+      .declarations
+      .iter()
+      .flat_map(|declarator| declarator.id.get_binding_identifiers())
+      .collect(),
+    _ => declaration.id().into_iter().collect(),
+  }
+}
+
+fn declaration_statement(declaration: &Declaration, comments: Vec<Span>) -> DeclarationStatement {
+  let needs_declare = !declaration.declare()
+    && matches!(
+      declaration,
+      Declaration::VariableDeclaration(_)
+        | Declaration::FunctionDeclaration(_)
+        | Declaration::ClassDeclaration(_)
+        | Declaration::TSEnumDeclaration(_)
+        | Declaration::TSNamespaceDeclaration(_)
+    );
+  DeclarationStatement {
+    span: declaration.span(),
+    comments,
+    needs_declare,
+    anonymous_default_name_at: None,
+    is_augmentation: matches!(
+      declaration,
+      Declaration::TSExternalModuleDeclaration(_) | Declaration::TSGlobalDeclaration(_)
+    ),
+  }
+}
+
+struct DtsBundler {
+  modules: Vec<Module>,
+  included: FxIndexSet<Binding>,
+  included_statements: FxHashSet<(ModuleIdx, usize)>,
+  names: FxHashMap<Binding, String>,
+}
+
+impl DtsBundler {
+  fn resolve_export(
+    &self,
+    module_idx: ModuleIdx,
+    name: &str,
+    visited: &mut FxHashSet<(ModuleIdx, String)>,
+  ) -> Option<Binding> {
+    if !visited.insert((module_idx, name.to_string())) {
+      return None;
+    }
+    let module = &self.modules[module_idx];
+    match module.exports.get(name) {
+      Some(ExportEntry::Local(symbol_id)) => self.resolve_symbol(module_idx, *symbol_id, visited),
+      Some(ExportEntry::AnonymousDefault) => Some(Binding::AnonymousDefault(module_idx)),
+      Some(ExportEntry::ReExport { target, imported, .. }) => {
+        self.resolve_import(target, imported, visited)
+      }
+      None if name == "default" => None,
+      None => module.star_exports.iter().find_map(|target| match target {
+        Target::Internal(idx) => self.resolve_export(*idx, name, visited),
+        Target::External(_) => None,
+      }),
+    }
+  }
+
+  fn resolve_symbol(
+    &self,
+    module_idx: ModuleIdx,
+    symbol_id: SymbolId,
+    visited: &mut FxHashSet<(ModuleIdx, String)>,
+  ) -> Option<Binding> {
+    match self.modules[module_idx].imports.get(&symbol_id) {
+      Some(import) => self.resolve_import(&import.target, &import.imported, visited),
+      None => Some(Binding::Local(module_idx, symbol_id)),
+    }
+  }
+
+  fn resolve_import(
+    &self,
+    target: &Target,
+    imported: &ImportedName,
+    visited: &mut FxHashSet<(ModuleIdx, String)>,
+  ) -> Option<Binding> {
+    match (target, imported) {
+      (Target::Internal(idx), ImportedName::Named(name)) => {
+        self.resolve_export(*idx, name, visited)
+      }
+      (Target::Internal(idx), ImportedName::Namespace) => Some(Binding::Namespace(*idx)),
+      (Target::External(specifier), imported) => Some(Binding::External(ExternalBinding {
+        specifier: specifier.clone(),
+        imported: imported.clone(),
+      })),
+    }
+  }
+
+  /// Names exported by a module, including the ones from `export * from './foo'`.
+  fn export_names(&self, module_idx: ModuleIdx) -> FxIndexSet<String> {
+    let mut names = FxIndexSet::default();
+    let mut visited = FxHashSet::default();
+    self.collect_export_names(module_idx, &mut names, &mut visited);
+    names
+  }
+
+  fn collect_export_names(
+    &self,
+    module_idx: ModuleIdx,
+    names: &mut FxIndexSet<String>,
+    visited: &mut FxHashSet<ModuleIdx>,
+  ) {
+    if !visited.insert(module_idx) {
+      return;
+    }
+    let module = &self.modules[module_idx];
+    names.extend(module.exports.keys().cloned());
+    for target in &module.star_exports {
+      if let Target::Internal(idx) = target {
+        let mut star_names = FxIndexSet::default();
+        self.collect_export_names(*idx, &mut star_names, visited);
+        names.extend(star_names.into_iter().filter(|name| name != "default"));
+      }
+    }
+  }
+
+  /// `export * from 'external'` reached through the entry's star exports.
+  fn external_star_exports(&self) -> FxIndexSet<&str> {
+    let mut specifiers = FxIndexSet::default();
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![0];
+    while let Some(module_idx) = stack.pop() {
+      if !visited.insert(module_idx) {
+        continue;
+      }
+      for target in &self.modules[module_idx].star_exports {
+        match target {
+          Target::Internal(idx) => stack.push(*idx),
+          Target::External(specifier) => {
+            specifiers.insert(specifier.as_str());
+          }
+        }
+      }
+    }
+    specifiers
+  }
+
+  fn entry_exports(&self) -> Vec<(String, Binding)> {
+    self
+      .export_names(0)
+      .into_iter()
+      .filter_map(|name| {
+        let binding = self.resolve_export(0, &name, &mut FxHashSet::default())?;
+        Some((name, binding))
+      })
+      .collect()
+  }
+
+  fn include_entry(&mut self) {
+    let mut stack =
+      self.entry_exports().into_iter().map(|(_, binding)| binding).collect::<Vec<_>>();
+    for module_idx in 0..self.modules.len() {
+      for (stmt_idx, stmt) in self.modules[module_idx].statements.iter().enumerate() {
+        if stmt.is_augmentation {
+          self.included_statements.insert((module_idx, stmt_idx));
+          stack.extend(self.statement_dependencies(module_idx, stmt_idx));
+        }
+      }
+    }
+    // Reversed so that the entry exports are included, and named, in order.
+    stack.reverse();
+
+    while let Some(binding) = stack.pop() {
+      if !self.included.insert(binding.clone()) {
+        continue;
+      }
+      let statements = match &binding {
+        Binding::Local(module_idx, symbol_id) => self.modules[*module_idx]
+          .declared_by
+          .get(symbol_id)
+          .map(|statements| statements.iter().map(|stmt_idx| (*module_idx, *stmt_idx)).collect())
+          .unwrap_or_default(),
+        Binding::AnonymousDefault(module_idx) => self.modules[*module_idx]
+          .anonymous_default
+          .map(|idx| (*module_idx, idx))
+          .into_iter()
+          .collect(),
+        Binding::Namespace(module_idx) => {
+          for name in self.export_names(*module_idx) {
+            stack.extend(self.resolve_export(*module_idx, &name, &mut FxHashSet::default()));
+          }
+          vec![]
+        }
+        Binding::External(_) => vec![],
+      };
+      for (module_idx, stmt_idx) in statements {
+        if self.included_statements.insert((module_idx, stmt_idx)) {
+          stack.extend(self.statement_dependencies(module_idx, stmt_idx));
+        }
+      }
+    }
+  }
+
+  fn statement_dependencies(&self, module_idx: ModuleIdx, stmt_idx: usize) -> Vec<Binding> {
+    let span = self.modules[module_idx].statements[stmt_idx].span;
+    self
+      .occurrences_in(module_idx, span)
+      .iter()
+      .filter_map(|(_, symbol_id)| {
+        self.resolve_symbol(module_idx, *symbol_id, &mut FxHashSet::default())
+      })
+      .collect()
+  }
+
+  fn occurrences_in(&self, module_idx: ModuleIdx, span: Span) -> &[(Span, SymbolId)] {
+    let occurrences = &self.modules[module_idx].occurrences;
+    let start = occurrences.partition_point(|(occurrence, _)| occurrence.start < span.start);
+    let end = occurrences.partition_point(|(occurrence, _)| occurrence.start < span.end);
+    &occurrences[start..end]
+  }
+
+  fn assign_names(&mut self) {
+    // Globals referenced by the declarations must not be shadowed.
+    let mut used_names = self
+      .modules
+      .iter()
+      .flat_map(|module| module.scoping.root_unresolved_references().keys())
+      .map(ToString::to_string)
+      .collect::<FxHashSet<_>>();
+    let external_local_names = self.external_local_names();
+
+    for binding in &self.included {
+      let original_name = match binding {
+        Binding::Local(module_idx, symbol_id) => {
+          self.modules[*module_idx].scoping.symbol_name(*symbol_id).to_string()
+        }
+        Binding::AnonymousDefault(module_idx) => {
+          format!("{}_default", self.module_stem(*module_idx))
+        }
+        Binding::Namespace(module_idx) => format!("{}_exports", self.module_stem(*module_idx)),
+        Binding::External(external) => match &external.imported {
+          ImportedName::Named(name) if name != "default" => name.clone(),
+          _ => external_local_names
+            .get(external)
+            .cloned()
+            .unwrap_or_else(|| legitimize_identifier_name(&external.specifier).into_owned()),
+        },
+      };
+      let mut name = original_name.clone();
+      let mut count = 1;
+      while used_names.contains(&name) {
+        name = format!("{original_name}${count}");
+        count += 1;
+      }
+      used_names.insert(name.clone());
+      self.names.insert(binding.clone(), name);
+    }
+  }
+
+  fn module_stem(&self, module_idx: ModuleIdx) -> String {
+    let id = &self.modules[module_idx].id;
+    let file_name = Path::new(id.as_str()).file_name().and_then(|name| name.to_str());
+    let stem = file_name.and_then(|name| name.split('.').next()).unwrap_or("module");
+    legitimize_identifier_name(stem).into_owned()
+  }
+
+  /// The names external default and namespace imports were given in the source modules.
+  fn external_local_names(&self) -> FxHashMap<ExternalBinding, String> {
+    let mut names = FxHashMap::default();
+    for module in &self.modules {
+      for (symbol_id, import) in &module.imports {
+        if let Target::External(specifier) = &import.target {
+          let binding =
+            ExternalBinding { specifier: specifier.clone(), imported: import.imported.clone() };
+          names
+            .entry(binding)
+            .or_insert_with(|| module.scoping.symbol_name(*symbol_id).to_string());
+        }
+      }
+    }
+    names
+  }
+
+  /// External bindings imported or re-exported without `type` by any module.
+  fn external_value_bindings(&self) -> FxHashSet<ExternalBinding> {
+    let imports = self.modules.iter().flat_map(|module| {
+      let imports =
+        module.imports.values().map(|import| (&import.target, &import.imported, import.is_type));
+      let re_exports = module.exports.values().filter_map(|export| match export {
+        ExportEntry::ReExport { target, imported, is_type } => Some((target, imported, *is_type)),
+        _ => None,
+      });
+      imports.chain(re_exports)
+    });
+    imports
+      .filter_map(|(target, imported, is_type)| match target {
+        Target::External(specifier) if !is_type => {
+          Some(ExternalBinding { specifier: specifier.clone(), imported: imported.clone() })
+        }
+        _ => None,
+      })
+      .collect()
+  }
+
+  /// Modules with their dependencies first, like the js execution order.
+  fn module_order(&self) -> Vec<ModuleIdx> {
+    let mut order = vec![];
+    let mut visited = FxHashSet::default();
+    self.visit_module(0, &mut visited, &mut order);
+    order
+  }
+
+  fn visit_module(
+    &self,
+    module_idx: ModuleIdx,
+    visited: &mut FxHashSet<ModuleIdx>,
+    order: &mut Vec<ModuleIdx>,
+  ) {
+    if !visited.insert(module_idx) {
+      return;
+    }
+    for dependency in &self.modules[module_idx].dependencies {
+      self.visit_module(*dependency, visited, order);
+    }
+    order.push(module_idx);
+  }
+
+  fn render(&self) -> String {
+    let mut code = String::new();
+
+    let value_bindings = self.external_value_bindings();
+    let mut external_imports: FxIndexMap<&str, Vec<&ExternalBinding>> = FxIndexMap::default();
+    for binding in &self.included {
+      if let Binding::External(external) = binding {
+        external_imports.entry(external.specifier.as_str()).or_default().push(external);
+      }
+    }
+    for (specifier, bindings) in &external_imports {
+      let specifier = to_string_literal(specifier);
+      let type_only = bindings.iter().all(|binding| !value_bindings.contains(*binding));
+      let import_keyword = if type_only { "import type" } else { "import" };
+      let mut specifiers = vec![];
+      for binding in bindings {
+        let local = &self.names[&Binding::External((*binding).clone())];
+        let type_prefix =
+          if !type_only && !value_bindings.contains(*binding) { "type " } else { "" };
+        match &binding.imported {
+          ImportedName::Namespace => {
+            let import_keyword =
+              if value_bindings.contains(*binding) { "import" } else { "import type" };
+            code.push_str(&concat_string!(
+              import_keyword,
+              " * as ",
+              local,
+              " from ",
+              specifier,
+              ";\n"
+            ));
+          }
+          ImportedName::Named(name) if name == local => {
+            specifiers.push(format!("{type_prefix}{name}"));
+          }
+          ImportedName::Named(name) => {
+            specifiers
+              .push(format!("{type_prefix}{} as {local}", to_module_import_export_name(name)));
+          }
+        }
+      }
+      if !specifiers.is_empty() {
+        code.push_str(&concat_string!(
+          import_keyword,
+          " { ",
+          specifiers.join(", "),
+          " } from ",
+          specifier,
+          ";\n"
+        ));
+      }
+    }
+    if !external_imports.is_empty() {
+      code.push('\n');
+    }
+
+    for module_idx in self.module_order() {
+      for stmt_idx in 0..self.modules[module_idx].statements.len() {
+        if self.included_statements.contains(&(module_idx, stmt_idx)) {
+          self.render_statement(module_idx, stmt_idx, &mut code);
+        }
+      }
+    }
+
+    for binding in &self.included {
+      if let Binding::Namespace(module_idx) = binding {
+        let specifiers = self.render_export_specifiers(
+          self.export_names(*module_idx).into_iter().filter_map(|name| {
+            let binding = self.resolve_export(*module_idx, &name, &mut FxHashSet::default())?;
+            Some((name, binding))
+          }),
+        );
+        code.push_str(&concat_string!(
+          "declare namespace ",
+          self.names[binding],
+          " {\n\texport { ",
+          specifiers,
+          " };\n}\n"
+        ));
+      }
+    }
+
+    let specifiers = self.render_export_specifiers(self.entry_exports().into_iter());
+    code.push_str(&concat_string!("export { ", specifiers, " };\n"));
+    for specifier in self.external_star_exports() {
+      code.push_str(&concat_string!("export * from ", to_string_literal(specifier), ";\n"));
+    }
+    code
+  }
+
+  fn render_export_specifiers(&self, exports: impl Iterator<Item = (String, Binding)>) -> String {
+    exports
+      .map(|(exported, binding)| {
+        let local = &self.names[&binding];
+        if *local == exported {
+          exported
+        } else {
+          format!("{local} as {}", to_module_import_export_name(&exported))
+        }
+      })
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+
+  fn render_statement(&self, module_idx: ModuleIdx, stmt_idx: usize, code: &mut String) {
+    let module = &self.modules[module_idx];
+    let stmt = &module.statements[stmt_idx];
+    for comment in &stmt.comments {
+      code.push_str(comment.source_text(&module.source));
+      code.push('\n');
+    }
+    if stmt.needs_declare {
+      code.push_str("declare ");
+    }
+
+    let mut edits = self
+      .occurrences_in(module_idx, stmt.span)
+      .iter()
+      .filter_map(|(span, symbol_id)| {
+        let binding = self.resolve_symbol(module_idx, *symbol_id, &mut FxHashSet::default())?;
+        let name = self.names.get(&binding)?;
+        (span.source_text(&module.source) != name).then(|| (*span, name.clone()))
+      })
+      .collect::<Vec<_>>();
+    if let Some(at) = stmt.anonymous_default_name_at {
+      let name = &self.names[&Binding::AnonymousDefault(module_idx)];
+      edits.push((Span::new(at, at), format!(" {name}")));
+      edits.sort_by_key(|(span, _)| span.start);
+    }
+
+    let mut cursor = stmt.span.start;
+    for (span, replacement) in edits {
+      code.push_str(Span::new(cursor, span.start).source_text(&module.source));
+      code.push_str(&replacement);
+      cursor = span.end;
+    }
+    code.push_str(Span::new(cursor, stmt.span.end).source_text(&module.source));
+    code.push('\n');
+  }
+}
@@ -1,23 +1,36 @@
 use std::{borrow::Cow, path::Path};
 
 use arcstr::ArcStr;
+use dts_bundler::{ModuleDeclaration, bundle_declarations};
 use oxc::{
   allocator::IntoIn,
   ast_visit::VisitJsMut,
   codegen::Codegen,
   isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions},
 };
-use rolldown_common::{ModuleType, ResolvedExternal};
+use rolldown_common::{EmittedAsset, ModuleType, Output, ResolvedExternal, WatcherChangeKind};
 use rolldown_error::{BatchedBuildDiagnostic, BuildDiagnostic, EventKind, Severity};
 use rolldown_plugin::{HookUsage, Plugin, PluginHookMeta, PluginOrder};
+use rolldown_utils::dashmap::FxDashMap;
+use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 use type_import_visitor::TypeImportVisitor;
 
+mod dts_bundler;
 mod type_import_visitor;
 
 #[derive(Debug, Default)]
 pub struct IsolatedDeclarationPlugin {
   pub strip_internal: bool,
+  /// Emit one rolled-up declaration file per entry chunk instead of one per module.
+  pub bundle: bool,
+  declarations: FxDashMap<ArcStr, ModuleDeclaration>,
+}
+
+impl IsolatedDeclarationPlugin {
+  pub fn new(strip_internal: bool, bundle: bool) -> Self {
+    Self { strip_internal, bundle, declarations: FxDashMap::default() }
+  }
 }
 
 impl Plugin for IsolatedDeclarationPlugin {
@@ -31,38 +44,53 @@ impl Plugin for IsolatedDeclarationPlugin {
     mut args: rolldown_plugin::HookTransformAstArgs<'_>,
   ) -> rolldown_plugin::HookTransformAstReturn {
     if matches!(args.module_type, ModuleType::Ts | ModuleType::Tsx) {
+      if self.bundle {
+        // Drop the declaration of the previous build, in case this one fails.
+        self.declarations.remove(args.id);
+      }
+
       let type_import_specifiers = args.ast.program.with_mut(|fields| {
         let mut visitor = TypeImportVisitor { imported: vec![].into_in(fields.allocator) };
         visitor.visit_program(fields.program);
         visitor.imported
       });
 
+      // Type only imports are erased from the js module, so they aren't part of the module graph.
+      let mut type_imports = FxHashMap::default();
       for specifier in type_import_specifiers {
         let resolved_id = ctx.resolve(&specifier, Some(args.id), None).await??;
         if matches!(resolved_id.external, ResolvedExternal::Bool(false)) {
           ctx.load(&resolved_id.id, None, resolved_id.module_def_format).await?;
+          type_imports.insert(specifier.to_string(), resolved_id.id.as_arc_str().clone());
         }
       }
 
-      let ret = args.ast.program.with_mut(|fields| {
-        IsolatedDeclarations::new(
-          fields.allocator,
-          IsolatedDeclarationsOptions { strip_internal: self.strip_internal },
-        )
-        .build(fields.program)
-      });
+      let code = {
+        let ret = args.ast.program.with_mut(|fields| {
+          IsolatedDeclarations::new(
+            fields.allocator,
+            IsolatedDeclarationsOptions { strip_internal: self.strip_internal },
+          )
+          .build(fields.program)
+        });
 
-      if !ret.diagnostics.is_empty() {
-        Err(BatchedBuildDiagnostic::new(BuildDiagnostic::from_oxc_diagnostics(
-          ret.diagnostics,
-          &ArcStr::from(ret.program.source_text),
-          args.id,
-          Severity::Error,
-          EventKind::ParseError,
-        )))?;
-      }
+        if !ret.diagnostics.is_empty() {
+          Err(BatchedBuildDiagnostic::new(BuildDiagnostic::from_oxc_diagnostics(
+            ret.diagnostics,
+            &ArcStr::from(ret.program.source_text),
+            args.id,
+            Severity::Error,
+            EventKind::ParseError,
+          )))?;
+        }
+
+        Codegen::new().build(&ret.program).code
+      };
 
-      let codegen_ret = Codegen::new().build(&ret.program);
+      if self.bundle {
+        self.declarations.insert(args.id.into(), ModuleDeclaration { source: code, type_imports });
+        return Ok(args.ast);
+      }
 
       let mut emit_dts_path = Path::new(args.stable_id).to_path_buf();
       emit_dts_path.set_extension("d.ts");
@@ -72,7 +100,7 @@ impl Plugin for IsolatedDeclarationPlugin {
           original_file_name: None,
           // TODO make sure to the .d.ts file relative to the output entry file
           file_name: Some(emit_dts_path.to_slash_lossy().into()),
-          source: codegen_ret.code.into(),
+          source: code.into(),
         },
         None,
         None,
@@ -86,7 +114,66 @@ impl Plugin for IsolatedDeclarationPlugin {
     Some(PluginHookMeta { order: Some(PluginOrder::Post) })
   }
 
+  async fn watch_change(
+    &self,
+    _ctx: &rolldown_plugin::PluginContext,
+    path: &str,
+    event: WatcherChangeKind,
+  ) -> rolldown_plugin::HookNoopReturn {
+    // Declarations of the other modules are kept across rebuilds, since unchanged modules aren't
+    // transformed again by incremental builds.
+    if matches!(event, WatcherChangeKind::Delete) {
+      self.declarations.remove(path);
+    }
+    Ok(())
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &mut rolldown_plugin::HookGenerateBundleArgs<'_>,
+  ) -> rolldown_plugin::HookNoopReturn {
+    if !self.bundle {
+      return Ok(());
+    }
+    for output in args.bundle.iter() {
+      let Output::Chunk(chunk) = output else { continue };
+      if !chunk.is_entry {
+        continue;
+      }
+      let Some(facade_module_id) = &chunk.facade_module_id else { continue };
+      let module_info = |id: &str| ctx.get_module_info(id);
+      let Some(source) = bundle_declarations(facade_module_id, &self.declarations, module_info)?
+      else {
+        continue;
+      };
+      ctx
+        .emit_file_async(EmittedAsset {
+          file_name: Some(declaration_file_name(&chunk.filename).into()),
+          source: source.into(),
+          ..Default::default()
+        })
+        .await?;
+    }
+    Ok(())
+  }
+
   fn register_hook_usage(&self) -> HookUsage {
-    HookUsage::TransformAst
+    if self.bundle {
+      HookUsage::TransformAst | HookUsage::WatchChange | HookUsage::GenerateBundle
+    } else {
+      HookUsage::TransformAst
+    }
   }
 }
+
+/// `index.js` -> `index.d.ts`, `index.mjs` -> `index.d.mts` and `index.cjs` -> `index.d.cts`.
+fn declaration_file_name(chunk_file_name: &str) -> String {
+  let (stem, extension) = match chunk_file_name.rsplit_once('.') {
+    Some((stem, "mjs")) => (stem, "d.mts"),
+    Some((stem, "cjs")) => (stem, "d.cts"),
+    Some((stem, _)) => (stem, "d.ts"),
+    None => (chunk_file_name, "d.ts"),
+  };
+  format!("{stem}.{extension}")
+}
@@ -0,0 +1,22 @@
+import type { Logger } from './utils';
+
+export interface Options {
+  name: string;
+  logger?: Logger;
+}
+
+/** Not exported from the entry, so it should be dropped. */
+export interface Unused {
+  value: number;
+}
+
+/**
+ * Creates an app.
+ */
+export function createApp(options: Options): App {
+  return { options };
+}
+
+interface App {
+  options: Options;
+}
@@ -0,0 +1,6 @@
+import { type Options, createApp } from './app';
+import type { Options as PluginOptions } from './plugin';
+
+export { createApp };
+export type { Options, PluginOptions };
+export * from './utils';
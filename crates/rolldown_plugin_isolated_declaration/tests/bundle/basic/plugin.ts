@@ -0,0 +1,11 @@
+export interface Options {
+  enforce?: 'pre' | 'post';
+}
+
+interface App {
+  plugins: Options[];
+}
+
+export function usePlugin(app: App): void {
+  app.plugins.push({});
+}
@@ -0,0 +1,3 @@
+export type Logger = (message: string) => void;
+
+export const version: string = '1.0.0';
@@ -0,0 +1,6 @@
+import greet from './greet';
+
+export { greet };
+export default function (name: string): string {
+  return greet(name);
+}
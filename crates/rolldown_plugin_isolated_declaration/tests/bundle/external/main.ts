@@ -0,0 +1,10 @@
+import type { Stats } from 'node:fs';
+import { EventEmitter } from 'node:events';
+import type * as fs from 'node:fs';
+
+export class Watcher extends EventEmitter {
+  stats?: Stats;
+}
+
+export type { fs };
+export * from 'node:fs';
@@ -0,0 +1,4 @@
+import * as shapes from './shapes';
+
+export { shapes };
+export type Shape = shapes.Circle | shapes.Square;
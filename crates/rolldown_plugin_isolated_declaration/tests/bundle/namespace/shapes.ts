@@ -0,0 +1,7 @@
+export interface Circle {
+  radius: number;
+}
+
+export interface Square {
+  size: number;
+}
@@ -4,9 +4,15 @@ use std::{path::PathBuf, sync::Arc};
 use testing_macros::fixture;
 
 #[expect(clippy::needless_pass_by_value)]
-#[fixture("./tests/**/_config.json")]
+#[fixture("./tests/**/_config.json", exclude("bundle"))]
 fn fixture_with_config(config_path: PathBuf) {
-  Fixture::new(config_path.parent().unwrap()).run_integration_test_with_plugins(vec![Arc::new(
-    IsolatedDeclarationPlugin { strip_internal: true },
-  )]);
+  Fixture::new(config_path.parent().unwrap())
+    .run_integration_test_with_plugins(vec![Arc::new(IsolatedDeclarationPlugin::new(true, false))]);
+}
+
+#[expect(clippy::needless_pass_by_value)]
+#[fixture("./tests/bundle/**/_config.json")]
+fn bundle_fixture_with_config(config_path: PathBuf) {
+  Fixture::new(config_path.parent().unwrap())
+    .run_integration_test_with_plugins(vec![Arc::new(IsolatedDeclarationPlugin::new(true, true))]);
 }
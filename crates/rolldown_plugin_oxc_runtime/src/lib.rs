@@ -29,6 +29,10 @@ impl Plugin for OxcRuntimePlugin {
     HookUsage::ResolveId | HookUsage::Load
   }
 
+  fn cache_key(&self) -> Option<Cow<'_, str>> {
+    Some(Cow::Borrowed(env!("CARGO_PKG_VERSION")))
+  }
+
   async fn resolve_id(
     &self,
     _ctx: &rolldown_plugin::PluginContext,
@@ -0,0 +1,183 @@
+use std::{borrow::Cow, io::Write as _};
+
+use arcstr::ArcStr;
+use flate2::{Compression, write::GzEncoder};
+use rolldown_common::{EmittedAsset, Output, PrecompressAlgorithm, PrecompressOptions, StrOrBytes};
+use rolldown_plugin::{HookUsage, Plugin};
+use rolldown_utils::{
+  concat_string,
+  pattern_filter::{self, FilterResult, StringOrRegex},
+  rayon::{IntoParallelIterator, ParallelIterator},
+};
+use rustc_hash::FxHashSet;
+
+/// Assets with these extensions are compressed when `include` is unset. Chunks always are.
+const COMPRESSIBLE_ASSETS: [&str; 11] =
+  [".css", ".html", ".js", ".cjs", ".mjs", ".json", ".svg", ".txt", ".xml", ".xhtml", ".wasm"];
+
+const DEFAULT_ALGORITHMS: [PrecompressAlgorithm; 2] =
+  [PrecompressAlgorithm::Gzip, PrecompressAlgorithm::Brotli];
+
+const DEFAULT_THRESHOLD: u32 = 1024;
+
+#[derive(Debug)]
+pub struct PrecompressPlugin {
+  pub algorithms: Vec<PrecompressAlgorithm>,
+  pub threshold: usize,
+  pub level: Option<u32>,
+  pub include: Option<Vec<StringOrRegex>>,
+  pub exclude: Option<Vec<StringOrRegex>>,
+}
+
+impl PrecompressPlugin {
+  pub fn new(options: &PrecompressOptions) -> Self {
+    Self {
+      algorithms: options.algorithms.clone().unwrap_or_else(|| DEFAULT_ALGORITHMS.to_vec()),
+      threshold: options.threshold.unwrap_or(DEFAULT_THRESHOLD) as usize,
+      level: options.level,
+      include: options.include.clone(),
+      exclude: options.exclude.clone(),
+    }
+  }
+
+  fn should_compress(&self, output: &Output) -> bool {
+    let filename = output.filename();
+    if self.algorithms.iter().any(|algorithm| filename.ends_with(algorithm.extension())) {
+      return false;
+    }
+    if output.content_as_bytes().len() < self.threshold {
+      return false;
+    }
+    let result =
+      pattern_filter::filter(self.exclude.as_deref(), self.include.as_deref(), filename, "");
+    match output {
+      Output::Asset(_)
+        if self.include.is_none() && matches!(result, FilterResult::NoneMatch(_)) =>
+      {
+        COMPRESSIBLE_ASSETS.iter().any(|ext| filename.ends_with(ext))
+      }
+      _ => result.inner(),
+    }
+  }
+}
+
+fn compress(
+  algorithm: PrecompressAlgorithm,
+  level: u32,
+  content: &[u8],
+) -> std::io::Result<Vec<u8>> {
+  match algorithm {
+    PrecompressAlgorithm::Gzip => {
+      let mut encoder = GzEncoder::new(Vec::new(), Compression::new(level));
+      encoder.write_all(content)?;
+      encoder.finish()
+    }
+    PrecompressAlgorithm::Brotli => {
+      let params = brotli::enc::BrotliEncoderParams {
+        quality: level.cast_signed(),
+        size_hint: content.len(),
+        ..Default::default()
+      };
+      let mut compressed = Vec::new();
+      brotli::BrotliCompress(&mut &*content, &mut compressed, &params)?;
+      Ok(compressed)
+    }
+    PrecompressAlgorithm::Zstd => zstd::bulk::compress(content, level.cast_signed()),
+  }
+}
+
+impl Plugin for PrecompressPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    Cow::Borrowed("builtin:precompress")
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::GenerateBundle
+  }
+
+  async fn generate_bundle(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &mut rolldown_plugin::HookGenerateBundleArgs<'_>,
+  ) -> rolldown_plugin::HookNoopReturn {
+    let existing = args.bundle.iter().map(Output::filename).collect::<FxHashSet<_>>();
+    let jobs = args
+      .bundle
+      .iter()
+      .filter(|output| self.should_compress(output))
+      .flat_map(|output| self.algorithms.iter().map(move |algorithm| (output.clone(), *algorithm)))
+      .filter(|(output, algorithm)| {
+        !existing.contains(concat_string!(output.filename(), algorithm.extension()).as_str())
+      })
+      .collect::<Vec<_>>();
+
+    // Compression is CPU bound, so keep it off the async runtime's worker threads.
+    let level = self.level;
+    let compressed = tokio::task::spawn_blocking(move || {
+      jobs
+        .into_par_iter()
+        .map(|(output, algorithm)| {
+          let content = output.content_as_bytes();
+          let compressed = compress(algorithm, algorithm.level(level), content)?;
+          // Serving a sibling that is not smaller than the original is pointless.
+          Ok((compressed.len() < content.len()).then(|| {
+            (ArcStr::from(concat_string!(output.filename(), algorithm.extension())), compressed)
+          }))
+        })
+        .collect::<std::io::Result<Vec<_>>>()
+    })
+    .await??;
+
+    for (file_name, source) in compressed.into_iter().flatten() {
+      ctx
+        .emit_file_async(EmittedAsset {
+          file_name: Some(file_name),
+          source: StrOrBytes::Bytes(source),
+          ..Default::default()
+        })
+        .await?;
+    }
+
+    Ok(())
+  }
+
+  fn generate_bundle_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    // Compress the final content, after every other plugin had the chance to change it.
+    Some(rolldown_plugin::PluginHookMeta { order: Some(rolldown_plugin::PluginOrder::PinPost) })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Read as _;
+
+  use super::*;
+
+  fn decompress(algorithm: PrecompressAlgorithm, compressed: &[u8]) -> Vec<u8> {
+    let mut decompressed = Vec::new();
+    match algorithm {
+      PrecompressAlgorithm::Gzip => {
+        flate2::read::GzDecoder::new(compressed).read_to_end(&mut decompressed).unwrap();
+      }
+      PrecompressAlgorithm::Brotli => {
+        brotli::BrotliDecompress(&mut &*compressed, &mut decompressed).unwrap();
+      }
+      PrecompressAlgorithm::Zstd => decompressed = zstd::decode_all(compressed).unwrap(),
+    }
+    decompressed
+  }
+
+  #[test]
+  fn compressed_outputs_round_trip() {
+    let content = "export const value = 'rolldown';\n".repeat(100);
+    for algorithm in
+      [PrecompressAlgorithm::Gzip, PrecompressAlgorithm::Brotli, PrecompressAlgorithm::Zstd]
+    {
+      for level in [None, Some(1)] {
+        let compressed = compress(algorithm, algorithm.level(level), content.as_bytes()).unwrap();
+        assert!(compressed.len() < content.len(), "{algorithm:?} should shrink the content");
+        assert_eq!(decompress(algorithm, &compressed), content.as_bytes(), "{algorithm:?}");
+      }
+    }
+  }
+}
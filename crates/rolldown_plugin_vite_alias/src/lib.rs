@@ -56,6 +56,7 @@ impl Plugin for ViteAliasPlugin {
           import_kind: args.kind,
           is_entry: args.is_entry,
           custom: Arc::clone(&args.custom),
+          attributes: args.attributes.cloned(),
         }),
       )
       .await?;
@@ -12,10 +12,20 @@ use rolldown_plugin::{
   HookResolveIdReturn, HookTransformAstArgs, HookTransformAstReturn, HookUsage, Plugin,
   PluginContext, SharedLoadPluginContext,
 };
+use rolldown_utils::concat_string;
 
 use self::ast_visit::BuildImportAnalysisVisitor;
 
 const PRELOAD_HELPER_ID: &str = "\0vite/preload-helper.js";
+/// Declared for a preload helper that references it, so the helper can set `integrity` on the
+/// `<link rel=modulepreload>` elements it creates.
+///
+/// With `output.integrity`, it's the `integrity` section of the page's import map, keyed by the
+/// URLs of the chunks, which is what the chunk import map plugin emits. The digests can't be
+/// inlined in the helper: they cover the final bytes of chunks that import the helper, so the
+/// helper's own content hash would depend on itself. The object is empty without
+/// `output.integrity`.
+const PRELOAD_INTEGRITY: &str = "__VITE_PRELOAD_INTEGRITY__";
 
 #[derive(derive_more::Debug)]
 pub struct ViteBuildImportAnalysisPlugin {
@@ -45,9 +55,32 @@ impl Plugin for ViteBuildImportAnalysisPlugin {
     )
   }
 
-  async fn load(&self, _ctx: SharedLoadPluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
-    Ok((args.id == PRELOAD_HELPER_ID).then_some(HookLoadOutput {
-      code: self.preload_code.clone(),
+  async fn load(&self, ctx: SharedLoadPluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if args.id != PRELOAD_HELPER_ID {
+      return Ok(None);
+    }
+    let code = if !self.preload_code.contains(PRELOAD_INTEGRITY) {
+      self.preload_code.clone()
+    } else if ctx.options().integrity.is_some() {
+      concat_string!(
+        "const ",
+        PRELOAD_INTEGRITY,
+        " = /* @__PURE__ */ (() => {\n",
+        "  const importMap = typeof document !== 'undefined' && document.querySelector('script[type=\"importmap\"]');\n",
+        "  try {\n",
+        "    return (importMap && JSON.parse(importMap.textContent).integrity) || {};\n",
+        "  } catch {\n",
+        "    return {};\n",
+        "  }\n",
+        "})();\n",
+        self.preload_code
+      )
+      .into()
+    } else {
+      concat_string!("const ", PRELOAD_INTEGRITY, " = {};\n", self.preload_code).into()
+    };
+    Ok(Some(HookLoadOutput {
+      code,
       side_effects: Some(HookSideEffects::False),
       ..Default::default()
     }))
@@ -0,0 +1,29 @@
+use rolldown_plugin_vite_build_import_analysis::ViteBuildImportAnalysisPlugin;
+use rolldown_testing::fixture::Fixture;
+use std::{path::PathBuf, sync::Arc};
+use testing_macros::fixture;
+
+/// Sets `integrity` on the links it creates, from the map declared by the plugin.
+const PRELOAD_CODE: &str = "export const __vitePreload = (load, deps) => {
+  for (const dep of deps) {
+    const link = document.createElement('link');
+    link.rel = 'modulepreload';
+    link.href = dep;
+    if (__VITE_PRELOAD_INTEGRITY__[dep]) link.integrity = __VITE_PRELOAD_INTEGRITY__[dep];
+    document.head.appendChild(link);
+  }
+  return load();
+};";
+
+#[expect(clippy::needless_pass_by_value)]
+#[fixture("./tests/**/_config.json")]
+fn fixture_with_config(config_path: PathBuf) {
+  Fixture::new(config_path.parent().unwrap()).run_integration_test_with_plugins(vec![Arc::new(
+    ViteBuildImportAnalysisPlugin {
+      preload_code: PRELOAD_CODE.into(),
+      insert_preload: true,
+      render_built_url: false,
+      is_relative_base: false,
+    },
+  )]);
+}
@@ -0,0 +1,3 @@
+const __VITE_PRELOAD__ = ['/lazy.js'];
+
+export const loadLazy = () => import('./lazy.js');
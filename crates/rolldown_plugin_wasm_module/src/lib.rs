@@ -0,0 +1,311 @@
+mod wasm_interface;
+
+use std::{borrow::Cow, fmt::Write as _, io, path::Path};
+
+use rolldown_common::{
+  EmittedAsset, ModuleType, OutputFormat, Platform, RUNTIME_MODULE_KEY, StrOrBytes,
+};
+use rolldown_fs::FileSystem;
+use rolldown_plugin::{
+  HookLoadArgs, HookLoadOutput, HookLoadReturn, HookUsage, Plugin, PluginHookMeta, PluginOrder,
+  SharedLoadPluginContext,
+};
+use rolldown_utils::{
+  ecmascript::{property_access_str, to_module_import_export_name},
+  indexmap::FxIndexMap,
+  url::clean_url,
+};
+use rustc_hash::FxHashSet;
+
+use crate::wasm_interface::{WasmExternalKind, parse_wasm_module_interface};
+
+/// Query appended to the id of a module imported with `import source`, which only exposes the
+/// compiled `WebAssembly.Module` instead of instantiating it.
+pub const SOURCE_PHASE_QUERY: &str = "?source";
+
+/// Implements the WebAssembly ESM integration for modules with type `wasm`.
+///
+/// The module is replaced by js code that compiles the binary, instantiates it with the modules
+/// listed in its import section and re-exports the entries of its export section.
+///
+/// The `esm` and `system` formats compile and instantiate the module asynchronously with top-level
+/// await. The other formats can't await at the top level, so they use the synchronous
+/// `WebAssembly.Module` and `WebAssembly.Instance` constructors, which is only supported on node:
+/// browsers reject synchronous compilation of binaries larger than 4KB on the main thread. On node
+/// these formats also require the binary to be embedded or, for `cjs`, read with `readFileSync`.
+///
+/// Exported mutable globals are live bindings refreshed after every call to an exported function
+/// and before every call to an imported one, which replaces those functions with js wrappers.
+/// Changes made while wasm calls a function reached through an exported table aren't observed
+/// until the next refresh.
+#[derive(Debug)]
+pub struct WasmModulePlugin<Fs> {
+  fs: Fs,
+  wasm_extensions: FxHashSet<String>,
+  platform: Platform,
+  format: OutputFormat,
+  embed: bool,
+}
+
+impl<Fs: FileSystem + Clone + 'static> WasmModulePlugin<Fs> {
+  pub fn new(
+    module_types: &rustc_hash::FxHashMap<Cow<'static, str>, ModuleType>,
+    platform: Platform,
+    format: OutputFormat,
+    embed: bool,
+    fs: Fs,
+  ) -> Self {
+    let mut wasm_extensions = FxHashSet::default();
+    for (ext, module_type) in module_types {
+      if matches!(module_type, ModuleType::Wasm) {
+        let ext = ext.strip_prefix('.').unwrap_or(ext);
+        wasm_extensions.insert(ext.to_string());
+      }
+    }
+    Self { fs, wasm_extensions, platform, format, embed }
+  }
+}
+
+impl<Fs: FileSystem + Clone + std::fmt::Debug + 'static> Plugin for WasmModulePlugin<Fs> {
+  fn name(&self) -> Cow<'static, str> {
+    Cow::Borrowed("builtin:wasm-module")
+  }
+
+  fn register_hook_usage(&self) -> HookUsage {
+    HookUsage::Load
+  }
+
+  fn cache_key(&self) -> Option<Cow<'_, str>> {
+    let mut extensions = self.wasm_extensions.iter().map(String::as_str).collect::<Vec<_>>();
+    extensions.sort_unstable();
+    Some(
+      format!(
+        "{}:{}:{:?}:{:?}:{}",
+        env!("CARGO_PKG_VERSION"),
+        extensions.join(","),
+        self.platform,
+        self.format,
+        self.embed
+      )
+      .into(),
+    )
+  }
+
+  fn load_meta(&self) -> Option<PluginHookMeta> {
+    // Run after user plugins so they can override wasm loading
+    Some(PluginHookMeta { order: Some(PluginOrder::Post) })
+  }
+
+  fn load(
+    &self,
+    ctx: SharedLoadPluginContext,
+    args: &HookLoadArgs<'_>,
+  ) -> impl std::future::Future<Output = HookLoadReturn> + Send {
+    self.load_impl(ctx, args)
+  }
+}
+
+impl<Fs: FileSystem + Clone + 'static> WasmModulePlugin<Fs> {
+  async fn load_impl(
+    &self,
+    ctx: SharedLoadPluginContext,
+    args: &HookLoadArgs<'_>,
+  ) -> HookLoadReturn {
+    let clean_id = clean_url(args.id);
+    let is_wasm = args.asserted_module_type.is_some_and(|ty| matches!(ty, ModuleType::Wasm))
+      || self.is_wasm_by_extension(clean_id);
+    if !is_wasm {
+      return Ok(None);
+    }
+    let is_source_phase = args.id.ends_with(SOURCE_PHASE_QUERY);
+    // Without top-level await the module has to be compiled synchronously.
+    let is_sync = !self.format.supports_top_level_await();
+    if is_sync && !matches!(self.platform, Platform::Node) {
+      anyhow::bail!(
+        "Failed to load wasm module {clean_id}: the `{}` output format has no top-level await to compile it asynchronously, and browsers reject synchronous compilation of binaries larger than 4KB on the main thread. Use the `esm` or `system` format, or set `platform` to `node`.",
+        self.format
+      );
+    }
+    if is_sync && !self.embed && !matches!(self.format, OutputFormat::Cjs) {
+      anyhow::bail!(
+        "Failed to load wasm module {clean_id}: the `{}` output format has no top-level await to load it from a separate file. Set `wasm.embed` to inline the binary or use the `esm` or `system` format.",
+        self.format
+      );
+    }
+
+    let bytes = self
+      .read_file(clean_id)
+      .await
+      .map_err(|e| anyhow::anyhow!("Failed to read wasm module {clean_id}: {e}"))?;
+    let interface = parse_wasm_module_interface(&bytes)
+      .map_err(|e| anyhow::anyhow!("Failed to parse wasm module {clean_id}: {e}"))?;
+    // Through LoadPluginContext, which also records the module's HMR transform dependency.
+    ctx.add_watch_file(clean_id);
+
+    let mut code = String::new();
+
+    let bytes_expr = if self.embed {
+      let to_binary = match self.platform {
+        Platform::Node => "__toBinaryNode",
+        _ => "__toBinary",
+      };
+      writeln!(code, "import {{ {to_binary} }} from {};", quote(RUNTIME_MODULE_KEY))?;
+      let encoded = rolldown_utils::base64::to_standard_base64(&bytes);
+      Some(format!("{to_binary}({})", quote(&encoded)))
+    } else {
+      let path = Path::new(clean_id);
+      let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("asset").to_string();
+      let original_file_name =
+        path.strip_prefix(ctx.cwd()).unwrap_or(path).to_string_lossy().into_owned();
+      let reference_id = ctx
+        .emit_file_async(EmittedAsset {
+          name: Some(file_name),
+          original_file_name: Some(original_file_name),
+          source: StrOrBytes::Bytes(bytes),
+          ..Default::default()
+        })
+        .await?;
+      if is_sync {
+        code.push_str("import { readFileSync as __wasm_read_file } from \"node:fs\";\n");
+        Some(format!("__wasm_read_file(new URL(import.meta.ROLLDOWN_FILE_URL_{reference_id}))"))
+      } else if matches!(self.platform, Platform::Node) {
+        code.push_str("import { readFile as __wasm_read_file } from \"node:fs/promises\";\n");
+        Some(format!(
+          "await __wasm_read_file(new URL(import.meta.ROLLDOWN_FILE_URL_{reference_id}))"
+        ))
+      } else {
+        // `compileStreaming` starts compiling while the binary is still downloading.
+        writeln!(
+          code,
+          "const __wasm_module = await WebAssembly.compileStreaming(fetch(import.meta.ROLLDOWN_FILE_URL_{reference_id}));"
+        )?;
+        None
+      }
+    };
+    if let Some(bytes_expr) = bytes_expr {
+      if is_sync {
+        writeln!(code, "const __wasm_module = new WebAssembly.Module({bytes_expr});")?;
+      } else {
+        writeln!(code, "const __wasm_module = await WebAssembly.compile({bytes_expr});")?;
+      }
+    }
+
+    if is_source_phase {
+      code.push_str("export default __wasm_module;\n");
+    } else {
+      let has_live_globals =
+        interface.exports.iter().any(|export| interface.is_mutable_global(export));
+
+      // Named imports let the link stage check the bindings the instance needs.
+      let mut import_modules: FxIndexMap<&str, FxIndexMap<&str, WasmExternalKind>> =
+        FxIndexMap::default();
+      for import in &interface.imports {
+        import_modules
+          .entry(import.module.as_str())
+          .or_default()
+          .insert(import.name.as_str(), import.kind);
+      }
+      let mut import_object = String::new();
+      for (module_idx, (module, names)) in import_modules.iter().enumerate() {
+        let mut specifiers = Vec::with_capacity(names.len());
+        let mut fields = Vec::with_capacity(names.len());
+        for (name_idx, (name, kind)) in names.iter().enumerate() {
+          let local = format!("__wasm_import_{module_idx}_{name_idx}");
+          specifiers.push(format!("{} as {local}", to_module_import_export_name(name)));
+          if has_live_globals && *kind == WasmExternalKind::Func {
+            // Lets the imported function observe the globals changed by the ongoing wasm call.
+            fields.push(format!(
+              "{}: (...args) => (__wasm_sync_globals(), {local}(...args))",
+              quote(name)
+            ));
+          } else {
+            fields.push(format!("{}: {local}", quote(name)));
+          }
+        }
+        let module = quote(module);
+        writeln!(code, "import {{ {} }} from {module};", specifiers.join(", "))?;
+        write!(import_object, "{module}: {{ {} }}, ", fields.join(", "))?;
+      }
+      let instance = if is_sync {
+        format!("new WebAssembly.Instance(__wasm_module, {{ {import_object}}})")
+      } else {
+        format!("await WebAssembly.instantiate(__wasm_module, {{ {import_object}}})")
+      };
+      if has_live_globals {
+        // Imported functions may run during instantiation, before `__wasm_exports` is assigned.
+        code.push_str("let __wasm_exports;\n");
+        let mut refreshes = String::new();
+        for (idx, export) in interface.exports.iter().enumerate() {
+          if interface.is_mutable_global(export) {
+            writeln!(code, "let __wasm_export_{idx};")?;
+            let global = property_access_str("__wasm_exports", &export.name);
+            write!(refreshes, " __wasm_export_{idx} = {global}.value;")?;
+          }
+        }
+        writeln!(
+          code,
+          "function __wasm_sync_globals() {{ if (__wasm_exports) {{{refreshes} }} }}"
+        )?;
+        writeln!(code, "({{ exports: __wasm_exports }} = {instance});")?;
+        code.push_str("__wasm_sync_globals();\n");
+      } else {
+        writeln!(code, "const {{ exports: __wasm_exports }} = {instance};")?;
+      }
+
+      let mut export_specifiers = Vec::with_capacity(interface.exports.len());
+      for (idx, export) in interface.exports.iter().enumerate() {
+        let value = property_access_str("__wasm_exports", &export.name);
+        let value = match export.kind {
+          // Declared above and kept up to date by `__wasm_sync_globals`.
+          WasmExternalKind::Global if interface.is_mutable_global(export) => None,
+          // Immutable globals are exposed by their value.
+          WasmExternalKind::Global => Some(format!("{value}.value")),
+          WasmExternalKind::Func if has_live_globals => Some(format!(
+            "(...args) => {{ try {{ return {value}(...args); }} finally {{ __wasm_sync_globals(); }} }}"
+          )),
+          _ => Some(value),
+        };
+        if let Some(value) = value {
+          writeln!(code, "const __wasm_export_{idx} = {value};")?;
+        }
+        export_specifiers
+          .push(format!("__wasm_export_{idx} as {}", to_module_import_export_name(&export.name)));
+      }
+      if !export_specifiers.is_empty() {
+        writeln!(code, "export {{ {} }};", export_specifiers.join(", "))?;
+      }
+    }
+
+    Ok(Some(HookLoadOutput {
+      code: code.into(),
+      module_type: Some(ModuleType::Js),
+      ..Default::default()
+    }))
+  }
+
+  async fn read_file(&self, path: &str) -> io::Result<Vec<u8>> {
+    #[cfg(not(target_family = "wasm"))]
+    {
+      let fs = self.fs.clone();
+      let path = path.to_string();
+      tokio::task::spawn_blocking(move || fs.read(Path::new(&path)))
+        .await
+        .map_err(io::Error::other)?
+    }
+    #[cfg(target_family = "wasm")]
+    {
+      self.fs.read(Path::new(path))
+    }
+  }
+
+  fn is_wasm_by_extension(&self, id: &str) -> bool {
+    Path::new(id)
+      .extension()
+      .and_then(|e| e.to_str())
+      .is_some_and(|ext| self.wasm_extensions.contains(ext))
+  }
+}
+
+fn quote(s: &str) -> String {
+  serde_json::to_string(s).expect("a string always serializes")
+}
@@ -0,0 +1,396 @@
+use anyhow::{Context, bail};
+
+/// An import entry of the import section.
+#[derive(Debug)]
+pub struct WasmImport {
+  pub module: String,
+  pub name: String,
+  pub kind: WasmExternalKind,
+}
+
+/// An export entry of the export section.
+#[derive(Debug)]
+pub struct WasmExport {
+  pub name: String,
+  pub kind: WasmExternalKind,
+  /// Index in the index space of `kind`.
+  pub index: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmExternalKind {
+  Func,
+  Table,
+  Memory,
+  Global,
+  Tag,
+}
+
+/// The parts of a Wasm binary that form its ESM interface.
+#[derive(Debug, Default)]
+pub struct WasmModuleInterface {
+  pub imports: Vec<WasmImport>,
+  pub exports: Vec<WasmExport>,
+  /// Whether each global of the global index space, imported ones first, is mutable.
+  pub globals: Vec<bool>,
+}
+
+impl WasmModuleInterface {
+  /// Globals whose initializer couldn't be skipped are assumed to be mutable.
+  pub fn is_mutable_global(&self, export: &WasmExport) -> bool {
+    export.kind == WasmExternalKind::Global
+      && self.globals.get(export.index as usize).copied().unwrap_or(true)
+  }
+}
+
+const IMPORT_SECTION_ID: u8 = 2;
+const GLOBAL_SECTION_ID: u8 = 6;
+const EXPORT_SECTION_ID: u8 = 7;
+
+/// Reads the import, global and export sections of a Wasm binary. Other sections are skipped without
+/// being validated, that's left to `WebAssembly.compile` at runtime.
+pub fn parse_wasm_module_interface(bytes: &[u8]) -> anyhow::Result<WasmModuleInterface> {
+  let mut reader = Reader { bytes, pos: 0 };
+  if reader.read_bytes(4)? != b"\0asm" {
+    bail!("Invalid magic number, not a WebAssembly binary");
+  }
+  if reader.read_bytes(4)? != [1, 0, 0, 0] {
+    bail!("Unsupported WebAssembly binary version");
+  }
+
+  let mut interface = WasmModuleInterface::default();
+  while !reader.is_empty() {
+    let id = reader.read_u8()?;
+    let size = reader.read_u32()? as usize;
+    let mut section = Reader { bytes: reader.read_bytes(size)?, pos: 0 };
+    match id {
+      IMPORT_SECTION_ID => {
+        for _ in 0..section.read_u32()? {
+          let module = section.read_name()?;
+          let name = section.read_name()?;
+          let kind = section.read_external_kind()?;
+          if kind == WasmExternalKind::Global {
+            interface.globals.push(section.read_global_type()?);
+          } else {
+            section.skip_import_desc(kind)?;
+          }
+          interface.imports.push(WasmImport { module, name, kind });
+        }
+      }
+      GLOBAL_SECTION_ID => {
+        let count = section.read_u32()?;
+        for parsed in 0..count {
+          interface.globals.push(section.read_global_type()?);
+          if !section.skip_const_expr()? {
+            // The end of an unknown instruction can't be found, so the remaining globals are
+            // conservatively assumed to be mutable.
+            interface.globals.extend((parsed + 1..count).map(|_| true));
+            break;
+          }
+        }
+      }
+      EXPORT_SECTION_ID => {
+        for _ in 0..section.read_u32()? {
+          let name = section.read_name()?;
+          let kind = section.read_external_kind()?;
+          let index = section.read_u32()?;
+          interface.exports.push(WasmExport { name, kind, index });
+        }
+      }
+      _ => {}
+    }
+  }
+  Ok(interface)
+}
+
+struct Reader<'a> {
+  bytes: &'a [u8],
+  pos: usize,
+}
+
+impl<'a> Reader<'a> {
+  fn is_empty(&self) -> bool {
+    self.pos >= self.bytes.len()
+  }
+
+  fn read_u8(&mut self) -> anyhow::Result<u8> {
+    let byte = *self.bytes.get(self.pos).context("Unexpected end of WebAssembly binary")?;
+    self.pos += 1;
+    Ok(byte)
+  }
+
+  fn read_bytes(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+    let end = self.pos.checked_add(len).filter(|end| *end <= self.bytes.len());
+    let end = end.context("Unexpected end of WebAssembly binary")?;
+    let bytes = &self.bytes[self.pos..end];
+    self.pos = end;
+    Ok(bytes)
+  }
+
+  /// Unsigned LEB128
+  fn read_u32(&mut self) -> anyhow::Result<u32> {
+    let mut result = 0u32;
+    for shift in (0..35).step_by(7) {
+      let byte = self.read_u8()?;
+      result |= u32::from(byte & 0x7f) << shift;
+      if byte & 0x80 == 0 {
+        return Ok(result);
+      }
+    }
+    bail!("Invalid LEB128 integer in WebAssembly binary")
+  }
+
+  fn read_name(&mut self) -> anyhow::Result<String> {
+    let len = self.read_u32()? as usize;
+    let bytes = self.read_bytes(len)?;
+    String::from_utf8(bytes.to_vec()).context("Invalid UTF-8 name in WebAssembly binary")
+  }
+
+  fn read_external_kind(&mut self) -> anyhow::Result<WasmExternalKind> {
+    Ok(match self.read_u8()? {
+      0 => WasmExternalKind::Func,
+      1 => WasmExternalKind::Table,
+      2 => WasmExternalKind::Memory,
+      3 => WasmExternalKind::Global,
+      4 => WasmExternalKind::Tag,
+      kind => bail!("Unknown external kind {kind} in WebAssembly binary"),
+    })
+  }
+
+  /// Limits of 64-bit memories don't fit in a `u32`, so they are skipped as raw LEB128.
+  fn skip_limits(&mut self) -> anyhow::Result<()> {
+    let flags = self.read_u8()?;
+    self.skip_leb128()?;
+    if flags & 1 != 0 {
+      self.skip_leb128()?;
+    }
+    Ok(())
+  }
+
+  fn skip_leb128(&mut self) -> anyhow::Result<()> {
+    while self.read_u8()? & 0x80 != 0 {}
+    Ok(())
+  }
+
+  fn skip_import_desc(&mut self, kind: WasmExternalKind) -> anyhow::Result<()> {
+    match kind {
+      WasmExternalKind::Func => {
+        self.read_u32()?;
+      }
+      WasmExternalKind::Table => {
+        self.skip_ref_type()?;
+        self.skip_limits()?;
+      }
+      WasmExternalKind::Memory => self.skip_limits()?,
+      WasmExternalKind::Global => {
+        self.read_global_type()?;
+      }
+      WasmExternalKind::Tag => {
+        self.read_u8()?;
+        self.read_u32()?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Returns whether the global is mutable.
+  fn read_global_type(&mut self) -> anyhow::Result<bool> {
+    self.skip_val_type()?;
+    Ok(self.read_u8()? == 1)
+  }
+
+  /// Skips the constant expression initializing a global. Returns `false` when it contains an
+  /// instruction this reader doesn't know, leaving the reader in the middle of the expression.
+  fn skip_const_expr(&mut self) -> anyhow::Result<bool> {
+    loop {
+      match self.read_u8()? {
+        // end
+        0x0b => return Ok(true),
+        // i32.const, i64.const
+        0x41 | 0x42 => self.skip_leb128()?,
+        // f32.const
+        0x43 => {
+          self.read_bytes(4)?;
+        }
+        // f64.const
+        0x44 => {
+          self.read_bytes(8)?;
+        }
+        // global.get, ref.func
+        0x23 | 0xd2 => {
+          self.read_u32()?;
+        }
+        // ref.null
+        0xd0 => self.skip_heap_type()?,
+        // i32.add, i32.sub, i32.mul, i64.add, i64.sub, i64.mul from the extended constant
+        // expressions proposal
+        0x6a | 0x6b | 0x6c | 0x7c | 0x7d | 0x7e => {}
+        // v128.const
+        0xfd if self.read_u32()? == 12 => {
+          self.read_bytes(16)?;
+        }
+        _ => return Ok(false),
+      }
+    }
+  }
+
+  fn skip_val_type(&mut self) -> anyhow::Result<()> {
+    match self.read_u8()? {
+      // (ref null ht) and (ref ht) from the GC proposal carry a heap type
+      0x63 | 0x64 => self.skip_heap_type(),
+      _ => Ok(()),
+    }
+  }
+
+  fn skip_ref_type(&mut self) -> anyhow::Result<()> {
+    self.skip_val_type()
+  }
+
+  /// Heap types are signed LEB128, abstract ones fit in a single byte.
+  fn skip_heap_type(&mut self) -> anyhow::Result<()> {
+    self.skip_leb128()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const HEADER: &[u8] = b"\0asm\x01\0\0\0";
+
+  fn name(name: &str) -> Vec<u8> {
+    let mut bytes = vec![u8::try_from(name.len()).unwrap()];
+    bytes.extend_from_slice(name.as_bytes());
+    bytes
+  }
+
+  fn section(id: u8, content: &[u8]) -> Vec<u8> {
+    assert!(content.len() < 0x80, "sizes in tests fit in a single LEB128 byte");
+    let mut bytes = vec![id, u8::try_from(content.len()).unwrap()];
+    bytes.extend_from_slice(content);
+    bytes
+  }
+
+  fn module(sections: &[Vec<u8>]) -> Vec<u8> {
+    let mut bytes = HEADER.to_vec();
+    sections.iter().for_each(|section| bytes.extend_from_slice(section));
+    bytes
+  }
+
+  fn read_u32(bytes: &[u8]) -> anyhow::Result<u32> {
+    Reader { bytes, pos: 0 }.read_u32()
+  }
+
+  #[test]
+  fn reads_unsigned_leb128() {
+    assert_eq!(read_u32(&[0x00]).unwrap(), 0);
+    assert_eq!(read_u32(&[0x7f]).unwrap(), 127);
+    assert_eq!(read_u32(&[0x80, 0x01]).unwrap(), 128);
+    assert_eq!(read_u32(&[0xe5, 0x8e, 0x26]).unwrap(), 624_485);
+    assert_eq!(read_u32(&[0xff, 0xff, 0xff, 0xff, 0x0f]).unwrap(), u32::MAX);
+    // Padded encodings are valid as long as they fit in five bytes.
+    assert_eq!(read_u32(&[0x81, 0x80, 0x80, 0x00]).unwrap(), 1);
+
+    let error = |bytes: &[u8]| read_u32(bytes).unwrap_err().to_string();
+    assert!(error(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x00]).contains("Invalid LEB128"));
+    assert!(error(&[0x80, 0x80]).contains("Unexpected end"));
+    assert!(error(&[]).contains("Unexpected end"));
+  }
+
+  #[test]
+  fn parses_imports_and_exports() {
+    let mut imports = vec![4];
+    // (import "env" "log" (func (type 0)))
+    imports.extend([name("env"), name("log"), vec![0x00, 0x00]].concat());
+    // (import "env" "mem" (memory 1 128)), the maximum takes two bytes
+    imports.extend([name("env"), name("mem"), vec![0x02, 0x01, 0x01, 0x80, 0x01]].concat());
+    // (import "env" "g" (global (mut i32)))
+    imports.extend([name("env"), name("g"), vec![0x03, 0x7f, 0x01]].concat());
+    // (import "wasi" "t" (table 1 (ref null func)))
+    imports.extend([name("wasi"), name("t"), vec![0x01, 0x63, 0x70, 0x00, 0x01]].concat());
+
+    let globals = [
+      vec![4],
+      // (global i32 (i32.const 11)), the immediate looks like `end`
+      vec![0x7f, 0x00, 0x41, 0x0b, 0x0b],
+      // (global (mut i64) (i64.const 1))
+      vec![0x7e, 0x01, 0x42, 0x01, 0x0b],
+      // (global i32 ...) with an initializer using an unknown instruction
+      vec![0x7f, 0x00, 0xfb, 0x00, 0x0b],
+      // Never read, assumed to be mutable.
+      vec![0x7f, 0x00, 0x41, 0x00, 0x0b],
+    ]
+    .concat();
+
+    let mut exports = vec![4];
+    exports.extend([name("add"), vec![0x00, 0x01]].concat());
+    exports.extend([name("memory"), vec![0x02, 0x00]].concat());
+    exports.extend([name("answer"), vec![0x03, 0x01]].concat());
+    exports.extend([name("counter"), vec![0x03, 0x02]].concat());
+
+    let bytes = module(&[
+      section(0, &[name("name"), vec![0x01, 0x02]].concat()),
+      section(1, &[0x01, 0x60, 0x00, 0x00]),
+      section(IMPORT_SECTION_ID, &imports),
+      section(GLOBAL_SECTION_ID, &globals),
+      section(EXPORT_SECTION_ID, &exports),
+    ]);
+    let interface = parse_wasm_module_interface(&bytes).unwrap();
+
+    let imports = interface
+      .imports
+      .iter()
+      .map(|import| (import.module.as_str(), import.name.as_str(), import.kind))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      imports,
+      [
+        ("env", "log", WasmExternalKind::Func),
+        ("env", "mem", WasmExternalKind::Memory),
+        ("env", "g", WasmExternalKind::Global),
+        ("wasi", "t", WasmExternalKind::Table)
+      ]
+    );
+    assert_eq!(interface.globals, [true, false, true, false, true]);
+    let exports = interface
+      .exports
+      .iter()
+      .map(|export| (export.name.as_str(), export.kind, interface.is_mutable_global(export)))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      exports,
+      [
+        ("add", WasmExternalKind::Func, false),
+        ("memory", WasmExternalKind::Memory, false),
+        ("answer", WasmExternalKind::Global, false),
+        ("counter", WasmExternalKind::Global, true)
+      ]
+    );
+  }
+
+  #[test]
+  fn parses_module_without_sections() {
+    let interface = parse_wasm_module_interface(HEADER).unwrap();
+    assert!(interface.imports.is_empty());
+    assert!(interface.exports.is_empty());
+  }
+
+  #[test]
+  fn rejects_malformed_binaries() {
+    let error = |bytes: &[u8]| parse_wasm_module_interface(bytes).unwrap_err().to_string();
+
+    assert!(error(b"\0elf\x01\0\0\0").contains("Invalid magic number"));
+    assert!(error(b"\0asm\x02\0\0\0").contains("Unsupported WebAssembly binary version"));
+    assert!(error(b"\0asm").contains("Unexpected end"));
+    // The section claims more bytes than are left.
+    assert!(error(&module(&[vec![EXPORT_SECTION_ID, 0x05, 0x01]])).contains("Unexpected end"));
+    // An export entry of kind 9.
+    let exports = [vec![1], name("f"), vec![0x09, 0x00]].concat();
+    assert!(
+      error(&module(&[section(EXPORT_SECTION_ID, &exports)])).contains("Unknown external kind")
+    );
+    // A name that isn't valid UTF-8.
+    let exports = [0x01, 0x01, 0xff, 0x00, 0x00];
+    assert!(error(&module(&[section(EXPORT_SECTION_ID, &exports)])).contains("Invalid UTF-8"));
+  }
+}
@@ -0,0 +1,257 @@
+use oxc_sourcemap::{ConcatSourceMapBuilder, JSONSourceMap, Token};
+
+use crate::SourceMap;
+
+/// Where a section starts in the generated code, as a zero-based line and column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SectionOffset {
+  pub line: u32,
+  pub column: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexSourceMapSection {
+  pub offset: SectionOffset,
+  /// Mappings of the generated code from `offset` up to the next section, relative to `offset`.
+  pub map: SourceMap,
+}
+
+/// An [index map](https://tc39.es/ecma426/#sec-index-source-map), made of regular sourcemaps for
+/// consecutive sections of the generated code.
+///
+/// Moving a section only changes its offset, so sourcemaps of joined sources don't need to be
+/// merged into a single map. Use [`IndexSourceMap::flatten`] for consumers that don't support
+/// sections.
+#[derive(Debug, Clone, Default)]
+pub struct IndexSourceMap {
+  file: Option<String>,
+  debug_id: Option<String>,
+  sections: Vec<IndexSourceMapSection>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JSONIndexSourceMap {
+  version: u32,
+  file: Option<String>,
+  debug_id: Option<String>,
+  sections: Vec<JSONSection>,
+}
+
+#[derive(serde::Deserialize)]
+struct JSONSection {
+  offset: JSONSectionOffset,
+  map: JSONSourceMap,
+}
+
+#[derive(serde::Deserialize)]
+struct JSONSectionOffset {
+  line: u32,
+  column: u32,
+}
+
+impl IndexSourceMap {
+  /// Sections are expected to be sorted by offset and not to overlap.
+  pub fn new(sections: Vec<IndexSourceMapSection>) -> Self {
+    debug_assert!(sections.is_sorted_by_key(|section| section.offset));
+    Self { file: None, debug_id: None, sections }
+  }
+
+  /// Parses an index map. Nested index maps aren't allowed by the spec and are rejected as
+  /// regular sourcemaps without `mappings`.
+  ///
+  /// # Errors
+  ///
+  /// The JSON is malformed or a section's map can't be decoded.
+  pub fn from_json_string(value: &str) -> Result<Self, oxc_sourcemap::Error> {
+    let json: JSONIndexSourceMap = serde_json::from_str(value)?;
+    if json.version != 3 {
+      return Err(oxc_sourcemap::Error::BadJson(<serde_json::Error as serde::de::Error>::custom(
+        format!("unsupported source map version: {}", json.version),
+      )));
+    }
+    let sections = json
+      .sections
+      .into_iter()
+      .map(|section| {
+        Ok(IndexSourceMapSection {
+          offset: SectionOffset { line: section.offset.line, column: section.offset.column },
+          map: SourceMap::from_json(section.map)?,
+        })
+      })
+      .collect::<Result<Vec<_>, oxc_sourcemap::Error>>()?;
+    Ok(Self { file: json.file, debug_id: json.debug_id, sections })
+  }
+
+  pub fn get_file(&self) -> Option<&str> {
+    self.file.as_deref()
+  }
+
+  pub fn set_file(&mut self, file: &str) {
+    self.file = Some(file.to_string());
+  }
+
+  pub fn get_debug_id(&self) -> Option<&str> {
+    self.debug_id.as_deref()
+  }
+
+  pub fn set_debug_id(&mut self, debug_id: &str) {
+    self.debug_id = Some(debug_id.to_string());
+  }
+
+  pub fn sections(&self) -> &[IndexSourceMapSection] {
+    &self.sections
+  }
+
+  pub fn sections_mut(&mut self) -> &mut [IndexSourceMapSection] {
+    &mut self.sections
+  }
+
+  /// Moves every section down by `lines`, e.g. after prepending a banner to the generated code.
+  pub fn offset_lines(&mut self, lines: u32) {
+    for section in &mut self.sections {
+      section.offset.line += lines;
+    }
+  }
+
+  pub fn to_json_string(&self) -> String {
+    let mut json = String::from(r#"{"version":3"#);
+    if let Some(file) = &self.file {
+      json.push_str(r#","file":"#);
+      json.push_str(&serde_json::to_string(file).expect("a string should serialize"));
+    }
+    json.push_str(r#","sections":["#);
+    for (index, section) in self.sections.iter().enumerate() {
+      if index > 0 {
+        json.push(',');
+      }
+      json.push_str(r#"{"offset":{"line":"#);
+      json.push_str(&section.offset.line.to_string());
+      json.push_str(r#","column":"#);
+      json.push_str(&section.offset.column.to_string());
+      json.push_str(r#"},"map":"#);
+      json.push_str(&section.map.to_json_string());
+      json.push('}');
+    }
+    json.push(']');
+    if let Some(debug_id) = &self.debug_id {
+      json.push_str(r#","debugId":"#);
+      json.push_str(&serde_json::to_string(debug_id).expect("a string should serialize"));
+    }
+    json.push('}');
+    json
+  }
+
+  /// Merges the sections into a single sourcemap.
+  pub fn flatten(self) -> SourceMap {
+    let mut builder = ConcatSourceMapBuilder::with_capacity(
+      self.sections.iter().map(|section| section.map.get_names().len()).sum(),
+      self.sections.iter().map(|section| section.map.get_sources().len()).sum(),
+      self.sections.iter().map(|section| section.map.get_tokens().len()).sum(),
+      self.sections.len(),
+    );
+    for section in self.sections {
+      let map = if section.offset.column == 0 {
+        section.map
+      } else {
+        offset_first_line_columns(section.map, section.offset.column)
+      };
+      builder.add_sourcemap_owned(map, section.offset.line);
+    }
+    let mut map = builder.into_owned_sourcemap().into_inner();
+    if let Some(file) = &self.file {
+      map.set_file(file);
+    }
+    if let Some(debug_id) = &self.debug_id {
+      map.set_debug_id(debug_id);
+    }
+    map
+  }
+}
+
+/// Columns in a section's first line are relative to the section's offset column.
+fn offset_first_line_columns(map: SourceMap, columns: u32) -> SourceMap {
+  let mut parts = map.into_parts();
+  for token in parts.tokens.iter_mut().take_while(|token| token.get_dst_line() == 0) {
+    *token = Token::new(
+      0,
+      token.get_dst_col() + columns,
+      token.get_src_line(),
+      token.get_src_col(),
+      token.get_source_id(),
+      token.get_name_id(),
+    );
+  }
+  parts.token_chunks = None;
+  SourceMap::from_parts(parts)
+}
+
+#[cfg(test)]
+mod tests {
+  use oxc_sourcemap::SourceMapBuilder;
+
+  use super::*;
+
+  fn map(source: &str, tokens: &[(u32, u32, u32, u32)]) -> SourceMap {
+    let mut builder = SourceMapBuilder::default();
+    let source_id = builder.add_source_and_content(source, "");
+    for &(dst_line, dst_col, src_line, src_col) in tokens {
+      builder.add_token(dst_line, dst_col, src_line, src_col, Some(source_id), None);
+    }
+    builder.into_sourcemap().into_owned()
+  }
+
+  fn sections() -> IndexSourceMap {
+    IndexSourceMap::new(vec![
+      IndexSourceMapSection {
+        offset: SectionOffset { line: 0, column: 0 },
+        map: map("a.js", &[(0, 0, 0, 0), (1, 2, 1, 0)]),
+      },
+      IndexSourceMapSection {
+        offset: SectionOffset { line: 3, column: 4 },
+        map: map("b.js", &[(0, 0, 0, 0), (1, 0, 2, 0)]),
+      },
+    ])
+  }
+
+  #[test]
+  fn to_json_string() {
+    let mut map = sections();
+    map.set_file("chunk.js");
+    assert_eq!(
+      map.to_json_string(),
+      r#"{"version":3,"file":"chunk.js","sections":[{"offset":{"line":0,"column":0},"map":{"version":3,"names":[],"sources":["a.js"],"sourcesContent":[""],"mappings":"AAAA;EACA"}},{"offset":{"line":3,"column":4},"map":{"version":3,"names":[],"sources":["b.js"],"sourcesContent":[""],"mappings":"AAAA;AAEA"}}]}"#
+    );
+  }
+
+  #[test]
+  fn round_trip() {
+    let mut map = sections();
+    map.set_debug_id("00000000-0000-0000-0000-000000000000");
+    map.offset_lines(2);
+    let parsed = IndexSourceMap::from_json_string(&map.to_json_string()).unwrap();
+    assert_eq!(parsed.to_json_string(), map.to_json_string());
+    assert_eq!(parsed.sections()[1].offset, SectionOffset { line: 5, column: 4 });
+  }
+
+  #[test]
+  fn flatten() {
+    let mut map = sections();
+    map.set_file("chunk.js");
+    let flattened = map.flatten();
+    assert_eq!(flattened.get_file(), Some("chunk.js"));
+    assert_eq!(flattened.get_sources().collect::<Vec<_>>(), ["a.js", "b.js"]);
+    let tokens = flattened
+      .get_tokens()
+      .map(|token| {
+        (
+          token.get_dst_line(),
+          token.get_dst_col(),
+          token.get_src_line(),
+          token.get_source_id().unwrap(),
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(tokens, [(0, 0, 0, 0), (1, 2, 1, 0), (3, 4, 0, 1), (4, 0, 2, 1)]);
+  }
+}
@@ -1,3 +1,5 @@
+mod index_source_map;
+mod scopes;
 mod source;
 mod source_joiner;
 
@@ -5,6 +7,7 @@ use std::borrow::Cow;
 
 use oxc_sourcemap::Token;
 
+pub use index_source_map::{IndexSourceMap, IndexSourceMapSection, SectionOffset};
 pub use oxc_sourcemap::{JSONSourceMap, OwnedSourceMap, SourceMapBuilder, SourcemapVisualizer};
 pub use source_joiner::SourceJoiner;
 
@@ -12,6 +15,10 @@ pub use source_joiner::SourceJoiner;
 /// lifetime-parameterized `oxc_sourcemap::SourceMap` to its `'static` form.
 pub type SourceMap = oxc_sourcemap::SourceMap<'static>;
 
+pub use crate::scopes::{
+  OriginalScope, OriginalScopeKind, PositionLocator, ScopePosition, ScopeVariable, SourceScopes,
+  generate_scopes, to_json_string_with_scopes,
+};
 pub use crate::source::{Source, SourceMapSource};
 
 /// Strips the first `lines` destination lines from the sourcemap, decrementing all remaining
@@ -0,0 +1,642 @@
+//! Support for the [ECMA-426 scopes proposal](https://github.com/tc39/ecma426/blob/main/proposals/scopes.md).
+//!
+//! Original scopes are collected per module from the parsed AST, in the coordinates of the
+//! module's original source. The generated ranges and their bindings are derived afterwards from
+//! the final, collapsed sourcemap of a chunk and the generated code, so deconflicting renames and
+//! minifier mangling are reflected without any bookkeeping in the passes in between.
+
+use std::borrow::Cow;
+
+use oxc::syntax::identifier::{is_identifier_part, is_identifier_start};
+use rustc_hash::FxHashMap;
+
+use crate::SourceMap;
+
+/// A zero-based line and UTF-16 column, like the positions of sourcemap tokens.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ScopePosition {
+  pub line: u32,
+  pub column: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OriginalScopeKind {
+  Global,
+  Module,
+  Function,
+  Class,
+}
+
+impl OriginalScopeKind {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Self::Global => "global",
+      Self::Module => "module",
+      Self::Function => "function",
+      Self::Class => "class",
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct ScopeVariable {
+  pub name: String,
+  /// Position of the binding identifier that declares the variable. Its generated name is read
+  /// from the generated code at the position this maps to.
+  pub declaration: ScopePosition,
+}
+
+#[derive(Debug, Clone)]
+pub struct OriginalScope {
+  pub start: ScopePosition,
+  pub end: ScopePosition,
+  /// The function or class name, inferred from the surrounding declaration for anonymous ones.
+  pub name: Option<String>,
+  pub kind: OriginalScopeKind,
+  /// Whether the scope shows up as a frame in stack traces, i.e. it's a function.
+  pub is_stack_frame: bool,
+  pub variables: Vec<ScopeVariable>,
+  pub children: Vec<OriginalScope>,
+}
+
+/// The original scope tree of one source, together with the name of that source in the sourcemap.
+#[derive(Debug, Clone)]
+pub struct SourceScopes {
+  pub source: String,
+  pub scope: OriginalScope,
+}
+
+impl SourceScopes {
+  /// Traces a scope tree collected in the generated coordinates of `map` back to its original
+  /// source. Scopes and variables that can't be traced into the same source as the tree itself
+  /// are dropped.
+  pub fn remap(&self, map: &SourceMap) -> Option<SourceScopes> {
+    let source_id = map.get_tokens().find_map(|token| token.get_source_id())?;
+    let source = map.get_source(source_id)?.to_string();
+    let lookup_table = map.generate_lookup_table();
+    let trace = |position: ScopePosition| {
+      map
+        .lookup_token(&lookup_table, position.line, position.column)
+        .filter(|token| token.get_source_id() == Some(source_id))
+        .map(|token| ScopePosition { line: token.get_src_line(), column: token.get_src_col() })
+    };
+
+    // The root scope always spans the whole source, regardless of where its first token is.
+    let end = map
+      .get_tokens()
+      .filter(|token| token.get_source_id() == Some(source_id))
+      .map(|token| ScopePosition { line: token.get_src_line(), column: token.get_src_col() })
+      .max()?;
+    let root = &self.scope;
+    let scope = OriginalScope {
+      start: ScopePosition::default(),
+      end,
+      name: root.name.clone(),
+      kind: root.kind,
+      is_stack_frame: root.is_stack_frame,
+      variables: remap_variables(&root.variables, &trace),
+      children: remap_children(&root.children, ScopePosition::default(), end, &trace),
+    };
+    Some(SourceScopes { source, scope })
+  }
+}
+
+fn remap_variables(
+  variables: &[ScopeVariable],
+  trace: &impl Fn(ScopePosition) -> Option<ScopePosition>,
+) -> Vec<ScopeVariable> {
+  variables
+    .iter()
+    .filter_map(|variable| {
+      Some(ScopeVariable { name: variable.name.clone(), declaration: trace(variable.declaration)? })
+    })
+    .collect()
+}
+
+fn remap_children(
+  children: &[OriginalScope],
+  parent_start: ScopePosition,
+  parent_end: ScopePosition,
+  trace: &impl Fn(ScopePosition) -> Option<ScopePosition>,
+) -> Vec<OriginalScope> {
+  let mut remapped: Vec<OriginalScope> = Vec::with_capacity(children.len());
+  for child in children {
+    let (Some(start), Some(end)) = (trace(child.start), trace(child.end)) else {
+      continue;
+    };
+    // Keep the tree well-formed: children stay inside their parent and don't overlap each other.
+    let previous_end = remapped.last().map_or(parent_start, |previous| previous.end);
+    if start < previous_end || end < start || end > parent_end {
+      continue;
+    }
+    remapped.push(OriginalScope {
+      start,
+      end,
+      name: child.name.clone(),
+      kind: child.kind,
+      is_stack_frame: child.is_stack_frame,
+      variables: remap_variables(&child.variables, trace),
+      children: remap_children(&child.children, start, end, trace),
+    });
+  }
+  remapped
+}
+
+/// Converts between byte offsets in a text and the positions used by sourcemaps.
+pub struct PositionLocator<'text> {
+  text: &'text str,
+  line_starts: Vec<usize>,
+}
+
+impl<'text> PositionLocator<'text> {
+  pub fn new(text: &'text str) -> Self {
+    let line_starts = std::iter::once(0)
+      .chain(memchr::memchr_iter(b'\n', text.as_bytes()).map(|index| index + 1))
+      .collect();
+    Self { text, line_starts }
+  }
+
+  pub fn locate(&self, offset: usize) -> ScopePosition {
+    let offset = offset.min(self.text.len());
+    let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+    let line_prefix = &self.text[self.line_starts[line]..offset];
+    let column =
+      if line_prefix.is_ascii() { line_prefix.len() } else { line_prefix.encode_utf16().count() };
+    #[expect(clippy::cast_possible_truncation)]
+    ScopePosition { line: line as u32, column: column as u32 }
+  }
+
+  pub fn offset(&self, position: ScopePosition) -> Option<usize> {
+    let line = position.line as usize;
+    let line_start = *self.line_starts.get(line)?;
+    let line_end = self.line_starts.get(line + 1).map_or(self.text.len(), |next| next - 1);
+    let line_text = &self.text[line_start..line_end];
+    let mut column = 0;
+    for (index, char) in line_text.char_indices() {
+      if column >= position.column {
+        return (column == position.column).then_some(line_start + index);
+      }
+      #[expect(clippy::cast_possible_truncation)]
+      let char_len = char.len_utf16() as u32;
+      column += char_len;
+    }
+    (column == position.column).then_some(line_end)
+  }
+
+  /// The identifier starting at `position`, if any.
+  fn identifier_at(&self, position: ScopePosition) -> Option<&'text str> {
+    let start = self.offset(position)?;
+    let rest = &self.text[start..];
+    let mut chars = rest.char_indices();
+    if !chars.next().is_some_and(|(_, char)| is_identifier_start(char)) {
+      return None;
+    }
+    let end = chars.find(|(_, char)| !is_identifier_part(*char)).map_or(rest.len(), |(i, _)| i);
+    Some(&rest[..end])
+  }
+
+  fn end(&self) -> ScopePosition {
+    self.locate(self.text.len())
+  }
+}
+
+struct GeneratedRange<'a> {
+  start: ScopePosition,
+  end: ScopePosition,
+  definition: u32,
+  is_stack_frame: bool,
+  bindings: Vec<Option<&'a str>>,
+}
+
+/// Encodes the `scopes` field of `map`, where `original_scopes[i]` is the scope tree of the
+/// source at index `i`. The generated ranges and bindings are derived from the tokens of `map`
+/// and the generated `code`. Names used by the encoding are appended to the `names` of `map`.
+///
+/// Returns `None` if none of the sources has a scope tree.
+pub fn generate_scopes(
+  map: &mut SourceMap,
+  code: &str,
+  original_scopes: &[Option<&OriginalScope>],
+) -> Option<String> {
+  if original_scopes.iter().all(Option::is_none) {
+    return None;
+  }
+
+  let locator = PositionLocator::new(code);
+  let generated_positions = map
+    .get_tokens()
+    .map(|token| ScopePosition { line: token.get_dst_line(), column: token.get_dst_col() })
+    .collect::<Vec<_>>();
+  let mut tokens_by_source = vec![vec![]; original_scopes.len()];
+  for token in map.get_tokens() {
+    if let Some(tokens) =
+      token.get_source_id().and_then(|source_id| tokens_by_source.get_mut(source_id as usize))
+    {
+      tokens.push((
+        ScopePosition { line: token.get_src_line(), column: token.get_src_col() },
+        ScopePosition { line: token.get_dst_line(), column: token.get_dst_col() },
+      ));
+    }
+  }
+
+  let mut ranges = vec![];
+  let mut definition = 0;
+  for (tokens, scope) in tokens_by_source.iter_mut().zip(original_scopes) {
+    let Some(scope) = scope else { continue };
+    tokens.sort_unstable();
+    collect_generated_ranges(
+      scope,
+      tokens,
+      &generated_positions,
+      &locator,
+      &mut definition,
+      &mut ranges,
+    );
+  }
+  ranges.sort_by_key(|range| (range.start, std::cmp::Reverse(range.end), range.definition));
+
+  let mut encoder = ScopesEncoder::new(map);
+  for scope in original_scopes {
+    match scope {
+      Some(scope) => {
+        encoder.state.original_line = 0;
+        encoder.encode_original_scope(scope);
+      }
+      None => encoder.start_item(),
+    }
+  }
+
+  let mut open_ranges: Vec<&GeneratedRange> = vec![];
+  for range in &ranges {
+    while open_ranges.last().is_some_and(|open| open.end <= range.start) {
+      let open = open_ranges.pop().expect("checked above");
+      encoder.encode_generated_range_end(open.end);
+    }
+    // Drop ranges that cross their parent instead of nesting in it.
+    if open_ranges.last().is_some_and(|open| range.end > open.end) {
+      continue;
+    }
+    encoder.encode_generated_range_start(range);
+    open_ranges.push(range);
+  }
+  while let Some(open) = open_ranges.pop() {
+    encoder.encode_generated_range_end(open.end);
+  }
+
+  Some(encoder.finish(map))
+}
+
+fn collect_generated_ranges<'a>(
+  scope: &OriginalScope,
+  tokens: &[(ScopePosition, ScopePosition)],
+  generated_positions: &[ScopePosition],
+  locator: &PositionLocator<'a>,
+  definition: &mut u32,
+  ranges: &mut Vec<GeneratedRange<'a>>,
+) {
+  let scope_definition = *definition;
+  *definition += 1;
+
+  let scope_tokens = {
+    let from = tokens.partition_point(|(original, _)| *original < scope.start);
+    let to = tokens.partition_point(|(original, _)| *original < scope.end);
+    &tokens[from..to.max(from)]
+  };
+  let start = scope_tokens.iter().map(|(_, generated)| *generated).min();
+  let last = scope_tokens.iter().map(|(_, generated)| *generated).max();
+  if let (Some(start), Some(last)) = (start, last) {
+    // The range ends where the next mapped segment after the scope's last one starts.
+    let next = generated_positions.partition_point(|position| *position <= last);
+    let end = generated_positions.get(next).copied().unwrap_or_else(|| locator.end());
+    let bindings = scope
+      .variables
+      .iter()
+      .map(|variable| {
+        let from = tokens.partition_point(|(original, _)| *original < variable.declaration);
+        tokens[from..]
+          .iter()
+          .take_while(|(original, _)| *original == variable.declaration)
+          .find(|(_, generated)| (start..end).contains(generated))
+          .and_then(|(_, generated)| locator.identifier_at(*generated))
+      })
+      .collect();
+    ranges.push(GeneratedRange {
+      start,
+      end,
+      definition: scope_definition,
+      is_stack_frame: scope.is_stack_frame,
+      bindings,
+    });
+  }
+
+  for child in &scope.children {
+    collect_generated_ranges(child, tokens, generated_positions, locator, definition, ranges);
+  }
+}
+
+mod tag {
+  pub const ORIGINAL_SCOPE_START: u64 = 0x1;
+  pub const ORIGINAL_SCOPE_END: u64 = 0x2;
+  pub const ORIGINAL_SCOPE_VARIABLES: u64 = 0x3;
+  pub const GENERATED_RANGE_START: u64 = 0x4;
+  pub const GENERATED_RANGE_END: u64 = 0x5;
+  pub const GENERATED_RANGE_BINDINGS: u64 = 0x6;
+}
+
+mod original_scope_flags {
+  pub const HAS_NAME: u64 = 0x1;
+  pub const HAS_KIND: u64 = 0x2;
+  pub const IS_STACK_FRAME: u64 = 0x4;
+}
+
+mod generated_range_flags {
+  pub const HAS_LINE: u64 = 0x1;
+  pub const HAS_DEFINITION: u64 = 0x2;
+  pub const IS_STACK_FRAME: u64 = 0x4;
+}
+
+/// The values the relative fields of the encoding are relative to.
+#[derive(Default)]
+struct EncoderState {
+  original_line: u32,
+  name: i64,
+  kind: i64,
+  variable: i64,
+  generated_line: u32,
+  generated_column: u32,
+  definition: i64,
+}
+
+struct ScopesEncoder {
+  output: String,
+  has_items: bool,
+  state: EncoderState,
+  name_indexes: FxHashMap<String, u32>,
+  names_len: u32,
+  new_names: Vec<String>,
+}
+
+impl ScopesEncoder {
+  fn new(map: &SourceMap) -> Self {
+    let mut name_indexes = FxHashMap::default();
+    for (index, name) in map.get_names().enumerate() {
+      #[expect(clippy::cast_possible_truncation)]
+      name_indexes.entry(name.to_string()).or_insert(index as u32);
+    }
+    Self {
+      output: String::new(),
+      has_items: false,
+      state: EncoderState::default(),
+      name_indexes,
+      #[expect(clippy::cast_possible_truncation)]
+      names_len: map.get_names().len() as u32,
+      new_names: vec![],
+    }
+  }
+
+  fn name_index(&mut self, name: &str) -> u32 {
+    if let Some(index) = self.name_indexes.get(name) {
+      return *index;
+    }
+    let index = self.names_len;
+    self.names_len += 1;
+    self.name_indexes.insert(name.to_string(), index);
+    self.new_names.push(name.to_string());
+    index
+  }
+
+  fn start_item(&mut self) {
+    if self.has_items {
+      self.output.push(',');
+    }
+    self.has_items = true;
+  }
+
+  fn encode_original_scope(&mut self, scope: &OriginalScope) {
+    let mut flags = original_scope_flags::HAS_KIND;
+    if scope.name.is_some() {
+      flags |= original_scope_flags::HAS_NAME;
+    }
+    if scope.is_stack_frame {
+      flags |= original_scope_flags::IS_STACK_FRAME;
+    }
+    self.start_item();
+    encode_unsigned(&mut self.output, tag::ORIGINAL_SCOPE_START);
+    encode_unsigned(&mut self.output, flags);
+    encode_unsigned(&mut self.output, u64::from(scope.start.line - self.state.original_line));
+    encode_unsigned(&mut self.output, u64::from(scope.start.column));
+    self.state.original_line = scope.start.line;
+    if let Some(name) = &scope.name {
+      let index = i64::from(self.name_index(name));
+      encode_signed(&mut self.output, index - self.state.name);
+      self.state.name = index;
+    }
+    let kind = i64::from(self.name_index(scope.kind.as_str()));
+    encode_signed(&mut self.output, kind - self.state.kind);
+    self.state.kind = kind;
+
+    if !scope.variables.is_empty() {
+      self.start_item();
+      encode_unsigned(&mut self.output, tag::ORIGINAL_SCOPE_VARIABLES);
+      for variable in &scope.variables {
+        let index = i64::from(self.name_index(&variable.name));
+        encode_signed(&mut self.output, index - self.state.variable);
+        self.state.variable = index;
+      }
+    }
+
+    for child in &scope.children {
+      self.encode_original_scope(child);
+    }
+
+    self.start_item();
+    encode_unsigned(&mut self.output, tag::ORIGINAL_SCOPE_END);
+    encode_unsigned(&mut self.output, u64::from(scope.end.line - self.state.original_line));
+    encode_unsigned(&mut self.output, u64::from(scope.end.column));
+    self.state.original_line = scope.end.line;
+  }
+
+  fn encode_generated_range_start(&mut self, range: &GeneratedRange) {
+    let mut flags = generated_range_flags::HAS_DEFINITION;
+    let has_line = range.start.line != self.state.generated_line;
+    if has_line {
+      flags |= generated_range_flags::HAS_LINE;
+    }
+    if range.is_stack_frame {
+      flags |= generated_range_flags::IS_STACK_FRAME;
+    }
+    self.start_item();
+    encode_unsigned(&mut self.output, tag::GENERATED_RANGE_START);
+    encode_unsigned(&mut self.output, flags);
+    self.encode_generated_position(range.start);
+    let definition = i64::from(range.definition);
+    encode_signed(&mut self.output, definition - self.state.definition);
+    self.state.definition = definition;
+
+    if !range.bindings.is_empty() {
+      self.start_item();
+      encode_unsigned(&mut self.output, tag::GENERATED_RANGE_BINDINGS);
+      for binding in &range.bindings {
+        // `0` marks a variable that's unavailable in this range.
+        let value = binding.map_or(0, |name| u64::from(self.name_index(name)) + 1);
+        encode_unsigned(&mut self.output, value);
+      }
+    }
+  }
+
+  fn encode_generated_range_end(&mut self, end: ScopePosition) {
+    self.start_item();
+    encode_unsigned(&mut self.output, tag::GENERATED_RANGE_END);
+    self.encode_generated_position(end);
+  }
+
+  /// The line is only encoded when it changes; the column is relative to the previous one
+  /// unless the line was encoded.
+  fn encode_generated_position(&mut self, position: ScopePosition) {
+    if position.line == self.state.generated_line {
+      encode_unsigned(&mut self.output, u64::from(position.column - self.state.generated_column));
+    } else {
+      encode_unsigned(&mut self.output, u64::from(position.line - self.state.generated_line));
+      encode_unsigned(&mut self.output, u64::from(position.column));
+    }
+    self.state.generated_line = position.line;
+    self.state.generated_column = position.column;
+  }
+
+  fn finish(self, map: &mut SourceMap) -> String {
+    if !self.new_names.is_empty() {
+      let mut parts = std::mem::take(map).into_parts();
+      parts.names.extend(self.new_names.into_iter().map(Cow::Owned));
+      *map = SourceMap::from_parts(parts);
+    }
+    self.output
+  }
+}
+
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn encode_unsigned(output: &mut String, mut value: u64) {
+  loop {
+    let mut digit = value & 0b1_1111;
+    value >>= 5;
+    if value > 0 {
+      digit |= 0b10_0000;
+    }
+    #[expect(clippy::cast_possible_truncation)]
+    output.push(BASE64_CHARS[digit as usize] as char);
+    if value == 0 {
+      break;
+    }
+  }
+}
+
+fn encode_signed(output: &mut String, value: i64) {
+  let value = if value < 0 { (value.unsigned_abs() << 1) | 1 } else { value.unsigned_abs() << 1 };
+  encode_unsigned(output, value);
+}
+
+/// Serializes `map` like [`SourceMap::to_json_string`], adding the encoded `scopes` field.
+pub fn to_json_string_with_scopes(map: &SourceMap, scopes: &str) -> String {
+  let mut json = map.to_json_string();
+  debug_assert!(json.ends_with('}'));
+  json.pop();
+  // The encoding only consists of base64 characters and commas, nothing needs escaping.
+  json.push_str(",\"scopes\":\"");
+  json.push_str(scopes);
+  json.push_str("\"}");
+  json
+}
+
+#[cfg(test)]
+mod tests {
+  use oxc_sourcemap::SourceMapBuilder;
+
+  use super::*;
+
+  fn position(line: u32, column: u32) -> ScopePosition {
+    ScopePosition { line, column }
+  }
+
+  #[test]
+  fn vlq() {
+    let mut output = String::new();
+    for value in [0, 1, 15, 16, 31, 32, 1000] {
+      encode_unsigned(&mut output, value);
+      output.push(',');
+    }
+    for value in [0, 1, -1, 16, -16] {
+      encode_signed(&mut output, value);
+      output.push(',');
+    }
+    assert_eq!(output, "A,B,P,Q,f,gB,of,A,C,D,gB,hB,");
+  }
+
+  #[test]
+  fn locator_uses_utf16_columns() {
+    let text = "ß💣 a\nb";
+    let locator = PositionLocator::new(text);
+    assert_eq!(locator.locate(text.find('a').unwrap()), position(0, 4));
+    assert_eq!(locator.locate(text.find('b').unwrap()), position(1, 0));
+    assert_eq!(locator.offset(position(0, 4)), text.find('a'));
+    assert_eq!(locator.offset(position(0, 2)), None);
+    assert_eq!(locator.identifier_at(position(1, 0)), Some("b"));
+  }
+
+  #[test]
+  fn renamed_function_and_variable() {
+    // Original: `function foo(bar) {\n  return bar;\n}\n`
+    // Generated: `function a(b){return b}`
+    let mut builder = SourceMapBuilder::default();
+    let source = builder.add_source_and_content("foo.js", "");
+    for (dst_col, src_line, src_col) in [(0, 0, 0), (9, 0, 9), (11, 0, 13), (14, 1, 2), (21, 1, 9)]
+    {
+      builder.add_token(0, dst_col, src_line, src_col, Some(source), None);
+    }
+    let mut map = builder.into_sourcemap().into_owned();
+
+    let scope = OriginalScope {
+      start: position(0, 0),
+      end: position(3, 0),
+      name: None,
+      kind: OriginalScopeKind::Module,
+      is_stack_frame: false,
+      variables: vec![ScopeVariable { name: "foo".into(), declaration: position(0, 9) }],
+      children: vec![OriginalScope {
+        start: position(0, 0),
+        end: position(2, 1),
+        name: Some("foo".into()),
+        kind: OriginalScopeKind::Function,
+        is_stack_frame: true,
+        variables: vec![ScopeVariable { name: "bar".into(), declaration: position(0, 13) }],
+        children: vec![],
+      }],
+    };
+
+    let scopes = generate_scopes(&mut map, "function a(b){return b}", &[Some(&scope)]).unwrap();
+    assert_eq!(map.get_names().collect::<Vec<_>>(), ["module", "foo", "function", "bar", "a", "b"]);
+    assert_eq!(scopes, "BCAAA,DC,BHAACE,DE,CCB,CBA,ECAA,GF,EGAC,GG,FX,FA");
+  }
+
+  #[test]
+  fn sources_without_scopes_are_empty_items() {
+    let mut builder = SourceMapBuilder::default();
+    let first = builder.add_source_and_content("a.js", "");
+    let second = builder.add_source_and_content("b.js", "");
+    builder.add_token(0, 0, 0, 0, Some(first), None);
+    builder.add_token(1, 0, 0, 0, Some(second), None);
+    let mut map = builder.into_sourcemap().into_owned();
+    let scope = OriginalScope {
+      start: position(0, 0),
+      end: position(1, 0),
+      name: None,
+      kind: OriginalScopeKind::Module,
+      is_stack_frame: false,
+      variables: vec![],
+      children: vec![],
+    };
+
+    let scopes = generate_scopes(&mut map, "a;\nb;", &[None, Some(&scope)]).unwrap();
+    assert_eq!(scopes, ",BCAAA,CBA,EDBAA,FC");
+    assert!(generate_scopes(&mut map, "a;\nb;", &[None, None]).is_none());
+  }
+}
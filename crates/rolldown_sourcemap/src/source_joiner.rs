@@ -1,6 +1,7 @@
 use oxc_sourcemap::ConcatSourceMapBuilder;
 
 use crate::SourceMap;
+use crate::index_source_map::{IndexSourceMap, IndexSourceMapSection, SectionOffset};
 use crate::source::Source;
 
 #[derive(Default)]
@@ -90,6 +91,40 @@ impl<'source> SourceJoiner<'source> {
     (ret_source, sourcemap_builder.map(|builder| builder.into_owned_sourcemap().into_inner()))
   }
 
+  /// Like [`SourceJoiner::join`], but keeps the sourcemap of each source as a section of an index
+  /// map instead of merging them, so each map is only offset by the line its source starts at.
+  pub fn join_sections(&mut self) -> (String, Option<IndexSourceMap>) {
+    if !self.enable_sourcemap {
+      return (self.join().0, None);
+    }
+    let sources_len = self.prepend_source.len() + self.inner.len();
+    let size_hint_of_ret_source = self
+      .prepend_source
+      .iter()
+      .chain(self.inner.iter())
+      .map(|source| source.content().len())
+      .sum::<usize>()
+      + sources_len;
+    let mut ret_source = String::with_capacity(size_hint_of_ret_source);
+
+    let mut sections = Vec::with_capacity(self.token_chunks_len);
+    let mut line_offset = 0;
+    for (index, source) in self.prepend_source.iter_mut().chain(self.inner.iter_mut()).enumerate() {
+      ret_source.push_str(source.content());
+      if let Some(map) = source.take_sourcemap().or_else(|| source.sourcemap().cloned()) {
+        sections.push(IndexSourceMapSection {
+          offset: SectionOffset { line: line_offset, column: 0 },
+          map,
+        });
+      }
+      if index < sources_len - 1 {
+        ret_source.push('\n');
+        line_offset += source.lines_count() + 1; // +1 for the newline
+      }
+    }
+    (ret_source, Some(IndexSourceMap::new(sections)))
+  }
+
   fn accumulate_sourcemap_data_size(&mut self, hint: &SourceMap) {
     self.enable_sourcemap = true;
     self.names_len += hint.get_names().count();
@@ -195,3 +230,32 @@ fn test_concat_mixed_owned_and_borrowed_sourcemaps() {
   assert_eq!(map.get_token(1).map(|token| token.get_dst_line()), Some(1));
   assert_eq!(map.get_token(1).and_then(|token| token.get_source_id()), Some(1));
 }
+
+#[test]
+fn test_join_sections() {
+  use crate::{SourceJoiner, SourceMapSource};
+  use oxc_sourcemap::SourceMapBuilder;
+
+  fn map(filename: &str) -> SourceMap {
+    let mut builder = SourceMapBuilder::default();
+    let source_id = builder.add_source_and_content(filename, "");
+    builder.add_token(0, 0, 0, 0, Some(source_id), None);
+    builder.into_sourcemap().into_owned()
+  }
+
+  let mut joiner = SourceJoiner::default();
+  joiner.append_source("// banner\n// second line");
+  joiner.append_source(SourceMapSource::new("foo();".to_string(), map("foo.js")));
+  joiner.append_source(SourceMapSource::new("bar();\n".to_string(), map("bar.js")));
+
+  let (content, map) = joiner.join_sections();
+  let map = map.expect("sourcemaps should be kept as sections");
+
+  assert_eq!(content, "// banner\n// second line\nfoo();\nbar();\n");
+  let sections = map
+    .sections()
+    .iter()
+    .map(|section| (section.offset.line, section.map.get_sources().next().unwrap()))
+    .collect::<Vec<_>>();
+  assert_eq!(sections, [(2, "foo.js"), (3, "bar.js")]);
+}
@@ -2,7 +2,10 @@ use std::borrow::Cow;
 use std::path::Path;
 
 use rolldown::BundleOutput;
-use rolldown_common::Output;
+use rolldown_common::{
+  InclusionReason, InclusionReasonKind, InclusionSite, Metafile, Output, SideEffectKind,
+  TreeShakingProvenance,
+};
 use rolldown_error::{BuildDiagnostic, DiagnosticOptions};
 use rolldown_sourcemap::SourcemapVisualizer;
 use rolldown_testing_config::TestMeta;
@@ -53,6 +56,24 @@ impl BuildArtifactsSnapshot {
             {
               build_round_sections.push(sourcemap_section);
             }
+
+            // Render `# Metafile` (if `output.metafile` is enabled)
+            build_round_sections
+              .extend(Self::create_metafile_section(bundle_output.metafile.as_ref()));
+
+            // Render `# Integrity` (if `output.integrity` is set)
+            build_round_sections.extend(Self::create_integrity_section(&assets));
+
+            // Render `# Transitive Imports` (if `output.hoistTransitiveImports` is enabled)
+            build_round_sections.extend(Self::create_transitive_imports_section(&assets));
+
+            // Render `# Imported CSS` (if any chunk contains CSS modules)
+            build_round_sections.extend(Self::create_imported_css_section(&assets));
+
+            // Render `# Tree-shaking Provenance` (if `experimental.treeShakingProvenance` is enabled)
+            build_round_sections.extend(Self::create_tree_shaking_provenance_section(
+              bundle_output.tree_shaking_provenance.as_ref(),
+            ));
           }
 
           Err(errs) => {
@@ -201,6 +222,117 @@ impl BuildArtifactsSnapshot {
     Some(sourcemap_section)
   }
 
+  pub(crate) fn create_metafile_section(metafile: Option<&Metafile>) -> Option<SnapshotSection> {
+    let metafile = metafile?;
+    let mut metafile_section = SnapshotSection::with_title("Metafile");
+    metafile_section.add_content("```json\n");
+    metafile_section.add_content(
+      &serde_json::to_string_pretty(&metafile.to_json()).expect("Metafile should be valid JSON"),
+    );
+    metafile_section.add_content("\n```");
+    Some(metafile_section)
+  }
+
+  pub(crate) fn create_integrity_section(assets: &[Output]) -> Option<SnapshotSection> {
+    let mut lines = assets
+      .iter()
+      .filter_map(|asset| {
+        let integrity = match asset {
+          Output::Chunk(chunk) => chunk.integrity.as_ref(),
+          Output::Asset(asset) => asset.integrity.as_ref(),
+        }?;
+        Some(format!("- {}: {integrity}\n", asset.filename()))
+      })
+      .peekable();
+    lines.peek()?;
+    let mut section = SnapshotSection::with_title("Integrity");
+    for line in lines {
+      section.add_content(&line);
+    }
+    Some(section)
+  }
+
+  pub(crate) fn create_transitive_imports_section(assets: &[Output]) -> Option<SnapshotSection> {
+    let mut lines = assets
+      .iter()
+      .filter_map(|asset| {
+        let Output::Chunk(chunk) = asset else { return None };
+        if chunk.transitive_imports.is_empty() {
+          return None;
+        }
+        Some(format!("- {}: {}\n", chunk.filename, chunk.transitive_imports.join(", ")))
+      })
+      .peekable();
+    lines.peek()?;
+    let mut section = SnapshotSection::with_title("Transitive Imports");
+    for line in lines {
+      section.add_content(&line);
+    }
+    Some(section)
+  }
+
+  pub(crate) fn create_imported_css_section(assets: &[Output]) -> Option<SnapshotSection> {
+    let mut lines = assets
+      .iter()
+      .filter_map(|asset| {
+        let Output::Chunk(chunk) = asset else { return None };
+        if chunk.imported_css.is_empty() {
+          return None;
+        }
+        Some(format!("- {}: {}\n", chunk.filename, chunk.imported_css.join(", ")))
+      })
+      .peekable();
+    lines.peek()?;
+    let mut section = SnapshotSection::with_title("Imported CSS");
+    for line in lines {
+      section.add_content(&line);
+    }
+    Some(section)
+  }
+
+  pub(crate) fn create_tree_shaking_provenance_section(
+    provenance: Option<&TreeShakingProvenance>,
+  ) -> Option<SnapshotSection> {
+    fn render_reason(reason: &InclusionReason) -> String {
+      let mut rendered = reason.kind.as_str().to_string();
+      if let InclusionReasonKind::SideEffect(side_effect) = &reason.kind {
+        match side_effect {
+          SideEffectKind::GlobalAccess(name) => {
+            rendered = format!("{rendered}({}: {name})", side_effect.as_str());
+          }
+          _ => rendered = format!("{rendered}({})", side_effect.as_str()),
+        }
+      }
+      if let Some(symbol) = &reason.symbol {
+        rendered = format!("{rendered} `{symbol}`");
+      }
+      match &reason.by {
+        Some(InclusionSite::Module(module_id)) => format!("{rendered} <- {module_id}"),
+        Some(InclusionSite::Statement(module_id, index)) => {
+          format!("{rendered} <- {module_id}#{index}")
+        }
+        None => rendered,
+      }
+    }
+
+    let provenance = provenance?;
+    let mut section = SnapshotSection::with_title("Tree-shaking Provenance");
+    section.add_content("```text\n");
+    for (module_id, module) in &provenance.modules {
+      section.add_content(&format!("{module_id}: {}\n", render_reason(&module.reason)));
+      for stmt in &module.statements {
+        section.add_content(&format!(
+          "  #{} `{}`: {}\n",
+          stmt.index,
+          stmt.code,
+          render_reason(&stmt.reason)
+        ));
+      }
+    }
+    section.add_content("```");
+    Some(section)
+  }
+
   pub(crate) fn create_bundle_output_sections(
     test_meta: &TestMeta,
     bundle_output: BundleOutput,
@@ -229,6 +361,15 @@ impl BuildArtifactsSnapshot {
       sections.push(sourcemap_section);
     }
 
+    // Render `# Metafile` (if `output.metafile` is enabled)
+    sections.extend(Self::create_metafile_section(bundle_output.metafile.as_ref()));
+    sections.extend(Self::create_integrity_section(&assets));
+    sections.extend(Self::create_transitive_imports_section(&assets));
+    sections.extend(Self::create_imported_css_section(&assets));
+    sections.extend(Self::create_tree_shaking_provenance_section(
+      bundle_output.tree_shaking_provenance.as_ref(),
+    ));
+
     sections
   }
 }
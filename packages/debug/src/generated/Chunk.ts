@@ -50,4 +50,4 @@ advanced_chunk_group_id: number | null, is_user_defined_entry: boolean,
 /**
  * A entry could be both user-defined and async.
  */
-is_async_entry: boolean, entry_module: string | null, modules: Array<string>, reason: 'advanced-chunks' | 'preserve-modules' | 'entry' | 'common', imports: Array<ChunkImport>, };
+is_async_entry: boolean, entry_module: string | null, modules: Array<string>, reason: 'advanced-chunks' | 'preserve-modules' | 'entry' | 'common' | 'chunk-record', imports: Array<ChunkImport>, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IncludedStatement } from "./IncludedStatement";
+import type { InclusionReason } from "./InclusionReason";
+
+export type IncludedModule = { module_id: string, reason: InclusionReason, statements: Array<IncludedStatement>, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InclusionReason } from "./InclusionReason";
+
+export type IncludedStatement = { 
+/**
+ * `0` is the module's namespace object. `n` is the `n`-th top-level statement of the module.
+ */
+stmt_index: number, 
+/**
+ * The first line of the statement.
+ */
+code: string, reason: InclusionReason, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type InclusionReason = { 
+/**
+ * Refer to crates/rolldown_common/src/types/tree_shaking_provenance.rs
+ * - `entry`, `dynamic-entry`, `entry-export`, `runtime-helper`, `commonjs-bailout` and
+ * `preserved-reexport` are roots, which have no `by_module_id`.
+ * - `side-effectful-import`: `by_module_id` imports this module, which has side effects.
+ * - `used-symbol`: `symbol` is used by the `by_*` statement.
+ * - `side-effect`: the statement has side effects, described by `side_effect`.
+ */
+kind: 'entry' | 'dynamic-entry' | 'entry-export' | 'runtime-helper' | 'commonjs-bailout' | 'preserved-reexport' | 'side-effectful-import' | 'used-symbol' | 'side-effect' | 'body-demand' | 'property-write' | 'no-treeshake' | 'eval' | 'dev-mode-namespace', symbol: string | null, side_effect: 'property-write' | 'global-access' | 'call' | 'other' | null, 
+/**
+ * Name of the accessed global, for `global-access` side effects.
+ */
+global_name: string | null, 
+/**
+ * The module whose inclusion caused this one.
+ */
+by_module_id: string | null, 
+/**
+ * Set if it was a statement of `by_module_id`.
+ */
+by_stmt_index: number | null, };
@@ -14,5 +14,6 @@ import type { HookTransformCallStart } from "./HookTransformCallStart";
 import type { ModuleGraphReady } from "./ModuleGraphReady";
 import type { PackageGraphReady } from "./PackageGraphReady";
 import type { SessionMeta } from "./SessionMeta";
+import type { TreeShakingProvenanceReady } from "./TreeShakingProvenanceReady";
 
-export type Meta = HookTransformCallStart | HookTransformCallEnd | HookLoadCallStart | HookLoadCallEnd | BuildStart | BuildEnd | HookResolveIdCallStart | HookResolveIdCallEnd | ModuleGraphReady | SessionMeta | ChunkGraphReady | PackageGraphReady | HookRenderChunkStart | HookRenderChunkEnd | AssetsReady;
+export type Meta = HookTransformCallStart | HookTransformCallEnd | HookLoadCallStart | HookLoadCallEnd | BuildStart | BuildEnd | HookResolveIdCallStart | HookResolveIdCallEnd | ModuleGraphReady | SessionMeta | ChunkGraphReady | PackageGraphReady | HookRenderChunkStart | HookRenderChunkEnd | AssetsReady | TreeShakingProvenanceReady;
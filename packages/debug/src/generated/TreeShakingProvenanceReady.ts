@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IncludedModule } from "./IncludedModule";
+
+/**
+ * Emitted after tree shaking when `experimental.treeShakingProvenance` is enabled.
+ */
+export type TreeShakingProvenanceReady = { action: 'TreeShakingProvenanceReady', 
+/**
+ * Included modules, in the order they were included.
+ */
+modules: Array<IncludedModule>, };
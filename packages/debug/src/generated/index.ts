@@ -13,6 +13,9 @@ export * from './HookResolveIdCallEnd.js'
 export * from './HookResolveIdCallStart.js'
 export * from './HookTransformCallEnd.js'
 export * from './HookTransformCallStart.js'
+export * from './IncludedModule.js'
+export * from './IncludedStatement.js'
+export * from './InclusionReason.js'
 export * from './InputItem.js'
 export * from './Meta.js'
 export * from './Module.js'
@@ -21,4 +24,5 @@ export * from './ModuleImport.js'
 export * from './PackageGraphReady.js'
 export * from './PackageInfo.js'
 export * from './PluginItem.js'
-export * from './SessionMeta.js'
\ No newline at end of file
+export * from './SessionMeta.js'
+export * from './TreeShakingProvenanceReady.js'
\ No newline at end of file
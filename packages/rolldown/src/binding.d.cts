@@ -1747,11 +1747,13 @@ export declare class BindingNormalizedOptions {
   get name(): string | null
   get entryFilenames(): string | undefined
   get chunkFilenames(): string | undefined
+  get cssEntryFilenames(): string | undefined
+  get cssChunkFilenames(): string | undefined
   get sourcemapFilenames(): string | undefined
   get assetFilenames(): string | undefined
   get dir(): string | null
   get file(): string | null
-  get format(): 'es' | 'cjs' | 'iife' | 'umd'
+  get format(): 'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'system'
   get exports(): 'default' | 'named' | 'none' | 'auto'
   get esModule(): boolean | 'if-default-prop'
   get codeSplitting(): boolean
@@ -1769,10 +1771,12 @@ export declare class BindingNormalizedOptions {
   get globals(): Record<string, string> | undefined
   get hashCharacters(): 'base64' | 'base36' | 'hex'
   get sourcemapDebugIds(): boolean
+  get sourcemapScopes(): boolean
+  get sourcemapIndexMap(): boolean
   get sourcemapExcludeSources(): boolean
   get polyfillRequire(): boolean
   get minify(): false | 'dce-only' | MinifyOptions
-  get legalComments(): 'none' | 'inline'
+  get legalComments(): 'none' | 'inline' | 'eof' | 'linked' | 'external'
   get comments(): BindingCommentsOptions
   get preserveModules(): boolean
   get preserveModulesRoot(): string | undefined
@@ -1787,6 +1791,7 @@ export declare class BindingOutputAsset {
   getFileName(): string
   getOriginalFileName(): string | null
   getOriginalFileNames(): Array<string>
+  getIntegrity(): string | null
   getSource(): BindingAssetSource
   getName(): string | null
   getNames(): Array<string>
@@ -1803,10 +1808,13 @@ export declare class BindingOutputChunk {
   getModules(): BindingModules
   getImports(): Array<string>
   getDynamicImports(): Array<string>
+  getTransitiveImports(): Array<string>
+  getImportedCss(): Array<string>
   getCode(): string
   getMap(): string | null
   getSourcemapFileName(): string | null
   getPreliminaryFileName(): string
+  getIntegrity(): string | null
   getName(): string
 }
 
@@ -1933,6 +1941,11 @@ export interface AliasItem {
   replacements: Array<string | undefined | null>
 }
 
+export interface BindingAmdOptions {
+  id?: string
+  define?: string
+}
+
 export interface BindingAssetSource {
   inner: string | Uint8Array
 }
@@ -2019,6 +2032,9 @@ export interface BindingChecksOptions {
   largeBarrelModules?: boolean
   sourcemapBroken?: boolean
   namespaceConflict?: boolean
+  emptyGlobImport?: boolean
+  exceededMaxRequests?: boolean
+  couldNotWriteRecords?: boolean
 }
 
 export interface BindingChunkImportMap {
@@ -2304,6 +2320,10 @@ export interface BindingExperimentalOptions {
   nativeMagicString?: boolean
   chunkOptimization?: boolean | BindingChunkOptimizationOptions
   lazyBarrel?: boolean
+  precompress?: boolean | BindingPrecompressOptions
+  asyncModuleWrappers?: boolean
+  persistentCacheDir?: string
+  recordsPath?: string
 }
 
 export interface BindingFilterToken {
@@ -2379,7 +2399,7 @@ export interface BindingHookResolveFileUrlArgs {
   chunkId: string
   /** Filename of the emitted file, relative to the output directory. */
   fileName: string
-  format: 'es' | 'cjs' | 'iife' | 'umd'
+  format: 'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'system'
   /** Id of the module containing the `import.meta.ROLLDOWN_FILE_URL_*` reference. */
   moduleId: string
   referenceId: string
@@ -2405,6 +2425,8 @@ export interface BindingHookResolveIdExtraArgs {
    * - `hot-accept`: `import.meta.hot.accept('./lib.js', () => {})`
    */
   kind: 'import-statement' | 'dynamic-import' | 'require-call' | 'import-rule' | 'url-token' | 'new-url' | 'hot-accept'
+  /** The import attributes of the import, e.g. `{ type: 'json' }` for `with { type: 'json' }`. */
+  attributes: Record<string, string>
 }
 
 export interface BindingHookResolveIdOutput {
@@ -2502,10 +2524,13 @@ export interface BindingInputOptions {
   optimization?: BindingOptimization
   context?: string
   tsconfig?: boolean | string
+  worker?: BindingWorkerOptions
+  wasm?: BindingWasmOptions
 }
 
 export interface BindingIsolatedDeclarationPluginConfig {
   stripInternal?: boolean
+  bundle?: boolean
 }
 
 export interface BindingJsonSourcemap {
@@ -2539,6 +2564,14 @@ export interface BindingLazyChunkOutput {
   sourcemapFilename?: string
 }
 
+export interface BindingLoadHookExtraArgs {
+  /**
+   * The import attributes of the import that created the module, e.g. `{ type: 'text' }` for
+   * `with { type: 'text' }`. Empty if the import has none.
+   */
+  attributes: Record<string, string>
+}
+
 export interface BindingLog {
   message: string
   id?: string
@@ -2587,6 +2620,8 @@ export interface BindingManualCodeSplittingOptions {
   maxSize?: number
   minModuleSize?: number
   maxModuleSize?: number
+  maxInitialRequests?: number
+  maxAsyncRequests?: number
 }
 
 export interface BindingMatchGroup {
@@ -2634,6 +2669,7 @@ export interface BindingNativeMemoryStats {
 export interface BindingOptimization {
   inlineConst?: boolean | BindingInlineConstConfig
   pifeForModuleWrappers?: boolean
+  commonjsToEsm?: boolean
 }
 
 export interface BindingOutputOptions {
@@ -2641,7 +2677,10 @@ export interface BindingOutputOptions {
   assetFileNames?: string | ((chunk: BindingPreRenderedAsset) => string)
   entryFileNames?: string | ((chunk: PreRenderedChunk) => string)
   chunkFileNames?: string | ((chunk: PreRenderedChunk) => string)
+  cssEntryFileNames?: string | ((chunk: PreRenderedChunk) => string)
+  cssChunkFileNames?: string | ((chunk: PreRenderedChunk) => string)
   sanitizeFileName?: boolean | ((name: string) => string)
+  amd?: BindingAmdOptions
   banner?: string | ((chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>)
   postBanner?: string | ((chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>)
   footer?: string | ((chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>)
@@ -2652,12 +2691,14 @@ export interface BindingOutputOptions {
   exports?: 'default' | 'named' | 'none' | 'auto'
   extend?: boolean
   externalLiveBindings?: boolean
-  format?: 'es' | 'cjs' | 'iife' | 'umd'
+  format?: 'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'system'
   generatedCode?: BindingGeneratedCodeOptions
   globals?: Record<string, string> | ((name: string) => string)
   hashCharacters?: 'base64' | 'base36' | 'hex'
+  hoistTransitiveImports?: boolean | 'modulepreload'
   inlineDynamicImports?: boolean
   dynamicImportInCjs?: boolean
+  interop?: 'auto' | 'esModule' | 'default' | 'defaultOnly' | 'compat' | Record<string, string> | ((id: string) => VoidNullable<string>)
   intro?: string | ((chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>)
   outro?: string | ((chunk: BindingRenderedChunk) => MaybePromise<VoidNullable<string>>)
   paths?: Record<string, string> | ((id: string) => string)
@@ -2667,12 +2708,14 @@ export interface BindingOutputOptions {
   sourcemapBaseUrl?: string
   sourcemapIgnoreList?: boolean | string | RegExp | ((source: string, sourcemapPath: string) => boolean)
   sourcemapDebugIds?: boolean
+  sourcemapScopes?: boolean
+  sourcemapIndexMap?: boolean
   sourcemapPathTransform?: (source: string, sourcemapPath: string) => string
   sourcemapExcludeSources?: boolean
   strict?: boolean | 'auto'
   minify?: boolean | 'dce-only' | MinifyOptions
   manualCodeSplitting?: BindingManualCodeSplittingOptions
-  legalComments?: 'none' | 'inline'
+  legalComments?: 'none' | 'inline' | 'eof' | 'linked' | 'external'
   comments?: boolean | BindingCommentsOptions
   polyfillRequire?: boolean
   preserveModules?: boolean
@@ -2681,12 +2724,16 @@ export interface BindingOutputOptions {
   topLevelVar?: boolean
   minifyInternalExports?: boolean
   cleanDir?: boolean
+  metafile?: boolean
+  integrity?: 'sha256' | 'sha384' | 'sha512'
   strictExecutionOrder?: boolean
 }
 
 export interface BindingOutputs {
   chunks: Array<BindingOutputChunk>
   assets: Array<BindingOutputAsset>
+  /** The metafile serialized as JSON, only present when `output.metafile` is enabled. */
+  metafile?: string
 }
 
 export interface BindingOverwriteOptions {
@@ -2717,6 +2764,7 @@ export interface BindingPluginContextResolveOptions {
   skipSelf?: boolean
   custom?: number
   vitePluginCustom?: BindingVitePluginCustom
+  attributes?: Record<string, string>
 }
 
 export interface BindingPluginHookMeta {
@@ -2726,6 +2774,7 @@ export interface BindingPluginHookMeta {
 export interface BindingPluginOptions {
   name: string
   hookUsage: number
+  cacheKey?: string
   buildStart?: (ctx: BindingPluginContext, opts: BindingNormalizedOptions) => MaybePromise<VoidNullable>
   buildStartMeta?: BindingPluginHookMeta
   resolveId?: (ctx: BindingPluginContext, specifier: string, importer: Nullable<string>, options: BindingHookResolveIdExtraArgs) => MaybePromise<VoidNullable<BindingHookResolveIdOutput>>
@@ -2733,7 +2782,7 @@ export interface BindingPluginOptions {
   resolveIdFilter?: BindingHookFilter
   resolveDynamicImport?: (ctx: BindingPluginContext, specifier: string, importer: Nullable<string>) => MaybePromise<VoidNullable<BindingHookResolveIdOutput>>
   resolveDynamicImportMeta?: BindingPluginHookMeta
-  load?: (ctx: BindingLoadPluginContext, id: string) => MaybePromise<VoidNullable<BindingHookLoadOutput>>
+  load?: (ctx: BindingLoadPluginContext, id: string, meta: BindingLoadHookExtraArgs) => MaybePromise<VoidNullable<BindingHookLoadOutput>>
   loadMeta?: BindingPluginHookMeta
   loadFilter?: BindingHookFilter
   transform?: (ctx:  BindingTransformPluginContext, id: string, code: string, module_type: BindingTransformHookExtraArgs) => MaybePromise<VoidNullable<BindingHookTransformOutput>>
@@ -2821,6 +2870,14 @@ export interface BindingPreRenderedAsset {
   source: BindingAssetSource
 }
 
+export interface BindingPrecompressOptions {
+  algorithms?: Array<'gzip' | 'brotli' | 'zstd'>
+  threshold?: number
+  level?: number
+  include?: Array<BindingStringOrRegex>
+  exclude?: Array<BindingStringOrRegex>
+}
+
 export type BindingPreserveEntrySignatures =
   | { type: 'Bool', field0: boolean }
   | { type: 'String', field0: string }
@@ -3087,6 +3144,10 @@ export interface BindingViteTransformPluginConfig {
   yarnPnp?: boolean
 }
 
+export interface BindingWasmOptions {
+  embed?: boolean
+}
+
 export interface BindingWatchOption {
   skipWrite?: boolean
   include?: Array<BindingStringOrRegex>
@@ -3101,6 +3162,10 @@ export interface BindingWatchOption {
   onInvalidate?: ((id: string) => void) | undefined
 }
 
+export interface BindingWorkerOptions {
+  format?: 'es' | 'cjs' | 'iife' | 'umd'
+}
+
 export declare function collapseSourcemaps(sourcemapChain: Array<BindingSourcemap>): BindingJsonSourcemap
 
 export declare function enhancedTransform(filename: string, sourceText: string, options: BindingEnhancedTransformOptions | undefined | null, cache: TsconfigCache | undefined | null, yarnPnp: boolean): Promise<BindingEnhancedTransformResult>
@@ -75,6 +75,12 @@ export const alias: Partial<Record<keyof CliOptions, OptionConfig>> = {
   chunkFileNames: {
     hint: 'name',
   },
+  cssEntryFileNames: {
+    hint: 'name',
+  },
+  cssChunkFileNames: {
+    hint: 'name',
+  },
   entryFileNames: {
     hint: 'name',
   },
@@ -28,6 +28,7 @@ import type {
   InputOptions,
   ModuleTypes,
   OptimizationOptions,
+  PrecompressOptions,
   WatcherFileWatcherOptions,
   WatcherOptions,
 } from './options/input-options';
@@ -47,10 +48,13 @@ import type {
   CodeSplittingNameFunction,
   AdvancedChunksGroup,
   AdvancedChunksOptions,
+  AmdOptions,
   CommentsOptions,
   GeneratedCodeOptions,
   GeneratedCodePreset,
   GlobalsFunction,
+  InteropFunction,
+  InteropType,
   MinifyOptions,
   ModuleFormat,
   OutputOptions,
@@ -105,6 +109,8 @@ import type { TreeshakingOptions } from './types/module-side-effects';
 import type { OutputBundle } from './types/output-bundle';
 import type { RolldownOptions } from './types/rolldown-options';
 import type {
+  Metafile,
+  MetafileImport,
   OutputAsset,
   OutputChunk,
   RenderedChunk,
@@ -132,6 +138,7 @@ export type {
   AsyncPluginHooks,
   AdvancedChunksGroup,
   AdvancedChunksOptions,
+  AmdOptions,
   BufferEncoding,
   BuildOptions,
   BuiltinModuleTag,
@@ -157,6 +164,8 @@ export type {
   GeneratedCodePreset,
   GetModuleInfo,
   GlobalsFunction,
+  InteropFunction,
+  InteropType,
   TransformOptions,
   HookFilter,
   HookFilterExtension,
@@ -169,6 +178,8 @@ export type {
   LogLevel,
   LogLevelOption,
   LogOrStringHandler,
+  Metafile,
+  MetafileImport,
   MinifyOptions,
   MinimalPluginContext,
   ModuleFormat,
@@ -193,6 +204,7 @@ export type {
   PluginContext,
   PluginContextMeta,
   PluginMeta,
+  PrecompressOptions,
   PreRenderedAsset,
   PreRenderedChunk,
   RenderedChunk,
@@ -196,4 +196,28 @@ export interface ChecksOptions {
    * @default true
    * */
   namespaceConflict?: boolean;
+
+  /**
+   * Whether to emit warnings when the path of an `import()` or `require()` built at runtime, like `` import(`./locales/${lang}.js`) ``, matches no file.
+   *
+   * Such calls are kept as-is, so they will fail at runtime unless the file exists next to the output.
+   * @default true
+   * */
+  emptyGlobImport?: boolean;
+
+  /**
+   * Whether to emit warnings when an entry loads more chunks than `codeSplitting.maxInitialRequests` or `codeSplitting.maxAsyncRequests` allow.
+   *
+   * Shared chunks are only merged when that changes no entry signature and runs no module an entry doesn't need.
+   * @default true
+   * */
+  exceededMaxRequests?: boolean;
+
+  /**
+   * Whether to emit warnings when Rolldown could not write the file of `experimental.recordsPath`.
+   *
+   * The next build then starts without the recorded chunks.
+   * @default true
+   * */
+  couldNotWriteRecords?: boolean;
 }
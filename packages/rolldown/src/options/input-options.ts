@@ -60,6 +60,37 @@ export interface ChunkOptimizationOptions {
   avoidRedundantChunkLoads?: boolean;
 }
 
+export interface PrecompressOptions {
+  /**
+   * Compression formats to emit. Each one produces a sibling with its extension appended,
+   * e.g. `main.js.gz`, `main.js.br` or `main.js.zst`.
+   *
+   * @default ['gzip', 'brotli']
+   */
+  algorithms?: Array<'gzip' | 'brotli' | 'zstd'>;
+  /**
+   * Outputs smaller than this many bytes are skipped.
+   *
+   * @default 1024
+   */
+  threshold?: number;
+  /**
+   * Compression level, clamped to the range each algorithm supports.
+   *
+   * @default 9 for gzip, 11 for brotli and 19 for zstd
+   */
+  level?: number;
+  /**
+   * Output file names to compress. When unset, all chunks and the assets with a text-like
+   * extension are compressed.
+   */
+  include?: StringOrRegExp | StringOrRegExp[];
+  /**
+   * Output file names to skip.
+   */
+  exclude?: StringOrRegExp | StringOrRegExp[];
+}
+
 export type ModuleTypes = Record<
   string,
   | 'js'
@@ -75,6 +106,8 @@ export type ModuleTypes = Record<
   | 'css'
   | 'asset'
   | 'copy'
+  | 'wasm'
+  | 'html'
 >;
 
 export interface WatcherFileWatcherOptions {
@@ -279,6 +312,22 @@ export type OptimizationOptions = {
    * @default true
    */
   pifeForModuleWrappers?: boolean;
+
+  /**
+   * Convert statically analyzable CommonJS modules to ESM instead of wrapping them with `__commonJS`.
+   *
+   * A CommonJS module is converted when it only writes its exports through top-level `exports.foo = ...`
+   * or `module.exports = { ... }` statements, never reads `exports` or `module` otherwise, and only calls
+   * `require` at the top level unconditionally. The converted module joins scope hoisting, so its unused
+   * exports can be tree-shaken like any ES module. The ES modules it `require`s before any other side
+   * effect of its own are hoisted along with it, as if they were imported statically.
+   *
+   * A module is still wrapped if some importer relies on CommonJS semantics, e.g. a default import or a
+   * `require` call of a module without the `__esModule` flag, or a named import the module doesn't export.
+   *
+   * @default false
+   */
+  commonjsToEsm?: boolean;
 };
 
 /** @inline */
@@ -722,6 +771,71 @@ export interface InputOptions {
      * @default false
      */
     lazyBarrel?: boolean;
+    /**
+     * Emit pre-compressed `.gz`, `.br` or `.zst` siblings of the generated chunks and assets, for
+     * static hosting that serves them as-is.
+     *
+     * Compression runs in parallel after all `generateBundle` hooks, and a sibling is only emitted
+     * when it is smaller than the original.
+     *
+     * @example
+     * ```js
+     * export default {
+     *   experimental: {
+     *     precompress: {
+     *       algorithms: ['gzip', 'brotli', 'zstd'],
+     *       exclude: /\.map$/,
+     *     },
+     *   },
+     * }
+     * ```
+     *
+     * @default false
+     */
+    precompress?: boolean | PrecompressOptions;
+    /**
+     * Allow top-level await with the `cjs`, `iife`, `umd` and `amd` output formats, which have no
+     * native support for it.
+     *
+     * When an entry depends on a module containing top-level await, the entry and every module it
+     * imports are wrapped in `init_*` functions. Wrappers of modules that contain or depend on
+     * top-level await are `async` and await the wrappers of their dependencies in import order.
+     * The entry's named exports are exposed as live bindings that are populated once its wrapper
+     * has settled, along with a non-enumerable `__tla` export holding the promise of the wrapper.
+     * Await it before reading the exports; it rejects if the top-level await throws. With
+     * `output.exports: 'default'`, the bundle exports a promise of the default export instead.
+     *
+     * `require()` of a module that depends on top-level await is still an error, since its
+     * exports can't be produced synchronously.
+     *
+     * @default false
+     */
+    asyncModuleWrappers?: boolean;
+    /**
+     * Directory, relative to `cwd`, where the results of the `load` and `transform` hooks are
+     * persisted across builds and processes.
+     *
+     * Entries are keyed by the module's content, the rolldown version, the options that affect
+     * loading and the {@linkcode Plugin.cacheKey | cacheKey} of every plugin. Files added with
+     * `this.addWatchFile` and assets emitted with `this.emitFile` by these hooks are watched and
+     * emitted again on a cache hit, and an entry is not reused once one of its watch files changed.
+     *
+     * The cache is bypassed when a plugin with a `load` or `transform` hook doesn't provide a
+     * `cacheKey`.
+     *
+     * @default undefined
+     */
+    persistentCacheDir?: string;
+    /**
+     * File, relative to `cwd`, recording the common chunks of the previous build.
+     *
+     * Code splitting recreates the recorded chunks, with the same names and modules, as long as
+     * they are still valid, so that unchanged chunks keep their filenames across builds. The file
+     * is rewritten after each build and should be committed along with the sources.
+     *
+     * @default undefined
+     */
+    recordsPath?: string;
   };
   /**
    * Configure how the code is transformed. This process happens after the `transform` hook.
@@ -812,6 +926,42 @@ export interface InputOptions {
    * @default true
    */
   tsconfig?: boolean | string;
+  /**
+   * Bundle web workers referenced by `new Worker(new URL('./worker.js', import.meta.url))`,
+   * `new SharedWorker(...)`, `navigator.serviceWorker.register(...)` and worklets such as
+   * `CSS.paintWorklet.addModule(...)` or `audioContext.audioWorklet.addModule(...)`.
+   *
+   * Each worker is bundled as the entry of its own chunk graph, and the URL in the generated code
+   * is rewritten to point at the emitted worker chunk.
+   * @default undefined
+   */
+  worker?: {
+    /**
+     * The output format of worker bundles.
+     * @default 'iife'
+     */
+    format?: 'es' | 'cjs' | 'iife' | 'umd';
+  };
+  /**
+   * Options for modules with the `wasm` module type, which `.wasm` files have by default.
+   *
+   * Their exports are importable like the ones of a JavaScript module, and the modules named in
+   * their import section are bundled and passed to the instance. `import source mod from './x.wasm'`
+   * imports the compiled `WebAssembly.Module` instead.
+   *
+   * The `es` and `system` formats instantiate the module with top-level await. The other formats
+   * compile it synchronously, so they are only supported with `platform: 'node'`: browsers reject
+   * synchronous compilation of binaries larger than 4KB on the main thread.
+   * @default undefined
+   */
+  wasm?: {
+    /**
+     * Inline the binary as base64 instead of emitting it as an asset that is fetched (browser) or
+     * read from disk (node) at runtime.
+     * @default false
+     */
+    embed?: boolean;
+  };
 }
 
 interface OverwriteInputOptionsForCli {
@@ -10,6 +10,8 @@ import type {
   ChunkFileNamesFunction,
   CommentsOptions,
   GlobalsFunction,
+  InteropFunction,
+  InteropType,
   MinifyOptions,
   OutputOptions,
 } from './output-options';
@@ -22,7 +24,7 @@ type PathsFunction = (id: string) => string;
  * A normalized version of {@linkcode ModuleFormat}.
  * @category Plugin APIs
  */
-export type InternalModuleFormat = 'es' | 'cjs' | 'iife' | 'umd';
+export type InternalModuleFormat = 'es' | 'cjs' | 'iife' | 'umd' | 'amd' | 'system';
 
 /** @category Plugin APIs */
 export interface NormalizedOutputOptions {
@@ -38,6 +40,10 @@ export interface NormalizedOutputOptions {
   sourcemapFileNames: string | ChunkFileNamesFunction | undefined;
   /** @see {@linkcode OutputOptions.chunkFileNames | chunkFileNames} */
   chunkFileNames: string | ChunkFileNamesFunction;
+  /** @see {@linkcode OutputOptions.cssEntryFileNames | cssEntryFileNames} */
+  cssEntryFileNames: string | ChunkFileNamesFunction;
+  /** @see {@linkcode OutputOptions.cssChunkFileNames | cssChunkFileNames} */
+  cssChunkFileNames: string | ChunkFileNamesFunction;
   /** @see {@linkcode OutputOptions.assetFileNames | assetFileNames} */
   assetFileNames: string | AssetFileNamesFunction;
   /** @see {@linkcode OutputOptions.format | format} */
@@ -70,16 +76,24 @@ export interface NormalizedOutputOptions {
   outro: AddonFunction;
   /** @see {@linkcode OutputOptions.esModule | esModule} */
   esModule: boolean | 'if-default-prop';
+  /** @see {@linkcode OutputOptions.hoistTransitiveImports | hoistTransitiveImports} */
+  hoistTransitiveImports: boolean | 'modulepreload';
   /** @see {@linkcode OutputOptions.extend | extend} */
   extend: boolean;
   /** @see {@linkcode OutputOptions.globals | globals} */
   globals: Record<string, string> | GlobalsFunction;
   /** @see {@linkcode OutputOptions.paths | paths} */
   paths: Record<string, string> | PathsFunction | undefined;
+  /** @see {@linkcode OutputOptions.interop | interop} */
+  interop: InteropType | Record<string, InteropType> | InteropFunction | undefined;
   /** @see {@linkcode OutputOptions.hashCharacters | hashCharacters} */
   hashCharacters: 'base64' | 'base36' | 'hex';
   /** @see {@linkcode OutputOptions.sourcemapDebugIds | sourcemapDebugIds} */
   sourcemapDebugIds: boolean;
+  /** @see {@linkcode OutputOptions.sourcemapScopes | sourcemapScopes} */
+  sourcemapScopes: boolean;
+  /** @see {@linkcode OutputOptions.sourcemapIndexMap | sourcemapIndexMap} */
+  sourcemapIndexMap: boolean;
   /** @see {@linkcode OutputOptions.sourcemapExcludeSources | sourcemapExcludeSources} */
   sourcemapExcludeSources: boolean;
   /** @see {@linkcode OutputOptions.sourcemapIgnoreList | sourcemapIgnoreList} */
@@ -88,11 +102,8 @@ export interface NormalizedOutputOptions {
   sourcemapPathTransform: SourcemapPathTransformOption | undefined;
   /** @see {@linkcode OutputOptions.minify | minify} */
   minify: false | MinifyOptions | 'dce-only';
-  /**
-   * @deprecated Use `comments.legal` instead.
-   * @see {@linkcode OutputOptions.legalComments | legalComments}
-   */
-  legalComments: 'none' | 'inline';
+  /** @see {@linkcode OutputOptions.legalComments | legalComments} */
+  legalComments: 'none' | 'inline' | 'eof' | 'linked' | 'external';
   /** @see {@linkcode OutputOptions.comments | comments} */
   comments: Required<CommentsOptions>;
   /** @see {@linkcode OutputOptions.polyfillRequire | polyfillRequire} */
@@ -138,13 +149,23 @@ export class NormalizedOutputOptionsImpl
     return this.inner.chunkFilenames || this.outputOptions.chunkFileNames!;
   }
 
+  @lazyProp
+  get cssEntryFileNames(): string | ChunkFileNamesFunction {
+    return this.inner.cssEntryFilenames || this.outputOptions.cssEntryFileNames!;
+  }
+
+  @lazyProp
+  get cssChunkFileNames(): string | ChunkFileNamesFunction {
+    return this.inner.cssChunkFilenames || this.outputOptions.cssChunkFileNames!;
+  }
+
   @lazyProp
   get assetFileNames(): string | AssetFileNamesFunction {
     return this.inner.assetFilenames || this.outputOptions.assetFileNames!;
   }
 
   @lazyProp
-  get format(): 'es' | 'cjs' | 'iife' | 'umd' {
+  get format(): InternalModuleFormat {
     return this.inner.format;
   }
 
@@ -240,6 +261,11 @@ export class NormalizedOutputOptionsImpl
     return this.inner.esModule;
   }
 
+  @lazyProp
+  get hoistTransitiveImports(): boolean | 'modulepreload' {
+    return this.outputOptions.hoistTransitiveImports ?? true;
+  }
+
   @lazyProp
   get extend(): boolean {
     return this.inner.extend;
@@ -255,6 +281,11 @@ export class NormalizedOutputOptionsImpl
     return this.outputOptions.paths;
   }
 
+  @lazyProp
+  get interop(): InteropType | Record<string, InteropType> | InteropFunction | undefined {
+    return this.outputOptions.interop;
+  }
+
   @lazyProp
   get hashCharacters(): 'base64' | 'base36' | 'hex' {
     return this.inner.hashCharacters;
@@ -265,6 +296,16 @@ export class NormalizedOutputOptionsImpl
     return this.inner.sourcemapDebugIds;
   }
 
+  @lazyProp
+  get sourcemapScopes(): boolean {
+    return this.inner.sourcemapScopes;
+  }
+
+  @lazyProp
+  get sourcemapIndexMap(): boolean {
+    return this.inner.sourcemapIndexMap;
+  }
+
   @lazyProp
   get sourcemapExcludeSources(): boolean {
     return this.inner.sourcemapExcludeSources;
@@ -293,7 +334,7 @@ export class NormalizedOutputOptionsImpl
   }
 
   @lazyProp
-  get legalComments(): 'none' | 'inline' {
+  get legalComments(): 'none' | 'inline' | 'eof' | 'linked' | 'external' {
     return this.inner.legalComments;
   }
 
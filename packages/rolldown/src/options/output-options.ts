@@ -2,7 +2,12 @@ import type { MinifyOptions as BindingMinifyOptions, PreRenderedChunk } from '..
 import type { RolldownOutputPluginOption } from '../plugin';
 import type { SourcemapIgnoreListOption, SourcemapPathTransformOption } from '../types/misc';
 import type { ModuleInfo } from '../types/module-info';
-import type { RenderedChunk } from '../types/rolldown-output';
+import type {
+  OutputAsset,
+  OutputChunk,
+  RenderedChunk,
+  RolldownOutput,
+} from '../types/rolldown-output';
 import type { NullValue, StringOrRegExp } from '../types/utils';
 import type { AssetSource } from '../utils/asset-source';
 // oxlint-disable-next-line no-unused-vars -- this is used in JSDoc links
@@ -51,7 +56,29 @@ export interface GeneratedCodeOptions {
 }
 
 /** @inline */
-export type ModuleFormat = 'es' | 'cjs' | 'esm' | 'module' | 'commonjs' | 'iife' | 'umd';
+export type ModuleFormat =
+  | 'es'
+  | 'cjs'
+  | 'esm'
+  | 'module'
+  | 'commonjs'
+  | 'iife'
+  | 'umd'
+  | 'amd'
+  | 'system'
+  | 'systemjs';
+
+export interface AmdOptions {
+  /**
+   * An ID to use for AMD/UMD bundles, passed to `define`. Only supported for single-file builds.
+   */
+  id?: string;
+  /**
+   * The function name to use instead of `define`.
+   * @default 'define'
+   */
+  define?: string;
+}
 
 /** @inline */
 export type AddonFunction = (chunk: RenderedChunk) => string | Promise<string>;
@@ -82,6 +109,12 @@ export type AssetFileNamesFunction = (chunkInfo: PreRenderedAsset) => string;
 /** @inline */
 export type PathsFunction = (id: string) => string;
 
+/** @inline */
+export type InteropType = 'auto' | 'esModule' | 'default' | 'defaultOnly' | 'compat';
+
+/** @inline */
+export type InteropFunction = (id: string) => InteropType | NullValue;
+
 /** @inline */
 export type ManualChunksFunction = (
   moduleId: string,
@@ -166,6 +199,8 @@ export interface OutputOptions {
    * - `'cjs'` and `'commonjs'` are the same format, all stand for CommonJS module.
    * - `'iife'` stands for [Immediately Invoked Function Expression](https://developer.mozilla.org/en-US/docs/Glossary/IIFE).
    * - `'umd'` stands for [Universal Module Definition](https://github.com/umdjs/umd).
+   * - `'amd'` stands for [Asynchronous Module Definition](https://github.com/amdjs/amdjs-api/blob/master/AMD.md), as used by RequireJS.
+   * - `'system'` and `'systemjs'` are the same format, both stand for the [SystemJS](https://github.com/systemjs/systemjs) `System.register` format.
    *
    * @default 'es'
    *
@@ -213,6 +248,26 @@ export interface OutputOptions {
    * @default false
    */
   sourcemapDebugIds?: boolean;
+  /**
+   * Whether to include the `scopes` field of the [ECMA-426 scopes proposal](https://github.com/tc39/ecma426/blob/main/proposals/scopes.md) in the sourcemap.
+   *
+   * The field describes the functions and variables of the original sources and what they are called in the generated code, so tools like error reporting services can show original function and variable names even after renaming and minification.
+   *
+   * The field is only added to the emitted sourcemap, not to the `map` of output chunks.
+   *
+   * @default false
+   */
+  sourcemapScopes?: boolean;
+  /**
+   * Whether to emit the sourcemap of a chunk as an [index map](https://tc39.es/ecma426/#sec-index-source-map) with a section for each module.
+   *
+   * Building an index map only needs to offset the sourcemap of each module, which is faster than merging them into a single map for large chunks.
+   *
+   * Chunks whose sourcemaps have to be combined with other sourcemaps, because a `renderChunk` hook returned a sourcemap, the chunk is minified or {@linkcode sourcemapScopes} is enabled, are still emitted with a regular sourcemap. As this includes the default `'dce-only'` pass, set {@linkcode minify} to `false` to emit index maps. The `map` of output chunks emitted with an index map is `null`.
+   *
+   * @default false
+   */
+  sourcemapIndexMap?: boolean;
   /**
    * Control which source files are included in the sourcemap ignore list.
    *
@@ -330,6 +385,10 @@ export interface OutputOptions {
    * @default false
    */
   extend?: boolean;
+  /**
+   * Options for the `define` call of the `amd` {@link OutputOptions.format | format} and of the AMD branch of `umd`.
+   */
+  amd?: AmdOptions;
   /**
    * Whether to add a `__esModule: true` property when generating exports for non-ES {@link OutputOptions.format | formats}.
    *
@@ -390,6 +449,26 @@ export interface OutputOptions {
    * @default '[name]-[hash].js'
    */
   chunkFileNames?: string | ChunkFileNamesFunction;
+  /**
+   * The pattern to use for the `.css` files generated from the CSS modules of entry chunks, or a function that is called per entry chunk with {@linkcode PreRenderedChunk} to return such a pattern.
+   *
+   * Supports the same placeholders as {@linkcode entryFileNames | output.entryFileNames}. `[hash]` is based on the content of the generated stylesheet.
+   *
+   * See also {@linkcode cssChunkFileNames | output.cssChunkFileNames}.
+   *
+   * @default '[name].css'
+   */
+  cssEntryFileNames?: string | ChunkFileNamesFunction;
+  /**
+   * The pattern to use for the `.css` files generated from the CSS modules of shared chunks, or a function that is called per chunk with {@linkcode PreRenderedChunk} to return such a pattern.
+   *
+   * Supports the same placeholders as {@linkcode chunkFileNames | output.chunkFileNames}. `[hash]` is based on the content of the generated stylesheet.
+   *
+   * See also {@linkcode cssEntryFileNames | output.cssEntryFileNames}.
+   *
+   * @default '[name]-[hash].css'
+   */
+  cssChunkFileNames?: string | ChunkFileNamesFunction;
   /**
    * Whether to enable chunk name sanitization (removal of non-URL-safe characters like `\0`, `?` and `*`).
    *
@@ -492,6 +571,26 @@ export interface OutputOptions {
    * ```
    */
   paths?: Record<string, string> | PathsFunction;
+  /**
+   * Controls how default and namespace imports of {@link InputOptions.external | external} modules are read from `require()` in the `cjs`, `iife`, `umd` and `amd` {@link OutputOptions.format | formats}.
+   *
+   * - `'auto'`: The default import is `.default` of a module flagged with `__esModule`, and the whole module otherwise.
+   * - `'esModule'`: The module is an ES module transpiled to CommonJS, so the default import is always `.default`. No helper is emitted.
+   * - `'default'`: The default import is always the whole module, as in Node.js.
+   * - `'defaultOnly'`: Like `'default'`, but the namespace only has a `default` property and named imports are an error. No helper is emitted.
+   * - `'compat'`: The default import is `.default` of a module owning a `default` property, and the whole module otherwise.
+   *
+   * The object form maps module IDs to a type, and the function form returns one for a module ID.
+   * Externals without a type use `'default'` when the importing module is an ES module by Node.js rules, and `'auto'` otherwise.
+   *
+   * @example
+   * ```js
+   * {
+   *   interop: (id) => (id === 'legacy-lib' ? 'defaultOnly' : 'auto')
+   * }
+   * ```
+   */
+  interop?: InteropType | Record<string, InteropType> | InteropFunction;
   /**
    * Which language features Rolldown can safely use in generated code.
    *
@@ -631,10 +730,13 @@ export interface OutputOptions {
    *
    * - `none`: no legal comments
    * - `inline`: preserve legal comments that contain `@license`, `@preserve` or starts with `//!` `/*!`
+   * - `eof`: move the deduplicated legal comments of each chunk to the end of the chunk
+   * - `linked`: move them to a `[chunk].LEGAL.txt` asset and link to it with a `/*! For license information please see … *\/` comment
+   * - `external`: move them to a `[chunk].LEGAL.txt` asset without linking to it
    *
-   * @deprecated Use `comments.legal` instead. When both `legalComments` and `comments.legal` are set, `comments.legal` takes priority.
+   * `none` and `inline` are deprecated, use `comments.legal` instead. When both `legalComments` and `comments.legal` are set, `comments.legal` takes priority.
    */
-  legalComments?: 'none' | 'inline';
+  legalComments?: 'none' | 'inline' | 'eof' | 'linked' | 'external';
   /**
    * Control which comments are preserved in the output.
    *
@@ -665,10 +767,19 @@ export interface OutputOptions {
    */
   polyfillRequire?: boolean;
   /**
-   * This option is not implemented yet.
-   * @hidden
+   * Whether entry chunks in the `es` format should reference the chunks they only depend on through other chunks.
+   *
+   * A browser only discovers the imports of a chunk once it has downloaded it, so a deep chain of chunks is loaded one round-trip at a time.
+   *
+   * - `true`: Add an empty import, such as `import "./chunk.js";`, of each of these chunks to the entry chunks. They are also listed in {@linkcode OutputChunk.imports | imports}.
+   * - `'modulepreload'`: Leave the code untouched and only list these chunks in {@linkcode OutputChunk.transitiveImports | transitiveImports}, e.g. to emit `<link rel="modulepreload">` tags for them.
+   * - `false`: Entry chunks only import the chunks they depend on directly.
+   *
+   * This option has no effect in the {@linkcode OutputOptions.preserveModules | preserveModules} mode.
+   *
+   * @default true
    */
-  hoistTransitiveImports?: false;
+  hoistTransitiveImports?: boolean | 'modulepreload';
   /**
    * Whether to use preserve modules mode.
    *
@@ -725,6 +836,25 @@ export interface OutputOptions {
    * {@include ./docs/output-clean-dir.md}
    */
   cleanDir?: boolean;
+  /**
+   * Attach a report of the build to the output as {@linkcode RolldownOutput.metafile}.
+   *
+   * The report follows esbuild's [metafile](https://esbuild.github.io/api/#metafile) schema,
+   * so tools built for esbuild, such as bundle size visualizers, can consume it unchanged.
+   *
+   * @default false
+   */
+  metafile?: boolean;
+  /**
+   * Compute a [Subresource Integrity](https://developer.mozilla.org/en-US/docs/Web/Security/Subresource_Integrity)
+   * digest of each output with the given hash function, and expose it as `integrity` on
+   * {@linkcode OutputChunk} and {@linkcode OutputAsset}.
+   *
+   * The digest covers the final bytes, after minification, banners and footers. It is available
+   * in the `generateBundle` hook, and recomputed once that hook has run. When
+   * `experimental.chunkImportMap` is enabled, the import map gets an `integrity` section too.
+   */
+  integrity?: 'sha256' | 'sha384' | 'sha512';
   /**
    * Keep `name` property of functions and classes after bundling.
    *
@@ -1004,6 +1134,20 @@ export type CodeSplittingOptions = {
    * Global fallback of {@linkcode CodeSplittingGroup.minShareCount | group.minShareCount}, if it's not specified in the group.
    */
   minShareCount?: number;
+  /**
+   * Maximum number of chunks loading a user-defined entry requests, the entry chunk included.
+   *
+   * When an entry needs more, the shared chunks it loads are merged, least-shared first, until it
+   * fits. Chunks created by `groups` are never merged away. If the limit can't be met without
+   * changing an entry's signature or running modules other entries don't need, an
+   * `EXCEEDED_MAX_REQUESTS` warning is emitted.
+   */
+  maxInitialRequests?: number;
+  /**
+   * Like {@linkcode CodeSplittingOptions.maxInitialRequests | maxInitialRequests}, but for the
+   * chunks loaded by a dynamic `import()`.
+   */
+  maxAsyncRequests?: number;
   /**
    * Groups to be used for code splitting.
    */
@@ -259,7 +259,7 @@ export function bindingifyLoad(
   args: BindingifyPluginArgs,
 ): PluginHookWithBindingExt<BindingPluginOptions['load'], BindingHookFilter | undefined> {
   return bindingifyHook(args.plugin.load, ({ handler, options }) => ({
-    plugin: async (ctx, id) => {
+    plugin: async (ctx, id, meta) => {
       const ret = await handler.call(
         new LoadPluginContextImpl(
           args.outputOptions,
@@ -273,6 +273,7 @@ export function bindingifyLoad(
           args.watchMode,
         ),
         id,
+        meta,
       );
 
       if (ret == null) {
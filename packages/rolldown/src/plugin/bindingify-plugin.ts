@@ -137,6 +137,7 @@ export function bindingifyPlugin(
   const result: BindingPluginOptions = {
     // The plugin name already normalized at `normalizePlugins`, see `packages/rolldown/src/utils/normalize-plugin-option.ts`
     name: plugin.name!,
+    cacheKey: plugin.cacheKey,
     buildStart,
     buildStartMeta,
     resolveId,
@@ -1,6 +1,10 @@
 import type { Program } from '@oxc-project/types';
 import type { InputOptions, OutputOptions } from '..';
-import type { BindingHookResolveIdExtraArgs, BindingTransformHookExtraArgs } from '../binding.cjs';
+import type {
+  BindingHookResolveIdExtraArgs,
+  BindingLoadHookExtraArgs,
+  BindingTransformHookExtraArgs,
+} from '../binding.cjs';
 import type { RolldownMagicString } from '../binding-magic-string';
 import type { BuiltinPlugin } from '../builtin-plugin/utils';
 import type { DefinedHookNames } from '../constants/plugin';
@@ -245,6 +249,11 @@ export interface ResolveIdExtraOptions {
    * - `hot-accept`: `import.meta.hot.accept('./lib.js', () => {})` (experimental)
    */
   kind: BindingHookResolveIdExtraArgs['kind'];
+  /**
+   * The import attributes of the import, e.g. `{ type: 'json' }` for
+   * `import data from './data.json' with { type: 'json' }`. Empty if the import has none.
+   */
+  attributes: Record<string, string>;
 }
 
 /** @inline @category Plugin APIs */
@@ -423,10 +432,18 @@ export interface FunctionPluginHooks {
    *
    * You can use {@linkcode PluginContext.getModuleInfo | this.getModuleInfo()} to find out the previous values of `meta`, `moduleSideEffects` inside this hook.
    *
+   * `meta.attributes` holds the import attributes of the import that created the module. A module
+   * whose type is selected by them, e.g. `with { type: 'text' }` on a `.js` file, gets an id
+   * starting with `\0rolldown/with-type/`.
+   *
    * @kind async first
    * @group Build Hooks
    */
-  [DEFINED_HOOK_NAMES.load]: (this: PluginContext, id: string) => MaybePromise<LoadResult>;
+  [DEFINED_HOOK_NAMES.load]: (
+    this: PluginContext,
+    id: string,
+    meta: BindingLoadHookExtraArgs,
+  ) => MaybePromise<LoadResult>;
 
   /**
    * Can be used to transform individual modules.
@@ -810,11 +827,20 @@ interface OutputPlugin
       [K in keyof PluginHooks as K & OutputPluginHooks]: PluginHooks[K];
     }>,
     Partial<{ [K in keyof AddonHooks]: ObjectHook<AddonHook> }> {
-  // cacheKey?: string
   /** The name of the plugin, for use in error messages and logs. */
   name: string;
   /** The version of the plugin, for use in inter-plugin communication scenarios. */
   version?: string;
+  /**
+   * Identifies everything besides the module itself that affects the output of the `load` and
+   * `transform` hooks of this plugin, such as its version and options.
+   *
+   * `experimental.persistentCacheDir` includes it in the keys of its entries, and is bypassed when
+   * a plugin with one of these hooks doesn't provide it.
+   *
+   * @experimental
+   */
+  cacheKey?: string;
   /**
    * Descriptive metadata about the plugin, such as the npm package it ships in.
    *
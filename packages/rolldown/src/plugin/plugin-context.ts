@@ -172,6 +172,11 @@ export interface PluginContextResolveOptions {
    * See [Custom resolver options section](https://rolldown.rs/apis/plugin-api/inter-plugin-communication#custom-resolver-options) for more details.
    */
   custom?: CustomPluginOptions;
+  /**
+   * The value for {@linkcode ResolveIdExtraOptions.attributes | attributes} passed to
+   * {@linkcode Plugin.resolveId | resolveId} hooks.
+   */
+  attributes?: Record<string, string>;
 }
 
 /** @inline */
@@ -350,6 +355,7 @@ export class PluginContextImpl extends MinimalPluginContextImpl {
       isEntry: options?.isEntry,
       skipSelf: options?.skipSelf,
       vitePluginCustom,
+      attributes: options?.attributes,
     } satisfies Record<keyof BindingPluginContextResolveOptions, unknown>);
     if (receipt != null) {
       this.data.removeSavedResolveOptions(receipt);
@@ -37,6 +37,11 @@ export class OutputAssetImpl extends PlainObjectLike implements OutputAsset {
     return this.bindingAsset.getNames();
   }
 
+  @lazyProp
+  get integrity(): string | null {
+    return this.bindingAsset.getIntegrity() || null;
+  }
+
   @lazyProp
   get source(): AssetSource {
     return transformAssetSource(this.bindingAsset.getSource());
@@ -52,6 +52,11 @@ export class OutputChunkImpl extends PlainObjectLike implements OutputChunk {
     return this.bindingChunk.getPreliminaryFileName();
   }
 
+  @lazyProp
+  get integrity(): string | null {
+    return this.bindingChunk.getIntegrity() || null;
+  }
+
   @lazyProp
   get code(): string {
     return this.bindingChunk.getCode();
@@ -72,6 +77,16 @@ export class OutputChunkImpl extends PlainObjectLike implements OutputChunk {
     return this.bindingChunk.getDynamicImports();
   }
 
+  @lazyProp
+  get transitiveImports(): string[] {
+    return this.bindingChunk.getTransitiveImports();
+  }
+
+  @lazyProp
+  get importedCss(): string[] {
+    return this.bindingChunk.getImportedCss();
+  }
+
   @lazyProp
   get moduleIds(): string[] {
     return this.bindingChunk.getModuleIds();
@@ -3,7 +3,7 @@ import { lazyProp } from '../decorators/lazy';
 import { transformToRollupOutput } from '../utils/transform-to-rollup-output';
 import type { ExternalMemoryHandle } from './external-memory-handle';
 import { PlainObjectLike } from './plain-object-like';
-import type { RolldownOutput } from './rolldown-output';
+import type { Metafile, RolldownOutput } from './rolldown-output';
 
 export class RolldownOutputImpl
   extends PlainObjectLike
@@ -18,6 +18,12 @@ export class RolldownOutputImpl
     return transformToRollupOutput(this.bindingOutputs).output;
   }
 
+  @lazyProp
+  get metafile(): Metafile | undefined {
+    const metafile = this.bindingOutputs.metafile;
+    return metafile == null ? undefined : JSON.parse(metafile);
+  }
+
   __rolldown_external_memory_handle__(keepDataAlive?: boolean): ExternalMemoryStatus {
     const outputs = this.output;
     const results = outputs.map((item) => item.__rolldown_external_memory_handle__(keepDataAlive));
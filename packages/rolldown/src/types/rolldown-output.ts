@@ -20,6 +20,11 @@ export interface OutputAsset extends ExternalMemoryHandle {
   /** @deprecated Use {@linkcode names} instead. */
   name: string | undefined;
   names: string[];
+  /**
+   * The Subresource Integrity digest of {@linkcode source}, such as `sha384-...`.
+   * Only present when `output.integrity` is set.
+   */
+  integrity: string | null;
 }
 
 /** @category Plugin APIs */
@@ -115,6 +120,16 @@ export interface OutputChunk extends ExternalMemoryHandle {
   imports: string[];
   /** External modules imported dynamically by this chunk. */
   dynamicImports: string[];
+  /**
+   * Chunks only imported by the dependencies of this entry chunk.
+   * Empty when `output.hoistTransitiveImports` is `false`.
+   */
+  transitiveImports: string[];
+  /**
+   * The `.css` files generated from the CSS modules of this chunk, which need to be loaded along
+   * with it.
+   */
+  importedCss: string[];
   /** The id of a module that this chunk corresponds to. */
   facadeModuleId: string | null;
   /** Whether this chunk is a dynamic entry point. */
@@ -125,6 +140,11 @@ export interface OutputChunk extends ExternalMemoryHandle {
   sourcemapFileName: string | null;
   /** The preliminary file name of this chunk with hash placeholders. */
   preliminaryFileName: string;
+  /**
+   * The Subresource Integrity digest of {@linkcode code}, such as `sha384-...`.
+   * Only present when `output.integrity` is set.
+   */
+  integrity: string | null;
 }
 
 /**
@@ -140,4 +160,60 @@ export interface RolldownOutput extends ExternalMemoryHandle {
    * {@linkcode OutputChunk} and/or {@linkcode OutputAsset}s.
    */
   output: [OutputChunk, ...(OutputChunk | OutputAsset)[]];
+  /**
+   * A report of the inputs and outputs of the build, following esbuild's metafile schema.
+   *
+   * Only present when `output.metafile` is enabled.
+   */
+  metafile?: Metafile;
+}
+
+/**
+ * An import as reported in the {@linkcode Metafile}.
+ *
+ * @category Programmatic APIs
+ */
+export interface MetafileImport {
+  path: string;
+  kind:
+    | 'import-statement'
+    | 'dynamic-import'
+    | 'require-call'
+    | 'import-rule'
+    | 'url-token'
+    | 'new-url';
+  external?: boolean;
+  /** The specifier as written in the source, if it differs from `path`. */
+  original?: string;
+}
+
+/**
+ * A report of the inputs and outputs of the build, compatible with esbuild's
+ * [metafile](https://esbuild.github.io/api/#metafile).
+ *
+ * Input paths are module ids relative to `cwd`, output paths are file paths relative to `cwd`.
+ *
+ * @category Programmatic APIs
+ */
+export interface Metafile {
+  inputs: {
+    [path: string]: {
+      bytes: number;
+      imports: MetafileImport[];
+      format?: 'esm' | 'cjs';
+    };
+  };
+  outputs: {
+    [path: string]: {
+      bytes: number;
+      inputs: {
+        [path: string]: {
+          bytesInOutput: number;
+        };
+      };
+      imports: MetafileImport[];
+      exports: string[];
+      entryPoint?: string;
+    };
+  };
 }
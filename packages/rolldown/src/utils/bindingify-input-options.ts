@@ -126,6 +126,8 @@ export function bindingifyInputOptions(
     optimization: inputOptions.optimization,
     context: inputOptions.context,
     tsconfig: inputOptions.resolve?.tsconfigFilename ?? inputOptions.tsconfig,
+    worker: inputOptions.worker,
+    wasm: inputOptions.wasm,
   };
 }
 
@@ -205,6 +207,23 @@ function bindingifyExperimental(
     nativeMagicString: experimental?.nativeMagicString,
     chunkOptimization: experimental?.chunkOptimization,
     lazyBarrel: experimental?.lazyBarrel,
+    precompress: bindingifyPrecompress(experimental?.precompress),
+    asyncModuleWrappers: experimental?.asyncModuleWrappers,
+    persistentCacheDir: experimental?.persistentCacheDir,
+    recordsPath: experimental?.recordsPath,
+  };
+}
+
+function bindingifyPrecompress(
+  precompress: NonNullable<InputOptions['experimental']>['precompress'],
+): BindingExperimentalOptions['precompress'] {
+  if (typeof precompress !== 'object') {
+    return precompress;
+  }
+  return {
+    ...precompress,
+    include: normalizedStringOrRegex(precompress.include),
+    exclude: normalizedStringOrRegex(precompress.exclude),
   };
 }
 
@@ -26,6 +26,8 @@ export function bindingifyOutputOptions(
     sourcemap,
     sourcemapBaseUrl,
     sourcemapDebugIds,
+    sourcemapScopes,
+    sourcemapIndexMap,
     sourcemapFileNames,
     sourcemapExcludeSources,
     sourcemapIgnoreList,
@@ -34,6 +36,8 @@ export function bindingifyOutputOptions(
     assetFileNames,
     entryFileNames,
     chunkFileNames,
+    cssEntryFileNames,
+    cssChunkFileNames,
     banner,
     footer,
     postBanner,
@@ -41,8 +45,10 @@ export function bindingifyOutputOptions(
     intro,
     outro,
     esModule,
+    hoistTransitiveImports,
     globals,
     paths,
+    interop,
     generatedCode,
     file,
     // Already measured at the source; see `createBundlerOptions`.
@@ -58,7 +64,7 @@ export function bindingifyOutputOptions(
     strictExecutionOrder,
   } = outputOptions;
 
-  if (legalComments != null) {
+  if (legalComments === 'none' || legalComments === 'inline') {
     logger.warn('`legalComments` option is deprecated, please use `comments.legal` instead.');
   }
 
@@ -82,6 +88,8 @@ export function bindingifyOutputOptions(
     sourcemap: bindingifySourcemap(sourcemap),
     sourcemapBaseUrl,
     sourcemapDebugIds,
+    sourcemapScopes,
+    sourcemapIndexMap,
     sourcemapFileNames: measureIfFunction(
       timings,
       OUTPUT_OPTIONS_OWNER,
@@ -108,10 +116,13 @@ export function bindingifyOutputOptions(
     intro: bindingifyAddon(intro, 'intro', timings),
     outro: bindingifyAddon(outro, 'outro', timings),
     extend: outputOptions.extend,
+    amd: outputOptions.amd,
     globals: measureIfFunction(timings, OUTPUT_OPTIONS_OWNER, 'globals', globals),
     paths: measureIfFunction(timings, OUTPUT_OPTIONS_OWNER, 'paths', paths),
+    interop: measureIfFunction(timings, OUTPUT_OPTIONS_OWNER, 'interop', interop),
     generatedCode,
     esModule,
+    hoistTransitiveImports: bindingifyHoistTransitiveImports(hoistTransitiveImports),
     name,
     // Already measured at the source; see `createBundlerOptions`.
     assetFileNames: bindingifyAssetFilenames(assetFileNames),
@@ -127,6 +138,18 @@ export function bindingifyOutputOptions(
       'chunkFileNames',
       chunkFileNames,
     ),
+    cssEntryFileNames: measureIfFunction(
+      timings,
+      OUTPUT_OPTIONS_OWNER,
+      'cssEntryFileNames',
+      cssEntryFileNames,
+    ),
+    cssChunkFileNames: measureIfFunction(
+      timings,
+      OUTPUT_OPTIONS_OWNER,
+      'cssChunkFileNames',
+      cssChunkFileNames,
+    ),
     // TODO(sapphi-red): support parallel plugins
     plugins: [],
     minify: outputOptions.minify,
@@ -144,6 +167,8 @@ export function bindingifyOutputOptions(
     topLevelVar,
     minifyInternalExports: outputOptions.minifyInternalExports,
     cleanDir,
+    metafile: outputOptions.metafile,
+    integrity: outputOptions.integrity,
     strictExecutionOrder,
     strict: outputOptions.strict,
   };
@@ -186,6 +211,13 @@ function bindingifyFormat(format: OutputOptions['format']): BindingOutputOptions
     case 'umd': {
       return 'umd';
     }
+    case 'amd': {
+      return 'amd';
+    }
+    case 'system':
+    case 'systemjs': {
+      return 'system';
+    }
     default:
       unimplemented(`output.format: ${format}`);
   }
@@ -227,6 +259,16 @@ function bindingifyAssetFilenames(
   return assetFileNames;
 }
 
+function bindingifyHoistTransitiveImports(
+  hoistTransitiveImports: OutputOptions['hoistTransitiveImports'],
+): BindingOutputOptions['hoistTransitiveImports'] {
+  // The validator only warns about invalid values, so drop them here to fall back to the default.
+  if (typeof hoistTransitiveImports === 'boolean' || hoistTransitiveImports === 'modulepreload') {
+    return hoistTransitiveImports;
+  }
+  return undefined;
+}
+
 function bindingifyComments(comments: OutputOptions['comments']): BindingOutputOptions['comments'] {
   if (comments == null) {
     return undefined;
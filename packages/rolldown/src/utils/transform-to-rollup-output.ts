@@ -56,6 +56,12 @@ function transformToMutableRollupOutputChunk(
     get dynamicImports() {
       return bindingChunk.getDynamicImports();
     },
+    get transitiveImports() {
+      return bindingChunk.getTransitiveImports();
+    },
+    get importedCss() {
+      return bindingChunk.getImportedCss();
+    },
     exports: bindingChunk.getExports(),
     isEntry: bindingChunk.getIsEntry(),
     facadeModuleId: bindingChunk.getFacadeModuleId() || null,
@@ -69,6 +75,7 @@ function transformToMutableRollupOutputChunk(
     },
     sourcemapFileName: bindingChunk.getSourcemapFileName() || null,
     preliminaryFileName: bindingChunk.getPreliminaryFileName(),
+    integrity: bindingChunk.getIntegrity() || null,
   } as OutputChunk;
   const cache: Record<string | symbol, any> = {};
   return new Proxy(chunk, {
@@ -110,6 +117,7 @@ function transformToMutableRollupOutputAsset(
     },
     name: bindingAsset.getName() ?? undefined,
     names: bindingAsset.getNames(),
+    integrity: bindingAsset.getIntegrity() || null,
   } as OutputAsset;
   const cache: Record<string | symbol, any> = {};
   return new Proxy(asset, {
@@ -29,6 +29,7 @@ import type {
   AssetFileNamesFunction,
   ChunkFileNamesFunction,
   GlobalsFunction,
+  InteropFunction,
   ManualChunksFunction,
   OutputOptions,
   PathsFunction,
@@ -123,12 +124,14 @@ const ModuleTypesSchema = v.record(
     v.literal('css'),
     v.literal('dataurl'),
     v.literal('empty'),
+    v.literal('html'),
     v.literal('js'),
     v.literal('json'),
     v.literal('jsx'),
     v.literal('text'),
     v.literal('ts'),
     v.literal('tsx'),
+    v.literal('wasm'),
   ]),
 );
 isTypeTrue<IsSchemaSubType<typeof ModuleTypesSchema, ModuleTypes>>();
@@ -435,6 +438,24 @@ const ChecksOptionsSchema = v.strictObject({
       'Whether to emit warnings when multiple star re-exports provide the same name from different modules',
     ),
   ),
+  emptyGlobImport: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warnings when the path of an `import()` or `require()` built at runtime matches no file',
+    ),
+  ),
+  exceededMaxRequests: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warnings when an entry loads more chunks than `codeSplitting.maxInitialRequests` or `codeSplitting.maxAsyncRequests` allow',
+    ),
+  ),
+  couldNotWriteRecords: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warnings when Rolldown could not write the file of `experimental.recordsPath`',
+    ),
+  ),
 });
 isTypeTrue<IsSchemaSubType<typeof ChecksOptionsSchema, ChecksOptions>>();
 
@@ -560,6 +581,10 @@ const OptimizationOptionsSchema = v.strictObject({
     v.optional(v.boolean()),
     v.description('Use PIFE pattern for module wrappers'),
   ),
+  commonjsToEsm: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Convert statically analyzable CommonJS modules to ESM'),
+  ),
 });
 isTypeTrue<IsSchemaSubType<typeof OptimizationOptionsSchema, OptimizationOptions>>();
 
@@ -676,6 +701,23 @@ const InputOptionsSchema = v.strictObject({
         ]),
       ),
       lazyBarrel: v.optional(v.boolean()),
+      precompress: v.optional(
+        v.union([
+          v.boolean(),
+          v.strictObject({
+            algorithms: v.optional(
+              v.array(v.union([v.literal('gzip'), v.literal('brotli'), v.literal('zstd')])),
+            ),
+            threshold: v.optional(v.number()),
+            level: v.optional(v.number()),
+            include: v.optional(v.union([StringOrRegExpSchema, v.array(StringOrRegExpSchema)])),
+            exclude: v.optional(v.union([StringOrRegExpSchema, v.array(StringOrRegExpSchema)])),
+          }),
+        ]),
+      ),
+      asyncModuleWrappers: v.optional(v.boolean()),
+      persistentCacheDir: v.optional(v.string()),
+      recordsPath: v.optional(v.string()),
     }),
   ),
   transform: v.optional(TransformOptionsSchema),
@@ -709,6 +751,30 @@ const InputOptionsSchema = v.strictObject({
     v.optional(v.union([v.boolean(), v.string()])),
     v.description('Path to the tsconfig.json file.'),
   ),
+  worker: v.pipe(
+    v.optional(
+      v.strictObject({
+        format: v.pipe(
+          v.optional(
+            v.union([v.literal('es'), v.literal('cjs'), v.literal('iife'), v.literal('umd')]),
+          ),
+          v.description('Output format of worker bundles'),
+        ),
+      }),
+    ),
+    v.description('Bundle referenced web workers as separate entries'),
+  ),
+  wasm: v.pipe(
+    v.optional(
+      v.strictObject({
+        embed: v.pipe(
+          v.optional(v.boolean()),
+          v.description('Inline wasm binaries instead of emitting them as assets'),
+        ),
+      }),
+    ),
+    v.description('Options for WebAssembly modules'),
+  ),
 });
 isTypeTrue<IsSchemaSubType<typeof InputOptionsSchema, InputOptions>>();
 
@@ -750,9 +816,17 @@ const ModuleFormatSchema = v.union([
   v.literal('commonjs'),
   v.literal('iife'),
   v.literal('umd'),
+  v.literal('amd'),
+  v.literal('system'),
+  v.literal('systemjs'),
 ]);
 isTypeTrue<IsSchemaSubType<typeof ModuleFormatSchema, ModuleFormat>>();
 
+const AmdOptionsSchema = v.strictObject({
+  id: v.pipe(v.optional(v.string()), v.description('ID for AMD/UMD bundles')),
+  define: v.pipe(v.optional(v.string()), v.description('Function name to use instead of `define`')),
+});
+
 const AddonFunctionSchema = v.pipe(
   vFunction<AddonFunction>(),
   v.args(v.tuple([v.custom<RenderedChunk>(() => true)])),
@@ -801,6 +875,21 @@ const PathsFunctionSchema = v.pipe(
 );
 isTypeTrue<IsSchemaSubType<typeof PathsFunctionSchema, PathsFunction>>();
 
+const InteropTypeSchema = v.union([
+  v.literal('auto'),
+  v.literal('esModule'),
+  v.literal('default'),
+  v.literal('defaultOnly'),
+  v.literal('compat'),
+]);
+
+const InteropFunctionSchema = v.pipe(
+  vFunction<InteropFunction>(),
+  v.args(v.tuple([v.string()])),
+  v.returns(v.nullish(InteropTypeSchema)),
+);
+isTypeTrue<IsSchemaSubType<typeof InteropFunctionSchema, InteropFunction>>();
+
 const ManualChunksFunctionSchema = v.pipe(
   vFunction<ManualChunksFunction>(),
   v.args(v.tuple([v.string(), v.object({})])),
@@ -829,6 +918,8 @@ const AdvancedChunksSchema = v.strictObject({
   minModuleSize: v.optional(v.number()),
   maxModuleSize: v.optional(v.number()),
   minShareCount: v.optional(v.number()),
+  maxInitialRequests: v.optional(v.number()),
+  maxAsyncRequests: v.optional(v.number()),
   groups: v.optional(
     v.array(
       v.strictObject({
@@ -906,6 +997,14 @@ const OutputOptionsSchema = v.strictObject({
     v.description('Name pattern for emitted sourcemaps'),
   ),
   sourcemapDebugIds: v.pipe(v.optional(v.boolean()), v.description('Inject sourcemap debug IDs')),
+  sourcemapScopes: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Include original scopes and function names in sourcemaps'),
+  ),
+  sourcemapIndexMap: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Emit sourcemaps as index maps with a section per module'),
+  ),
   sourcemapExcludeSources: v.pipe(
     v.optional(v.boolean()),
     v.description('Exclude source content from sourcemaps'),
@@ -924,10 +1023,13 @@ const OutputOptionsSchema = v.strictObject({
     v.optional(v.boolean()),
     v.description('Extend global variable defined by name in IIFE / UMD formats'),
   ),
+  amd: v.optional(AmdOptionsSchema),
   esModule: v.optional(v.union([v.boolean(), v.literal('if-default-prop')])),
   assetFileNames: v.optional(AssetFileNamesSchema),
   entryFileNames: v.optional(ChunkFileNamesSchema),
   chunkFileNames: v.optional(ChunkFileNamesSchema),
+  cssEntryFileNames: v.optional(ChunkFileNamesSchema),
+  cssChunkFileNames: v.optional(ChunkFileNamesSchema),
   sanitizeFileName: v.optional(SanitizeFileNameSchema),
   minify: v.pipe(
     v.optional(v.union([v.boolean(), v.literal('dce-only'), MinifyOptionsSchema])),
@@ -942,6 +1044,18 @@ const OutputOptionsSchema = v.strictObject({
     v.optional(v.union([v.record(v.string(), v.string()), PathsFunctionSchema])),
     v.description('Maps external module IDs to paths'),
   ),
+  interop: v.pipe(
+    v.optional(
+      v.union([
+        InteropTypeSchema,
+        v.record(v.string(), InteropTypeSchema),
+        InteropFunctionSchema,
+      ]),
+    ),
+    v.description(
+      'How default and namespace imports of external modules are read in non-ESM formats',
+    ),
+  ),
   generatedCode: v.pipe(
     v.optional(v.partial(GeneratedCodeOptionsSchema)),
     v.description('Generated code options'),
@@ -956,7 +1070,15 @@ const OutputOptionsSchema = v.strictObject({
   codeSplitting: v.optional(v.union([v.boolean(), AdvancedChunksSchema])),
   advancedChunks: v.optional(AdvancedChunksSchema),
   legalComments: v.pipe(
-    v.optional(v.union([v.literal('none'), v.literal('inline')])),
+    v.optional(
+      v.union([
+        v.literal('none'),
+        v.literal('inline'),
+        v.literal('eof'),
+        v.literal('linked'),
+        v.literal('external'),
+      ]),
+    ),
     v.description('Control legal comments in the output'),
   ),
   comments: v.pipe(
@@ -977,7 +1099,10 @@ const OutputOptionsSchema = v.strictObject({
     v.optional(v.boolean()),
     v.description('Disable require polyfill injection'),
   ),
-  hoistTransitiveImports: v.optional(v.literal(false)),
+  hoistTransitiveImports: v.pipe(
+    v.optional(v.union([v.boolean(), v.literal('modulepreload')])),
+    v.description('Reference the chunks entry chunks only depend on transitively'),
+  ),
   preserveModules: v.pipe(v.optional(v.boolean()), v.description('Preserve module structure')),
   preserveModulesRoot: v.pipe(
     v.optional(v.string()),
@@ -993,6 +1118,14 @@ const OutputOptionsSchema = v.strictObject({
     v.optional(v.boolean()),
     v.description('Clean output directory before emitting output'),
   ),
+  metafile: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Attach an esbuild-compatible metafile to the output'),
+  ),
+  integrity: v.pipe(
+    v.optional(v.union([v.literal('sha256'), v.literal('sha384'), v.literal('sha512')])),
+    v.description('Hash function of the Subresource Integrity digest of each output'),
+  ),
   keepNames: v.pipe(
     v.optional(v.boolean()),
     v.description('Keep function and class names after bundling'),
@@ -1026,6 +1159,14 @@ const OutputCliOverrideSchema = v.strictObject({
     v.optional(v.string()),
     v.description('Name pattern for emitted secondary chunks'),
   ),
+  cssEntryFileNames: v.pipe(
+    v.optional(v.string()),
+    v.description('Name pattern for emitted css entry chunks'),
+  ),
+  cssChunkFileNames: v.pipe(
+    v.optional(v.string()),
+    v.description('Name pattern for emitted css secondary chunks'),
+  ),
   sanitizeFileName: v.pipe(v.optional(v.boolean()), v.description('Sanitize file name')),
   banner: v.pipe(v.optional(v.string()), v.description(getAddonDescription('top', 'outside'))),
   footer: v.pipe(v.optional(v.string()), v.description(getAddonDescription('bottom', 'outside'))),
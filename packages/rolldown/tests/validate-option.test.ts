@@ -42,7 +42,7 @@ test('validate output option', async () => {
   );
 });
 
-test('give a warning for hoistTransitiveImports: true', async () => {
+test('give a warning for an invalid hoistTransitiveImports value', async () => {
   const consoleSpy = vi.spyOn(console, 'warn');
   const bundle = await rolldown({
     input: './build-api/main.js',
@@ -50,9 +50,9 @@ test('give a warning for hoistTransitiveImports: true', async () => {
   });
   await bundle.write({
     // @ts-ignore  invalid value
-    hoistTransitiveImports: true,
+    hoistTransitiveImports: 'preload',
   });
   expect(consoleSpy).toHaveBeenCalledWith(
-    `\x1b[33mWarning: Invalid output options (1 issue found)\n- For the "hoistTransitiveImports". Invalid type: Expected false but received true. \x1b[0m`,
+    `\x1b[33mWarning: Invalid output options (1 issue found)\n- For the "hoistTransitiveImports". Invalid type: Expected (boolean | "modulepreload") but received "preload". \x1b[0m`,
   );
 });